@@ -1,78 +1,547 @@
-use std::{collections::HashSet, str::FromStr};
+use std::str::FromStr;
 
-use quote::{quote, ToTokens};
+use proc_macro2::Span;
+use quote::{quote_spanned, ToTokens};
+use syn::{
+    parse::{Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Token,
+};
 
 use crate::Backend;
 
 pub type Result<T = (), E = proc_macro2::TokenStream> = std::result::Result<T, E>;
 
-fn parse_button_declaration(input: &str) -> Button {
-    // TODO just use the Punctuated.
-    let regex = regex::Regex::new(r#"^\s*\"(.*)\"\s*:\s*\"(.*)\"\s*$"#).unwrap();
-    let mut captures = regex.captures_iter(input);
-    let captured: (_, [&str; 2]) = captures.next().expect("Parse the button.").extract();
-    Button {
-        title: captured
-            .1
-            .first()
-            .expect("Parse the button title.")
-            .to_string(),
-        method_name: captured
-            .1
-            .get(1)
-            .expect("Parse the button method name.")
-            .to_string(),
+/// The names of all the options of the `#[imgui_presentation]`
+/// attribute.
+const ATTRIBUTE_NAMES: &[&str] = &[
+    "skip",
+    "readonly",
+    "rename",
+    "format",
+    "prefix",
+    "suffix",
+    "speed",
+    "range",
+    "tooltip",
+    "button",
+    "backend",
+    "main_menu_item",
+    "constructor",
+    "page_size",
+    "widget",
+    "color",
+    "validate",
+    "non_empty",
+    "min_len",
+    "max_len",
+    "regex",
+    "reject_invalid",
+    "visible_if",
+    "enabled_if",
+    "group",
+    "tab",
+    "order",
+    "separator",
+    "same_line",
+    "layout",
+    "flatten",
+    "inline",
+    "bound",
+    "window",
+];
+
+/// Converts the error into a `compile_error!` invocation pointing at
+/// the offending tokens.
+pub fn to_compile_error(error: syn::Error) -> proc_macro2::TokenStream {
+    let message = error.to_string();
+    quote_spanned! { error.span() =>
+        compile_error!(#message);
     }
 }
 
-fn parse_main_menu_item_declaration(input: &str) -> MainMenuItem {
-    // TODO just use the Punctuated.
-    let regex = regex::Regex::new(r#"^\s*\"(.*)\"\s*:\s*\"(.*)\"\s*$"#).unwrap();
-    let mut captures = regex.captures_iter(input);
-    let captured: (_, [&str; 2]) = captures
-        .next()
-        .expect("Parse the main menu item.")
-        .extract();
-    MainMenuItem {
-        title: captured
-            .1
-            .first()
-            .expect("Parse the main menu item title.")
-            .to_string(),
-        method_name: captured
-            .1
-            .get(1)
-            .expect("Parse the main menu item method name.")
-            .to_string(),
-        hot_key: None,
+/// Returns the number of single-character edits needed to turn one
+/// string into another.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+    for (i, a) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, b) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(a != *b);
+            current.push(substitution.min(previous[j + 1] + 1).min(current[j] + 1));
+        }
+        previous = current;
+    }
+    previous[b.len()]
+}
+
+/// Returns the known attribute name closest to the one provided, if
+/// it is close enough to be a typo.
+fn closest_attribute_name(name: &str) -> Option<&'static str> {
+    ATTRIBUTE_NAMES
+        .iter()
+        .map(|known| (edit_distance(name, known), *known))
+        .filter(|(distance, known)| *distance <= known.len() / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, known)| known)
+}
+
+/// Parses the `("title": "method_name")` part of the button-like
+/// attributes.
+fn parse_titled_method(input: ParseStream) -> syn::Result<(String, syn::Ident)> {
+    let content;
+    let _ = syn::parenthesized!(content in input);
+    let title: syn::LitStr = content.parse()?;
+    let _: Token![:] = content.parse()?;
+    let method_name: syn::LitStr = content.parse()?;
+    let method_name = method_name.parse::<syn::Ident>().map_err(|_| {
+        syn::Error::new(
+            method_name.span(),
+            format!("\"{}\" is not a valid method name.", method_name.value()),
+        )
+    })?;
+    if !content.is_empty() {
+        return Err(content.error("Expected `(\"title\": \"method_name\")`."));
     }
+    Ok((title.value(), method_name))
 }
 
 /// A button with title and the method name which should be called on
 /// [`self`] (for the [`ImguiPresentable`] object) once the button is
 /// pushed.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Button {
     /// The title of the button.
     pub title: String,
     /// The method name to call on `self` once the button is clicked.
-    pub method_name: String,
+    pub method_name: syn::Ident,
 }
 
 /// A menu item with title and the method name which should be called on
 /// [`self`] (for the [`ImguiPresentable`] object) once the button is
 /// pushed.
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct MainMenuItem {
     /// The title of the button.
     pub title: String,
     /// The method name to call on `self` once the button is clicked.
-    pub method_name: String,
+    pub method_name: syn::Ident,
     /// The hotkey combination which can also trigger the menu item.
     pub hot_key: Option<String>,
 }
 
-#[derive(Debug, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
+/// How a field presented as a color stores it, which is the sRGB
+/// space within `0.0..=1.0` with the alpha edited by default.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct ColorOptions {
+    /// The alpha channel is kept as it is.
+    pub no_alpha: bool,
+    /// The float channels may exceed `1.0`.
+    pub hdr: bool,
+    /// The float channels are stored in the linear space.
+    pub linear: bool,
+}
+
+impl ColorOptions {
+    /// The names of the options, in the order those are listed.
+    const NAMES: [&'static str; 3] = ["no_alpha", "hdr", "linear"];
+
+    /// Parses the `(no_alpha, hdr, linear)` part of the `color`
+    /// attribute, any of which may be omitted.
+    fn parse_list(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+        if !input.peek(syn::token::Paren) {
+            return Ok(options);
+        }
+
+        let content;
+        let _ = syn::parenthesized!(content in input);
+        for name in Punctuated::<syn::Ident, Token![,]>::parse_terminated(&content)? {
+            let option = match name.to_string().as_str() {
+                "no_alpha" => &mut options.no_alpha,
+                "hdr" => &mut options.hdr,
+                "linear" => &mut options.linear,
+                unknown => {
+                    return Err(syn::Error::new(
+                        name.span(),
+                        format!(
+                            "{unknown} is an unknown color option, expected one of: {}.",
+                            Self::NAMES.join(", ")
+                        ),
+                    ))
+                }
+            };
+            if std::mem::replace(option, true) {
+                return Err(syn::Error::new(
+                    name.span(),
+                    format!("The `{name}` color option is duplicated."),
+                ));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// The point of the screen a window is anchored to.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Anchor {
+    TopLeft,
+    Top,
+    TopRight,
+    Left,
+    Center,
+    Right,
+    BottomLeft,
+    Bottom,
+    BottomRight,
+}
+
+impl Anchor {
+    /// The names of the anchors, in the order those are listed.
+    const NAMES: [&'static str; 9] = [
+        "top_left",
+        "top",
+        "top_right",
+        "left",
+        "center",
+        "right",
+        "bottom_left",
+        "bottom",
+        "bottom_right",
+    ];
+
+    /// Returns the position of the anchor relative to the size of the
+    /// screen, which is also the point of the window put at it.
+    pub fn fractions(self) -> [f32; 2] {
+        match self {
+            Self::TopLeft => [0.0, 0.0],
+            Self::Top => [0.5, 0.0],
+            Self::TopRight => [1.0, 0.0],
+            Self::Left => [0.0, 0.5],
+            Self::Center => [0.5, 0.5],
+            Self::Right => [1.0, 0.5],
+            Self::BottomLeft => [0.0, 1.0],
+            Self::Bottom => [0.5, 1.0],
+            Self::BottomRight => [1.0, 1.0],
+        }
+    }
+}
+
+impl FromStr for Anchor {
+    type Err = String;
+
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        Ok(match s {
+            "top_left" => Self::TopLeft,
+            "top" => Self::Top,
+            "top_right" => Self::TopRight,
+            "left" => Self::Left,
+            "center" => Self::Center,
+            "right" => Self::Right,
+            "bottom_left" => Self::BottomLeft,
+            "bottom" => Self::Bottom,
+            "bottom_right" => Self::BottomRight,
+            _ => {
+                return Err(format!(
+                    "{s} is an unknown anchor, expected one of: {}.",
+                    Self::NAMES.join(", ")
+                ))
+            }
+        })
+    }
+}
+
+/// How the window of a struct or an enum is shown, the same way for
+/// every backend.
+#[derive(Debug, Default, Clone, Copy, PartialEq)]
+pub struct WindowOptions {
+    /// Whether the window may be resized, which it may by default.
+    pub resizable: Option<bool>,
+    /// The initial size of the window.
+    pub size: Option<[f32; 2]>,
+    /// The initial position of the window, or its offset from the
+    /// anchor if it has one.
+    pub position: Option<[f32; 2]>,
+    /// The point of the screen the window is kept at.
+    pub anchor: Option<Anchor>,
+}
+
+impl WindowOptions {
+    /// The names of the options, in the order those are listed.
+    const NAMES: [&'static str; 4] = ["resizable", "size", "position", "anchor"];
+
+    /// Parses a pair of numbers, like `[400, 300]` or `[-10.0, 0.0]`.
+    fn parse_pair(expr: &syn::Expr) -> syn::Result<[f32; 2]> {
+        let error = || syn::Error::new(expr.span(), "Expected two numbers, like `[400, 300]`.");
+        let number = |expr: &syn::Expr| -> syn::Result<f32> {
+            let (negative, expr) = match expr {
+                syn::Expr::Unary(syn::ExprUnary {
+                    op: syn::UnOp::Neg(_),
+                    expr,
+                    ..
+                }) => (true, expr.as_ref()),
+                expr => (false, expr),
+            };
+            let value = match expr {
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Int(int),
+                    ..
+                }) => int.base10_parse::<f32>()?,
+                syn::Expr::Lit(syn::ExprLit {
+                    lit: syn::Lit::Float(float),
+                    ..
+                }) => float.base10_parse::<f32>()?,
+                _ => return Err(error()),
+            };
+            Ok(if negative { -value } else { value })
+        };
+
+        match expr {
+            syn::Expr::Array(array) if array.elems.len() == 2 => {
+                Ok([number(&array.elems[0])?, number(&array.elems[1])?])
+            }
+            _ => Err(error()),
+        }
+    }
+
+    /// Parses the `(resizable = false, size = [400, 300])` part of the
+    /// `window` attribute, any of which may be omitted.
+    fn parse_list(input: ParseStream) -> syn::Result<Self> {
+        let mut options = Self::default();
+        let content;
+        let _ = syn::parenthesized!(content in input);
+        for option in Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(&content)? {
+            let name = option.path.to_token_stream().to_string();
+            let duplicated = match name.as_str() {
+                "resizable" => {
+                    let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Bool(value),
+                        ..
+                    }) = &option.value
+                    else {
+                        return Err(syn::Error::new(
+                            option.value.span(),
+                            "Expected `true` or `false`.",
+                        ));
+                    };
+                    options.resizable.replace(value.value).is_some()
+                }
+                "size" => options
+                    .size
+                    .replace(Self::parse_pair(&option.value)?)
+                    .is_some(),
+                "position" => options
+                    .position
+                    .replace(Self::parse_pair(&option.value)?)
+                    .is_some(),
+                "anchor" => {
+                    let syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Str(value),
+                        ..
+                    }) = &option.value
+                    else {
+                        return Err(syn::Error::new(
+                            option.value.span(),
+                            "Expected a string literal.",
+                        ));
+                    };
+                    let anchor = Anchor::from_str(&value.value())
+                        .map_err(|e| syn::Error::new(value.span(), e))?;
+                    options.anchor.replace(anchor).is_some()
+                }
+                unknown => {
+                    return Err(syn::Error::new(
+                        option.path.span(),
+                        format!(
+                            "{unknown} is an unknown window option, expected one of: {}.",
+                            Self::NAMES.join(", ")
+                        ),
+                    ))
+                }
+            };
+            if duplicated {
+                return Err(syn::Error::new(
+                    option.path.span(),
+                    format!("The `{name}` window option is duplicated."),
+                ));
+            }
+        }
+        Ok(options)
+    }
+}
+
+/// The bounds of the generated implementations, replacing the ones
+/// inferred from the types of the fields.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Bounds {
+    /// The bounds of all the implementations.
+    pub all: Option<Vec<syn::WherePredicate>>,
+    /// The bounds of the presentations for a single backend.
+    pub backends: Vec<(Backend, Vec<syn::WherePredicate>)>,
+}
+
+impl Bounds {
+    /// Returns the bounds of the presentation for the backend, or of the
+    /// implementations not depending on the backend if it is `None`.
+    pub fn get(&self, backend: Option<Backend>) -> Option<&[syn::WherePredicate]> {
+        backend
+            .and_then(|backend| {
+                self.backends
+                    .iter()
+                    .find(|(b, _)| *b == backend)
+                    .map(|(_, predicates)| predicates.as_slice())
+            })
+            .or(self.all.as_deref())
+    }
+
+    /// Parses the predicates, like `"T: Clone, U: Default"`.
+    fn parse_predicates(value: &syn::LitStr) -> syn::Result<Vec<syn::WherePredicate>> {
+        value
+            .parse_with(Punctuated::<syn::WherePredicate, Token![,]>::parse_terminated)
+            .map(|predicates| predicates.into_iter().collect())
+            .map_err(|_| {
+                syn::Error::new(
+                    value.span(),
+                    format!("\"{}\" is not a valid list of bounds.", value.value()),
+                )
+            })
+    }
+
+    /// Parses the `= "..."` part of the `bound` attribute, or the
+    /// `(imgui = "...", egui = "...")` one with the bounds of the
+    /// backends.
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let mut bounds = Self::default();
+        if input.peek(Token![=]) {
+            let _: Token![=] = input.parse()?;
+            bounds.all = Some(Self::parse_predicates(&input.parse()?)?);
+            return Ok(bounds);
+        }
+
+        let content;
+        let _ = syn::parenthesized!(content in input);
+        let entries = Punctuated::<syn::MetaNameValue, Token![,]>::parse_terminated(&content)?;
+        for entry in entries {
+            let name = entry.path.to_token_stream().to_string();
+            let backend =
+                Backend::from_str(&name).map_err(|e| syn::Error::new(entry.path.span(), e))?;
+            let syn::Expr::Lit(syn::ExprLit {
+                lit: syn::Lit::Str(value),
+                ..
+            }) = &entry.value
+            else {
+                return Err(syn::Error::new(
+                    entry.value.span(),
+                    "Expected a string literal.",
+                ));
+            };
+            if bounds.backends.iter().any(|(b, _)| *b == backend) {
+                return Err(syn::Error::new(
+                    entry.path.span(),
+                    format!("The bounds of the {name} backend are duplicated."),
+                ));
+            }
+            bounds
+                .backends
+                .push((backend, Self::parse_predicates(value)?));
+        }
+        Ok(bounds)
+    }
+}
+
+/// The widget a field is edited with, instead of the one its type is
+/// presented with by default.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Widget {
+    /// Drags the scalar value, which is the default.
+    Drag,
+    /// A slider within the `range`.
+    Slider,
+    /// A slider within the `range`, changing the value logarithmically.
+    LogSlider,
+    /// A text input of the scalar value.
+    Input,
+    /// An angle stored in radians and shown in degrees.
+    Angle,
+    /// A fraction shown in percents.
+    Percent,
+    /// A color picker of an RGB or an RGBA array of floats.
+    Color,
+    /// A read-only bar showing the fraction or the part of the `range`.
+    Progress,
+}
+
+impl Widget {
+    const ALL: [Self; 8] = [
+        Self::Drag,
+        Self::Slider,
+        Self::LogSlider,
+        Self::Input,
+        Self::Angle,
+        Self::Percent,
+        Self::Color,
+        Self::Progress,
+    ];
+
+    /// Returns the name the widget is specified with.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Drag => "drag",
+            Self::Slider => "slider",
+            Self::LogSlider => "log_slider",
+            Self::Input => "input",
+            Self::Angle => "angle",
+            Self::Percent => "percent",
+            Self::Color => "color",
+            Self::Progress => "progress",
+        }
+    }
+}
+
+/// How the fields of a struct are laid out.
+#[derive(Debug, Default, Clone, Copy, Eq, PartialEq, Hash)]
+pub enum Layout {
+    /// Every field is a collapsible node, which is the default.
+    #[default]
+    Tree,
+    /// The names and the values of the fields are the two columns of
+    /// a table.
+    Grid,
+}
+
+impl FromStr for Layout {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "tree" => Ok(Self::Tree),
+            "grid" => Ok(Self::Grid),
+            _ => Err(format!(
+                "{s} is an unknown layout, expected one of: tree, grid."
+            )),
+        }
+    }
+}
+
+impl FromStr for Widget {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|widget| widget.name() == s)
+            .ok_or_else(|| {
+                format!(
+                    "{s} is an unknown widget, expected one of: {}.",
+                    Self::ALL.map(Self::name).join(", ")
+                )
+            })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
 pub enum Attribute {
     /// Skips generating the code for the marked field.
     Skip,
@@ -83,11 +552,14 @@ pub enum Attribute {
     Rename(String),
     /// Set the display format for the field (printf syntax).
     Format(String),
-    /// Sets the drag speed (only for scalars). The range of values is
-    /// of type `f32`.
-    DragSpeed(String),
+    /// The text shown before the value (only for scalars).
+    Prefix(String),
+    /// The text shown after the value (only for scalars).
+    Suffix(String),
+    /// Sets the drag speed (only for scalars).
+    DragSpeed(f32),
     /// The range of the values the scalar can have.
-    DragRange(String),
+    DragRange(syn::ExprRange),
     /// Allows to have a tooltip text that overrides the documentation.
     Tooltip(String),
     /// The documentation string.
@@ -101,106 +573,364 @@ pub enum Attribute {
     Backend(Backend),
     /// A main menu item.
     MainMenuItem(MainMenuItem),
+    /// The name of an associated function of the enum creating the
+    /// value of the marked variant once it is selected in the GUI.
+    Constructor(String),
+    /// The number of the rows shown at once for a collection, which is
+    /// then split into pages.
+    PageSize(usize),
+    /// The widget the field is edited with.
+    Widget(Widget),
+    /// Presents the field as a color stored as the options tell.
+    Color(ColorOptions),
+    /// The path of a function validating the field, or the whole
+    /// struct or enum, returning `Result<(), String>`.
+    Validate(syn::Path),
+    /// The string or the collection must not be empty.
+    NonEmpty,
+    /// The minimal length of the string or the collection.
+    MinLen(usize),
+    /// The maximal length of the string or the collection.
+    MaxLen(usize),
+    /// The pattern the string must match.
+    Regex(String),
+    /// Undoes the edits making the valid value invalid.
+    RejectInvalid,
+    /// The condition on `self` the field is only shown if.
+    VisibleIf(syn::Expr),
+    /// The condition on `self` the field may only be edited if.
+    EnabledIf(syn::Expr),
+    /// The collapsible section the field is shown in.
+    Group(String),
+    /// The tab the field is shown in.
+    Tab(String),
+    /// The position of the field among the others, which is the order
+    /// of its declaration by default.
+    Order(i64),
+    /// Separates the field from the previous one with a line.
+    Separator,
+    /// Shows the field on the same line as the previous one.
+    SameLine,
+    /// How the fields of the struct are laid out.
+    Layout(Layout),
+    /// Shows the fields of the field's value among the fields of the
+    /// owner, instead of in a node of the field.
+    Flatten,
+    /// Shows the field and the fields of its value on a single row,
+    /// instead of in a node.
+    Inline,
+    /// The bounds of the generated implementations, replacing the
+    /// inferred ones.
+    Bound(Bounds),
+    /// How the window of the struct or the enum is shown.
+    Window(WindowOptions),
 }
 
-impl FromStr for Attribute {
-    type Err = String;
+impl Attribute {
+    /// Returns the name the attribute is specified with.
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Skip => "skip",
+            Self::ReadOnly => "readonly",
+            Self::Rename(_) => "rename",
+            Self::Format(_) => "format",
+            Self::Prefix(_) => "prefix",
+            Self::Suffix(_) => "suffix",
+            Self::DragSpeed(_) => "speed",
+            Self::DragRange(_) => "range",
+            Self::Tooltip(_) => "tooltip",
+            Self::Documentation(_) => "doc",
+            Self::Button(_) => "button",
+            Self::Backend(_) => "backend",
+            Self::MainMenuItem(_) => "main_menu_item",
+            Self::Constructor(_) => "constructor",
+            Self::PageSize(_) => "page_size",
+            Self::Widget(_) => "widget",
+            Self::Color(_) => "color",
+            Self::Validate(_) => "validate",
+            Self::NonEmpty => "non_empty",
+            Self::MinLen(_) => "min_len",
+            Self::MaxLen(_) => "max_len",
+            Self::Regex(_) => "regex",
+            Self::RejectInvalid => "reject_invalid",
+            Self::VisibleIf(_) => "visible_if",
+            Self::EnabledIf(_) => "enabled_if",
+            Self::Group(_) => "group",
+            Self::Tab(_) => "tab",
+            Self::Order(_) => "order",
+            Self::Separator => "separator",
+            Self::SameLine => "same_line",
+            Self::Layout(_) => "layout",
+            Self::Flatten => "flatten",
+            Self::Inline => "inline",
+            Self::Bound(_) => "bound",
+            Self::Window(_) => "window",
+        }
+    }
 
-    fn from_str(input: &str) -> Result<Self, Self::Err> {
-        // If an attribute has value.
-        if input.contains('=') {
-            let (attribute, value) = {
-                let mut split = input.split('=');
-                let attribute = split.next().unwrap().trim().to_lowercase();
-                let value = split
-                    .next()
-                    .unwrap_or_else(|| panic!("A value for {attribute} attribute is required."))
-                    .trim()
-                    .replace('"', "")
-                    .to_owned();
-                (attribute, value)
-            };
-            Ok(match attribute.as_ref() {
-                "rename" => Self::Rename(value),
-                "format" => Self::Format(value),
-                "speed" => Self::DragSpeed(value),
-                "range" => Self::DragRange(value),
-                "tooltip" => Self::Tooltip(value),
-                "backend" => Self::Backend(Backend::from_str(&value)?),
-                a => return Err(a.to_owned()),
-            })
-        } else if input.contains('(') {
-            let (attribute, value) = {
-                let mut split = input.split('(');
-                let attribute = split.next().unwrap().trim().to_lowercase();
-                let value = split
-                    .next()
-                    .unwrap_or_else(|| panic!("A value for {attribute} attribute is required."))
-                    .trim()
-                    .replace([')'], "")
-                    .to_owned();
-                (attribute, value)
-            };
-            Ok(match attribute.as_ref() {
-                "button" => {
-                    let button = parse_button_declaration(&value);
-                    Self::Button(button)
-                }
-                "main_menu_item" => {
-                    let main_menu_item = parse_main_menu_item_declaration(&value);
-                    Self::MainMenuItem(main_menu_item)
-                }
-                a => return Err(a.to_owned()),
-            })
-        } else {
-            // Attributes without a value.
-            Ok(match input.trim().to_lowercase().as_ref() {
-                "skip" => Self::Skip,
-                "readonly" => Self::ReadOnly,
-                a => return Err(a.to_owned()),
-            })
+    /// Returns `true` if the attribute conflicts with the other one,
+    /// already specified for the same item.
+    fn conflicts_with(&self, other: &Self) -> bool {
+        match (self, other) {
+            // Every line of the documentation is a separate attribute.
+            (Self::Documentation(_), Self::Documentation(_)) => false,
+            (Self::Button(a), Self::Button(b)) => a.title == b.title,
+            (Self::MainMenuItem(a), Self::MainMenuItem(b)) => a.title == b.title,
+            (Self::Backend(a), Self::Backend(b)) => a == b,
+            (Self::Validate(a), Self::Validate(b)) => a == b,
+            (Self::Flatten | Self::Inline, Self::Flatten | Self::Inline) => true,
+            (a, b) => a.name() == b.name(),
         }
     }
 }
 
-#[derive(Debug, Default, Clone, Eq, PartialEq)]
-pub struct Attributes {
-    attributes: HashSet<Attribute>,
+/// Parses a single option of the `#[imgui_presentation]` attribute,
+/// like `rename = "name"`.
+impl Parse for Attribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let name: syn::Ident = input.parse()?;
+        let name_string = name.to_string();
+
+        // Checks the name is known before demanding a value for it.
+        if !ATTRIBUTE_NAMES.contains(&name_string.as_str()) {
+            let message = match closest_attribute_name(&name_string) {
+                Some(closest) => {
+                    format!("Unknown attribute `{name_string}`, did you mean `{closest}`?")
+                }
+                None => format!(
+                    "Unknown attribute `{name_string}`, expected one of: {}.",
+                    ATTRIBUTE_NAMES
+                        .iter()
+                        .map(|n| format!("`{n}`"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+            };
+            return Err(syn::Error::new(name.span(), message));
+        }
+
+        let string_value = |input: ParseStream| -> syn::Result<String> {
+            let _: Token![=] = input.parse()?;
+            let value: syn::LitStr = input.parse()?;
+            Ok(value.value())
+        };
+
+        Ok(match name_string.as_str() {
+            "skip" => Self::Skip,
+            "readonly" => Self::ReadOnly,
+            "non_empty" => Self::NonEmpty,
+            "reject_invalid" => Self::RejectInvalid,
+            "separator" => Self::Separator,
+            "same_line" => Self::SameLine,
+            "flatten" => Self::Flatten,
+            "inline" => Self::Inline,
+            "group" => Self::Group(string_value(input)?),
+            "tab" => Self::Tab(string_value(input)?),
+            "order" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::Expr = input.parse()?;
+                let order = match &value {
+                    syn::Expr::Lit(syn::ExprLit {
+                        lit: syn::Lit::Int(int),
+                        ..
+                    }) => int.base10_parse::<i64>(),
+                    syn::Expr::Unary(syn::ExprUnary {
+                        op: syn::UnOp::Neg(_),
+                        expr,
+                        ..
+                    }) => match expr.as_ref() {
+                        syn::Expr::Lit(syn::ExprLit {
+                            lit: syn::Lit::Int(int),
+                            ..
+                        }) => int.base10_parse::<i64>().map(|order| -order),
+                        _ => Err(syn::Error::new(value.span(), "Expected an integer.")),
+                    },
+                    _ => Err(syn::Error::new(value.span(), "Expected an integer.")),
+                }?;
+                Self::Order(order)
+            }
+            "layout" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                Self::Layout(
+                    Layout::from_str(&value.value())
+                        .map_err(|e| syn::Error::new(value.span(), e))?,
+                )
+            }
+            "rename" => Self::Rename(string_value(input)?),
+            "format" => Self::Format(string_value(input)?),
+            "prefix" => Self::Prefix(string_value(input)?),
+            "suffix" => Self::Suffix(string_value(input)?),
+            "tooltip" => Self::Tooltip(string_value(input)?),
+            "constructor" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                let _: syn::Ident = value.parse().map_err(|_| {
+                    syn::Error::new(
+                        value.span(),
+                        format!("\"{}\" is not a valid function name.", value.value()),
+                    )
+                })?;
+                Self::Constructor(value.value())
+            }
+            "backend" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                Self::Backend(
+                    Backend::from_str(&value.value())
+                        .map_err(|e| syn::Error::new(value.span(), e))?,
+                )
+            }
+            "widget" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                Self::Widget(
+                    Widget::from_str(&value.value())
+                        .map_err(|e| syn::Error::new(value.span(), e))?,
+                )
+            }
+            "color" => Self::Color(ColorOptions::parse_list(input)?),
+            "validate" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                Self::Validate(value.parse().map_err(|_| {
+                    syn::Error::new(
+                        value.span(),
+                        format!("\"{}\" is not a valid function path.", value.value()),
+                    )
+                })?)
+            }
+            "bound" => Self::Bound(Bounds::parse(input)?),
+            "window" => Self::Window(WindowOptions::parse_list(input)?),
+            "visible_if" | "enabled_if" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                let condition: syn::Expr = value.parse().map_err(|_| {
+                    syn::Error::new(
+                        value.span(),
+                        format!("\"{}\" is not a valid expression.", value.value()),
+                    )
+                })?;
+                if name_string == "visible_if" {
+                    Self::VisibleIf(condition)
+                } else {
+                    Self::EnabledIf(condition)
+                }
+            }
+            "min_len" | "max_len" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitInt = input.parse()?;
+                let length = value.base10_parse::<usize>()?;
+                if name_string == "min_len" {
+                    Self::MinLen(length)
+                } else {
+                    Self::MaxLen(length)
+                }
+            }
+            "regex" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitStr = input.parse()?;
+                check_regex(&value)?;
+                Self::Regex(value.value())
+            }
+            "speed" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::Lit = input.parse()?;
+                let speed = match &value {
+                    syn::Lit::Float(f) => f.base10_parse::<f32>(),
+                    syn::Lit::Int(i) => i.base10_parse::<f32>(),
+                    _ => Err(syn::Error::new(value.span(), "Expected a number.")),
+                }?;
+                Self::DragSpeed(speed)
+            }
+            "page_size" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::LitInt = input.parse()?;
+                match value.base10_parse::<usize>()? {
+                    0 => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "The page size must be positive.",
+                        ))
+                    }
+                    page_size => Self::PageSize(page_size),
+                }
+            }
+            "range" => {
+                let _: Token![=] = input.parse()?;
+                let value: syn::Expr = input.parse()?;
+                match value {
+                    syn::Expr::Range(range) => Self::DragRange(range),
+                    value => {
+                        return Err(syn::Error::new(
+                            value.span(),
+                            "Expected a range, like `0.0f32..1.0f32`.",
+                        ))
+                    }
+                }
+            }
+            "button" => {
+                let (title, method_name) = parse_titled_method(input)?;
+                Self::Button(Button { title, method_name })
+            }
+            "main_menu_item" => {
+                let (title, method_name) = parse_titled_method(input)?;
+                Self::MainMenuItem(MainMenuItem {
+                    title,
+                    method_name,
+                    hot_key: None,
+                })
+            }
+            _ => unreachable!("All the known attribute names are handled."),
+        })
+    }
 }
 
-impl From<HashSet<Attribute>> for Attributes {
-    fn from(attributes: HashSet<Attribute>) -> Self {
-        Self { attributes }
+/// Checks the pattern of the `regex` attribute compiles, which is only
+/// possible with the `regex` feature.
+fn check_regex(pattern: &syn::LitStr) -> syn::Result<()> {
+    #[cfg(feature = "regex")]
+    {
+        regex::Regex::new(&pattern.value())
+            .map(|_| ())
+            .map_err(|e| syn::Error::new(pattern.span(), e.to_string()))
+    }
+    #[cfg(not(feature = "regex"))]
+    {
+        Err(syn::Error::new(
+            pattern.span(),
+            "The `regex` attribute requires the `regex` feature.",
+        ))
     }
 }
 
-impl std::ops::Deref for Attributes {
-    type Target = HashSet<Attribute>;
+/// An option of the `#[imgui_presentation]` attribute along with the
+/// span of its name.
+struct SpannedAttribute(Attribute, Span);
 
-    fn deref(&self) -> &Self::Target {
-        &self.attributes
+impl Parse for SpannedAttribute {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let span = input.span();
+        Ok(Self(input.parse()?, span))
     }
 }
 
-impl std::ops::DerefMut for Attributes {
-    fn deref_mut(&mut self) -> &mut Self::Target {
-        &mut self.attributes
-    }
+/// The attributes of an item (a struct, an enum, a variant or a field)
+/// in the order those were specified.
+#[derive(Debug, Default, Clone)]
+pub struct Attributes {
+    attributes: Vec<(Attribute, Span)>,
 }
 
 /// Allows an easier access to the documentation part of the attribute.
 pub trait AttributeHasDocumentation {
+    #[allow(dead_code)]
     fn has_documentation(&self) -> bool;
     fn get_documentation_name_value(&self) -> Option<&syn::MetaNameValue>;
     fn get_documentation(&self) -> Option<&syn::Expr>;
     fn get_documentation_string(&self) -> Option<String>;
 }
 
-/// Allows an easier access the meta list.
-pub trait AttributeHasMetaList {
-    fn get_meta_list(&self) -> Option<&syn::MetaList>;
-}
-
 impl AttributeHasDocumentation for syn::Attribute {
     fn has_documentation(&self) -> bool {
         self.get_documentation_name_value().is_some()
@@ -235,104 +965,94 @@ impl AttributeHasDocumentation for syn::Attribute {
     }
 }
 
-impl AttributeHasMetaList for syn::Attribute {
-    fn get_meta_list(&self) -> Option<&syn::MetaList> {
-        match &self.meta {
-            syn::Meta::List(list) => Some(list),
-            _ => None,
-        }
-    }
-}
-
 impl Attributes {
-    pub fn new(attributes: &[String]) -> Result<Self> {
-        let mut unknown_attributes = Vec::new();
-        let attributes = attributes
+    /// Adds the attribute, reporting it if it has already been
+    /// specified or conflicts with another one.
+    fn push(&mut self, attribute: Attribute, span: Span) -> syn::Result<()> {
+        if let Some((existing, _)) = self
+            .attributes
             .iter()
-            .filter_map(|a| {
-                if let Ok(a) = Attribute::from_str(a) {
-                    Some(a)
-                } else {
-                    unknown_attributes.push(a);
-                    None
+            .find(|(existing, _)| attribute.conflicts_with(existing))
+        {
+            let message = match (&attribute, existing) {
+                (Attribute::Button(b), _) => {
+                    format!("A button titled \"{}\" is already specified.", b.title)
                 }
-            })
-            .collect();
-
-        if unknown_attributes.is_empty() {
-            Ok(Self { attributes })
-        } else {
-            let attrs = unknown_attributes
-                .into_iter()
-                .map(|s| format!("#[imgui_presentation]: unknown attributes: {s}"))
-                .collect::<Vec<String>>()
-                .join("\n");
-
-            Err(quote! {
-                compile_error!(#attrs);
-            })
+                (Attribute::MainMenuItem(m), _) => {
+                    format!(
+                        "A main menu item titled \"{}\" is already specified.",
+                        m.title
+                    )
+                }
+                (a, b) if a == b => format!("The `{}` attribute is duplicated.", a.name()),
+                (Attribute::Flatten | Attribute::Inline, _) => {
+                    "The `flatten` and `inline` attributes can't be combined.".to_owned()
+                }
+                (a, _) => format!(
+                    "The `{}` attribute is already specified with a different value.",
+                    a.name()
+                ),
+            };
+            return Err(syn::Error::new(span, message));
         }
-    }
-
-    pub fn parse(attribute: &syn::Attribute) -> Result<Self> {
-        // println!("Parsing attribute: {}", attribute.meta.to_token_stream());
 
-        let docs = attribute.get_documentation_string().map(|value| {
-            let mut attributes = HashSet::new();
-            attributes.insert(Attribute::Documentation(value.to_owned()));
-            Self { attributes }
-        });
+        self.attributes.push((attribute, span));
+        Ok(())
+    }
 
-        if let Some(docs) = docs {
-            return Ok(docs);
+    fn parse_into(&mut self, attribute: &syn::Attribute) -> syn::Result<()> {
+        if let Some(docs) = attribute.get_documentation_string() {
+            return self.push(Attribute::Documentation(docs), attribute.span());
         }
 
-        let list = attribute
-            .get_meta_list()
-            .ok_or_else(|| quote! { compile_error!("Couldn't extract the meta list.") })?;
-
-        let our_attribute = list.path.to_token_stream().to_string();
-        if our_attribute != "imgui_presentation" {
-            return Ok(Self::default());
+        if !attribute.path().is_ident("imgui_presentation") {
+            return Ok(());
         }
 
-        let strings: Vec<String> = list
-            .tokens
-            .to_token_stream()
-            .to_string()
-            .split(',')
-            .map(|s| s.to_owned())
-            .collect();
-
-        Self::new(&strings)
+        attribute
+            .meta
+            .require_list()?
+            .parse_args_with(Punctuated::<SpannedAttribute, Token![,]>::parse_terminated)?
+            .into_iter()
+            .try_for_each(|SpannedAttribute(attribute, span)| self.push(attribute, span))
     }
 
     pub fn parse_many(attributes: &[syn::Attribute]) -> Result<Self> {
-        let attributes: HashSet<Attribute> = attributes
+        let mut parsed = Self::default();
+        attributes
             .iter()
-            .map(Self::parse)
-            // .flat_map(|a| a.unwrap().attributes)
-            .collect::<Result<Vec<Attributes>>>()?
-            .into_iter()
-            .flat_map(|a| a.attributes)
-            .collect();
-        Ok(attributes.into())
+            .try_for_each(|a| parsed.parse_into(a))
+            .map_err(to_compile_error)?;
+        Ok(parsed)
     }
 
     pub fn parse_from_field(field: &syn::Field) -> Result<Self> {
         Self::parse_many(&field.attrs)
     }
 
+    fn iter(&self) -> impl Iterator<Item = &Attribute> {
+        self.attributes.iter().map(|(a, _)| a)
+    }
+
+    /// Returns the span of the first attribute with the name provided,
+    /// to report the errors about it.
+    pub fn span_of(&self, name: &str) -> Option<Span> {
+        self.attributes
+            .iter()
+            .find(|(a, _)| a.name() == name)
+            .map(|(_, span)| *span)
+    }
+
     pub fn has_skip(&self) -> bool {
-        self.attributes.contains(&Attribute::Skip)
+        self.iter().any(|a| *a == Attribute::Skip)
     }
 
     pub fn has_readonly(&self) -> bool {
-        self.attributes.contains(&Attribute::ReadOnly)
+        self.iter().any(|a| *a == Attribute::ReadOnly)
     }
 
     pub fn get_rename(&self) -> Option<&str> {
-        self.attributes.iter().find_map(|a| {
+        self.iter().find_map(|a| {
             if let Attribute::Rename(s) = a {
                 Some(s.as_ref())
             } else {
@@ -342,7 +1062,7 @@ impl Attributes {
     }
 
     pub fn get_format(&self) -> Option<&str> {
-        self.attributes.iter().find_map(|a| {
+        self.iter().find_map(|a| {
             if let Attribute::Format(s) = a {
                 Some(s.as_ref())
             } else {
@@ -351,25 +1071,42 @@ impl Attributes {
         })
     }
 
+    pub fn get_prefix(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Prefix(s) = a {
+                Some(s.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_suffix(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Suffix(s) = a {
+                Some(s.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn get_speed(&self) -> Option<f32> {
-        self.attributes.iter().find_map(|a| {
-            if let Attribute::DragSpeed(s) = a {
-                f32::from_str(s).ok()
+        self.iter().find_map(|a| {
+            if let Attribute::DragSpeed(speed) = a {
+                Some(*speed)
             } else {
                 None
             }
         })
     }
 
-    /// Returns two strings: the start and the end, the rest follows the
-    /// known Rust range syntax.
-    pub fn get_range(&self) -> Option<(Option<String>, Option<String>)> {
-        self.attributes.iter().find_map(|a| {
-            if let Attribute::DragRange(s) = a {
-                let range: syn::ExprRange = syn::parse2(s.to_token_stream()).ok()?;
-                let start = range.start.map(|s| s.to_token_stream().to_string());
-                let end = range.end.map(|s| s.to_token_stream().to_string());
-                Some((start, end))
+    /// Returns the start and the end of the range, either of which may
+    /// be omitted, following the Rust range syntax.
+    pub fn get_range(&self) -> Option<(Option<&syn::Expr>, Option<&syn::Expr>)> {
+        self.iter().find_map(|a| {
+            if let Attribute::DragRange(range) = a {
+                Some((range.start.as_deref(), range.end.as_deref()))
             } else {
                 None
             }
@@ -379,7 +1116,6 @@ impl Attributes {
     pub fn get_documentation(&self) -> Option<String> {
         // There might be many, due to how Rust creates those.
         let strings = self
-            .attributes
             .iter()
             .filter_map(|a| {
                 if let Attribute::Documentation(s) = a {
@@ -393,12 +1129,12 @@ impl Attributes {
         if strings.is_empty() {
             None
         } else {
-            Some(strings.join(""))
+            Some(strings.join("\n"))
         }
     }
 
     pub fn get_tooltip(&self) -> Option<&str> {
-        self.attributes.iter().find_map(|a| {
+        self.iter().find_map(|a| {
             if let Attribute::Tooltip(s) = a {
                 Some(s.as_ref())
             } else {
@@ -407,9 +1143,215 @@ impl Attributes {
         })
     }
 
+    pub fn get_constructor(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Constructor(s) = a {
+                Some(s.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_page_size(&self) -> Option<usize> {
+        self.iter().find_map(|a| {
+            if let Attribute::PageSize(page_size) = a {
+                Some(*page_size)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_widget(&self) -> Option<Widget> {
+        self.iter().find_map(|a| {
+            if let Attribute::Widget(widget) = a {
+                Some(*widget)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the options of the color the field is presented as, if
+    /// it is one. The `color` widget is a color with the default
+    /// options.
+    pub fn get_window(&self) -> WindowOptions {
+        self.iter()
+            .find_map(|a| {
+                if let Attribute::Window(options) = a {
+                    Some(*options)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_color(&self) -> Option<ColorOptions> {
+        self.iter()
+            .find_map(|a| {
+                if let Attribute::Color(options) = a {
+                    Some(*options)
+                } else {
+                    None
+                }
+            })
+            .or_else(|| (self.get_widget() == Some(Widget::Color)).then(ColorOptions::default))
+    }
+
+    /// Returns the paths of the functions validating the item.
+    pub fn get_validators(&self) -> Vec<&syn::Path> {
+        self.iter()
+            .filter_map(|a| {
+                if let Attribute::Validate(path) = a {
+                    Some(path)
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    pub fn has_non_empty(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::NonEmpty)
+    }
+
+    pub fn get_min_len(&self) -> Option<usize> {
+        self.iter().find_map(|a| {
+            if let Attribute::MinLen(length) = a {
+                Some(*length)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_max_len(&self) -> Option<usize> {
+        self.iter().find_map(|a| {
+            if let Attribute::MaxLen(length) = a {
+                Some(*length)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_regex(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Regex(pattern) = a {
+                Some(pattern.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn has_reject_invalid(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::RejectInvalid)
+    }
+
+    pub fn get_group(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Group(group) = a {
+                Some(group.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_tab(&self) -> Option<&str> {
+        self.iter().find_map(|a| {
+            if let Attribute::Tab(tab) = a {
+                Some(tab.as_ref())
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_order(&self) -> Option<i64> {
+        self.iter().find_map(|a| {
+            if let Attribute::Order(order) = a {
+                Some(*order)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn has_separator(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::Separator)
+    }
+
+    pub fn has_same_line(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::SameLine)
+    }
+
+    pub fn has_flatten(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::Flatten)
+    }
+
+    pub fn has_inline(&self) -> bool {
+        self.iter().any(|a| *a == Attribute::Inline)
+    }
+
+    /// Flattens the field unless it is inline, like the single field of
+    /// a newtype is.
+    pub fn flatten_by_default(&mut self) {
+        if !self.has_inline() && !self.has_flatten() {
+            self.attributes
+                .push((Attribute::Flatten, Span::call_site()));
+        }
+    }
+
+    pub fn get_layout(&self) -> Layout {
+        self.iter()
+            .find_map(|a| {
+                if let Attribute::Layout(layout) = a {
+                    Some(*layout)
+                } else {
+                    None
+                }
+            })
+            .unwrap_or_default()
+    }
+
+    pub fn get_visible_if(&self) -> Option<&syn::Expr> {
+        self.iter().find_map(|a| {
+            if let Attribute::VisibleIf(condition) = a {
+                Some(condition)
+            } else {
+                None
+            }
+        })
+    }
+
+    /// Returns the bounds of the presentation for the backend, or of the
+    /// implementations not depending on the backend if it is `None`.
+    pub fn get_bound(&self, backend: Option<Backend>) -> Option<&[syn::WherePredicate]> {
+        self.iter().find_map(|a| {
+            if let Attribute::Bound(bounds) = a {
+                bounds.get(backend)
+            } else {
+                None
+            }
+        })
+    }
+
+    pub fn get_enabled_if(&self) -> Option<&syn::Expr> {
+        self.iter().find_map(|a| {
+            if let Attribute::EnabledIf(condition) = a {
+                Some(condition)
+            } else {
+                None
+            }
+        })
+    }
+
     pub fn get_buttons(&self) -> Vec<&Button> {
-        self.attributes
-            .iter()
+        self.iter()
             .filter_map(|a| {
                 if let Attribute::Button(s) = a {
                     Some(s)
@@ -421,8 +1363,7 @@ impl Attributes {
     }
 
     pub fn get_main_menu_items(&self) -> Vec<&MainMenuItem> {
-        self.attributes
-            .iter()
+        self.iter()
             .filter_map(|a| {
                 if let Attribute::MainMenuItem(s) = a {
                     Some(s)
@@ -434,8 +1375,7 @@ impl Attributes {
     }
 
     pub fn get_backends(&self) -> Vec<&Backend> {
-        self.attributes
-            .iter()
+        self.iter()
             .filter_map(|a| {
                 if let Attribute::Backend(b) = a {
                     Some(b)
@@ -452,3 +1392,455 @@ impl Attributes {
             .or(self.get_documentation())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{Anchor, Attribute, Attributes, ColorOptions, Layout, Widget, WindowOptions};
+    use crate::Backend;
+
+    fn parse(item: syn::ItemStruct) -> Result<Attributes, String> {
+        let mut parsed = Attributes::default();
+        item.attrs
+            .iter()
+            .try_for_each(|a| parsed.parse_into(a))
+            .map_err(|e| e.to_string())?;
+        Ok(parsed)
+    }
+
+    #[test]
+    fn parses_values_with_commas_and_equal_signs() {
+        let attributes = parse(syn::parse_quote! {
+            /// First line.
+            /// Second line.
+            #[imgui_presentation(tooltip = "a, b = c", rename = "x=y")]
+            #[imgui_presentation(button("Save, then quit": "save_and_quit"))]
+            #[imgui_presentation(button("Quit": "quit"), range = ..10, speed = 2)]
+            struct A;
+        })
+        .unwrap();
+
+        assert_eq!(attributes.get_tooltip(), Some("a, b = c"));
+        assert_eq!(attributes.get_rename(), Some("x=y"));
+        assert_eq!(attributes.get_speed(), Some(2.0));
+        assert!(matches!(attributes.get_range(), Some((None, Some(_)))));
+        assert_eq!(
+            attributes.get_documentation().as_deref(),
+            Some("First line.\nSecond line.")
+        );
+        let buttons: Vec<_> = attributes
+            .get_buttons()
+            .into_iter()
+            .map(|b| (b.title.as_str(), b.method_name.to_string()))
+            .collect();
+        assert_eq!(
+            buttons,
+            [
+                ("Save, then quit", "save_and_quit".to_owned()),
+                ("Quit", "quit".to_owned())
+            ]
+        );
+    }
+
+    #[test]
+    fn parses_the_widgets() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(widget = "log_slider", range = 1.0..100.0)]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_widget(), Some(Widget::LogSlider));
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(widget = "slider", widget = "drag")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "The `widget` attribute is already specified with a different value."
+        );
+    }
+
+    #[test]
+    fn parses_the_color_options() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(color(linear, hdr))]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(
+            attributes.get_color(),
+            Some(ColorOptions {
+                no_alpha: false,
+                hdr: true,
+                linear: true,
+            })
+        );
+
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(widget = "color")]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_color(), Some(ColorOptions::default()));
+    }
+
+    #[test]
+    fn parses_the_window_options() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(window(resizable = false, size = [400, 300.5], position = [-10, 20]))]
+            #[imgui_presentation(rename = "Settings")]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(
+            attributes.get_window(),
+            WindowOptions {
+                resizable: Some(false),
+                size: Some([400.0, 300.5]),
+                position: Some([-10.0, 20.0]),
+                anchor: None,
+            }
+        );
+
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(window(anchor = "bottom_right"))]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_window().anchor, Some(Anchor::BottomRight));
+        assert_eq!(Anchor::BottomRight.fractions(), [1.0, 1.0]);
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(window(anchor = "middle"))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "middle is an unknown anchor, expected one of: top_left, top, top_right, left, center, right, bottom_left, bottom, bottom_right."
+        );
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(window(size = [1]))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "Expected two numbers, like `[400, 300]`.");
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(window(movable = false))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "movable is an unknown window option, expected one of: resizable, size, position, anchor."
+        );
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(window(size = [1, 2], size = [3, 4]))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "The `size` window option is duplicated.");
+    }
+
+    #[test]
+    fn parses_the_checks() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(validate = "checks::is_port", validate = "is_open")]
+            #[imgui_presentation(non_empty, min_len = 2, max_len = 8, reject_invalid)]
+            struct A;
+        })
+        .unwrap();
+        let validators: Vec<_> = attributes
+            .get_validators()
+            .into_iter()
+            .map(|path| quote::quote!(#path).to_string())
+            .collect();
+        assert_eq!(validators, ["checks :: is_port", "is_open"]);
+        assert!(attributes.has_non_empty());
+        assert_eq!(attributes.get_min_len(), Some(2));
+        assert_eq!(attributes.get_max_len(), Some(8));
+        assert!(attributes.has_reject_invalid());
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(validate = "not a path")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "\"not a path\" is not a valid function path.");
+    }
+
+    #[test]
+    fn parses_the_layout() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(group = "Physics", tab = "World", order = -2)]
+            #[imgui_presentation(separator, same_line, layout = "grid")]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_group(), Some("Physics"));
+        assert_eq!(attributes.get_tab(), Some("World"));
+        assert_eq!(attributes.get_order(), Some(-2));
+        assert!(attributes.has_separator());
+        assert!(attributes.has_same_line());
+        assert_eq!(attributes.get_layout(), Layout::Grid);
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(layout = "columns")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "columns is an unknown layout, expected one of: tree, grid."
+        );
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(order = 1.5)]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "Expected an integer.");
+    }
+
+    #[test]
+    fn parses_the_flattening() {
+        let mut attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(inline)]
+            struct A;
+        })
+        .unwrap();
+        assert!(attributes.has_inline());
+        attributes.flatten_by_default();
+        assert!(!attributes.has_flatten());
+
+        let mut attributes = parse(syn::parse_quote! {
+            struct A;
+        })
+        .unwrap();
+        attributes.flatten_by_default();
+        assert!(attributes.has_flatten());
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(flatten, inline)]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "The `flatten` and `inline` attributes can't be combined."
+        );
+    }
+
+    #[test]
+    fn parses_the_conditions() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(visible_if = "self.kind == Kind::Point", enabled_if = "self.on")]
+            struct A;
+        })
+        .unwrap();
+        let condition = attributes.get_visible_if().unwrap();
+        assert_eq!(
+            quote::quote!(#condition).to_string(),
+            "self . kind == Kind :: Point"
+        );
+        assert!(attributes.get_enabled_if().is_some());
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(visible_if = "self.")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "\"self.\" is not a valid expression.");
+    }
+
+    #[test]
+    fn parses_the_bounds() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(bound = "T: Clone + Default, U: 'static")]
+            struct A;
+        })
+        .unwrap();
+        let bound = attributes.get_bound(Some(Backend::Egui)).unwrap();
+        assert_eq!(bound.len(), 2);
+        assert_eq!(
+            quote::quote!(#(#bound),*).to_string(),
+            "T : Clone + Default , U : 'static"
+        );
+
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(bound(imgui = "", egui = "T: Clone"))]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_bound(Some(Backend::Imgui)), Some(&[][..]));
+        assert_eq!(attributes.get_bound(Some(Backend::Egui)).unwrap().len(), 1);
+        assert!(attributes.get_bound(Some(Backend::Ratatui)).is_none());
+        assert!(attributes.get_bound(None).is_none());
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(bound(imgui = "", imgui = ""))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "The bounds of the imgui backend are duplicated.");
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(bound = "T:: Clone")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "\"T:: Clone\" is not a valid list of bounds.");
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn checks_the_regular_expressions() {
+        let attributes = parse(syn::parse_quote! {
+            #[imgui_presentation(regex = "^[a-z]+$")]
+            struct A;
+        })
+        .unwrap();
+        assert_eq!(attributes.get_regex(), Some("^[a-z]+$"));
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(regex = "(")]
+            struct A;
+        })
+        .unwrap_err();
+        assert!(error.contains("regex parse error"), "{error}");
+    }
+
+    #[test]
+    fn suggests_the_closest_attribute() {
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(tooltpi = "a")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "Unknown attribute `tooltpi`, did you mean `tooltip`?"
+        );
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(abcd)]
+            struct A;
+        })
+        .unwrap_err();
+        assert!(error.starts_with("Unknown attribute `abcd`, expected one of: `skip`"));
+    }
+
+    #[test]
+    fn reports_duplicates_and_conflicts() {
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(rename = "a")]
+            #[imgui_presentation(rename = "b")]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(
+            error,
+            "The `rename` attribute is already specified with a different value."
+        );
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(skip, skip)]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "The `skip` attribute is duplicated.");
+
+        let error = parse(syn::parse_quote! {
+            #[imgui_presentation(button("A": "a"), button("A": "b"))]
+            struct A;
+        })
+        .unwrap_err();
+        assert_eq!(error, "A button titled \"A\" is already specified.");
+    }
+
+    #[test]
+    fn reports_malformed_values() {
+        let errors = [
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(button("A", "a"))]
+                    struct A;
+                }),
+                "expected `:`",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(button("A": "not a method"))]
+                    struct A;
+                }),
+                "\"not a method\" is not a valid method name.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(rename = 5)]
+                    struct A;
+                }),
+                "expected string literal",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(range = 5)]
+                    struct A;
+                }),
+                "Expected a range, like `0.0f32..1.0f32`.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(backend = "qt")]
+                    struct A;
+                }),
+                "qt is an unknown backend.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(page_size = 0)]
+                    struct A;
+                }),
+                "The page size must be positive.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(widget = "knob")]
+                    struct A;
+                }),
+                "knob is an unknown widget, expected one of: drag, slider, log_slider, input, angle, percent, color, progress.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(color(srgb))]
+                    struct A;
+                }),
+                "srgb is an unknown color option, expected one of: no_alpha, hdr, linear.",
+            ),
+            (
+                parse(syn::parse_quote! {
+                    #[imgui_presentation(color(hdr, hdr))]
+                    struct A;
+                }),
+                "The `hdr` color option is duplicated.",
+            ),
+        ];
+
+        for (result, expected) in errors {
+            assert_eq!(result.unwrap_err(), expected);
+        }
+    }
+
+    #[test]
+    fn ignores_other_attributes() {
+        let attributes = parse(syn::parse_quote! {
+            #[must_use]
+            #[serde(rename = "b")]
+            #[imgui_presentation(readonly)]
+            struct A;
+        })
+        .unwrap();
+        assert!(attributes.has_readonly());
+        assert!(attributes.iter().eq([&Attribute::ReadOnly]));
+    }
+}
@@ -0,0 +1,165 @@
+use quote::{quote, ToTokens};
+
+use crate::{attributes::Attributes, Backend};
+
+/// Returns `true` if the tokens, like the ones of a type, mention the
+/// identifier, like the one of a type parameter.
+fn mentions(tokens: proc_macro2::TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(i) => i == *ident,
+        proc_macro2::TokenTree::Group(group) => mentions(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Returns `true` if the type is a `PhantomData`, which needs nothing
+/// of the types it mentions.
+fn is_phantom(typ: &syn::Type) -> bool {
+    match typ {
+        syn::Type::Path(path) => path
+            .path
+            .segments
+            .last()
+            .is_some_and(|segment| segment.ident == "PhantomData"),
+        _ => false,
+    }
+}
+
+/// Returns the trait the implementations for the backend require of
+/// the types of the fields.
+pub(crate) fn backend_trait(backend: Backend) -> proc_macro2::TokenStream {
+    match backend {
+        Backend::Imgui => quote! { imgui_presentable::ImguiPresentable },
+        Backend::Egui => quote! { imgui_presentable::EguiPresentable },
+        Backend::Ratatui => quote! { imgui_presentable::TuiPresentable },
+    }
+}
+
+/// Returns the generics of an item with the bounds an implementation
+/// needs added to its `where` clause. Those are the predicates of the
+/// `bound` attribute of the item for the `backend` of the
+/// implementation if it has one. Otherwise, every type of a requirement
+/// mentioning a type parameter is bound by the trait of the
+/// requirement, like the types of the presented fields by the
+/// presentation trait, except for the `PhantomData`. The types
+/// mentioning the `item` itself would require the implementation to
+/// prove itself, so the type parameters of those are bound instead.
+pub(crate) fn with_bounds<'a>(
+    item: &syn::Ident,
+    generics: &syn::Generics,
+    attributes: &Attributes,
+    backend: Option<Backend>,
+    requirements: impl IntoIterator<Item = (&'a syn::Type, proc_macro2::TokenStream)>,
+) -> syn::Generics {
+    let mut generics = generics.clone();
+
+    let predicates: Vec<syn::WherePredicate> = if let Some(bound) = attributes.get_bound(backend) {
+        bound.to_vec()
+    } else {
+        let mut predicates: Vec<syn::WherePredicate> = Vec::new();
+        for (typ, required_trait) in requirements {
+            if is_phantom(typ) {
+                continue;
+            }
+            let tokens = typ.to_token_stream();
+            let params: Vec<_> = generics
+                .type_params()
+                .map(|param| &param.ident)
+                .filter(|ident| mentions(tokens.clone(), ident))
+                .collect();
+            let bounded: Vec<syn::WherePredicate> = if params.is_empty() {
+                Vec::new()
+            } else if mentions(tokens, item) {
+                params
+                    .into_iter()
+                    .map(|ident| syn::parse_quote! { #ident: #required_trait })
+                    .collect()
+            } else {
+                vec![syn::parse_quote! { #typ: #required_trait }]
+            };
+            for predicate in bounded {
+                if !predicates.contains(&predicate) {
+                    predicates.push(predicate);
+                }
+            }
+        }
+        predicates
+    };
+
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+    generics
+}
+
+#[cfg(test)]
+mod tests {
+    use quote::ToTokens;
+
+    use super::with_bounds;
+    use crate::{attributes::Attributes, Backend};
+
+    fn bounded(input: syn::DeriveInput) -> String {
+        let syn::Data::Struct(strukt) = &input.data else {
+            unreachable!();
+        };
+        let attributes = Attributes::parse_many(&input.attrs).unwrap();
+        let generics = with_bounds(
+            &input.ident,
+            &input.generics,
+            &attributes,
+            Some(Backend::Imgui),
+            strukt
+                .fields
+                .iter()
+                .map(|f| (&f.ty, quote::quote! { Trait })),
+        );
+        generics
+            .where_clause
+            .map(|w| w.to_token_stream().to_string())
+            .unwrap_or_default()
+    }
+
+    #[test]
+    fn bounds_the_types_of_the_fields() {
+        assert_eq!(
+            bounded(syn::parse_quote! {
+                struct A<'a, T, U, const N: usize> where U: Copy {
+                    items: Vec<T>,
+                    name: &'a str,
+                    values: [f32; N],
+                    value: T,
+                }
+            }),
+            "where U : Copy , Vec < T > : Trait , T : Trait"
+        );
+        assert_eq!(
+            bounded(syn::parse_quote! {
+                #[imgui_presentation(bound = "T: Other")]
+                struct A<T> {
+                    items: Vec<T>,
+                }
+            }),
+            "where T : Other"
+        );
+        assert_eq!(
+            bounded(syn::parse_quote! {
+                struct A<T, U> {
+                    first: Option<T>,
+                    second: std::marker::PhantomData<U>,
+                }
+            }),
+            "where Option < T > : Trait"
+        );
+        // The recursive types bound the type parameters.
+        assert_eq!(
+            bounded(syn::parse_quote! {
+                struct A<T> {
+                    value: T,
+                    children: Vec<A<T>>,
+                }
+            }),
+            "where T : Trait"
+        );
+    }
+}
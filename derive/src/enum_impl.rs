@@ -1,8 +1,17 @@
 use proc_macro2::Span;
-use quote::quote;
-use syn::Ident;
+use quote::{format_ident, quote};
+use syn::{Fields, Ident};
 
-use crate::Backend;
+use crate::{
+    attributes::{self, Attributes},
+    bounds, reflect_impl,
+    struct_impl::{
+        check_field_only, check_item_only, check_no_layout, check_struct_field_only, check_widget,
+        generate_element_actions, generate_field_matches_filter, generate_render_window_methods,
+        generate_ui_field_for_struct, get_type, Access,
+    },
+    validate_impl, Backend,
+};
 
 #[allow(clippy::too_many_arguments)]
 fn generate_ui_field_for_pod_enum(
@@ -50,10 +59,18 @@ fn generate_ui_field_for_pod_enum(
 
     match backend {
         Backend::Imgui => {
+            code.extend(quote! {
+                let _highlight = imgui_presentable::filter::highlight_imgui(
+                    #ui,
+                    imgui_presentable::filter::Filter::active_matches(&values[current_value]),
+                );
+            });
+
             if mutable {
                 code.extend(quote! {
+                    let self_pointer: *const Self = self;
                     let used = #ui.combo_simple_string(
-                        &format!("{}##{:p}", stringify!(#enum_name), std::ptr::addr_of!(self)),
+                        &format!("{}##{:p}", stringify!(#enum_name), self_pointer),
                         &mut current_value,
                         &values,
                     );
@@ -64,12 +81,15 @@ fn generate_ui_field_for_pod_enum(
                             _ => unreachable!("All the fields were checked."),
                         }
                     }
+
+                    imgui_presentable::EditResponse::from_last_imgui_item(#ui, used)
                 });
             } else {
                 code.extend(quote! {
+                    let self_pointer: *const Self = self;
                     #ui.disabled(true, || {
                         let _used = #ui.combo_simple_string(
-                            &format!("{}##{:p}", stringify!(#enum_name), std::ptr::addr_of!(self)),
+                            &format!("{}##{:p}", stringify!(#enum_name), self_pointer),
                             &mut current_value,
                             &values,
                         );
@@ -80,23 +100,27 @@ fn generate_ui_field_for_pod_enum(
         Backend::Egui => {
             let ui_element = if mutable {
                 quote! {
-                egui::containers::ComboBox::from_label("")
-                    .show_index(
-                        #ui,
-                        &mut current_value,
-                        values.len(),
-                        |i| values[i],
-                    );
+                    let self_pointer: *const Self = self;
+                    let response = egui::containers::ComboBox::from_id_salt(self_pointer)
+                        .show_index(
+                            #ui,
+                            &mut current_value,
+                            values.len(),
+                            |i| values[i],
+                        );
 
                     *self = match current_value {
                         #(#order_to_idents,)*
                         _ => unreachable!("All the fields were checked."),
-                    }
+                    };
+
+                    imgui_presentable::EditResponse::from(&response)
                 }
             } else {
                 quote! {
+                     let self_pointer: *const Self = self;
                      #ui.add_enabled_ui(false, |ui| {
-                        egui::containers::ComboBox::from_label("")
+                        egui::containers::ComboBox::from_id_salt(self_pointer)
                             .show_index(
                                 #ui,
                                 &mut current_value,
@@ -107,7 +131,33 @@ fn generate_ui_field_for_pod_enum(
                 }
             };
 
-            code.extend(ui_element);
+            code.extend(quote! {
+                let highlighted =
+                    imgui_presentable::filter::Filter::active_matches(&values[current_value]);
+                imgui_presentable::filter::highlight_egui(#ui, highlighted, |#ui| {
+                    #ui_element
+                })
+            });
+        }
+        Backend::Ratatui => {
+            if mutable {
+                code.extend(quote! {
+                    let response = #ui.choice(&mut current_value, &values);
+
+                    *self = match current_value {
+                        #(#order_to_idents,)*
+                        _ => unreachable!("All the fields were checked."),
+                    };
+
+                    response
+                });
+            } else {
+                code.extend(quote! {
+                    #ui.disabled(true, |ui| {
+                        let _ = ui.choice(&mut current_value, &values);
+                    });
+                });
+            }
         }
     }
 
@@ -117,10 +167,19 @@ fn generate_ui_field_for_pod_enum(
 fn derive_for_pod_enum(
     derive_input: &syn::DeriveInput,
     enumm: &syn::DataEnum,
+    enum_attributes: &Attributes,
     backend: Backend,
 ) -> proc_macro2::TokenStream {
     let enum_name = &derive_input.ident;
-    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+    // Only the `bound` attribute bounds the enums without the data.
+    let generics = bounds::with_bounds(
+        &derive_input.ident,
+        &derive_input.generics,
+        enum_attributes,
+        Some(backend),
+        [],
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
     let variants: Vec<_> = enumm
         .variants
@@ -147,6 +206,27 @@ fn derive_for_pod_enum(
         generate_ui_field_for_pod_enum(&ui_ident, enum_name, &variants, false, backend);
     let ui_elements_mut =
         generate_ui_field_for_pod_enum(&ui_ident, enum_name, &variants, true, backend);
+    let (ui_elements, ui_elements_mut) = generate_validated_ui(
+        &ui_ident,
+        enum_attributes,
+        ui_elements,
+        ui_elements_mut,
+        backend,
+    );
+
+    let variant_names = variants.iter().map(|(ident, _)| {
+        quote! { Self::#ident => stringify!(#ident) }
+    });
+    let matches_filter = quote! {
+        fn matches_filter(&self, filter: &imgui_presentable::filter::Filter) -> bool {
+            filter.matches(match self {
+                #(#variant_names,)*
+            })
+        }
+    };
+    let element_actions = generate_element_actions(backend);
+    let render_window_methods =
+        generate_render_window_methods(backend, enum_attributes, &quote! {}, false, &impl_generics);
 
     match backend {
         Backend::Imgui => {
@@ -154,13 +234,19 @@ fn derive_for_pod_enum(
                 /// # Renders [`#name`] using
                 /// [`imgui_presentable::ImguiPresentable`] derive macro.
                 impl #impl_generics imgui_presentable::ImguiPresentable for #enum_name #ty_generics #where_clause {
+                    #render_window_methods
+
                     fn render_component(&self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) {
                         #ui_elements;
                     }
 
-                    fn render_component_mut(&mut self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) {
-                        #ui_elements_mut;
+                    fn render_component_mut(&mut self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                        #ui_elements_mut
                     }
+
+                    #matches_filter
+
+                    #element_actions
                 }
             }
         }
@@ -169,16 +255,616 @@ fn derive_for_pod_enum(
                 /// # Renders [`#name`] using
                 /// [`imgui_presentable::EguiPresentable`] derive macro.
                 impl #impl_generics imgui_presentable::EguiPresentable for #enum_name #ty_generics #where_clause {
+                    #render_window_methods
+
                     fn render_component(&self, #ui_ident: &mut egui::Ui) {
                         #ui_elements;
                     }
 
-                    fn render_component_mut(&mut self, #ui_ident: &mut egui::Ui) {
-                        #ui_elements_mut;
+                    fn render_component_mut(&mut self, #ui_ident: &mut egui::Ui) -> imgui_presentable::EditResponse {
+                        #ui_elements_mut
                     }
+
+                    #matches_filter
+
+                    #element_actions
                 }
             }
         }
+        Backend::Ratatui => {
+            quote! {
+                /// # Renders [`#name`] using
+                /// [`imgui_presentable::TuiPresentable`] derive macro.
+                impl #impl_generics imgui_presentable::TuiPresentable for #enum_name #ty_generics #where_clause {
+                    fn render_component(&self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) {
+                        #ui_elements;
+                    }
+
+                    fn render_component_mut(&mut self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) -> imgui_presentable::EditResponse {
+                        #ui_elements_mut
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Wraps the code presenting an enum with showing the errors of its
+/// checks, and undoing the edits making it invalid if it has the
+/// `reject_invalid` attribute. The mutable code evaluates to the
+/// response of the edits.
+fn generate_validated_ui(
+    ui: &Ident,
+    enum_attributes: &Attributes,
+    ui_elements: proc_macro2::TokenStream,
+    ui_elements_mut: proc_macro2::TokenStream,
+    backend: Backend,
+) -> (proc_macro2::TokenStream, proc_macro2::TokenStream) {
+    let errors = validate_impl::generate_item_error_display(ui, enum_attributes, backend);
+    if errors.is_empty() {
+        return (ui_elements, ui_elements_mut);
+    }
+
+    let edit = validate_impl::wrap_item_ui(
+        ui,
+        enum_attributes,
+        quote! {
+            response |= { #ui_elements_mut };
+        },
+    );
+    (
+        quote! {
+            #errors
+            { #ui_elements }
+        },
+        quote! {
+            #errors
+            let mut response = imgui_presentable::EditResponse::default();
+            #edit
+            response
+        },
+    )
+}
+
+/// A variant of an enum, which may carry data.
+pub(crate) struct DataVariant {
+    pub(crate) ident: Ident,
+    pub(crate) attributes: Attributes,
+    fields: Fields,
+    pub(crate) parsed_fields: Vec<(Option<Ident>, syn::Type, Attributes)>,
+}
+
+impl DataVariant {
+    pub(crate) fn parse(variant: &syn::Variant) -> attributes::Result<Self> {
+        let attributes = Attributes::parse_many(&variant.attrs)?;
+        check_struct_field_only(&attributes)?;
+        check_field_only(&attributes, &["flatten", "inline"])?;
+        check_no_layout(&attributes)?;
+        check_item_only(&attributes)?;
+        let parsed_fields = variant
+            .fields
+            .iter()
+            .map(|f| {
+                let a = Attributes::parse_from_field(f)?;
+                let field_type = get_type(&f.ty);
+                check_widget(&field_type, &a)?;
+                validate_impl::check_field_checks(&a)?;
+                check_struct_field_only(&a)?;
+                check_no_layout(&a)?;
+                check_item_only(&a)?;
+                Ok((f.ident.clone(), field_type, a))
+            })
+            .collect::<attributes::Result<Vec<_>>>()?;
+
+        Ok(Self {
+            ident: variant.ident.clone(),
+            attributes,
+            fields: variant.fields.clone(),
+            parsed_fields,
+        })
+    }
+
+    /// Returns the types of the fields along with the traits an
+    /// implementation requires of those: the `field_trait` of the fields
+    /// which aren't skipped, and [`Default`] of all the fields if the
+    /// variant has no constructor.
+    pub(crate) fn requirements(
+        &self,
+        field_trait: proc_macro2::TokenStream,
+    ) -> impl Iterator<Item = (&syn::Type, proc_macro2::TokenStream)> {
+        let constructed = self.attributes.get_constructor().is_none();
+        self.parsed_fields
+            .iter()
+            .flat_map(move |(_, typ, attributes)| {
+                let presented = (!attributes.has_skip()).then(|| (typ, field_trait.clone()));
+                let created = constructed.then(|| (typ, quote! { ::core::default::Default }));
+                presented.into_iter().chain(created)
+            })
+    }
+
+    /// The name shown in the variant selector.
+    pub(crate) fn title(&self) -> String {
+        self.attributes
+            .get_rename()
+            .map(|s| s.to_owned())
+            .unwrap_or_else(|| self.ident.to_string())
+    }
+
+    /// The identifier the field with the passed order number is bound
+    /// to in the `match` arm of this variant.
+    pub(crate) fn binding(&self, order: usize) -> Ident {
+        match &self.parsed_fields[order].0 {
+            Some(ident) => format_ident!("__field_{}", ident),
+            None => format_ident!("__field_{}", order),
+        }
+    }
+
+    /// The pattern which binds all the non-skipped fields of the
+    /// variant.
+    pub(crate) fn pattern(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+        match &self.fields {
+            Fields::Named(_) => {
+                let bindings = self
+                    .parsed_fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !f.2.has_skip())
+                    .map(|(i, f)| {
+                        let field = &f.0;
+                        let binding = self.binding(i);
+                        quote! { #field: #binding }
+                    });
+                quote! { Self::#ident { #(#bindings,)* .. } }
+            }
+            Fields::Unnamed(_) => {
+                let bindings = self.parsed_fields.iter().enumerate().map(|(i, f)| {
+                    if f.2.has_skip() {
+                        quote! { _ }
+                    } else {
+                        let binding = self.binding(i);
+                        quote! { #binding }
+                    }
+                });
+                quote! { Self::#ident(#(#bindings,)*) }
+            }
+            Fields::Unit => quote! { Self::#ident },
+        }
+    }
+
+    /// The expression creating a new value of this variant, once it is
+    /// selected.
+    pub(crate) fn constructor(&self) -> proc_macro2::TokenStream {
+        let ident = &self.ident;
+
+        if let Some(constructor) = self.attributes.get_constructor() {
+            let constructor = Ident::new(constructor, Span::call_site());
+            return quote! { Self::#constructor() };
+        }
+
+        match &self.fields {
+            Fields::Named(_) => {
+                let fields = self.parsed_fields.iter().map(|f| {
+                    let field = &f.0;
+                    quote! { #field: ::core::default::Default::default() }
+                });
+                quote! { Self::#ident { #(#fields,)* } }
+            }
+            Fields::Unnamed(_) => {
+                let fields = self
+                    .parsed_fields
+                    .iter()
+                    .map(|_| quote! { ::core::default::Default::default() });
+                quote! { Self::#ident(#(#fields,)*) }
+            }
+            Fields::Unit => quote! { Self::#ident },
+        }
+    }
+}
+
+fn generate_ui_for_data_enum(
+    ui: &Ident,
+    extent: &Ident,
+    enum_name: &Ident,
+    variants: &[DataVariant],
+    access: Access,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let mutable = access == Access::Mutable;
+    let titles: Vec<String> = variants.iter().map(DataVariant::title).collect();
+    let idents_to_order = variants.iter().enumerate().map(|(i, v)| {
+        let ident = &v.ident;
+        quote! { Self::#ident { .. } => #i }
+    });
+    let order_to_constructors = variants.iter().enumerate().map(|(i, v)| {
+        let constructor = v.constructor();
+        quote! { #i => #constructor }
+    });
+
+    let owner_pointer = quote! { self_pointer };
+    let arms = variants.iter().map(|v| {
+        let pattern = v.pattern();
+        let total_field_count = v.parsed_fields.len();
+        let fields = v
+            .parsed_fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.2.has_skip())
+            .map(|(i, f)| {
+                let binding = v.binding(i);
+                generate_ui_field_for_struct(
+                    ui,
+                    extent,
+                    &v.ident,
+                    &f.0,
+                    &quote! { (*#binding) },
+                    &owner_pointer,
+                    i,
+                    total_field_count,
+                    &f.1,
+                    &f.2,
+                    access,
+                    false,
+                    backend,
+                )
+            });
+
+        quote! {
+            #pattern => {
+                #(#fields;)*
+            }
+        }
+    });
+
+    // The terminal backend doesn't identify the widgets by the
+    // addresses.
+    let self_pointer = match backend {
+        Backend::Ratatui => quote! {},
+        _ => quote! { let self_pointer: *const Self = self; },
+    };
+
+    let mut code = quote! {
+        #self_pointer
+        let mut current_value = match self {
+            #(#idents_to_order,)*
+        };
+        let previous_value = current_value;
+
+        let values = [
+            #(#titles,)*
+        ];
+    };
+
+    let selector = match backend {
+        Backend::Imgui => {
+            if mutable {
+                quote! {
+                    let used = #ui.combo_simple_string(
+                        &format!("{}##{:p}", stringify!(#enum_name), self_pointer),
+                        &mut current_value,
+                        &values,
+                    );
+                    response |= imgui_presentable::EditResponse::from_last_imgui_item(#ui, used);
+                }
+            } else {
+                quote! {
+                    #ui.disabled(true, || {
+                        let _used = #ui.combo_simple_string(
+                            &format!("{}##{:p}", stringify!(#enum_name), self_pointer),
+                            &mut current_value,
+                            &values,
+                        );
+                    });
+                }
+            }
+        }
+        Backend::Egui => {
+            if mutable {
+                quote! {
+                    response |= egui::containers::ComboBox::from_id_salt(self_pointer)
+                        .show_index(
+                            #ui,
+                            &mut current_value,
+                            values.len(),
+                            |i| values[i],
+                        )
+                        .into();
+                }
+            } else {
+                quote! {
+                    #ui.add_enabled_ui(false, |ui| {
+                        egui::containers::ComboBox::from_id_salt(self_pointer)
+                            .show_index(
+                                #ui,
+                                &mut current_value,
+                                values.len(),
+                                |i| values[i],
+                            );
+                    });
+                }
+            }
+        }
+        Backend::Ratatui => {
+            if mutable {
+                quote! {
+                    response |= #ui.choice(&mut current_value, &values);
+                }
+            } else {
+                quote! {
+                    #ui.disabled(true, |ui| {
+                        let _ = ui.choice(&mut current_value, &values);
+                    });
+                }
+            }
+        }
+    };
+
+    // The selector is highlighted if the title of the current variant
+    // matches the applied filter.
+    let selector = match backend {
+        Backend::Imgui => quote! {
+            {
+                let _highlight = imgui_presentable::filter::highlight_imgui(
+                    #ui,
+                    imgui_presentable::filter::Filter::active_matches(&values[current_value]),
+                );
+                #selector
+            }
+        },
+        Backend::Egui => quote! {
+            let highlighted =
+                imgui_presentable::filter::Filter::active_matches(&values[current_value]);
+            imgui_presentable::filter::highlight_egui(#ui, highlighted, |#ui| {
+                #selector
+            });
+        },
+        Backend::Ratatui => selector,
+    };
+
+    if mutable {
+        code.extend(quote! {
+            let mut response = imgui_presentable::EditResponse::default();
+
+            #selector
+
+            if current_value != previous_value {
+                *self = match current_value {
+                    #(#order_to_constructors,)*
+                    _ => unreachable!("All the variants were checked."),
+                };
+            }
+
+            match self {
+                #(#arms)*
+            }
+
+            response
+        });
+    } else if access == Access::Shared {
+        // The variant can't be switched through a shared reference, so
+        // only the fields with interior mutability are edited.
+        code.extend(quote! {
+            #[allow(unused_mut)]
+            let mut response = imgui_presentable::EditResponse::default();
+
+            #selector
+
+            match self {
+                #(#arms)*
+            }
+
+            response
+        });
+    } else {
+        code.extend(quote! {
+            #selector
+
+            match self {
+                #(#arms)*
+            }
+        });
+    }
+
+    code
+}
+
+fn derive_for_data_enum(
+    derive_input: &syn::DeriveInput,
+    enumm: &syn::DataEnum,
+    enum_attributes: &Attributes,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let enum_name = &derive_input.ident;
+
+    let variants = match enumm
+        .variants
+        .iter()
+        .map(DataVariant::parse)
+        .collect::<attributes::Result<Vec<DataVariant>>>()
+    {
+        Ok(variants) => variants,
+        Err(e) => return e,
+    };
+
+    let generics = bounds::with_bounds(
+        &derive_input.ident,
+        &derive_input.generics,
+        enum_attributes,
+        Some(backend),
+        variants
+            .iter()
+            .flat_map(|v| v.requirements(bounds::backend_trait(backend))),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let ui_ident = syn::Ident::new("ui", Span::call_site());
+    let extent_ident = syn::Ident::new("extent", Span::call_site());
+
+    let ui_elements = generate_ui_for_data_enum(
+        &ui_ident,
+        &extent_ident,
+        enum_name,
+        &variants,
+        Access::Immutable,
+        backend,
+    );
+    let ui_elements_mut = generate_ui_for_data_enum(
+        &ui_ident,
+        &extent_ident,
+        enum_name,
+        &variants,
+        Access::Mutable,
+        backend,
+    );
+    let ui_elements_shared = generate_ui_for_data_enum(
+        &ui_ident,
+        &extent_ident,
+        enum_name,
+        &variants,
+        Access::Shared,
+        backend,
+    );
+    // Only the errors are shown above the fields changed through a shared
+    // reference, as undoing the invalid edits needs a mutable one.
+    let errors = validate_impl::generate_item_error_display(&ui_ident, enum_attributes, backend);
+    let ui_elements_shared = quote! {
+        #errors
+        { #ui_elements_shared }
+    };
+    let (ui_elements, ui_elements_mut) = generate_validated_ui(
+        &ui_ident,
+        enum_attributes,
+        ui_elements,
+        ui_elements_mut,
+        backend,
+    );
+
+    let matches_filter = match backend {
+        Backend::Imgui | Backend::Egui => {
+            let arms = variants.iter().map(|v| {
+                let pattern = v.pattern();
+                let title = v.title();
+                let total_field_count = v.parsed_fields.len();
+                let field_matches = v
+                    .parsed_fields
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, f)| !f.2.has_skip())
+                    .filter_map(|(i, f)| {
+                        let binding = v.binding(i);
+                        generate_field_matches_filter(
+                            &v.ident,
+                            &f.0,
+                            &quote! { (*#binding) },
+                            i,
+                            total_field_count,
+                            &f.2,
+                            backend,
+                        )
+                    });
+                quote! {
+                    #pattern => filter.matches(#title) #(|| #field_matches)*
+                }
+            });
+
+            quote! {
+                fn matches_filter(&self, filter: &imgui_presentable::filter::Filter) -> bool {
+                    match self {
+                        #(#arms,)*
+                    }
+                }
+            }
+        }
+        Backend::Ratatui => quote! {},
+    };
+
+    let element_actions = generate_element_actions(backend);
+    let render_window_methods = generate_render_window_methods(
+        backend,
+        enum_attributes,
+        &quote! {},
+        enum_attributes.has_readonly(),
+        &impl_generics,
+    );
+
+    let (trait_name, immutable_render, mutable_render, shared_render) = match backend {
+        Backend::Imgui => (
+            quote! { imgui_presentable::ImguiPresentable },
+            quote! {
+                fn render_component(&self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) {
+                    #ui_elements
+                }
+            },
+            quote! {
+                fn render_component_mut(&mut self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                    #ui_elements_mut
+                }
+            },
+            quote! {
+                fn render_component_shared(&self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                    #ui_elements_shared
+                }
+            },
+        ),
+        Backend::Egui => (
+            quote! { imgui_presentable::EguiPresentable },
+            quote! {
+                fn render_component(&self, #ui_ident: &mut egui::Ui) {
+                    #ui_elements
+                }
+            },
+            quote! {
+                fn render_component_mut(&mut self, #ui_ident: &mut egui::Ui) -> imgui_presentable::EditResponse {
+                    #ui_elements_mut
+                }
+            },
+            quote! {
+                fn render_component_shared(&self, #ui_ident: &mut egui::Ui) -> imgui_presentable::EditResponse {
+                    #ui_elements_shared
+                }
+            },
+        ),
+        Backend::Ratatui => (
+            quote! { imgui_presentable::TuiPresentable },
+            quote! {
+                fn render_component(&self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) {
+                    #ui_elements
+                }
+            },
+            quote! {
+                fn render_component_mut(&mut self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) -> imgui_presentable::EditResponse {
+                    #ui_elements_mut
+                }
+            },
+            quote! {
+                fn render_component_shared(&self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) -> imgui_presentable::EditResponse {
+                    #ui_elements_shared
+                }
+            },
+        ),
+    };
+
+    let (mutable_render, shared_render) = if enum_attributes.has_readonly() {
+        (quote! {}, quote! {})
+    } else {
+        (mutable_render, shared_render)
+    };
+
+    quote! {
+        #[doc = "Renders [`Self`] in the immediate gui. The code was automatically generated using the derive macro."]
+        impl #impl_generics #trait_name for #enum_name #ty_generics #where_clause {
+            #render_window_methods
+
+            #immutable_render
+
+            #mutable_render
+
+            #shared_render
+
+            #matches_filter
+
+            #element_actions
+        }
     }
 }
 
@@ -188,16 +874,50 @@ pub(crate) fn derive_for_enum(
     enumm: syn::DataEnum,
     backends: &[Backend],
 ) -> proc_macro2::TokenStream {
-    let is_pod_enum = enumm.variants.iter().any(|v| v.fields.is_empty());
+    let enum_attributes = match Attributes::parse_many(&derive_input.attrs) {
+        Ok(a) => a,
+        Err(c) => return c,
+    };
+    if let Err(e) = check_struct_field_only(&enum_attributes)
+        .and_then(|()| check_field_only(&enum_attributes, &["flatten", "inline"]))
+        .and_then(|()| check_no_layout(&enum_attributes))
+    {
+        return e;
+    }
+    let chosen_backend = enum_attributes.get_backends();
+    let backends = backends
+        .iter()
+        .filter(|b| chosen_backend.is_empty() || chosen_backend.contains(b));
+
+    let is_pod_enum = enumm.variants.iter().all(|v| v.fields.is_empty());
+
+    // The validation and the reflection don't depend on the backend.
+    let mut validation = validate_impl::derive_for_enum(&derive_input, &enumm, &enum_attributes);
+    validation.extend(reflect_impl::derive_for_enum(
+        &derive_input,
+        &enumm,
+        &enum_attributes,
+    ));
 
     if is_pod_enum {
-        return backends
-            .iter()
-            .fold(quote! {}, |mut implementation, backend| {
-                implementation.extend(derive_for_pod_enum(&derive_input, &enumm, *backend));
-                implementation
-            });
+        return backends.fold(validation, |mut implementation, backend| {
+            implementation.extend(derive_for_pod_enum(
+                &derive_input,
+                &enumm,
+                &enum_attributes,
+                *backend,
+            ));
+            implementation
+        });
     }
 
-    quote! { compile_error!("Only POD enums are supported as of this moment.") }
+    backends.fold(validation, |mut implementation, backend| {
+        implementation.extend(derive_for_data_enum(
+            &derive_input,
+            &enumm,
+            &enum_attributes,
+            *backend,
+        ));
+        implementation
+    })
 }
@@ -0,0 +1,387 @@
+use quote::quote;
+use syn::Ident;
+
+use crate::{attributes::Attributes, Backend};
+
+/// The attributes of a field and the code presenting it.
+type Field<'a> = (&'a Attributes, proc_macro2::TokenStream);
+
+/// A part of the layout of the fields of a struct.
+enum Node<'a> {
+    /// A field shown directly.
+    Field(Field<'a>),
+    /// A collapsible section with the fields of a group.
+    Group(&'a str, Vec<Node<'a>>),
+    /// The tab bar with the fields of every tab.
+    Tabs(Vec<(&'a str, Vec<Node<'a>>)>),
+}
+
+/// Puts the fields of the same group into a single section, placed
+/// where the first of those is.
+fn group(nodes: Vec<Node<'_>>) -> Vec<Node<'_>> {
+    let mut grouped: Vec<Node<'_>> = Vec::new();
+    for node in nodes {
+        let name = match &node {
+            Node::Field((attributes, _)) => attributes.get_group(),
+            _ => None,
+        };
+        let Some(name) = name else {
+            grouped.push(node);
+            continue;
+        };
+
+        let existing = grouped.iter_mut().find_map(|n| match n {
+            Node::Group(group, fields) if *group == name => Some(fields),
+            _ => None,
+        });
+        match existing {
+            Some(fields) => fields.push(node),
+            None => grouped.push(Node::Group(name, vec![node])),
+        }
+    }
+    grouped
+}
+
+/// Sorts the fields by their order, then puts those into the tabs and
+/// the groups. The tab bar is placed where the first field in a tab is,
+/// and the groups may be within the tabs.
+fn arrange(fields: Vec<Field<'_>>) -> Vec<Node<'_>> {
+    let mut fields: Vec<_> = fields.into_iter().enumerate().collect();
+    fields.sort_by_key(|(index, (attributes, _))| {
+        attributes
+            .get_order()
+            .unwrap_or_else(|| i64::try_from(*index).unwrap_or(i64::MAX))
+    });
+
+    let mut nodes = Vec::new();
+    let mut tabs: Vec<(&str, Vec<Node<'_>>)> = Vec::new();
+    let mut tab_bar_index = None;
+    for (_, field) in fields {
+        let Some(name) = field.0.get_tab() else {
+            nodes.push(Node::Field(field));
+            continue;
+        };
+
+        if tab_bar_index.is_none() {
+            tab_bar_index = Some(nodes.len());
+            nodes.push(Node::Tabs(Vec::new()));
+        }
+        match tabs.iter_mut().find(|(tab, _)| *tab == name) {
+            Some((_, tab_fields)) => tab_fields.push(Node::Field(field)),
+            None => tabs.push((name, vec![Node::Field(field)])),
+        }
+    }
+
+    if let Some(index) = tab_bar_index {
+        nodes[index] = Node::Tabs(
+            tabs.into_iter()
+                .map(|(name, fields)| (name, group(fields)))
+                .collect(),
+        );
+    }
+    group(nodes)
+}
+
+/// Generates the code laying the fields out.
+struct Generator<'a> {
+    ui: &'a Ident,
+    backend: Backend,
+    grid: bool,
+    /// The number of the tables generated, to identify those.
+    tables: usize,
+}
+
+impl Generator<'_> {
+    /// Returns the code of the separator before a field.
+    fn separator(&self) -> proc_macro2::TokenStream {
+        let ui = self.ui;
+        match self.backend {
+            Backend::Imgui | Backend::Egui => quote! { #ui.separator(); },
+            Backend::Ratatui => quote! {},
+        }
+    }
+
+    /// Generates the consecutive fields shown directly.
+    fn fields(&mut self, fields: Vec<Field<'_>>) -> proc_macro2::TokenStream {
+        if self.grid {
+            return self.grid_fields(fields);
+        }
+
+        let ui = self.ui;
+        let mut code = quote! {};
+        // The egui fields on the same line are laid out horizontally.
+        let mut line = quote! {};
+        let mut line_length = 0;
+        let end_line = |code: &mut proc_macro2::TokenStream,
+                        line: &mut proc_macro2::TokenStream,
+                        line_length: &mut usize| {
+            if *line_length > 1 {
+                code.extend(quote! {
+                    #ui.horizontal(|#ui| {
+                        #line
+                    });
+                });
+            } else {
+                code.extend(std::mem::take(line));
+            }
+            *line = quote! {};
+            *line_length = 0;
+        };
+
+        for (attributes, field) in fields {
+            let separator = attributes.has_separator().then(|| self.separator());
+            match self.backend {
+                Backend::Imgui => {
+                    let same_line = attributes
+                        .has_same_line()
+                        .then(|| quote! { #ui.same_line(); });
+                    code.extend(quote! {
+                        #separator
+                        #same_line
+                        #field;
+                    });
+                }
+                Backend::Egui => {
+                    if !attributes.has_same_line() {
+                        end_line(&mut code, &mut line, &mut line_length);
+                    }
+                    line.extend(quote! {
+                        #separator
+                        #field;
+                    });
+                    line_length += 1;
+                }
+                Backend::Ratatui => code.extend(quote! { #field; }),
+            }
+        }
+        end_line(&mut code, &mut line, &mut line_length);
+
+        code
+    }
+
+    /// Generates the consecutive fields as the rows of the tables,
+    /// which are split by the separators and the flattened fields.
+    fn grid_fields(&mut self, fields: Vec<Field<'_>>) -> proc_macro2::TokenStream {
+        let mut code = quote! {};
+        let mut rows = Vec::new();
+        for (attributes, field) in fields {
+            if attributes.has_separator() || attributes.has_flatten() {
+                code.extend(self.table(std::mem::take(&mut rows)));
+            }
+            if attributes.has_separator() {
+                code.extend(self.separator());
+            }
+            if attributes.has_flatten() {
+                code.extend(quote! { #field; });
+                continue;
+            }
+            rows.push(field);
+        }
+        code.extend(self.table(rows));
+        code
+    }
+
+    /// Generates a table of two columns with the rows of the fields.
+    fn table(&mut self, rows: Vec<proc_macro2::TokenStream>) -> proc_macro2::TokenStream {
+        if rows.is_empty() {
+            return quote! {};
+        }
+
+        let ui = self.ui;
+        let table = self.tables;
+        self.tables += 1;
+        match self.backend {
+            Backend::Imgui => quote! {
+                if let Some(_table) = #ui.begin_table(format!("##fields{}{:p}", #table, layout_id), 2) {
+                    #(#rows;)*
+                }
+            },
+            // The egui identifiers are relative to the parent `Ui`.
+            Backend::Egui => quote! {
+                egui::Grid::new(("imgui_presentable_fields", #table))
+                    .num_columns(2)
+                    .show(#ui, |#ui| {
+                        #(#rows;)*
+                    });
+            },
+            Backend::Ratatui => quote! { #(#rows;)* },
+        }
+    }
+
+    /// Generates the nodes in their order.
+    fn nodes(&mut self, nodes: Vec<Node<'_>>) -> proc_macro2::TokenStream {
+        let mut code = quote! {};
+        let mut fields = Vec::new();
+        for node in nodes {
+            match node {
+                Node::Field(field) => fields.push(field),
+                Node::Group(name, nodes) => {
+                    code.extend(self.fields(std::mem::take(&mut fields)));
+                    code.extend(self.group(name, nodes));
+                }
+                Node::Tabs(tabs) => {
+                    code.extend(self.fields(std::mem::take(&mut fields)));
+                    code.extend(self.tabs(tabs));
+                }
+            }
+        }
+        code.extend(self.fields(fields));
+        code
+    }
+
+    /// Generates a collapsible section, open by default.
+    fn group(&mut self, name: &str, nodes: Vec<Node<'_>>) -> proc_macro2::TokenStream {
+        let ui = self.ui;
+        let contents = self.nodes(nodes);
+        match self.backend {
+            Backend::Imgui => quote! {
+                if #ui.collapsing_header(
+                    format!("{}##{:p}", #name, layout_id),
+                    imgui::TreeNodeFlags::DEFAULT_OPEN,
+                ) {
+                    #contents
+                }
+            },
+            Backend::Egui => quote! {
+                egui::CollapsingHeader::new(#name)
+                    .default_open(true)
+                    .show(#ui, |#ui| {
+                        #contents
+                    });
+            },
+            Backend::Ratatui => quote! {
+                #ui.collapsing(#name, |#ui| {
+                    #contents
+                });
+            },
+        }
+    }
+
+    /// Generates a tab bar, showing the fields of the selected tab.
+    fn tabs(&mut self, tabs: Vec<(&str, Vec<Node<'_>>)>) -> proc_macro2::TokenStream {
+        let ui = self.ui;
+        let names: Vec<_> = tabs.iter().map(|(name, _)| *name).collect();
+        let contents: Vec<_> = tabs
+            .into_iter()
+            .map(|(_, nodes)| self.nodes(nodes))
+            .collect();
+        match self.backend {
+            Backend::Imgui => quote! {
+                if let Some(_tab_bar) = #ui.tab_bar(format!("##tabs{:p}", layout_id)) {
+                    #(
+                        if let Some(_tab) = #ui.tab_item(#names) {
+                            #contents
+                        }
+                    )*
+                }
+            },
+            Backend::Egui | Backend::Ratatui => {
+                let selected = if self.backend == Backend::Egui {
+                    quote! {
+                        imgui_presentable::layout::tab_bar_egui(#ui, "imgui_presentable_tabs", &[#(#names),*])
+                    }
+                } else {
+                    quote! {
+                        imgui_presentable::layout::tab_bar_tui(#ui, "tabs", &[#(#names),*])
+                    }
+                };
+                let indices = 0..names.len();
+                quote! {
+                    match #selected {
+                        #(#indices => { #contents })*
+                        _ => {}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Generates the code presenting the fields of a struct on a single
+/// row, sorted by their `order`, ignoring the groups, the tabs and the
+/// separators. The fields are presented as the rows of the grid, which
+/// are the cells of a single table row for ImGui and are laid out
+/// horizontally for egui. The terminals show the fields as usual.
+pub(crate) fn generate_inline_layout(
+    ui: &Ident,
+    backend: Backend,
+    fields: Vec<Field<'_>>,
+) -> proc_macro2::TokenStream {
+    let mut fields: Vec<_> = fields.into_iter().enumerate().collect();
+    fields.sort_by_key(|(index, (attributes, _))| {
+        attributes
+            .get_order()
+            .unwrap_or_else(|| i64::try_from(*index).unwrap_or(i64::MAX))
+    });
+
+    match backend {
+        Backend::Imgui => {
+            // The flattened fields take a single cell.
+            let mut columns = 0usize;
+            let cells = fields.into_iter().map(|(_, (attributes, field))| {
+                if attributes.has_flatten() {
+                    columns += 1;
+                    quote! {
+                        #ui.table_next_column();
+                        #field;
+                    }
+                } else {
+                    columns += 2;
+                    quote! { #field; }
+                }
+            });
+            let cells: Vec<_> = cells.collect();
+            let columns = columns.max(1);
+            quote! {
+                if let Some(_table) = #ui.begin_table(
+                    format!("##inline{:p}", std::ptr::addr_of!(*self)),
+                    #columns,
+                ) {
+                    #(#cells)*
+                }
+            }
+        }
+        Backend::Egui => {
+            let fields = fields.into_iter().map(|(_, (_, field))| field);
+            quote! {
+                #ui.horizontal(|#ui| {
+                    #(#fields;)*
+                });
+            }
+        }
+        Backend::Ratatui => quote! {},
+    }
+}
+
+/// Generates the code presenting the fields of a struct, sorted by
+/// their `order` and put into the groups and the tabs. With the grid
+/// layout, the fields shown directly are the rows of the tables.
+pub(crate) fn generate_layout(
+    ui: &Ident,
+    backend: Backend,
+    grid: bool,
+    fields: Vec<Field<'_>>,
+) -> proc_macro2::TokenStream {
+    let nodes = arrange(fields);
+    let has_containers = nodes.iter().any(|n| !matches!(n, Node::Field(_)));
+
+    let mut generator = Generator {
+        ui,
+        backend,
+        grid,
+        tables: 0,
+    };
+    let code = generator.nodes(nodes);
+
+    // The ImGui identifiers of the tables, the sections and the tab bars
+    // are unique for every value.
+    let needs_id = backend == Backend::Imgui && (has_containers || generator.tables > 0);
+    if needs_id {
+        quote! {
+            let layout_id = std::ptr::addr_of!(*self);
+            #code
+        }
+    } else {
+        code
+    }
+}
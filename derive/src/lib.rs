@@ -6,8 +6,12 @@ use struct_impl::derive_for_struct;
 use syn::Data;
 
 mod attributes;
+mod bounds;
 mod enum_impl;
+mod layout_impl;
+mod reflect_impl;
 mod struct_impl;
+mod validate_impl;
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Hash)]
 pub(crate) enum Backend {
@@ -15,6 +19,8 @@ pub(crate) enum Backend {
     Imgui,
     #[allow(dead_code)]
     Egui,
+    #[allow(dead_code)]
+    Ratatui,
 }
 
 impl FromStr for Backend {
@@ -24,14 +30,19 @@ impl FromStr for Backend {
         Ok(match s.to_lowercase().as_ref() {
             "imgui" => Self::Imgui,
             "egui" => Self::Egui,
+            "ratatui" | "tui" => Self::Ratatui,
             _ => return Err(format!("{s} is an unknown backend.")),
         })
     }
 }
 
-#[cfg(not(any(feature = "imgui_backend", feature = "egui_backend")))]
+#[cfg(not(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+)))]
 compile_error!(
-    "At least one backend has to be specified in the feature list: either egui or imgui. The derive macro is useless otherwise."
+    "At least one backend has to be specified in the feature list: egui, imgui or ratatui. The derive macro is useless otherwise."
 );
 
 fn derive_imgui_presentable_impl_for_backends(
@@ -61,13 +72,15 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
         Backend::Imgui,
         #[cfg(feature = "egui_backend")]
         Backend::Egui,
+        #[cfg(feature = "ratatui_backend")]
+        Backend::Ratatui,
     ];
 
     derive_imgui_presentable_impl_for_backends(tokens, &backends)
 }
 
-/// Generates the immediate gui (ImGui or egui) representation for a
-/// struct or an enum.
+/// Generates the immediate gui (ImGui, egui or ratatui) representation
+/// for a struct or an enum.
 ///
 /// # Options
 ///
@@ -76,23 +89,32 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
 /// following options implemented:
 ///
 /// - `readonly` makes a struct or a field have only immutable
-/// presentation.
+///   presentation.
 /// - `skip` skips the code generation for this field.
 /// - `rename` renames a struct or a field in the generated
-/// presentation code.
+///   presentation code.
 /// - `format` (only for scalars) allows to set custom display format.
 /// - `speed` (only for scalars) allows to set custom speed of
+///   the value change when dragging.
 /// - `range` (only for scalars) allows to set a range of values the
-/// scalar object can have.
-/// the value change when dragging.
+///   scalar object can have.
+/// - `prefix` and `suffix` (only for scalars) set the text shown
+///   before and after the value.
 /// - `tooltip` changes the hint text for a field or a struct.
 /// - `button` allows to generated custom buttons, can only be
-/// specified on a struct/enum.
-/// - `backend` allows a struct or enum to specify the backend it needs.
-/// only the chosen backend code will be derived.
+///   specified on a struct/enum.
+/// - `backend` allows a struct or enum to specify the backend it needs
+///   (`"imgui"`, `"egui"` or `"ratatui"`). Only the chosen backend code
+///   will be derived.
 /// - `main_menu_item` allows to specify a main menu item. The main
-/// menu items are always visible and start at the top-left corner of
-/// a window.
+///   menu items are always visible and start at the top-left corner of
+///   a window.
+/// - `window` (only for structs and enums) sets the size, the position
+///   and the anchor of the window, and whether it may be resized.
+/// - `constructor` (only for enum variants) names an associated
+///   function creating the variant once it is selected.
+/// - `page_size` (only for the collections) splits the collection into
+///   the pages of the size specified.
 ///
 /// # Examples
 ///
@@ -201,6 +223,260 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
 /// }
 /// ```
 ///
+/// ## Prefix and suffix
+///
+/// For the primitive Rust types, it is possible to show a text before
+/// and after the value, for example, the units:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct A {
+///     #[imgui_presentation(prefix = "~", suffix = " m/s")]
+///     value: f32,
+/// }
+/// ```
+///
+/// The `format`, `speed`, `range`, `prefix` and `suffix` attributes
+/// are honoured by both the ImGui and the egui backends. As egui
+/// doesn't use the "printf" formats, only the precision and the text
+/// around the value are taken from the `format` there.
+///
+/// ## Widgets
+///
+/// The numeric primitive fields are dragged by default. Another widget
+/// may be chosen with the `widget` attribute:
+///
+/// - `"drag"`, the default one;
+/// - `"slider"` and `"log_slider"`, which need a `range` with both the
+///   bounds, the latter changing the value logarithmically;
+/// - `"input"`, where the value is typed in;
+/// - `"angle"`, for the `f32` and the `f64` fields storing the radians,
+///   which are shown in degrees;
+/// - `"percent"`, for the `f32` and the `f64` fields storing the
+///   fractions, which are shown in percents;
+/// - `"progress"`, a read-only bar filled by the part of the `range`
+///   the value is at, or by the value itself if there is no range;
+/// - `"color"`, a color picker, the same as the `color` attribute
+///   below.
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct Light {
+///     #[imgui_presentation(widget = "slider", range = 0.0..1.0)]
+///     intensity: f32,
+///     // The `range` of the angles and the percents is in the units
+///     // shown.
+///     #[imgui_presentation(widget = "angle", range = -90.0..90.0)]
+///     pitch: f32,
+///     #[imgui_presentation(widget = "color")]
+///     tint: [f32; 3],
+/// }
+/// ```
+///
+/// The `format`, `range`, `prefix` and `suffix` attributes apply to
+/// all the scalar widgets, and the `speed` to the ones dragging the
+/// value. The ratatui backend edits the scalars the same way whatever
+/// the widget, only keeping the units of the angles and the percents.
+///
+/// ## Colors
+///
+/// The `color` attribute presents a field with a color picker rather
+/// than channel by channel. The arrays of three or four `f32` or `u8`,
+/// the `glam::Vec3`, `glam::Vec3A` and `glam::Vec4`, the
+/// `egui::Color32` and any other type implementing the
+/// `imgui_presentable::color::Color` trait may be colors. The channels
+/// are stored in the sRGB space within `0.0..=1.0` by default, which
+/// may be changed by the options listed in the parentheses:
+///
+/// - `no_alpha`, to keep the alpha channel as it is;
+/// - `hdr`, to allow the float channels to exceed `1.0`;
+/// - `linear`, to store the float channels in the linear space.
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct Material {
+///     #[imgui_presentation(color)]
+///     base: glam::Vec3,
+///     #[imgui_presentation(color(linear, hdr))]
+///     emission: [f32; 3],
+/// }
+/// ```
+///
+/// The `imgui_presentable::Rgb`, `Rgba` and `Srgba8` wrappers are
+/// presented as colors without the attribute.
+///
+/// ## Page size
+///
+/// Only the rows of a [`Vec`], a `HashMap` or a `BTreeMap` visible in
+/// the scrolled area are laid out, however long the collection is. To
+/// also show the rows page by page, with a selector like
+/// "rows 0–999 of 120 000" above those, specify the size of the pages:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct A {
+///     #[imgui_presentation(page_size = 1000)]
+///     values: Vec<f32>,
+/// }
+/// ```
+///
+/// ## Elements of the sequences
+///
+/// The derived implementations tell the presentations of the
+/// sequences, like a [`Vec`], that the elements may be added if the
+/// type implements [`Default`], and duplicated if it implements
+/// [`Clone`]. This is only detected for the types without the generic
+/// parameters.
+///
+/// ## Copying and presets
+///
+/// With the `serde` feature, the fields are copied as JSON or RON and
+/// pasted from their context menus, and the windows get the
+/// "Save preset…" and "Load preset…" buttons, as far as the types
+/// implement `serde::Serialize` and `serde::Deserialize`. Like the
+/// actions on the elements, this is only detected for the types without
+/// the generic parameters. The values of the `readonly` structs and
+/// fields are only copied and saved. The ratatui backend has no
+/// clipboard, so its presentations don't change.
+///
+/// ## Validation
+///
+/// The `validate = "path::to::function"` attribute checks a field, or
+/// the whole struct or enum, with a function taking a reference to the
+/// value and returning `Result<(), String>`. The fields may also use the
+/// built-in `non_empty`, `min_len = N` and `max_len = N` checks of the
+/// strings and the collections, and with the `regex` feature, the
+/// `regex = "pattern"` check of the strings, the pattern of which is
+/// compiled when the macro is expanded:
+///
+/// ```rust,ignore
+/// #[derive(Clone, ImguiPresentation)]
+/// #[imgui_presentation(validate = "has_primary")]
+/// pub struct Cluster {
+///     #[imgui_presentation(non_empty, max_len = 253, reject_invalid)]
+///     primary: String,
+///     #[imgui_presentation(min_len = 1)]
+///     servers: Vec<Server>,
+/// }
+/// ```
+///
+/// The errors are shown in red below the fields and above the fields of
+/// the structs and enums. With `reject_invalid`, the edits making the
+/// value or the values nested in it invalid are undone, and their errors
+/// are shown until the value is changed. The copy of the last valid
+/// value is kept between the frames, so the type must implement `Clone`
+/// and be `'static`. The `imgui_presentable::validation::Validate` trait is
+/// implemented for every derived type, returning all the errors with the
+/// paths of the fields from `validate_all()`.
+///
+/// ## Conditional fields
+///
+/// The `visible_if = "expression"` attribute of a struct field only
+/// shows the field while the expression is `true`, and the
+/// `enabled_if = "expression"` attribute grays the field out while it's
+/// `false`. The expressions are evaluated against `self` when the struct
+/// is rendered:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct Light {
+///     kind: LightKind,
+///     #[imgui_presentation(visible_if = "self.kind == LightKind::Point")]
+///     falloff: f32,
+///     on: bool,
+///     #[imgui_presentation(enabled_if = "self.on")]
+///     intensity: f32,
+/// }
+/// ```
+///
+/// The read-only presentations aren't grayed out. The attributes can't
+/// be used for the structs, the enums, or the fields of the variants.
+///
+/// ## Layout
+///
+/// The fields of a struct are sorted by their `order = n` attribute,
+/// the fields without it being ordered by their declarations. The
+/// `group = "name"` attribute puts a field into a collapsible section,
+/// and `tab = "name"` into a tab of the tab bar of the struct, both
+/// placed where their first fields are. A group may be within a tab.
+/// The `separator` attribute draws a line above a field, and
+/// `same_line` shows a field next to the previous one:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// #[imgui_presentation(layout = "grid")]
+/// pub struct Settings {
+///     #[imgui_presentation(group = "Physics")]
+///     gravity: f32,
+///     #[imgui_presentation(tab = "Rendering", separator)]
+///     fov: f32,
+/// }
+/// ```
+///
+/// The `layout = "grid"` attribute of a struct shows the fields as the
+/// rows of a table of their names and values, which the separators
+/// split. The `same_line` attribute has no effect in a grid. The ratatui
+/// backend only supports the order, the groups and the tabs.
+///
+/// The `flatten` attribute of a field shows the fields of its value
+/// among the fields of the owner, without a node of the field, and the
+/// `inline` attribute shows the name of the field and the fields of its
+/// value on a single row. The single field of a tuple struct is
+/// flattened unless it is inline. The structs shown inline are marked
+/// `inline` too, as only those have the single row layout generated:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct Object {
+///     #[imgui_presentation(flatten)]
+///     common: Common,
+///     #[imgui_presentation(inline)]
+///     transform: Transform,
+/// }
+///
+/// #[derive(ImguiPresentation)]
+/// #[imgui_presentation(inline)]
+/// pub struct Transform {
+///     x: f32,
+///     y: f32,
+/// }
+/// ```
+///
+/// Only the derived struct rendered first within an inline field is
+/// shown on a row, the structs nested into it are shown as usual.
+///
+/// ## Generic types
+///
+/// The `where` clause of the item is extended with the bounds of the
+/// types of the presented fields mentioning the type parameters: those
+/// are bound by the presentation trait of the backend, so for a
+/// `Vec<T>` field the ImGui implementation requires
+/// `Vec<T>: ImguiPresentable` and the egui one `Vec<T>: EguiPresentable`.
+/// The fields of the enum variants without a `constructor` are also
+/// bound by [`Default`]. The `PhantomData` fields aren't bound, and the
+/// fields of the recursive types bound the type parameters instead, as
+/// the implementations would otherwise require themselves. The `bound`
+/// attribute replaces the inferred bounds, either of all the
+/// implementations or of the presentations for the backends listed:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// #[imgui_presentation(bound = "T: ImguiPresentable + Default")]
+/// pub struct A<T> {
+///     value: Option<T>,
+/// }
+///
+/// #[derive(ImguiPresentation)]
+/// #[imgui_presentation(bound(imgui = "T: ImguiPresentable + Default"))]
+/// pub struct B<T> {
+///     value: Option<T>,
+/// }
+/// ```
+///
+/// The `Validate` implementation is only bound by the `bound` attribute
+/// applying to all the implementations, as the nested values are only
+/// validated if their types are known to implement it.
+///
 /// ## Tooltip
 ///
 /// The code generator grabs the doc-comment an enum, structure or a
@@ -282,6 +558,36 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
 /// }
 /// ```
 ///
+/// The items are shown in the menu bars of the windows of both the
+/// ImGui and the egui backends.
+///
+/// ## Windows
+///
+/// The windows of the structs and the enums are titled with the
+/// `rename` attribute, if there is one, and with the name of the type
+/// otherwise. The `window` attribute sets whether the window may be
+/// resized, its initial size and position and the corner or the side
+/// of the screen it is anchored to, where the position becomes the
+/// offset from there:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// #[imgui_presentation(rename = "Settings")]
+/// #[imgui_presentation(window(
+///     resizable = false,
+///     size = [300, 200],
+///     position = [-10, 20],
+///     anchor = "top_right"
+/// ))]
+/// pub struct Settings {
+///     volume: f32,
+/// }
+/// ```
+///
+/// The anchors are `top_left`, `top`, `top_right`, `left`, `center`,
+/// `right`, `bottom_left`, `bottom` and `bottom_right`. The anchored
+/// windows can't be moved.
+///
 /// ## Backends
 ///
 /// To specify a backend for the code generation for a struct, use the
@@ -324,14 +630,13 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
 /// structs, except for the field names, which:
 ///
 /// 1. In case there is only one field (`struct.0`), is prefixed with
-/// the struct type.
+///    the struct type.
 /// 2. In case there are more fields, prefixed with their order numbers.
 ///
 /// ## Enums
 ///
-/// Only simple enums with simple variants are supported. Such enums
-/// appear as a ComboBox which allows to select a new value from a set
-/// of available variants of the enum, for example:
+/// The enums appear as a ComboBox which allows to select a new value
+/// from a set of available variants of the enum, for example:
 ///
 /// ```rust,ignore
 /// /// The languages the engine supports.
@@ -346,7 +651,68 @@ fn derive_imgui_presentable_impl(tokens: proc_macro2::TokenStream) -> proc_macro
 ///     /// Dutch language.
 ///     Dutch,
 /// }
+/// ```
+///
+/// The variants carrying data are also supported. The fields of the
+/// currently selected variant are shown below the ComboBox, exactly
+/// the same way as the fields of the structs are, with the same field
+/// attributes available. Once another variant is selected, its fields
+/// are created using the [`Default`] implementations of the field
+/// types, unless the variant has the `constructor` attribute, naming an
+/// associated function of the enum to create the variant with:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub enum Shape {
+///     #[imgui_presentation(rename = "A sphere")]
+///     Sphere {
+///         #[imgui_presentation(range = 0.0f32..100.0f32)]
+///         radius: f32,
+///     },
+///     #[imgui_presentation(constructor = "unit_box")]
+///     Box(glam::Vec3),
+/// }
+///
+/// impl Shape {
+///     fn unit_box() -> Self {
+///         Self::Box(glam::Vec3::ONE)
+///     }
+/// }
+/// ```
+///
+/// ## Reflection
+///
+/// The derive also implements the `imgui_presentable::Reflect` trait,
+/// describing the fields and the variants with their names, the
+/// documentation and the presentation options, so that the other
+/// frontends and the tools may inspect and edit the values, including
+/// by the paths of the fields:
+///
+/// ```rust,ignore
+/// #[derive(ImguiPresentation)]
+/// pub struct A {
+///     /// The width of the box.
+///     #[imgui_presentation(range = 0.0f32..self.max_width)]
+///     width: f32,
+///     max_width: f32,
+///     #[imgui_presentation(readonly)]
+///     name: String,
+/// }
+///
+/// let mut a = A { width: 1.0, max_width: 10.0, name: "box".to_owned() };
+/// if let ReflectMut::Struct(s) = a.reflect_mut() {
+///     assert_eq!(s.info().fields[0].docs, Some("The width of the box."));
+///     assert_eq!(s.field_range(0), Some((0.0, 10.0)));
+///     assert!(s.field_mut(2).is_none());
+/// }
+///
+/// a.set_field("width", "2.5".into()).unwrap();
+/// assert!(a.set_field("name", "ball".into()).is_err());
+/// ```
 ///
+/// The ranges are evaluated on every call, so they may depend on the
+/// value. The fields of the types not implementing the trait are
+/// described, but have no values in the reflection.
 #[proc_macro_derive(ImguiPresentation, attributes(imgui_presentation))]
 pub fn derive_imgui_presentable(tokens: proc_macro::TokenStream) -> proc_macro::TokenStream {
     derive_imgui_presentable_impl(tokens.into()).into()
@@ -0,0 +1,429 @@
+use quote::{quote, ToTokens};
+use syn::Ident;
+
+use crate::{
+    attributes::{self, Attributes},
+    bounds,
+    enum_impl::DataVariant,
+};
+
+/// Returns the expression creating the
+/// `imgui_presentable::reflect::FieldInfo` of a field.
+fn generate_field_info(
+    field_ident: &Option<Ident>,
+    field_order: usize,
+    attributes: &Attributes,
+    readonly: bool,
+) -> proc_macro2::TokenStream {
+    let name = match field_ident {
+        Some(ident) => ident.to_string(),
+        None => field_order.to_string(),
+    };
+
+    let mut info = quote! { imgui_presentable::reflect::FieldInfo::new(#name) };
+
+    if let Some(rename) = attributes.get_rename() {
+        info.extend(quote! { .title(#rename) });
+    }
+    if let Some(docs) = attributes.get_tooltip_or_documentation() {
+        info.extend(quote! { .docs(#docs) });
+    }
+    if readonly || attributes.has_readonly() {
+        info.extend(quote! { .readonly() });
+    }
+    if let Some(format) = attributes.get_format() {
+        info.extend(quote! { .format(#format) });
+    }
+    if let Some(prefix) = attributes.get_prefix() {
+        info.extend(quote! { .prefix(#prefix) });
+    }
+    if let Some(suffix) = attributes.get_suffix() {
+        info.extend(quote! { .suffix(#suffix) });
+    }
+    if let Some(speed) = attributes.get_speed() {
+        info.extend(quote! { .speed(#speed) });
+    }
+
+    info
+}
+
+/// Returns the expression of the range of the values of a field, if it
+/// has one. Unlike the rest of the description, which is a static, the
+/// range is evaluated on every call, like in the presentations, so the
+/// ends may be any expressions.
+fn generate_field_range(attributes: &Attributes) -> Option<proc_macro2::TokenStream> {
+    let (min, max) = attributes.get_range()?;
+    let min = min
+        .map(|s| quote! { (#s) as f64 })
+        .unwrap_or_else(|| quote! { f64::NEG_INFINITY });
+    let max = max
+        .map(|s| quote! { (#s) as f64 })
+        .unwrap_or_else(|| quote! { f64::INFINITY });
+    Some(quote! { Some((#min, #max)) })
+}
+
+/// Returns the calls setting the title and the documentation of a
+/// struct or a variant description.
+fn generate_title_and_docs(attributes: &Attributes) -> proc_macro2::TokenStream {
+    let mut calls = quote! {};
+
+    if let Some(rename) = attributes.get_rename() {
+        calls.extend(quote! { .title(#rename) });
+    }
+    if let Some(docs) = attributes.get_tooltip_or_documentation() {
+        calls.extend(quote! { .docs(#docs) });
+    }
+
+    calls
+}
+
+/// Derives the `imgui_presentable::Reflect` trait for a struct, the
+/// fields of which have already been checked. The fields of the types
+/// not implementing the trait are left out of the views.
+pub(crate) fn derive_for_struct(
+    derive_input: &syn::DeriveInput,
+    fields: &[(Option<Ident>, syn::Type, Attributes)],
+    struct_attributes: &Attributes,
+) -> proc_macro2::TokenStream {
+    let struct_name = &derive_input.ident;
+    let readonly = struct_attributes.has_readonly();
+    let fields: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, _, a))| !a.has_skip())
+        .collect();
+
+    let generics = bounds::with_bounds(
+        struct_name,
+        &derive_input.generics,
+        struct_attributes,
+        None,
+        fields
+            .iter()
+            .map(|(_, (_, field_type, _))| (field_type, quote! { imgui_presentable::Reflect })),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let field_infos = fields
+        .iter()
+        .map(|(order, (ident, _, a))| generate_field_info(ident, *order, a, readonly));
+
+    let field_arms = fields
+        .iter()
+        .enumerate()
+        .map(|(index, (order, (ident, field_type, _)))| {
+            let place = field_place(ident, *order);
+            quote! { #index => imgui_presentable::reflect_nested!(#field_type, &self.#place), }
+        });
+    let field_mut_arms = fields
+        .iter()
+        .enumerate()
+        .filter(|(_, (_, (_, _, a)))| !readonly && !a.has_readonly())
+        .map(|(index, (order, (ident, field_type, _)))| {
+            let place = field_place(ident, *order);
+            quote! { #index => imgui_presentable::reflect_nested_mut!(#field_type, &mut self.#place), }
+        });
+    let range_arms: Vec<_> = fields
+        .iter()
+        .enumerate()
+        .filter_map(|(index, (_, (_, _, a)))| {
+            let range = generate_field_range(a)?;
+            Some(quote! { #index => #range, })
+        })
+        .collect();
+    let field_range = (!range_arms.is_empty()).then(|| {
+        quote! {
+            fn field_range(&self, index: usize) -> Option<(f64, f64)> {
+                match index {
+                    #(#range_arms)*
+                    _ => None,
+                }
+            }
+        }
+    });
+
+    let mut struct_info = quote! {
+        imgui_presentable::reflect::StructInfo::new(
+            stringify!(#struct_name),
+            &[#(#field_infos,)*],
+        )
+    };
+    struct_info.extend(generate_title_and_docs(struct_attributes));
+    if readonly {
+        struct_info.extend(quote! { .readonly() });
+    }
+
+    quote! {
+        #[doc = "Describes [`Self`] for the backend-agnostic tooling. The code was automatically generated using the derive macro."]
+        impl #impl_generics imgui_presentable::Reflect for #struct_name #ty_generics #where_clause {
+            fn reflect(&self) -> imgui_presentable::reflect::ReflectRef<'_> {
+                imgui_presentable::reflect::ReflectRef::Struct(self)
+            }
+
+            fn reflect_mut(&mut self) -> imgui_presentable::reflect::ReflectMut<'_> {
+                imgui_presentable::reflect::ReflectMut::Struct(self)
+            }
+        }
+
+        impl #impl_generics imgui_presentable::reflect::Struct for #struct_name #ty_generics #where_clause {
+            fn info(&self) -> &'static imgui_presentable::reflect::StructInfo {
+                static INFO: imgui_presentable::reflect::StructInfo = #struct_info;
+                &INFO
+            }
+
+            fn field(&self, index: usize) -> Option<&dyn imgui_presentable::Reflect> {
+                match index {
+                    #(#field_arms)*
+                    _ => None,
+                }
+            }
+
+            fn field_mut(&mut self, index: usize) -> Option<&mut dyn imgui_presentable::Reflect> {
+                match index {
+                    #(#field_mut_arms)*
+                    _ => None,
+                }
+            }
+
+            #field_range
+        }
+    }
+}
+
+/// Returns the member expression of a field, its identifier or its
+/// order number for the tuple structs.
+fn field_place(ident: &Option<Ident>, order: usize) -> proc_macro2::TokenStream {
+    match ident {
+        Some(ident) => ident.to_token_stream(),
+        None => syn::Index::from(order).to_token_stream(),
+    }
+}
+
+/// Returns the `match self` arms accessing the fields of the variants.
+fn generate_variant_field_arms(
+    variants: &[DataVariant],
+    readonly: bool,
+    mutable: bool,
+) -> Vec<proc_macro2::TokenStream> {
+    variants
+        .iter()
+        .map(|v| {
+            let pattern = v.pattern();
+            let arms = v
+                .parsed_fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.2.has_skip())
+                .enumerate()
+                .filter(|(_, (_, f))| !mutable || !(readonly || f.2.has_readonly()))
+                .map(|(index, (order, (_, field_type, _)))| {
+                    let binding = v.binding(order);
+                    if mutable {
+                        quote! { #index => imgui_presentable::reflect_nested_mut!(#field_type, #binding), }
+                    } else {
+                        quote! { #index => imgui_presentable::reflect_nested!(#field_type, #binding), }
+                    }
+                });
+
+            quote! {
+                #pattern => match index {
+                    #(#arms)*
+                    _ => None,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Returns the `match self` arms evaluating the ranges of the fields of
+/// the variants, or nothing if none of those has a range.
+fn generate_variant_range_arms(variants: &[DataVariant]) -> Vec<proc_macro2::TokenStream> {
+    let arms: Vec<_> = variants
+        .iter()
+        .map(|v| {
+            v.parsed_fields
+                .iter()
+                .filter(|f| !f.2.has_skip())
+                .enumerate()
+                .filter_map(|(index, f)| {
+                    let range = generate_field_range(&f.2)?;
+                    Some(quote! { #index => #range, })
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    if arms.iter().all(Vec::is_empty) {
+        return Vec::new();
+    }
+
+    variants
+        .iter()
+        .zip(arms)
+        .map(|(v, arms)| {
+            let pattern = v.pattern();
+            quote! {
+                #pattern => match index {
+                    #(#arms)*
+                    _ => None,
+                },
+            }
+        })
+        .collect()
+}
+
+/// Derives the `imgui_presentable::Reflect` trait for an enum, the
+/// variants of which have already been checked.
+pub(crate) fn derive_for_enum(
+    derive_input: &syn::DeriveInput,
+    enumm: &syn::DataEnum,
+    enum_attributes: &Attributes,
+) -> proc_macro2::TokenStream {
+    let enum_name = &derive_input.ident;
+    let variants = match enumm
+        .variants
+        .iter()
+        .map(DataVariant::parse)
+        .collect::<attributes::Result<Vec<DataVariant>>>()
+    {
+        Ok(variants) if !variants.is_empty() => variants,
+        // Already reported by the presentations.
+        _ => return quote! {},
+    };
+
+    let readonly = enum_attributes.has_readonly();
+
+    let generics = bounds::with_bounds(
+        enum_name,
+        &derive_input.generics,
+        enum_attributes,
+        None,
+        variants
+            .iter()
+            .flat_map(|v| v.requirements(quote! { imgui_presentable::Reflect })),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let variant_infos = variants.iter().map(|v| {
+        let name = v.ident.to_string();
+        let field_infos = v
+            .parsed_fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.2.has_skip())
+            .map(|(order, f)| generate_field_info(&f.0, order, &f.2, readonly));
+        let title = v.title();
+        let docs = v
+            .attributes
+            .get_tooltip_or_documentation()
+            .map(|docs| quote! { .docs(#docs) });
+
+        quote! {
+            imgui_presentable::reflect::VariantInfo::new(#name, &[#(#field_infos,)*])
+                .title(#title)
+                #docs
+        }
+    });
+
+    let mut enum_info = quote! {
+        imgui_presentable::reflect::EnumInfo::new(
+            stringify!(#enum_name),
+            &[#(#variant_infos,)*],
+        )
+    };
+    if let Some(docs) = enum_attributes.get_tooltip_or_documentation() {
+        enum_info.extend(quote! { .docs(#docs) });
+    }
+    if readonly {
+        enum_info.extend(quote! { .readonly() });
+    }
+
+    let idents_to_order = variants.iter().enumerate().map(|(i, v)| {
+        let ident = &v.ident;
+        quote! { Self::#ident { .. } => #i }
+    });
+
+    let set_variant = if readonly {
+        quote! {
+            let _ = index;
+            false
+        }
+    } else {
+        let order_to_constructors = variants.iter().enumerate().map(|(i, v)| {
+            let constructor = v.constructor();
+            quote! { #i => #constructor }
+        });
+
+        quote! {
+            if index == imgui_presentable::reflect::Enum::variant_index(self) {
+                return true;
+            }
+
+            *self = match index {
+                #(#order_to_constructors,)*
+                _ => return false,
+            };
+
+            true
+        }
+    };
+
+    let field_arms = generate_variant_field_arms(&variants, readonly, false);
+    let field_mut_arms = generate_variant_field_arms(&variants, readonly, true);
+    let range_arms = generate_variant_range_arms(&variants);
+    let field_range = (!range_arms.is_empty()).then(|| {
+        quote! {
+            #[allow(unused_variables)]
+            fn field_range(&self, index: usize) -> Option<(f64, f64)> {
+                match self {
+                    #(#range_arms)*
+                }
+            }
+        }
+    });
+
+    quote! {
+        #[doc = "Describes [`Self`] for the backend-agnostic tooling. The code was automatically generated using the derive macro."]
+        impl #impl_generics imgui_presentable::Reflect for #enum_name #ty_generics #where_clause {
+            fn reflect(&self) -> imgui_presentable::reflect::ReflectRef<'_> {
+                imgui_presentable::reflect::ReflectRef::Enum(self)
+            }
+
+            fn reflect_mut(&mut self) -> imgui_presentable::reflect::ReflectMut<'_> {
+                imgui_presentable::reflect::ReflectMut::Enum(self)
+            }
+        }
+
+        impl #impl_generics imgui_presentable::reflect::Enum for #enum_name #ty_generics #where_clause {
+            fn info(&self) -> &'static imgui_presentable::reflect::EnumInfo {
+                static INFO: imgui_presentable::reflect::EnumInfo = #enum_info;
+                &INFO
+            }
+
+            fn variant_index(&self) -> usize {
+                match self {
+                    #(#idents_to_order,)*
+                }
+            }
+
+            fn set_variant(&mut self, index: usize) -> bool {
+                #set_variant
+            }
+
+            #[allow(unused_variables)]
+            fn field(&self, index: usize) -> Option<&dyn imgui_presentable::Reflect> {
+                match self {
+                    #(#field_arms)*
+                }
+            }
+
+            #[allow(unused_variables)]
+            fn field_mut(&mut self, index: usize) -> Option<&mut dyn imgui_presentable::Reflect> {
+                match self {
+                    #(#field_mut_arms)*
+                }
+            }
+
+            #field_range
+        }
+    }
+}
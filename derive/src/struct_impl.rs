@@ -1,166 +1,809 @@
 use proc_macro2::Span;
-use quote::{quote, ToTokens};
-use syn::{Fields, Ident, TypePath};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::{spanned::Spanned, Fields, Ident};
 
 use crate::{
-    attributes::{self, Attributes},
-    Backend,
+    attributes::{self, Anchor, Attributes, ColorOptions, Layout, Widget},
+    bounds, layout_impl, reflect_impl, validate_impl, Backend,
 };
 
+/// A "printf" display format split into the parts egui understands:
+/// the text before the value, the number of decimals and the text after
+/// the value.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+struct PrintfFormat {
+    prefix: String,
+    decimals: Option<usize>,
+    suffix: String,
+}
+
+impl PrintfFormat {
+    /// Parses the format string, like `"%.2f dollars"`. Only the first
+    /// conversion specification is taken into account.
+    fn parse(format: &str) -> Self {
+        let mut result = Self::default();
+        let mut chars = format.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                result.prefix.push(c);
+                continue;
+            }
+
+            if chars.peek() == Some(&'%') {
+                let _ = chars.next();
+                result.prefix.push('%');
+                continue;
+            }
+
+            // Flags and width are irrelevant for egui.
+            while chars
+                .peek()
+                .is_some_and(|c| matches!(c, '-' | '+' | ' ' | '#' | '0'..='9'))
+            {
+                let _ = chars.next();
+            }
+
+            let mut precision = None;
+            if chars.peek() == Some(&'.') {
+                let _ = chars.next();
+                let mut digits = String::new();
+                while let Some(c) = chars.peek().filter(|c| c.is_ascii_digit()) {
+                    digits.push(*c);
+                    let _ = chars.next();
+                }
+                precision = Some(digits.parse().unwrap_or(0));
+            }
+
+            result.decimals = match chars.next() {
+                Some('d' | 'i' | 'u') => Some(0),
+                Some(_) => precision,
+                None => None,
+            };
+
+            for c in chars.by_ref() {
+                result.suffix.push(c);
+            }
+            result.suffix = result.suffix.replace("%%", "%");
+        }
+
+        result
+    }
+}
+
+/// Returns the name of the type of a field if it may be a scalar, like
+/// `f32`, or an empty string otherwise. Only the plain paths may be the
+/// scalars.
+fn scalar_type_name(field_type: &syn::Type) -> String {
+    match field_type {
+        syn::Type::Path(path) if path.qself.is_none() => path
+            .path
+            .segments
+            .last()
+            .map(|segment| segment.ident.to_string())
+            .unwrap_or_default(),
+        _ => String::new(),
+    }
+}
+
+fn is_numeric_primitive(type_name: &str) -> bool {
+    matches!(
+        type_name,
+        "usize"
+            | "isize"
+            | "u64"
+            | "i64"
+            | "f64"
+            | "f32"
+            | "u32"
+            | "i32"
+            | "u16"
+            | "i16"
+            | "u8"
+            | "i8"
+    )
+}
+
+/// The attributes only applicable to the fields of the structs, as
+/// those are evaluated against `self` or lay the fields out.
+const STRUCT_FIELD_ATTRIBUTES: &[&str] = &[
+    "visible_if",
+    "enabled_if",
+    "group",
+    "tab",
+    "order",
+    "separator",
+    "same_line",
+];
+
+/// Checks a struct, an enum, a variant or a field of a variant has none
+/// of the attributes only applicable to the fields of the structs.
+pub(crate) fn check_struct_field_only(attributes: &Attributes) -> attributes::Result {
+    match STRUCT_FIELD_ATTRIBUTES
+        .iter()
+        .find_map(|name| Some((name, attributes.span_of(name)?)))
+    {
+        Some((name, span)) => Err(attributes::to_compile_error(syn::Error::new(
+            span,
+            format!("The `{name}` attribute is only applicable to the fields of the structs."),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks an item other than a struct has no `layout` attribute.
+pub(crate) fn check_no_layout(attributes: &Attributes) -> attributes::Result {
+    match attributes.span_of("layout") {
+        Some(span) => Err(attributes::to_compile_error(syn::Error::new(
+            span,
+            "The `layout` attribute is only applicable to the structs.",
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks a field or a variant has neither the `bound` nor the `window`
+/// attribute.
+pub(crate) fn check_item_only(attributes: &Attributes) -> attributes::Result {
+    match ["bound", "window"]
+        .iter()
+        .find_map(|name| Some((name, attributes.span_of(name)?)))
+    {
+        Some((name, span)) => Err(attributes::to_compile_error(syn::Error::new(
+            span,
+            format!("The `{name}` attribute is only applicable to the structs and the enums."),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks a struct, an enum or a variant has none of the attributes
+/// applicable to the fields only, of the `names` of `flatten` and
+/// `inline`. The structs may be `inline`, which allows showing those on
+/// a single row.
+pub(crate) fn check_field_only(attributes: &Attributes, names: &[&str]) -> attributes::Result {
+    match names
+        .iter()
+        .find_map(|name| Some((name, attributes.span_of(name)?)))
+    {
+        Some((name, span)) => Err(attributes::to_compile_error(syn::Error::new(
+            span,
+            format!("The `{name}` attribute is only applicable to the fields."),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Checks the `widget` attribute of a field suits the type of the field,
+/// its `range` and its `color` attribute. Whether the field may be a
+/// color is checked by the compiler, as any type implementing
+/// `imgui_presentable::color::Color` may.
+pub(crate) fn check_widget(field_type: &syn::Type, attributes: &Attributes) -> attributes::Result {
+    let Some(widget) = attributes.get_widget() else {
+        return Ok(());
+    };
+    let type_name = scalar_type_name(field_type);
+    let has_both_bounds = matches!(attributes.get_range(), Some((Some(_), Some(_))));
+
+    let message = match widget {
+        Widget::Color => return Ok(()),
+        _ if attributes.span_of("color").is_some() => {
+            "can't be combined with the `color` attribute."
+        }
+        _ if !is_numeric_primitive(&type_name) => "is only supported for the numeric fields.",
+        Widget::Angle | Widget::Percent if !matches!(type_name.as_str(), "f32" | "f64") => {
+            "is only supported for the `f32` and `f64` fields."
+        }
+        Widget::Slider | Widget::LogSlider if !has_both_bounds => {
+            "needs a `range` with both the bounds."
+        }
+        Widget::Progress if attributes.get_range().is_some() && !has_both_bounds => {
+            "needs a `range` with both the bounds, if any."
+        }
+        _ => return Ok(()),
+    };
+
+    Err(attributes::to_compile_error(syn::Error::new(
+        attributes.span_of("widget").unwrap_or_else(Span::call_site),
+        format!("The `{}` widget {message}", widget.name()),
+    )))
+}
+
+/// Generates the widget of a numeric primitive field chosen by the
+/// `widget` attribute, which is a drag by default, using the `format`,
+/// `speed`, `range`, `prefix` and `suffix` attributes.
+fn generate_ui_for_numeric_primitive(
+    ui: &Ident,
+    field_place: &proc_macro2::TokenStream,
+    field_type: &syn::Type,
+    field_type_str: &str,
+    attributes: &Attributes,
+    mutable: bool,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let widget = attributes.get_widget().unwrap_or(Widget::Drag);
+    let is_float = matches!(field_type_str, "f32" | "f64");
+
+    // The angles and the fractions are edited in the degrees and the
+    // percents, and are converted back once changed.
+    let (to_shown, from_shown, unit) = match widget {
+        Widget::Angle => (quote! { .to_degrees() }, quote! { .to_radians() }, "°"),
+        Widget::Percent => (quote! { * 100.0 }, quote! { / 100.0 }, "%"),
+        _ => (quote! {}, quote! {}, ""),
+    };
+    let prefix = attributes.get_prefix().unwrap_or_default();
+    let suffix = attributes.get_suffix().unwrap_or(unit);
+
+    let range = attributes.get_range().map(|(min, max)| {
+        let min = min
+            .map(|s| s.to_token_stream())
+            .unwrap_or_else(|| quote! { <#field_type>::MIN });
+        let max = max
+            .map(|s| s.to_token_stream())
+            .unwrap_or_else(|| quote! { <#field_type>::MAX });
+        (min, max)
+    });
+
+    let speed = if let Some(speed) = attributes.get_speed() {
+        quote! { #speed }
+    } else if matches!(widget, Widget::Angle | Widget::Percent) {
+        quote! { 0.5f32 }
+    } else if is_float {
+        quote! { 0.001f32 }
+    } else {
+        quote! { 0.2f32 }
+    };
+
+    if widget == Widget::Progress {
+        return generate_progress_bar(
+            ui,
+            field_place,
+            range,
+            prefix,
+            suffix,
+            attributes,
+            mutable,
+            backend,
+        );
+    }
+
+    match backend {
+        Backend::Imgui => {
+            let default_format = match widget {
+                Widget::Angle | Widget::Percent => "%.1f",
+                _ if is_float => "%.3f",
+                _ => "%d",
+            };
+            let format_call = match attributes.get_format() {
+                Some(format) if prefix.is_empty() && suffix.is_empty() => {
+                    quote! { .display_format(#format) }
+                }
+                format if !prefix.is_empty() || !suffix.is_empty() => {
+                    let format = format.unwrap_or(default_format);
+                    let format = format!(
+                        "{}{format}{}",
+                        prefix.replace('%', "%%"),
+                        suffix.replace('%', "%%")
+                    );
+                    quote! { .display_format(#format) }
+                }
+                _ => quote! {},
+            };
+
+            let label = quote! {
+                &format!("{}##{:p}", #field_type_str, std::ptr::addr_of!(#field_place))
+            };
+            let edit = match (widget, range) {
+                (Widget::Slider | Widget::LogSlider, Some((min, max))) => {
+                    let flags = if widget == Widget::LogSlider {
+                        quote! { imgui::SliderFlags::ALWAYS_CLAMP | imgui::SliderFlags::LOGARITHMIC }
+                    } else {
+                        quote! { imgui::SliderFlags::ALWAYS_CLAMP }
+                    };
+                    quote! {
+                        #ui.slider_config(#label, #min, #max)
+                            #format_call
+                            .flags(#flags)
+                            .build(&mut shown)
+                    }
+                }
+                (Widget::Input, range) => {
+                    let step = attributes
+                        .get_speed()
+                        .map(|speed| quote! { .step(#speed as #field_type) });
+                    let clamp = range.map(|(min, max)| quote! { shown = shown.clamp(#min, #max); });
+                    quote! {{
+                        let changed = #ui.input_scalar(#label, &mut shown)
+                            #format_call
+                            #step
+                            .build();
+                        #clamp
+                        changed
+                    }}
+                }
+                (_, range) => {
+                    let range_call = range.map(|(min, max)| quote! { .range(#min, #max) });
+                    quote! {
+                        imgui::Drag::new(#label)
+                            #format_call
+                            #range_call
+                            .speed(#speed)
+                            .build(#ui, &mut shown)
+                    }
+                }
+            };
+
+            if mutable {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    let changed = #edit;
+                    if changed {
+                        #field_place = shown #from_shown;
+                    }
+                    imgui_presentable::EditResponse::from_last_imgui_item(#ui, changed)
+                }
+            } else {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    #ui.disabled(true, || {
+                        let _ = #edit;
+                    });
+                }
+            }
+        }
+        Backend::Egui => {
+            let format = attributes
+                .get_format()
+                .map(PrintfFormat::parse)
+                .unwrap_or_default();
+            let prefix = format!("{prefix}{}", format.prefix);
+            let suffix = format!("{}{suffix}", format.suffix);
+
+            let mut calls = quote! {};
+            if let Some(decimals) = format.decimals {
+                calls.extend(quote! { .fixed_decimals(#decimals) });
+            }
+            if !prefix.is_empty() {
+                calls.extend(quote! { .prefix(#prefix) });
+            }
+            if !suffix.is_empty() {
+                calls.extend(quote! { .suffix(#suffix) });
+            }
+
+            let edit = match (widget, range) {
+                (Widget::Slider | Widget::LogSlider, Some((min, max))) => {
+                    if widget == Widget::LogSlider {
+                        calls.extend(quote! { .logarithmic(true) });
+                    }
+                    if let Some(speed) = attributes.get_speed() {
+                        calls.extend(quote! { .drag_value_speed(#speed as f64) });
+                    }
+                    quote! { egui::Slider::new(&mut shown, #min..=#max)#calls }
+                }
+                (_, range) => {
+                    // The inputs are only changed by typing the value in.
+                    if widget == Widget::Input {
+                        calls.extend(quote! { .speed(0.0) });
+                    } else {
+                        calls.extend(quote! { .speed(#speed) });
+                    }
+                    if let Some((min, max)) = range {
+                        calls.extend(quote! { .range(#min..=#max) });
+                    }
+                    quote! { egui::DragValue::new(&mut shown)#calls }
+                }
+            };
+
+            if mutable {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    let response = #ui.add(#edit);
+                    if response.changed() {
+                        #field_place = shown #from_shown;
+                    }
+                    response.into()
+                }
+            } else {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    #ui.add_enabled_ui(false, |ui| {
+                        let _ = ui.add(#edit);
+                    });
+                }
+            }
+        }
+        Backend::Ratatui => {
+            let format = attributes
+                .get_format()
+                .map(PrintfFormat::parse)
+                .unwrap_or_default();
+            let prefix = format!("{prefix}{}", format.prefix);
+            let suffix = format!("{}{suffix}", format.suffix);
+
+            // The keys change the values by larger steps than the
+            // mouse does, so the default speed isn't used. The widgets
+            // are all edited the same way, only the units are kept.
+            let mut calls = quote! {};
+            if let Some(speed) = attributes.get_speed() {
+                calls.extend(quote! { .step(#speed as f64) });
+            }
+            if let Some((min, max)) = range {
+                calls.extend(quote! { .range(#min, #max) });
+            }
+            if let Some(decimals) = format.decimals {
+                calls.extend(quote! { .decimals(#decimals) });
+            }
+            if !prefix.is_empty() {
+                calls.extend(quote! { .prefix(#prefix) });
+            }
+            if !suffix.is_empty() {
+                calls.extend(quote! { .suffix(#suffix) });
+            }
+
+            if mutable {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    let response = imgui_presentable::tui::TuiScalar::new(&mut shown)#calls.show(#ui);
+                    if response.changed {
+                        #field_place = shown #from_shown;
+                    }
+                    response
+                }
+            } else {
+                quote! {
+                    let mut shown = #field_place #to_shown;
+                    #ui.disabled(true, |ui| {
+                        let _ = imgui_presentable::tui::TuiScalar::new(&mut shown)#calls.show(ui);
+                    });
+                }
+            }
+        }
+    }
+}
+
+/// Generates the read-only bar of a numeric primitive field with the
+/// `progress` widget, filled by the part of the `range` the value is
+/// at, or by the value itself if there is no range.
 #[allow(clippy::too_many_arguments)]
-fn generate_ui_field_for_struct(
+fn generate_progress_bar(
     ui: &Ident,
-    extent: &Ident,
+    field_place: &proc_macro2::TokenStream,
+    range: Option<(proc_macro2::TokenStream, proc_macro2::TokenStream)>,
+    prefix: &str,
+    suffix: &str,
+    attributes: &Attributes,
+    mutable: bool,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let fraction = match range {
+        Some((min, max)) => quote! {
+            ((#field_place as f64 - #min as f64) / (#max as f64 - #min as f64)) as f32
+        },
+        None => quote! { #field_place as f32 },
+    };
+
+    let format = attributes
+        .get_format()
+        .map(PrintfFormat::parse)
+        .unwrap_or_default();
+    let prefix = format!("{prefix}{}", format.prefix);
+    let suffix = format!("{}{suffix}", format.suffix);
+    let text = match format.decimals {
+        Some(decimals) => quote! {
+            format!("{}{:.*}{}", #prefix, #decimals, #field_place, #suffix)
+        },
+        None => quote! { format!("{}{}{}", #prefix, #field_place, #suffix) },
+    };
+
+    let bar = match backend {
+        Backend::Imgui => quote! {
+            imgui::ProgressBar::new((#fraction).clamp(0.0, 1.0))
+                .overlay_text(#text)
+                .build(#ui);
+        },
+        Backend::Egui => quote! {
+            let _ = #ui.add(egui::ProgressBar::new((#fraction).clamp(0.0, 1.0)).text(#text));
+        },
+        Backend::Ratatui => quote! {
+            #ui.progress(#fraction, &#text);
+        },
+    };
+
+    if mutable {
+        quote! {
+            #bar
+            imgui_presentable::EditResponse::default()
+        }
+    } else {
+        bar
+    }
+}
+
+/// Generates the color picker of a field with the `color` attribute or
+/// widget.
+fn generate_ui_for_color(
+    ui: &Ident,
+    field_place: &proc_macro2::TokenStream,
+    field_type: &syn::Type,
+    options: ColorOptions,
+    mutable: bool,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let mut options_code = quote! { imgui_presentable::color::ColorOptions::new() };
+    if options.no_alpha {
+        options_code.extend(quote! { .without_alpha() });
+    }
+    if options.hdr {
+        options_code.extend(quote! { .with_hdr() });
+    }
+    if options.linear {
+        options_code.extend(quote! { .with_linear() });
+    }
+
+    // The errors of the types which aren't colors point at the type.
+    let function = match (backend, mutable) {
+        (Backend::Imgui, true) => quote! { edit_imgui },
+        (Backend::Imgui, false) => quote! { show_imgui },
+        (Backend::Egui, true) => quote! { edit_egui },
+        (Backend::Egui, false) => quote! { show_egui },
+        (Backend::Ratatui, true) => quote! { edit_tui },
+        (Backend::Ratatui, false) => quote! { show_tui },
+    };
+    let function = quote_spanned! { field_type.span() =>
+        imgui_presentable::color::#function::<#field_type>
+    };
+    let label = match backend {
+        Backend::Imgui => quote! { &format!("##{:p}", std::ptr::addr_of!(#field_place)), },
+        _ => quote! {},
+    };
+
+    if mutable {
+        quote! { #function(#ui, #label &mut #field_place, #options_code) }
+    } else {
+        quote! { #function(#ui, #label &#field_place, #options_code); }
+    }
+}
+
+/// The way a presentation accesses the fields it shows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Access {
+    /// The fields are only shown.
+    Immutable,
+    /// The fields are changed through a mutable reference.
+    Mutable,
+    /// The fields with interior mutability are changed through a shared
+    /// reference, and the rest are only shown.
+    Shared,
+}
+
+/// Generates the name a field is shown with, which is passed to
+/// `stringify!`.
+fn generate_field_name(
     struct_name: &Ident,
     field_ident: &Option<Ident>,
     field_order: usize,
     total_field_count: usize,
-    field_type: &TypePath,
     attributes: &Attributes,
-    mutable: bool,
-    backend: Backend,
 ) -> proc_macro2::TokenStream {
-    let (field_name, field_ident) = match field_ident {
+    match field_ident {
         Some(s) => {
             if let Some(rename) = attributes.get_rename() {
-                (quote! { #rename }, quote! { #s })
+                quote! { #rename }
             } else {
-                (quote! { #s }, quote! { #s })
+                quote! { #s }
             }
         }
         None => {
             let index = syn::Index::from(field_order);
 
             if total_field_count == 1 {
-                (quote! { #struct_name }, quote! { #index })
+                quote! { #struct_name }
             } else {
-                (quote! { #struct_name.#index }, quote! { #index })
+                quote! { #struct_name.#index }
             }
         }
+    }
+}
+
+/// Generates the expression telling whether the name or the value of
+/// a field matches the `filter`, see
+/// `imgui_presentable::ImguiPresentable::matches_filter`. The ratatui
+/// backend doesn't filter the fields, so nothing is generated for it.
+pub(crate) fn generate_field_matches_filter(
+    struct_name: &Ident,
+    field_ident: &Option<Ident>,
+    field_place: &proc_macro2::TokenStream,
+    field_order: usize,
+    total_field_count: usize,
+    attributes: &Attributes,
+    backend: Backend,
+) -> Option<proc_macro2::TokenStream> {
+    let field_name = generate_field_name(
+        struct_name,
+        field_ident,
+        field_order,
+        total_field_count,
+        attributes,
+    );
+    let trait_name = match backend {
+        Backend::Imgui => quote! { imgui_presentable::ImguiPresentable },
+        Backend::Egui => quote! { imgui_presentable::EguiPresentable },
+        Backend::Ratatui => return None,
     };
 
-    let readonly_override = attributes.has_readonly();
-    let mutable = mutable && !readonly_override;
-
-    let field_type_string = field_type
-        .path
-        .segments
-        .last()
-        .expect("Couldn't reach the type of the field.")
-        .ident
-        .to_string();
-    let field_type_str = field_type_string.as_ref();
-    let is_numeric_primitive = matches!(
-        field_type_str,
-        "usize"
-            | "isize"
-            | "u64"
-            | "i64"
-            | "f64"
-            | "f32"
-            | "u32"
-            | "i32"
-            | "u16"
-            | "i16"
-            | "u8"
-            | "i8"
+    // The names of the flattened fields aren't shown.
+    let matches = if attributes.has_flatten() {
+        quote! { (&#field_place as &dyn #trait_name).matches_filter(filter) }
+    } else {
+        quote! {
+            (filter.matches(&stringify!(#field_name).replace('"', ""))
+                || (&#field_place as &dyn #trait_name).matches_filter(filter))
+        }
+    };
+
+    // The hidden fields don't match.
+    Some(match attributes.get_visible_if() {
+        Some(condition) => quote! { ((#condition) && #matches) },
+        None => matches,
+    })
+}
+
+/// Generates the presentation of a single field of a struct or an enum
+/// variant.
+///
+/// The `field_place` is the place expression the field is reachable
+/// by, like `self.field` for a struct or `(*field)` for a field bound
+/// in a `match` arm of an enum, and the `owner_pointer` is the
+/// expression of the pointer to the owner object, used to make the
+/// ImGui identifiers unique.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn generate_ui_field_for_struct(
+    ui: &Ident,
+    extent: &Ident,
+    struct_name: &Ident,
+    field_ident: &Option<Ident>,
+    field_place: &proc_macro2::TokenStream,
+    owner_pointer: &proc_macro2::TokenStream,
+    field_order: usize,
+    total_field_count: usize,
+    field_type: &syn::Type,
+    attributes: &Attributes,
+    access: Access,
+    grid: bool,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let field_name = generate_field_name(
+        struct_name,
+        field_ident,
+        field_order,
+        total_field_count,
+        attributes,
     );
-    let format_attribute = attributes.get_format();
-    let drag_range_attribute = attributes.get_range();
-    let drag_speed_attribute = attributes.get_speed();
-    let numeric_primitive_render = if is_numeric_primitive {
-        let mut code = quote! {};
 
-        let format_call = if let Some(format) = format_attribute {
-            quote! {
-                .display_format(#format)
-            }
-        } else {
-            quote! {}
-        };
+    let readonly_override = attributes.has_readonly();
+    let mutable = access == Access::Mutable && !readonly_override;
 
-        let range_call = if let Some((min, max)) = drag_range_attribute {
-            let min = min
-                .map(|s| s.to_token_stream())
-                .unwrap_or_else(|| quote! { #field_type::MIN });
-            let max = max
-                .map(|s| s.to_token_stream())
-                .unwrap_or_else(|| quote! { #field_type::MAX });
-            quote! {
-                .range(#min, #max)
-            }
-        } else {
-            quote! {}
+    // The flattened fields are shown without the nodes, and the inline
+    // ones as the rows of the name and the value, like in the grid.
+    let flatten = attributes.has_flatten();
+    let inline = attributes.has_inline() && backend != Backend::Ratatui;
+    let row = (grid || inline) && !flatten;
+
+    let field_type_string = scalar_type_name(field_type);
+    let field_type_str = field_type_string.as_ref();
+    // The scalars and the fields with a widget chosen are edited with
+    // the widgets directly, instead of their presentations.
+    let widget_render = if let Some(options) = attributes.get_color() {
+        Some(generate_ui_for_color(
+            ui,
+            field_place,
+            field_type,
+            options,
+            mutable,
+            backend,
+        ))
+    } else if is_numeric_primitive(field_type_str) {
+        Some(generate_ui_for_numeric_primitive(
+            ui,
+            field_place,
+            field_type,
+            field_type_str,
+            attributes,
+            mutable,
+            backend,
+        ))
+    } else {
+        None
+    };
+    let has_widget = widget_render.is_some();
+    let widget_render = widget_render.unwrap_or_default();
+    // Only the values with interior mutability may be changed through a
+    // shared reference, so the widgets are shown like the immutable ones.
+    let shared = access == Access::Shared && !readonly_override && !has_widget;
+    let edited = mutable || shared;
+
+    // The collections of the field are split into pages of the size
+    // specified.
+    let paginated = |render: proc_macro2::TokenStream| {
+        let render = match attributes.get_page_size() {
+            Some(page_size) => quote! {
+                imgui_presentable::pagination::with_page_size(#page_size, || { #render })
+            },
+            None => render,
         };
+        // The struct in the field, which has to be marked `inline` too,
+        // is shown on the same row.
+        if inline {
+            let with_inline = quote_spanned! {field_type.span()=>
+                imgui_presentable::layout::with_inline::<#field_type, _>
+            };
+            quote! { #with_inline(|| { #render }) }
+        } else {
+            render
+        }
+    };
 
-        let speed_call = if let Some(speed) = drag_speed_attribute {
-            quote! {
-                .speed(#speed)
-            }
-        } else if matches!(field_type_str, "f32" | "f64") {
+    // With the `serde` feature, the field may be copied and pasted from
+    // the context menu of its node.
+    let context_menu = |function: &str, response: proc_macro2::TokenStream| {
+        if !cfg!(feature = "serde") {
+            return quote! {};
+        }
+        let actions = generate_serde_actions(field_type, has_lifetimes(field_type));
+        if mutable {
+            let function = format_ident!("{}_mut", function);
             quote! {
-                .speed(0.001f32)
+                response |= imgui_presentable::serialization::#function(#response, &mut #field_place, #actions);
             }
         } else {
+            let function = format_ident!("{}", function);
             quote! {
-                .speed(0.2f32)
+                imgui_presentable::serialization::#function(#response, &#field_place, #actions);
             }
-        };
-
-        if mutable {
-            code.extend(quote! {
-                let _ = imgui::Drag::new(&format!("{}##{:p}", #field_type_str, std::ptr::addr_of!(self.#field_ident)))
-                    #format_call
-                    #range_call
-                    #speed_call
-                    .build(&ui, &mut self.#field_ident);
-            });
-        } else {
-            code.extend(quote! {
-                let mut data = self.#field_ident;
-                ui.disabled(true, || {
-                    let _ = imgui::Drag::new(&format!("{}##{:p}", #field_type_str, std::ptr::addr_of!(self.#field_ident)))
-                        #format_call
-                        #range_call
-                        #speed_call
-                        .build(&ui, &mut data);
-                });
-            });
         }
-        code
-    } else {
-        quote! {}
     };
-    // panic!("{numeric_primitive_render}");
 
     let element_subtree = match backend {
         Backend::Imgui => {
+            let context_menu = context_menu("context_menu_imgui", quote! { #ui });
             let ui_element = {
                 let mut code = quote! {
-                    let _id = #ui.push_id(&format!("{}##{:p}", stringify!(#field_name), std::ptr::addr_of!(self.#field_ident)));
+                    let _id = #ui.push_id(&format!("{}##{:p}", stringify!(#field_name), std::ptr::addr_of!(#field_place)));
                 };
 
+                if has_widget {
+                    code.extend(quote! {
+                        let _highlight = imgui_presentable::filter::highlight_imgui(#ui, filter_match.value_matches);
+                    });
+                }
+
                 if mutable {
                     code.extend(
-                    if is_numeric_primitive {
-                            numeric_primitive_render
+                    if has_widget {
+                            widget_render
                         } else {
-                            quote! {
-                                (&mut self.#field_ident as &mut dyn imgui_presentable::ImguiPresentable).render_component_mut(#ui, #extent);
-                            }
+                            paginated(quote! {
+                                (&mut #field_place as &mut dyn imgui_presentable::ImguiPresentable).render_component_mut(#ui, #extent)
+                            })
                         });
+                } else if shared {
+                    code.extend(paginated(quote! {
+                        (&#field_place as &dyn imgui_presentable::ImguiPresentable).render_component_shared(#ui, #extent)
+                    }));
                 } else {
                     code.extend(
-                        if is_numeric_primitive {
-                            numeric_primitive_render
+                        if has_widget {
+                            widget_render
                         } else {
-                            quote! {
-                                (&self.#field_ident as &dyn imgui_presentable::ImguiPresentable).render_component(#ui, #extent);
-                            }
+                            let render = paginated(quote! {
+                                (&#field_place as &dyn imgui_presentable::ImguiPresentable).render_component(#ui, #extent)
+                            });
+                            quote! { #render; }
                         });
                 };
 
-                if let Some(text) = attributes.get_tooltip_or_documentation() {
+                // The documentation is only shown on hover in the rows.
+                if let Some(text) = attributes.get_tooltip_or_documentation().filter(|_| !row) {
                     let mut tooltip = quote! {
                         {
                             let style = #ui.push_style_color(imgui::StyleColor::Text, [0.5, 0.5, 0.5, 1.0]);
@@ -175,32 +818,132 @@ fn generate_ui_field_for_struct(
                 code
             };
 
-            quote! {
-                let field_name = stringify!(#field_name).replace('"', "");
-                // #ui.separator();
-                #ui.tree_node_config(&format!("{field_name}##{self:p}"))
-                    .opened(true, imgui::Condition::FirstUseEver)
-                    .framed(true)
-                    .build(|| {
-                        #ui_element
-                    });
+            // The nodes containing the matches are expanded, and the
+            // name of the field is highlighted if it matches.
+            let node = quote! {
+                let condition = if filter_match.value_matches {
+                    imgui::Condition::Always
+                } else {
+                    imgui::Condition::FirstUseEver
+                };
+                let node = {
+                    let _highlight = imgui_presentable::filter::highlight_imgui(#ui, filter_match.name_matches);
+                    #ui.tree_node_config(&format!("{field_name}##{:p}", #owner_pointer))
+                        .opened(true, condition)
+                        .framed(true)
+                        .push()
+                };
+            };
+
+            let render = quote! {
+                filter_match.render(|| {
+                    #ui_element
+                })
+            };
+            let render = if edited {
+                quote! { response |= #render; }
+            } else {
+                quote! { #render; }
+            };
+
+            if flatten {
+                render
+            } else if row {
+                // The name and the value are the cells of a table row, or
+                // are on the same line. A table row ends once its last
+                // column is filled. The widgets fill the cells of the
+                // values, so that their labels don't spill into the next
+                // cells of the narrow columns of the inline rows.
+                let (name_cell, value_cell, size) = if grid {
+                    (
+                        quote! { #ui.table_next_column(); },
+                        quote! {
+                            #ui.table_next_column();
+                            let _width = #ui.push_item_width(-f32::MIN_POSITIVE);
+                        },
+                        quote! { [0.0, 0.0] },
+                    )
+                } else {
+                    (
+                        quote! {},
+                        quote! { #ui.same_line(); },
+                        quote! { [#ui.calc_text_size(&field_name)[0], 0.0] },
+                    )
+                };
+                quote! {
+                    #name_cell
+                    {
+                        // Selectable, so that the context menu has an item
+                        // to be opened on.
+                        let _highlight = imgui_presentable::filter::highlight_imgui(#ui, filter_match.name_matches);
+                        let _ = #ui
+                            .selectable_config(format!("{field_name}##{:p}", #owner_pointer))
+                            .size(#size)
+                            .build();
+                    }
+                    #context_menu
+                    {
+                        #value_cell
+                        #render
+                    }
+                }
+            } else if edited {
+                quote! {
+                    #node
+                    #context_menu
+                    if let Some(_node) = node {
+                        response |= filter_match.render(|| {
+                            #ui_element
+                        });
+                    }
+                }
+            } else {
+                quote! {
+                    #node
+                    #context_menu
+                    if let Some(_node) = node {
+                        filter_match.render(|| {
+                            #ui_element
+                        });
+                    }
+                }
             }
         }
         Backend::Egui => {
+            let header_response = if row {
+                quote! { &_label }
+            } else {
+                quote! { &_shown.header_response }
+            };
+            let hover_text = attributes
+                .get_tooltip_or_documentation()
+                .map(|text| quote! { let _ = (#header_response).clone().on_hover_text(#text); });
+            let context_menu = context_menu("context_menu_egui", header_response);
             let ui_element = {
                 let mut code = quote! {};
 
-                if mutable {
+                if has_widget {
                     code.extend(quote! {
-                        (&mut self.#field_ident as &mut dyn imgui_presentable::EguiPresentable).render_component_mut(#ui);
+                        imgui_presentable::filter::highlight_egui(#ui, filter_match.value_matches, |#ui| {
+                            #widget_render
+                        })
                     });
+                } else if mutable {
+                    code.extend(paginated(quote! {
+                        (&mut #field_place as &mut dyn imgui_presentable::EguiPresentable).render_component_mut(#ui)
+                    }));
+                } else if shared {
+                    code.extend(paginated(quote! {
+                        (&#field_place as &dyn imgui_presentable::EguiPresentable).render_component_shared(#ui)
+                    }));
                 } else {
-                    code.extend(quote! {
-                        (&self.#field_ident as &dyn imgui_presentable::EguiPresentable).render_component(#ui);
+                    let render = paginated(quote! {
+                        (&#field_place as &dyn imgui_presentable::EguiPresentable).render_component(#ui)
                     });
+                    code.extend(quote! { #render; });
                 };
 
-                if let Some(text) = attributes.get_tooltip_or_documentation() {
+                if let Some(text) = attributes.get_tooltip_or_documentation().filter(|_| !row) {
                     let mut tooltip = quote! {
                         {
                             #ui.label(#text);
@@ -214,12 +957,141 @@ fn generate_ui_field_for_struct(
                 code
             };
 
-            quote! {
-                let field_name = stringify!(#field_name).replace('"', "");
-                // #ui.separator();
-                #ui.collapsing(field_name, |ui| {
-                    #ui_element
-                });
+            // The sections containing the matches are expanded, and the
+            // name of the field is highlighted if it matches.
+            let header = quote! {
+                egui::CollapsingHeader::new(imgui_presentable::filter::highlighted_egui_text(
+                    field_name,
+                    filter_match.name_matches,
+                ))
+                .open(filter_match.value_matches.then_some(true))
+            };
+
+            if flatten {
+                if edited {
+                    quote! { response |= filter_match.render(|| { #ui_element }); }
+                } else {
+                    quote! { filter_match.render(|| { #ui_element }); }
+                }
+            } else if row {
+                // The name and the value are the cells of a grid row, or
+                // are laid out horizontally, so the errors and the
+                // disabling are added within the cell of the value.
+                let render = if edited {
+                    quote! { response |= filter_match.render(|| { #ui_element }); }
+                } else {
+                    quote! { filter_match.render(|| { #ui_element }); }
+                };
+                let render = validate_impl::wrap_field_ui(
+                    ui,
+                    field_place,
+                    field_type,
+                    attributes,
+                    mutable,
+                    backend,
+                    render,
+                );
+                let enabled = match attributes.get_enabled_if().filter(|_| edited) {
+                    Some(condition) => quote! { #condition },
+                    None => quote! { true },
+                };
+                let cells = quote! {
+                    let enabled: bool = #enabled;
+                    let field_id = egui::Id::new(&field_name);
+                    let _label = #ui.add_enabled(
+                        enabled,
+                        egui::Label::new(imgui_presentable::filter::highlighted_egui_text(
+                            field_name,
+                            filter_match.name_matches,
+                        ))
+                        .sense(egui::Sense::click()),
+                    );
+                    #hover_text
+                    #context_menu
+                    // The cells of the rows share the `Ui`, so the
+                    // widgets of the values are scoped by the field names.
+                    #ui.push_id(field_id, |#ui| {
+                        #ui.vertical(|#ui| {
+                            if !enabled {
+                                #ui.disable();
+                            }
+                            #render
+                        });
+                    });
+                    #ui.end_row();
+                };
+                if grid {
+                    cells
+                } else {
+                    quote! {
+                        #ui.horizontal(|#ui| {
+                            #cells
+                        });
+                    }
+                }
+            } else if edited {
+                quote! {
+                    let _shown = #header.show(#ui, |ui| filter_match.render(|| { #ui_element }));
+                    response |= _shown.body_returned.unwrap_or_default();
+                    #hover_text
+                    #context_menu
+                }
+            } else {
+                quote! {
+                    let _shown = #header.show(#ui, |ui| {
+                        filter_match.render(|| {
+                            #ui_element
+                        });
+                    });
+                    #hover_text
+                    #context_menu
+                }
+            }
+        }
+        Backend::Ratatui => {
+            let ui_element = if has_widget {
+                widget_render
+            } else if mutable {
+                quote! {
+                    (&mut #field_place as &mut dyn imgui_presentable::TuiPresentable).render_component_mut(#ui)
+                }
+            } else if shared {
+                quote! {
+                    (&#field_place as &dyn imgui_presentable::TuiPresentable).render_component_shared(#ui)
+                }
+            } else {
+                quote! {
+                    (&#field_place as &dyn imgui_presentable::TuiPresentable).render_component(#ui);
+                }
+            };
+
+            // The documentation is shown while the field is focused.
+            let hint = attributes
+                .get_tooltip_or_documentation()
+                .map(|text| quote! { #ui.hint(#text); });
+
+            if flatten {
+                if edited {
+                    quote! { response |= { #ui_element }; }
+                } else {
+                    quote! { #ui_element }
+                }
+            } else if edited {
+                quote! {
+                    let field_name = stringify!(#field_name).replace('"', "");
+                    #hint
+                    response |= #ui.field(&field_name, |ui| {
+                        #ui_element
+                    });
+                }
+            } else {
+                quote! {
+                    let field_name = stringify!(#field_name).replace('"', "");
+                    #hint
+                    #ui.field(&field_name, |ui| {
+                        #ui_element
+                    });
+                }
             }
         }
     };
@@ -228,34 +1100,325 @@ fn generate_ui_field_for_struct(
         #element_subtree
     };
 
-    #[allow(unused_variables)]
-    if let Some(tooltip_text) = attributes.get_tooltip_or_documentation() {
-        match backend {
-            Backend::Imgui => {
-                generated.extend(quote! {
-                    if #ui.is_item_hovered() {
-                        #ui.tooltip_text(#tooltip_text);
-                    }
-                });
+    // The egui fields show the tooltips on hovering their names.
+    if let Some(tooltip_text) = attributes
+        .get_tooltip_or_documentation()
+        .filter(|_| !flatten && backend == Backend::Imgui)
+    {
+        generated.extend(quote! {
+            if #ui.is_item_hovered() {
+                #ui.tooltip_text(#tooltip_text);
             }
-            Backend::Egui => {
-                generated.extend(quote! {
-                    // TODO
+        });
+    }
+
+    // The egui rows show the errors and disable the value by themselves,
+    // as the grid cells can't be wrapped.
+    let egui_row = row && backend == Backend::Egui;
+    if !egui_row {
+        generated = validate_impl::wrap_field_ui(
+            ui,
+            field_place,
+            field_type,
+            attributes,
+            mutable,
+            backend,
+            generated,
+        );
+    }
+
+    // The field is grayed out unless the condition on `self` holds.
+    if let Some(condition) = attributes.get_enabled_if().filter(|_| edited && !egui_row) {
+        let disabled = match backend {
+            Backend::Imgui => quote! {
+                let _disabled = #ui.begin_disabled(!enabled);
+                #generated
+            },
+            Backend::Egui => quote! {
+                #ui.add_enabled_ui(enabled, |#ui| {
+                    #generated
+                });
+            },
+            Backend::Ratatui => quote! {
+                #ui.disabled(!enabled, |#ui| {
+                    #generated
                 });
+            },
+        };
+        generated = quote! {
+            {
+                let enabled: bool = #condition;
+                #disabled
             }
-        }
+        };
+    }
+
+    // The fields which don't match the applied filter are hidden.
+    let trait_name = match backend {
+        Backend::Imgui => Some(quote! { imgui_presentable::ImguiPresentable }),
+        Backend::Egui => Some(quote! { imgui_presentable::EguiPresentable }),
+        Backend::Ratatui => None,
+    };
+    // The names of the flattened fields aren't shown.
+    let (name, name_matches) = if flatten {
+        (quote! {}, quote! { |_| false })
+    } else {
+        (
+            quote! { let field_name = stringify!(#field_name).replace('"', ""); },
+            quote! { |filter| filter.matches(&field_name) },
+        )
+    };
+    if let Some(trait_name) = trait_name {
+        generated = quote! {
+            #name
+            {
+                let filter_match = imgui_presentable::filter::FilterMatch::of(
+                    #name_matches,
+                    |filter| (&#field_place as &dyn #trait_name).matches_filter(filter),
+                );
+                if filter_match.shown {
+                    #generated
+                }
+            };
+        };
+    }
+
+    // The field is only shown while the condition on `self` holds.
+    if let Some(condition) = attributes.get_visible_if() {
+        generated = quote! {
+            let visible: bool = #condition;
+            if visible {
+                #generated
+            }
+        };
     }
 
     generated
 }
 
-fn get_type(typ: &syn::Type) -> TypePath {
+/// Generates the `element_actions` method, allowing the sequences of
+/// the type to create and duplicate the elements if the type implements
+/// [`Default`] and [`Clone`]. The ratatui backend doesn't need one.
+pub(crate) fn generate_element_actions(backend: Backend) -> proc_macro2::TokenStream {
+    match backend {
+        Backend::Imgui | Backend::Egui => quote! {
+            fn element_actions() -> imgui_presentable::elements::ElementActions<Self> {
+                imgui_presentable::element_actions!(Self)
+            }
+        },
+        Backend::Ratatui => quote! {},
+    }
+}
+
+/// Generates the `render_window` and `render_window_mut` methods. The
+/// windows are titled with the `rename` attribute of the type, if it
+/// has one, and placed and sized according to its `window` attribute.
+/// The imgui windows show the menu bar if the `menu` has the main menu
+/// items, which the components add to it, while the egui windows show
+/// the items of the `menu` in their menu bars themselves. With the
+/// `serde` feature, the windows also have the buttons saving the value
+/// as a preset and, if the value may be changed and doesn't borrow any
+/// data, loading it back. The ratatui backend uses the default methods.
+pub(crate) fn generate_render_window_methods(
+    backend: Backend,
+    attributes: &Attributes,
+    menu: &proc_macro2::TokenStream,
+    readonly: bool,
+    impl_generics: &syn::ImplGenerics<'_>,
+) -> proc_macro2::TokenStream {
+    let serde = cfg!(feature = "serde");
+    let borrowed = has_lifetimes(impl_generics);
+    let loadable = !readonly && !borrowed;
+    let suffix = match backend {
+        Backend::Imgui => "imgui",
+        Backend::Egui => "egui",
+        Backend::Ratatui => return quote! {},
+    };
+    let presets = format_ident!("presets_{}", suffix);
+    let presets_mut = format_ident!("presets_{}_mut", suffix);
+    let actions = generate_serde_actions(&quote! { Self }, borrowed);
+    let (immutable_presets, mutable_presets) = if !serde {
+        (quote! {}, quote! {})
+    } else if loadable {
+        (
+            quote! { imgui_presentable::serialization::#presets(ui, self, #actions); },
+            quote! { response |= imgui_presentable::serialization::#presets_mut(ui, self, #actions); },
+        )
+    } else {
+        (
+            quote! { imgui_presentable::serialization::#presets(ui, self, #actions); },
+            quote! { imgui_presentable::serialization::#presets(ui, &*self, #actions); },
+        )
+    };
+
+    let title = match attributes.get_rename() {
+        Some(name) => quote! { #name },
+        None => quote! { std::any::type_name::<Self>() },
+    };
+    let options = attributes.get_window();
+    let resizable = options.resizable.unwrap_or(true);
+    let [x, y] = options.position.unwrap_or_default();
+
+    match backend {
+        Backend::Imgui => {
+            let has_menu = !menu.is_empty();
+            let size = options
+                .size
+                .map(|[width, height]| quote! { .size([#width, #height], imgui::Condition::FirstUseEver) });
+            // The anchored windows are kept at their places relative to
+            // the display.
+            let position = match options.anchor.map(Anchor::fractions) {
+                Some([horizontal, vertical]) => quote! {
+                    .position(
+                        {
+                            let [width, height] = ui.io().display_size;
+                            [#horizontal * width + #x, #vertical * height + #y]
+                        },
+                        imgui::Condition::Always,
+                    )
+                    .position_pivot([#horizontal, #vertical])
+                    .movable(false)
+                },
+                None => quote! { .position([#x, #y], imgui::Condition::FirstUseEver) },
+            };
+            let window = quote! {
+                ui.window(#title)
+                    .resizable(#resizable)
+                    .collapsible(true)
+                    .bg_alpha(0.7f32)
+                    #size
+                    #position
+                    .menu_bar(#has_menu)
+            };
+
+            quote! {
+                /// Renders the implementor as a stand-alone window not allowing to
+                /// change the values.
+                fn render_window(&self, ui: &imgui::Ui, extent: imgui_presentable::Extent) {
+                    #window.build(|| {
+                        #immutable_presets
+                        (self as &dyn imgui_presentable::ImguiPresentable).render_component(ui, extent)
+                    });
+                }
+
+                /// Renders the implementor as a stand-alone window allowing to
+                /// change the values.
+                fn render_window_mut(&mut self, ui: &imgui::Ui, extent: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                    #window
+                        .build(|| {
+                            let mut response = imgui_presentable::EditResponse::default();
+                            #mutable_presets
+                            response |= (self as &mut dyn imgui_presentable::ImguiPresentable).render_component_mut(ui, extent);
+                            response
+                        })
+                        .unwrap_or_default()
+                }
+            }
+        }
+        Backend::Egui => {
+            let size = options
+                .size
+                .map(|[width, height]| quote! { .default_size([#width, #height]) });
+            let align = |fraction: f32| {
+                if fraction == 0.0 {
+                    quote! { egui::Align::Min }
+                } else if fraction == 1.0 {
+                    quote! { egui::Align::Max }
+                } else {
+                    quote! { egui::Align::Center }
+                }
+            };
+            let position = match options.anchor.map(Anchor::fractions) {
+                Some([horizontal, vertical]) => {
+                    let (horizontal, vertical) = (align(horizontal), align(vertical));
+                    quote! { .anchor(egui::Align2([#horizontal, #vertical]), [#x, #y]) }
+                }
+                None => options
+                    .position
+                    .map(|_| quote! { .default_pos([#x, #y]) })
+                    .unwrap_or_default(),
+            };
+            let window = quote! {
+                egui::Window::new(#title)
+                    .resizable(#resizable)
+                    #size
+                    #position
+            };
+            let menu = if menu.is_empty() {
+                quote! {}
+            } else {
+                quote! {
+                    egui::MenuBar::new().ui(ui, |ui| {
+                        #menu
+                    });
+                }
+            };
+
+            quote! {
+                /// Renders the implementor as a stand-alone window not allowing to
+                /// change the values.
+                fn render_window(&self, context: &egui::Context) {
+                    #window.show(context, |ui| {
+                        #immutable_presets
+                        (self as &dyn imgui_presentable::EguiPresentable).render_component(ui)
+                    });
+                }
+
+                /// Renders the implementor as a stand-alone window allowing to
+                /// change the values.
+                fn render_window_mut(&mut self, context: &egui::Context) -> imgui_presentable::EditResponse {
+                    #window
+                        .show(context, |ui| {
+                            let mut response = imgui_presentable::EditResponse::default();
+                            #menu
+                            #mutable_presets
+                            response |= (self as &mut dyn imgui_presentable::EguiPresentable).render_component_mut(ui);
+                            response
+                        })
+                        .and_then(|inner| inner.inner)
+                        .unwrap_or_default()
+                }
+            }
+        }
+        Backend::Ratatui => quote! {},
+    }
+}
+
+/// Returns `true` if the tokens, like the ones of a type, mention a
+/// lifetime.
+fn has_lifetimes(tokens: &impl ToTokens) -> bool {
+    fn check(tokens: proc_macro2::TokenStream) -> bool {
+        tokens.into_iter().any(|token| match token {
+            proc_macro2::TokenTree::Punct(punct) => punct.as_char() == '\'',
+            proc_macro2::TokenTree::Group(group) => check(group.stream()),
+            _ => false,
+        })
+    }
+
+    check(tokens.to_token_stream())
+}
+
+/// Generates the `SerdeActions` of the type, probing whether it may be
+/// copied and pasted. The types borrowing the data, which mention the
+/// lifetimes, may only be copied: the probing ignores the lifetimes and
+/// would pick their `Deserialize` implementations, which aren't general
+/// enough.
+fn generate_serde_actions(typ: &impl ToTokens, borrowed: bool) -> proc_macro2::TokenStream {
+    if borrowed {
+        quote_spanned! {typ.span()=> imgui_presentable::serde_actions!(#typ, borrowed) }
+    } else {
+        quote_spanned! {typ.span()=> imgui_presentable::serde_actions!(#typ) }
+    }
+}
+
+/// Returns the type of a field, looking through the parentheses and
+/// the invisible groups the types passed through the macros are
+/// wrapped into.
+pub(crate) fn get_type(typ: &syn::Type) -> syn::Type {
     match typ {
-        syn::Type::Path(path) => path.clone(),
-        _ => panic!(
-            "Complex types aren't supported yet: {}",
-            typ.into_token_stream()
-        ),
+        syn::Type::Group(group) => get_type(&group.elem),
+        syn::Type::Paren(paren) => get_type(&paren.elem),
+        typ => typ.clone(),
     }
 }
 
@@ -265,23 +1428,37 @@ pub(crate) fn derive_for_struct(
     strukt: syn::DataStruct,
     backends: &[Backend],
 ) -> proc_macro2::TokenStream {
-    let struct_name = derive_input.ident;
-    let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+    let struct_name = &derive_input.ident;
     let struct_attributes = match Attributes::parse_many(&derive_input.attrs) {
         Ok(a) => a,
         Err(c) => return c,
     };
 
     // Validate the arguments:
-    if struct_attributes.has_skip() {
-        return quote! { compile_error!("Structs shouldn't have the #[imgui_presentation(skip)] attribute.") };
+    if let Some(span) = struct_attributes.span_of("skip") {
+        return attributes::to_compile_error(syn::Error::new(
+            span,
+            "Structs shouldn't have the #[imgui_presentation(skip)] attribute.",
+        ));
+    }
+    if let Err(e) = check_struct_field_only(&struct_attributes)
+        .and_then(|()| check_field_only(&struct_attributes, &["flatten"]))
+    {
+        return e;
     }
 
-    let fields: Vec<(Option<proc_macro2::Ident>, TypePath, Attributes)> = match strukt.fields {
+    let fields: Vec<(Option<proc_macro2::Ident>, syn::Type, Attributes)> = match strukt.fields {
         Fields::Named(named) => match named
             .named
             .into_iter()
-            .map(|f| Attributes::parse_from_field(&f).map(move |a| (a, f)))
+            .map(|f| {
+                let a = Attributes::parse_from_field(&f)?;
+                check_widget(&get_type(&f.ty), &a)?;
+                validate_impl::check_field_checks(&a)?;
+                check_no_layout(&a)?;
+                check_item_only(&a)?;
+                Ok((a, f))
+            })
             .collect::<attributes::Result<Vec<(Attributes, syn::Field)>>>()
         {
             Ok(a) => a
@@ -293,9 +1470,23 @@ pub(crate) fn derive_for_struct(
         Fields::Unnamed(unnamed) => match unnamed
             .unnamed
             .into_iter()
-            .map(|f| Attributes::parse_from_field(&f).map(move |a| (a, f)))
+            .map(|f| {
+                let a = Attributes::parse_from_field(&f)?;
+                check_widget(&get_type(&f.ty), &a)?;
+                validate_impl::check_field_checks(&a)?;
+                check_no_layout(&a)?;
+                check_item_only(&a)?;
+                Ok((a, f))
+            })
             .collect::<attributes::Result<Vec<(Attributes, syn::Field)>>>()
         {
+            // The single field of a newtype isn't put into another node.
+            Ok(mut a) if a.len() == 1 => {
+                a[0].0.flatten_by_default();
+                a.into_iter()
+                    .map(|(a, f)| (f.ident.clone(), get_type(&f.ty), a))
+                    .collect()
+            }
             Ok(a) => a
                 .into_iter()
                 .map(|(a, f)| (f.ident.clone(), get_type(&f.ty), a))
@@ -312,6 +1503,22 @@ pub(crate) fn derive_for_struct(
 
     let chosen_backend = struct_attributes.get_backends();
 
+    // The validation and the reflection don't depend on the backend.
+    let mut validation =
+        validate_impl::derive_for_struct(&derive_input, &fields, &struct_attributes);
+    validation.extend(reflect_impl::derive_for_struct(
+        &derive_input,
+        &fields,
+        &struct_attributes,
+    ));
+    // Only the structs marked `inline` may be shown on a single row.
+    if struct_attributes.has_inline() {
+        let (impl_generics, ty_generics, where_clause) = derive_input.generics.split_for_impl();
+        validation.extend(quote! {
+            impl #impl_generics imgui_presentable::layout::Inline for #struct_name #ty_generics #where_clause {}
+        });
+    }
+
     backends
         .iter()
         .filter(|b| {
@@ -321,18 +1528,16 @@ pub(crate) fn derive_for_struct(
                 chosen_backend.contains(b)
             }
         })
-        .fold(quote! {}, |mut implementation, backend| {
+        .fold(validation, |mut implementation, backend| {
             implementation.extend(generate_for_backend(
                 &ui_ident,
                 &extent_ident,
-                &struct_name,
+                struct_name,
                 total_field_count,
                 *backend,
                 &fields,
                 &struct_attributes,
-                &impl_generics,
-                &ty_generics,
-                &where_clause,
+                &derive_input.generics,
             ));
             implementation
         })
@@ -345,57 +1550,114 @@ fn generate_for_backend(
     struct_name: &Ident,
     total_field_count: usize,
     backend: Backend,
-    fields: &[(Option<proc_macro2::Ident>, TypePath, Attributes)],
+    fields: &[(Option<proc_macro2::Ident>, syn::Type, Attributes)],
     struct_attributes: &Attributes,
-    impl_generics: &syn::ImplGenerics<'_>,
-    ty_generics: &syn::TypeGenerics<'_>,
-    where_clause: &Option<&syn::WhereClause>,
+    generics: &syn::Generics,
 ) -> proc_macro2::TokenStream {
-    let ui_elements: Vec<proc_macro2::TokenStream> = fields
+    let generics = bounds::with_bounds(
+        struct_name,
+        generics,
+        struct_attributes,
+        Some(backend),
+        fields
+            .iter()
+            .filter(|f| !f.2.has_skip())
+            .map(|f| (&f.1, bounds::backend_trait(backend))),
+    );
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    let self_pointer = quote! { self };
+    let grid = struct_attributes.get_layout() == Layout::Grid && backend != Backend::Ratatui;
+    let field_place = |i: usize, ident: &Option<Ident>| match ident {
+        Some(ident) => quote! { self.#ident },
+        None => {
+            let index = syn::Index::from(i);
+            quote! { self.#index }
+        }
+    };
+
+    let generate_fields = |access: Access, grid: bool| -> Vec<proc_macro2::TokenStream> {
+        fields
+            .iter()
+            .enumerate()
+            .filter(|(_, f)| !f.2.has_skip())
+            .map(|(i, f)| {
+                generate_ui_field_for_struct(
+                    ui_ident,
+                    extent_ident,
+                    struct_name,
+                    &f.0,
+                    &field_place(i, &f.0),
+                    &self_pointer,
+                    i,
+                    total_field_count,
+                    &f.1,
+                    &f.2,
+                    access,
+                    grid,
+                    backend,
+                )
+            })
+            .collect()
+    };
+    let readonly = struct_attributes.has_readonly();
+
+    let ui_elements = generate_fields(Access::Immutable, grid);
+    let (ui_elements_mut, ui_elements_shared) = if readonly {
+        (Vec::default(), Vec::default())
+    } else {
+        (
+            generate_fields(Access::Mutable, grid),
+            generate_fields(Access::Shared, grid),
+        )
+    };
+
+    // The struct marked `inline` may be shown on a single row, with the
+    // fields presented like in the grid.
+    let inline = struct_attributes.has_inline() && backend != Backend::Ratatui;
+    let (ui_elements_inline, ui_elements_mut_inline, ui_elements_shared_inline) = match backend {
+        _ if !inline => (Vec::default(), Vec::default(), Vec::default()),
+        _ if grid => (
+            ui_elements.clone(),
+            ui_elements_mut.clone(),
+            ui_elements_shared.clone(),
+        ),
+        _ if readonly => (
+            generate_fields(Access::Immutable, true),
+            Vec::default(),
+            Vec::default(),
+        ),
+        _ => (
+            generate_fields(Access::Immutable, true),
+            generate_fields(Access::Mutable, true),
+            generate_fields(Access::Shared, true),
+        ),
+    };
+
+    let field_matches: Vec<proc_macro2::TokenStream> = fields
         .iter()
-        .filter(|f| !f.2.has_skip())
         .enumerate()
-        .map(|(i, f)| {
-            generate_ui_field_for_struct(
-                ui_ident,
-                extent_ident,
+        .filter(|(_, f)| !f.2.has_skip())
+        .filter_map(|(i, f)| {
+            generate_field_matches_filter(
                 struct_name,
                 &f.0,
+                &field_place(i, &f.0),
                 i,
                 total_field_count,
-                &f.1,
                 &f.2,
-                false,
                 backend,
             )
         })
         .collect();
-
-    let ui_elements_mut: Vec<proc_macro2::TokenStream> = {
-        if struct_attributes.has_readonly() {
-            Vec::default()
-        } else {
-            fields
-                .iter()
-                .filter(|f| !f.2.has_skip())
-                .enumerate()
-                .map(|(i, f)| {
-                    generate_ui_field_for_struct(
-                        ui_ident,
-                        extent_ident,
-                        struct_name,
-                        &f.0,
-                        i,
-                        total_field_count,
-                        &f.1,
-                        &f.2,
-                        true,
-                        backend,
-                    )
-                })
-                .collect()
-        }
+    let matches_filter = match backend {
+        Backend::Imgui | Backend::Egui => quote! {
+            fn matches_filter(&self, filter: &imgui_presentable::filter::Filter) -> bool {
+                false #(|| #field_matches)*
+            }
+        },
+        Backend::Ratatui => quote! {},
     };
+    let element_actions = generate_element_actions(backend);
 
     let tooltip = if let Some(text) = struct_attributes.get_tooltip_or_documentation() {
         match backend {
@@ -414,20 +1676,23 @@ fn generate_for_backend(
                     }
                 }
             }
+            Backend::Ratatui => {
+                quote! {
+                    #ui_ident.weak(#text);
+                }
+            }
         }
     } else {
         quote! {}
     };
 
-    let main_menu_items = struct_attributes.get_main_menu_items();
-    let has_menu = !main_menu_items.is_empty();
-
+    let has_menu = !struct_attributes.get_main_menu_items().is_empty();
     let mut main_menu_items = struct_attributes
         .get_main_menu_items()
         .into_iter()
         .map(|b| {
             let title = &b.title;
-            let method_name = syn::Ident::new(&b.method_name, Span::call_site());
+            let method_name = &b.method_name;
             // TODO figure hot to handle the shortcuts.
             // let hot_key = b.hot_key;
 
@@ -438,6 +1703,7 @@ fn generate_for_backend(
                             if #ui_ident.menu_item_config(#title).build() {
                                 #[allow(clippy::ignored_unit_patterns)]
                                 let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
                             }
                         }
                     }
@@ -445,11 +1711,22 @@ fn generate_for_backend(
                 Backend::Egui => {
                     quote! {
                         {
-                            // TODO
-                            // if #ui_ident.button(#title).clicked() {
-                            //     #[allow(clippy::ignored_unit_patterns)]
-                            //     let _ = self.#method_name();
-                            // }
+                            if #ui_ident.button(#title).clicked() {
+                                #[allow(clippy::ignored_unit_patterns)]
+                                let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
+                            }
+                        }
+                    }
+                }
+                Backend::Ratatui => {
+                    quote! {
+                        {
+                            if #ui_ident.button(#title) {
+                                #[allow(clippy::ignored_unit_patterns)]
+                                let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
+                            }
                         }
                     }
                 }
@@ -467,7 +1744,13 @@ fn generate_for_backend(
                     #main_menu_items
                 }
             },
+            // The egui windows show the items in their menu bars.
             Backend::Egui => main_menu_items,
+            Backend::Ratatui => quote! {
+                #ui_ident.collapsing("Menu", |#ui_ident| {
+                    #main_menu_items
+                });
+            },
         };
     }
 
@@ -476,7 +1759,7 @@ fn generate_for_backend(
         .into_iter()
         .map(|b| {
             let title = &b.title;
-            let method_name = syn::Ident::new(&b.method_name, Span::call_site());
+            let method_name = &b.method_name;
 
             match backend {
                 Backend::Imgui => {
@@ -485,6 +1768,7 @@ fn generate_for_backend(
                             if #ui_ident.button(#title) {
                                 #[allow(clippy::ignored_unit_patterns)]
                                 let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
                             }
                         }
                     }
@@ -495,6 +1779,18 @@ fn generate_for_backend(
                             if #ui_ident.button(#title).clicked() {
                                 #[allow(clippy::ignored_unit_patterns)]
                                 let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
+                            }
+                        }
+                    }
+                }
+                Backend::Ratatui => {
+                    quote! {
+                        {
+                            if #ui_ident.button(#title) {
+                                #[allow(clippy::ignored_unit_patterns)]
+                                let _ = self.#method_name();
+                                response |= imgui_presentable::EditResponse::clicked(true);
                             }
                         }
                     }
@@ -506,13 +1802,62 @@ fn generate_for_backend(
             code
         });
 
+    let errors = validate_impl::generate_item_error_display(ui_ident, struct_attributes, backend);
+    let shown_attributes: Vec<&Attributes> = fields
+        .iter()
+        .map(|f| &f.2)
+        .filter(|a| !a.has_skip())
+        .collect();
+    let layout = |elements: Vec<proc_macro2::TokenStream>,
+                  inline_elements: Vec<proc_macro2::TokenStream>| {
+        let elements = layout_impl::generate_layout(
+            ui_ident,
+            backend,
+            grid,
+            shown_attributes.iter().copied().zip(elements).collect(),
+        );
+        if !inline {
+            return elements;
+        }
+        let inline_elements = layout_impl::generate_inline_layout(
+            ui_ident,
+            backend,
+            shown_attributes
+                .iter()
+                .copied()
+                .zip(inline_elements)
+                .collect(),
+        );
+        quote! {
+            if imgui_presentable::layout::take_inline() {
+                #inline_elements
+            } else {
+                #elements
+            }
+        }
+    };
+    let ui_elements = layout(ui_elements, ui_elements_inline);
+    let ui_elements_mut = layout(ui_elements_mut, ui_elements_mut_inline);
+    let ui_elements_shared = layout(ui_elements_shared, ui_elements_shared_inline);
+    let fields_mut = validate_impl::wrap_item_ui(
+        ui_ident,
+        struct_attributes,
+        quote! {
+            #ui_elements_mut
+
+            #buttons
+        },
+    );
+
     let immutable_render = match backend {
         Backend::Imgui => {
             quote! {
                 fn render_component(&self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) {
                     #tooltip
 
-                    #(#ui_elements;)*
+                    #errors
+
+                    #ui_elements
                 }
             }
         }
@@ -521,7 +1866,20 @@ fn generate_for_backend(
                 fn render_component(&self, #ui_ident: &mut egui::Ui) {
                     #tooltip
 
-                    #(#ui_elements;)*
+                    #errors
+
+                    #ui_elements
+                }
+            }
+        }
+        Backend::Ratatui => {
+            quote! {
+                fn render_component(&self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) {
+                    #tooltip
+
+                    #errors
+
+                    #ui_elements
                 }
             }
         }
@@ -530,76 +1888,135 @@ fn generate_for_backend(
     let mutable_render = match backend {
         Backend::Imgui => {
             quote! {
-                fn render_component_mut(&mut self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) {
+                fn render_component_mut(&mut self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                    let mut response = imgui_presentable::EditResponse::default();
+
                     #main_menu_items
 
                     #tooltip
 
-                    #(#ui_elements_mut;)*
+                    #errors
 
-                    #buttons
+                    #fields_mut
+
+                    response
                 }
             }
         }
         Backend::Egui => {
             quote! {
-                fn render_component_mut(&mut self, #ui_ident: &mut egui::Ui) {
+                fn render_component_mut(&mut self, #ui_ident: &mut egui::Ui) -> imgui_presentable::EditResponse {
+                    let mut response = imgui_presentable::EditResponse::default();
+
+                    #tooltip
+
+                    #errors
+
+                    #fields_mut
+
+                    response
+                }
+            }
+        }
+        Backend::Ratatui => {
+            quote! {
+                fn render_component_mut(&mut self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) -> imgui_presentable::EditResponse {
+                    let mut response = imgui_presentable::EditResponse::default();
+
+                    #main_menu_items
+
                     #tooltip
 
-                    #(#ui_elements_mut;)*
+                    #errors
+
+                    #fields_mut
 
-                    #buttons
+                    response
                 }
             }
         }
     };
 
-    let trait_name = match backend {
+    // Only the fields are changed through a shared reference, as the
+    // methods of the buttons and the rejection of the invalid edits need
+    // a mutable one.
+    let shared_render = match backend {
         Backend::Imgui => {
             quote! {
-                imgui_presentable::ImguiPresentable
+                fn render_component_shared(&self, #ui_ident: &imgui::Ui, #extent_ident: imgui_presentable::Extent) -> imgui_presentable::EditResponse {
+                    #[allow(unused_mut)]
+                    let mut response = imgui_presentable::EditResponse::default();
+
+                    #tooltip
+
+                    #errors
+
+                    #ui_elements_shared
+
+                    response
+                }
             }
         }
         Backend::Egui => {
             quote! {
-                imgui_presentable::EguiPresentable
+                fn render_component_shared(&self, #ui_ident: &mut egui::Ui) -> imgui_presentable::EditResponse {
+                    #[allow(unused_mut)]
+                    let mut response = imgui_presentable::EditResponse::default();
+
+                    #tooltip
+
+                    #errors
+
+                    #ui_elements_shared
+
+                    response
+                }
+            }
+        }
+        Backend::Ratatui => {
+            quote! {
+                fn render_component_shared(&self, #ui_ident: &mut imgui_presentable::tui::TuiUi<'_>) -> imgui_presentable::EditResponse {
+                    #[allow(unused_mut)]
+                    let mut response = imgui_presentable::EditResponse::default();
+
+                    #tooltip
+
+                    #errors
+
+                    #ui_elements_shared
+
+                    response
+                }
             }
         }
     };
 
-    let render_window_methods = match backend {
+    let trait_name = match backend {
         Backend::Imgui => {
             quote! {
-                /// Renders the implementor as a stand-alone window not allowing to
-                /// change the values.
-                fn render_window(&self, ui: &imgui::Ui, extent: imgui_presentable::Extent) {
-                    ui.window(std::any::type_name::<Self>())
-                        .resizable(true)
-                        .collapsible(true)
-                        .bg_alpha(0.7f32)
-                        .position([0.0, 0.0], imgui::Condition::FirstUseEver)
-                        .menu_bar(#has_menu)
-                        .build(|| (self as &dyn imgui_presentable::ImguiPresentable).render_component(ui, extent));
-                }
-
-                /// Renders the implementor as a stand-alone window allowing to
-                /// change the values.
-                fn render_window_mut(&mut self, ui: &imgui::Ui, extent: imgui_presentable::Extent) {
-                    ui.window(std::any::type_name::<Self>())
-                        .resizable(true)
-                        .collapsible(true)
-                        .bg_alpha(0.7f32)
-                        .position([0.0, 0.0], imgui::Condition::FirstUseEver)
-                        .menu_bar(#has_menu)
-                        .build(|| (self as &mut dyn imgui_presentable::ImguiPresentable).render_component_mut(ui, extent));
-                }
+                imgui_presentable::ImguiPresentable
             }
         }
         Backend::Egui => {
-            quote! {}
+            quote! {
+                imgui_presentable::EguiPresentable
+            }
+        }
+        Backend::Ratatui => {
+            quote! {
+                imgui_presentable::TuiPresentable
+            }
         }
     };
 
+    let render_window_methods = generate_render_window_methods(
+        backend,
+        struct_attributes,
+        &main_menu_items,
+        struct_attributes.has_readonly(),
+        &impl_generics,
+    );
+
     if struct_attributes.has_readonly() {
         quote! {
             #[doc = "Renders [`Self`] in the immediate gui. The code was automatically generated using the derive macro."]
@@ -607,6 +2024,10 @@ fn generate_for_backend(
                 #render_window_methods
 
                 #immutable_render
+
+                #matches_filter
+
+                #element_actions
             }
         }
     } else {
@@ -618,6 +2039,12 @@ fn generate_for_backend(
                 #immutable_render
 
                 #mutable_render
+
+                #shared_render
+
+                #matches_filter
+
+                #element_actions
             }
         }
     }
@@ -730,7 +2157,7 @@ mod tests {
         )
     }
 
-    fn assert_uses_imgui_control(statement: &syn::Stmt, mutably: bool, _is_primitive: bool) {
+    fn assert_uses_imgui_control(statement: &syn::Stmt, _mutably: bool, _is_primitive: bool) {
         let regex = regex::Regex::new(
             r"(.*)ui\s*\.\s*[checkbox|disabled|input_scalar|tree_node_config]\s*",
         )
@@ -754,6 +2181,29 @@ mod tests {
         ));
     }
 
+    /// Returns the code of the fields laid out as usual, rather than on
+    /// a single row.
+    fn get_tree_layout_code(statement: &syn::Stmt) -> &[syn::Stmt] {
+        let syn::Stmt::Expr(syn::Expr::If(layout), None) = statement else {
+            panic!(
+                "Isn't a choice of the layout: {}",
+                statement.to_token_stream()
+            );
+        };
+        assert!(layout
+            .cond
+            .to_token_stream()
+            .to_string()
+            .contains("take_inline"));
+        let Some((_, tree)) = layout.else_branch.as_ref() else {
+            panic!("The layout has no else branch.");
+        };
+        let syn::Expr::Block(tree) = tree.as_ref() else {
+            panic!("The else branch isn't a block.");
+        };
+        &tree.block.stmts
+    }
+
     fn assert_has_proper_immutable_implementation(
         item_impl: &syn::ItemImpl,
         number_of_fields: usize,
@@ -779,7 +2229,14 @@ mod tests {
     ) {
         let code = get_mutable_code_from_impl(item_impl).unwrap();
 
-        assert_eq!(code.len(), number_of_fields * 3);
+        // The response is declared first and returned last.
+        assert_eq!(code.len(), number_of_fields * 3 + 2);
+        assert_is_let_binding_declaration(&code[0], "response", true, true);
+        assert_eq!(
+            code.last().unwrap().to_token_stream().to_string(),
+            "response"
+        );
+        let code = &code[1..code.len() - 1];
         (0..number_of_fields).for_each(|i| {
             let statement = &code[i];
             assert_is_let_binding_declaration(statement, "field_name", false, true);
@@ -790,6 +2247,43 @@ mod tests {
         })
     }
 
+    #[test]
+    fn detects_the_borrowed_types() {
+        let borrowed: syn::Type = syn::parse_quote! { Vec<(u32, &'a str)> };
+        let owned: syn::Type = syn::parse_quote! { Vec<(u32, String)> };
+        assert!(has_lifetimes(&borrowed));
+        assert!(!has_lifetimes(&owned));
+    }
+
+    #[test]
+    fn splits_printf_formats() {
+        assert_eq!(
+            PrintfFormat::parse("%.2f dollars"),
+            PrintfFormat {
+                prefix: String::new(),
+                decimals: Some(2),
+                suffix: " dollars".to_owned(),
+            }
+        );
+        assert_eq!(
+            PrintfFormat::parse("speed: %d%%"),
+            PrintfFormat {
+                prefix: "speed: ".to_owned(),
+                decimals: Some(0),
+                suffix: "%".to_owned(),
+            }
+        );
+        assert_eq!(
+            PrintfFormat::parse("%8.3f"),
+            PrintfFormat {
+                prefix: String::new(),
+                decimals: Some(3),
+                suffix: String::new(),
+            }
+        );
+        assert_eq!(PrintfFormat::parse("%g").decimals, None);
+    }
+
     #[test]
     fn produces_error_for_an_empty_struct() {
         let s = r#"
@@ -849,6 +2343,190 @@ mod tests {
         }
     }
 
+    #[test]
+    fn produces_error_for_inconsistent_checks() {
+        let inputs = [
+            (
+                r#"
+                #[derive(ImguiPresentation)]
+                struct A {
+                    #[imgui_presentation(min_len = 4, max_len = 2)]
+                    field: String,
+                }
+                "#,
+                "The `max_len` (2) is less than the `min_len` (4).",
+            ),
+            (
+                r#"
+                #[derive(ImguiPresentation)]
+                #[imgui_presentation(non_empty)]
+                struct A {
+                    field: String,
+                }
+                "#,
+                "The `non_empty` attribute is only applicable to the fields.",
+            ),
+        ];
+        for (s, message) in inputs {
+            let generated =
+                derive_imgui_presentable_impl(TokenStream::from_str(s).unwrap()).to_string();
+            assert!(generated.starts_with("compile_error"));
+            assert!(generated.contains(message), "{generated}");
+        }
+    }
+
+    #[test]
+    fn produces_error_for_conditions_outside_of_the_struct_fields() {
+        let inputs = [
+            r#"
+            #[derive(ImguiPresentation)]
+            #[imgui_presentation(visible_if = "true")]
+            struct A {
+                field: bool,
+            }
+            "#,
+            r#"
+            #[derive(ImguiPresentation)]
+            enum A {
+                B {
+                    #[imgui_presentation(enabled_if = "true")]
+                    field: bool,
+                },
+            }
+            "#,
+            r#"
+            #[derive(ImguiPresentation)]
+            enum A {
+                B {
+                    #[imgui_presentation(group = "C")]
+                    field: bool,
+                },
+            }
+            "#,
+        ];
+        for s in inputs {
+            let generated =
+                derive_imgui_presentable_impl(TokenStream::from_str(s).unwrap()).to_string();
+            assert!(generated.starts_with("compile_error"));
+            assert!(
+                generated.contains("attribute is only applicable to the fields of the structs."),
+                "{generated}"
+            );
+        }
+    }
+
+    #[test]
+    fn produces_error_for_item_attributes_outside_of_the_items() {
+        let inputs = [
+            r#"
+            #[derive(ImguiPresentation)]
+            struct A<T> {
+                #[imgui_presentation(bound = "T: Clone")]
+                field: T,
+            }
+            "#,
+            r#"
+            #[derive(ImguiPresentation)]
+            enum A<T> {
+                #[imgui_presentation(bound = "T: Clone")]
+                B(T),
+            }
+            "#,
+            r#"
+            #[derive(ImguiPresentation)]
+            struct A {
+                #[imgui_presentation(window(resizable = false))]
+                field: bool,
+            }
+            "#,
+        ];
+        for s in inputs {
+            let generated =
+                derive_imgui_presentable_impl(TokenStream::from_str(s).unwrap()).to_string();
+            assert!(generated.starts_with("compile_error"));
+            assert!(
+                generated.contains("only applicable to the structs and the enums."),
+                "{generated}"
+            );
+        }
+    }
+
+    #[test]
+    fn produces_error_for_flattening_outside_of_the_fields() {
+        let inputs = [
+            r#"
+            #[derive(ImguiPresentation)]
+            #[imgui_presentation(flatten)]
+            struct A {
+                field: bool,
+            }
+            "#,
+            r#"
+            #[derive(ImguiPresentation)]
+            enum A {
+                #[imgui_presentation(inline)]
+                B {
+                    field: bool,
+                },
+            }
+            "#,
+        ];
+        for s in inputs {
+            let generated =
+                derive_imgui_presentable_impl(TokenStream::from_str(s).unwrap()).to_string();
+            assert!(generated.starts_with("compile_error"));
+            assert!(
+                generated.contains("attribute is only applicable to the fields."),
+                "{generated}"
+            );
+        }
+    }
+
+    #[test]
+    fn produces_error_for_a_widget_not_suiting_the_field() {
+        let inputs = [
+            (
+                r#"
+                #[derive(ImguiPresentation)]
+                struct A {
+                    #[imgui_presentation(widget = "slider")]
+                    field: f32,
+                }
+                "#,
+                "The `slider` widget needs a `range` with both the bounds.",
+            ),
+            (
+                r#"
+                #[derive(ImguiPresentation)]
+                struct A {
+                    #[imgui_presentation(widget = "angle")]
+                    field: i32,
+                }
+                "#,
+                "The `angle` widget is only supported for the `f32` and `f64` fields.",
+            ),
+            (
+                r#"
+                #[derive(ImguiPresentation)]
+                enum A {
+                    B {
+                        #[imgui_presentation(color, widget = "percent")]
+                        field: [f32; 3],
+                    },
+                }
+                "#,
+                "The `percent` widget can't be combined with the `color` attribute.",
+            ),
+        ];
+        // The enums report the error for every backend.
+        for (s, message) in inputs {
+            let generated =
+                derive_imgui_presentable_impl(TokenStream::from_str(s).unwrap()).to_string();
+            assert!(generated.starts_with("compile_error"));
+            assert!(generated.contains(message), "{generated}");
+        }
+    }
+
     #[test]
     fn generates_for_struct_with_one_primitive_field() {
         let inputs = [
@@ -871,12 +2549,21 @@ mod tests {
                 &[Backend::Imgui],
             );
             println!("{generated}");
-            let item_impl: syn::ItemImpl = syn::parse2(generated.clone())
-                .map_err(|e| {
+            let file: syn::File = syn::parse2(generated.clone())
+                .inspect_err(|_| {
                     println!("{generated}");
-                    e
                 })
                 .unwrap();
+            // The validation and the reflection are implemented first,
+            // then the presentation.
+            let [syn::Item::Impl(validation), syn::Item::Impl(reflect), syn::Item::Impl(reflect_struct), syn::Item::Impl(item_impl)] =
+                file.items.as_slice()
+            else {
+                panic!("Expected four implementations: {generated}");
+            };
+            assert_eq!(get_trait_name_from_impl(validation).unwrap(), "Validate");
+            assert_eq!(get_trait_name_from_impl(reflect).unwrap(), "Reflect");
+            assert_eq!(get_trait_name_from_impl(reflect_struct).unwrap(), "Struct");
             // eprintln!("item impl: {item_impl:#?}");
             let docs: String = item_impl
                 .attrs
@@ -890,19 +2577,53 @@ mod tests {
             );
             // The last in trait_ path segments must be "ImguiPresentable".
             assert!(matches!(
-                get_trait_name_from_impl(&item_impl).unwrap().as_str(),
+                get_trait_name_from_impl(item_impl).unwrap().as_str(),
                 "ImguiPresentable"
             ));
             // The type the trait is implemented for is "A".
-            assert_eq!(get_self_type_from_impl(&item_impl).unwrap(), "A");
-            // Has both, the immutable and mutable implementations
-            // and windows.
-            assert_eq!(item_impl.items.len(), 4);
-            assert_has_proper_immutable_implementation(&item_impl, 1);
-            assert_has_proper_mutable_implementation(&item_impl, 1);
+            assert_eq!(get_self_type_from_impl(item_impl).unwrap(), "A");
+            // Has the immutable, the mutable and the shared
+            // implementations, windows, the filter matching and the
+            // element actions.
+            assert_eq!(item_impl.items.len(), 7);
+            assert_has_proper_immutable_implementation(item_impl, 1);
+            assert_has_proper_mutable_implementation(item_impl, 1);
         }
     }
 
+    #[test]
+    fn generates_the_single_row_layout_for_the_inline_structs_only() {
+        let generated = derive_imgui_presentable_impl_for_backends(
+            TokenStream::from_str(
+                r#"
+                #[derive(ImguiPresentation)]
+                #[imgui_presentation(inline)]
+                struct A {
+                    field: bool,
+                }
+                "#,
+            )
+            .unwrap(),
+            &[Backend::Imgui],
+        );
+        let file: syn::File = syn::parse2(generated.clone()).unwrap();
+        let [.., syn::Item::Impl(inline), syn::Item::Impl(item_impl)] = file.items.as_slice()
+        else {
+            panic!("Expected the implementations: {generated}");
+        };
+        assert_eq!(get_trait_name_from_impl(inline).unwrap(), "Inline");
+        let code = get_immutable_code_from_impl(item_impl).unwrap();
+        assert_eq!(code.len(), 1);
+        assert_eq!(get_tree_layout_code(&code[0]).len(), 3);
+
+        // The other structs are only laid out as usual.
+        let generated = derive_imgui_presentable_impl_for_backends(
+            TokenStream::from_str("struct A { field: bool }").unwrap(),
+            &[Backend::Imgui],
+        );
+        assert!(!generated.to_string().contains("take_inline"));
+    }
+
     // TODO: enable this test.
     // #[test]
     // fn generates_for_struct_with_many_different_fields() {
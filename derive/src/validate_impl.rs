@@ -0,0 +1,350 @@
+use quote::{quote, quote_spanned};
+use syn::{spanned::Spanned, Ident};
+
+use crate::{
+    attributes::{self, Attributes},
+    bounds,
+    enum_impl::DataVariant,
+    Backend,
+};
+
+/// The checks which are only applicable to the fields.
+const FIELD_CHECKS: &[&str] = &["non_empty", "min_len", "max_len", "regex"];
+
+/// Checks the validation attributes of a field are consistent.
+pub(crate) fn check_field_checks(attributes: &Attributes) -> attributes::Result {
+    match (attributes.get_min_len(), attributes.get_max_len()) {
+        (Some(min), Some(max)) if min > max => Err(attributes::to_compile_error(syn::Error::new(
+            attributes
+                .span_of("max_len")
+                .unwrap_or_else(proc_macro2::Span::call_site),
+            format!("The `max_len` ({max}) is less than the `min_len` ({min})."),
+        ))),
+        _ => Ok(()),
+    }
+}
+
+/// Checks a struct or an enum has none of the checks which are only
+/// applicable to the fields.
+pub(crate) fn check_item_checks(attributes: &Attributes) -> attributes::Result {
+    match FIELD_CHECKS
+        .iter()
+        .find_map(|name| Some((name, attributes.span_of(name)?)))
+    {
+        Some((name, span)) => Err(attributes::to_compile_error(syn::Error::new(
+            span,
+            format!("The `{name}` attribute is only applicable to the fields."),
+        ))),
+        None => Ok(()),
+    }
+}
+
+/// Returns the expression of the `Vec<String>` with the messages of the
+/// checks of the value failing, or `None` if the value isn't checked.
+/// The `place` is the place expression the value is reachable by, like
+/// `self.field`.
+pub(crate) fn generate_checks(
+    place: &proc_macro2::TokenStream,
+    attributes: &Attributes,
+) -> Option<proc_macro2::TokenStream> {
+    let mut checks: Vec<_> = attributes
+        .get_validators()
+        .into_iter()
+        .map(|path| quote_spanned! {path.span()=> #path(&#place) })
+        .collect();
+
+    if attributes.has_non_empty() {
+        checks.push(quote! { imgui_presentable::validation::non_empty(&#place) });
+    }
+    if let Some(min) = attributes.get_min_len() {
+        checks.push(quote! { imgui_presentable::validation::min_len(&#place, #min) });
+    }
+    if let Some(max) = attributes.get_max_len() {
+        checks.push(quote! { imgui_presentable::validation::max_len(&#place, #max) });
+    }
+    if let Some(pattern) = attributes.get_regex() {
+        checks.push(quote! { imgui_presentable::validation::matches_regex(&#place, #pattern) });
+    }
+
+    (!checks.is_empty()).then(|| {
+        quote! { imgui_presentable::validation::messages([#(#checks),*]) }
+    })
+}
+
+/// Returns the code showing the errors in red below the value.
+fn generate_error_display(ui: &Ident, backend: Backend) -> proc_macro2::TokenStream {
+    match backend {
+        Backend::Imgui => {
+            quote! { imgui_presentable::validation::show_errors_imgui(#ui, &errors); }
+        }
+        Backend::Egui => quote! { imgui_presentable::validation::show_errors_egui(#ui, &errors); },
+        Backend::Ratatui => {
+            quote! { imgui_presentable::validation::show_errors_tui(#ui, &errors); }
+        }
+    }
+}
+
+/// Wraps the code presenting a field, which adds the edits to the
+/// `response`, with showing the errors of the checks of the field below
+/// it. With the `reject_invalid` attribute, the edits making the field
+/// or the values nested in it invalid are also undone.
+pub(crate) fn wrap_field_ui(
+    ui: &Ident,
+    place: &proc_macro2::TokenStream,
+    field_type: &syn::Type,
+    attributes: &Attributes,
+    mutable: bool,
+    backend: Backend,
+    render: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let checks = generate_checks(place, attributes);
+    let display = generate_error_display(ui, backend);
+
+    if !(mutable && attributes.has_reject_invalid()) {
+        return match checks {
+            Some(checks) => quote! {
+                #render
+                let errors = #checks;
+                #display
+            },
+            None => render,
+        };
+    }
+
+    let checks = checks.unwrap_or_else(|| quote! { Vec::<String>::new() });
+    let last_valid = quote_spanned! {field_type.span()=>
+        imgui_presentable::validation::LastValid::<#field_type>
+    };
+    quote! {
+        let nested_errors = |value: &#field_type| -> Vec<String> {
+            let mut nested = Vec::new();
+            imgui_presentable::validate_nested!(#field_type, value, "", &mut nested);
+            nested.iter().map(ToString::to_string).collect()
+        };
+        let last_valid = #last_valid::take(&*#ui, &#place, |value| {
+            #checks.is_empty() && nested_errors(value).is_empty()
+        });
+        let field_response = {
+            let mut response = imgui_presentable::EditResponse::default();
+            #render
+            response
+        };
+        let mut errors = #checks;
+        response |= last_valid.reject_invalid(&mut #place, &mut errors, nested_errors, field_response);
+        #display
+    }
+}
+
+/// Returns the code showing the errors of the checks of a struct or an
+/// enum above its fields, and the errors of its last edit rejected.
+pub(crate) fn generate_item_error_display(
+    ui: &Ident,
+    attributes: &Attributes,
+    backend: Backend,
+) -> proc_macro2::TokenStream {
+    let checks = match generate_checks(&quote! { *self }, attributes) {
+        Some(checks) => checks,
+        None if attributes.has_reject_invalid() => quote! { Vec::<String>::new() },
+        None => return quote! {},
+    };
+    let display = generate_error_display(ui, backend);
+
+    quote! {
+        {
+            let mut errors = #checks;
+            errors.extend(imgui_presentable::validation::rejected(self));
+            #display
+        }
+    }
+}
+
+/// Wraps the code presenting the fields of a struct or an enum, which
+/// adds the edits to the `response`, with undoing the edits making the
+/// value invalid if it has the `reject_invalid` attribute. The errors
+/// are shown above the fields (see [`generate_item_error_display`]).
+pub(crate) fn wrap_item_ui(
+    ui: &Ident,
+    attributes: &Attributes,
+    render: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    if !attributes.has_reject_invalid() {
+        return render;
+    }
+
+    quote! {
+        let last_valid = imgui_presentable::validation::LastValid::take(
+            &*#ui,
+            self,
+            imgui_presentable::validation::Validate::is_valid,
+        );
+        let item_response = {
+            let mut response = imgui_presentable::EditResponse::default();
+            #render
+            response
+        };
+        response |= last_valid.reject_invalid(
+            self,
+            &mut Vec::new(),
+            |value| {
+                imgui_presentable::validation::Validate::validate_all(value)
+                    .iter()
+                    .map(ToString::to_string)
+                    .collect()
+            },
+            item_response,
+        );
+    }
+}
+
+/// Returns the code appending the errors of a field, including the ones
+/// of the values nested in it, to the `errors`.
+fn generate_field_validation(
+    field_ident: &Option<Ident>,
+    field_order: usize,
+    field_type: &syn::Type,
+    place: &proc_macro2::TokenStream,
+    attributes: &Attributes,
+) -> proc_macro2::TokenStream {
+    let name = match field_ident {
+        Some(ident) => ident.to_string(),
+        None => field_order.to_string(),
+    };
+    let checks = generate_checks(place, attributes).map(|checks| {
+        quote! { imgui_presentable::validation::push_errors(&path, #checks, errors); }
+    });
+
+    quote! {
+        {
+            let path = imgui_presentable::validation::field_path(path, #name);
+            #checks
+            imgui_presentable::validate_nested!(#field_type, &#place, &path, errors);
+        }
+    }
+}
+
+/// Returns the code appending the errors of the whole struct or enum to
+/// the `errors`.
+fn generate_item_validation(attributes: &Attributes) -> proc_macro2::TokenStream {
+    match generate_checks(&quote! { *self }, attributes) {
+        Some(checks) => quote! {
+            imgui_presentable::validation::push_errors(path, #checks, errors);
+        },
+        None => quote! {},
+    }
+}
+
+/// Returns the `Validate` implementation validating the item with the
+/// code provided. The nested values are validated if their types are
+/// known to implement `Validate`, so only the `bound` attribute adds
+/// the bounds.
+fn generate_implementation(
+    derive_input: &syn::DeriveInput,
+    attributes: &Attributes,
+    validation: proc_macro2::TokenStream,
+) -> proc_macro2::TokenStream {
+    let name = &derive_input.ident;
+    let generics = bounds::with_bounds(name, &derive_input.generics, attributes, None, []);
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    quote! {
+        impl #impl_generics imgui_presentable::validation::Validate for #name #ty_generics #where_clause {
+            fn validate_into(
+                &self,
+                path: &str,
+                errors: &mut Vec<imgui_presentable::validation::ValidationError>,
+            ) {
+                #validation
+            }
+        }
+    }
+}
+
+/// Derives the `imgui_presentable::validation::Validate` trait for a
+/// struct, the fields of which have already been checked.
+pub(crate) fn derive_for_struct(
+    derive_input: &syn::DeriveInput,
+    fields: &[(Option<Ident>, syn::Type, Attributes)],
+    struct_attributes: &Attributes,
+) -> proc_macro2::TokenStream {
+    if let Err(e) = check_item_checks(struct_attributes) {
+        return e;
+    }
+
+    let mut validation = generate_item_validation(struct_attributes);
+    for (order, (ident, field_type, attributes)) in fields.iter().enumerate() {
+        if attributes.has_skip() {
+            continue;
+        }
+        let place = match ident {
+            Some(ident) => quote! { self.#ident },
+            None => {
+                let index = syn::Index::from(order);
+                quote! { self.#index }
+            }
+        };
+        validation.extend(generate_field_validation(
+            ident, order, field_type, &place, attributes,
+        ));
+    }
+
+    generate_implementation(derive_input, struct_attributes, validation)
+}
+
+/// Derives the `imgui_presentable::validation::Validate` trait for an
+/// enum, validating the fields of the current variant.
+pub(crate) fn derive_for_enum(
+    derive_input: &syn::DeriveInput,
+    enumm: &syn::DataEnum,
+    enum_attributes: &Attributes,
+) -> proc_macro2::TokenStream {
+    if let Err(e) = check_item_checks(enum_attributes) {
+        return e;
+    }
+
+    let variants = match enumm
+        .variants
+        .iter()
+        .map(DataVariant::parse)
+        .collect::<attributes::Result<Vec<DataVariant>>>()
+    {
+        Ok(variants) => variants,
+        // Already reported by the presentations.
+        Err(_) => return quote! {},
+    };
+
+    let mut validation = generate_item_validation(enum_attributes);
+    let arms: Vec<_> = variants
+        .iter()
+        .filter(|v| v.parsed_fields.iter().any(|f| !f.2.has_skip()))
+        .map(|variant| {
+            let pattern = variant.pattern();
+            let fields = variant
+                .parsed_fields
+                .iter()
+                .enumerate()
+                .filter(|(_, f)| !f.2.has_skip())
+                .map(|(order, (ident, field_type, attributes))| {
+                    let binding = variant.binding(order);
+                    generate_field_validation(
+                        ident,
+                        order,
+                        field_type,
+                        &quote! { (*#binding) },
+                        attributes,
+                    )
+                });
+            quote! { #pattern => { #(#fields)* } }
+        })
+        .collect();
+    if !arms.is_empty() {
+        validation.extend(quote! {
+            #[allow(unreachable_patterns)]
+            match self {
+                #(#arms)*
+                _ => {}
+            }
+        });
+    }
+
+    generate_implementation(derive_input, enum_attributes, validation)
+}
@@ -1,10 +1,12 @@
-//! This module implements the [`ImguiPresentable`] and/or the
-//! [`EguiPresentable`] traits for the basic Rust types, which are not
-//! collections.
+//! This module implements the [`ImguiPresentable`], the
+//! [`EguiPresentable`] and/or the [`TuiPresentable`] traits for the
+//! basic Rust types, which are not collections.
 
 #[cfg(feature = "imgui_backend")]
 mod imgui_backend {
-    use crate::{Extent, ImguiPresentable};
+    use crate::elements::ElementActions;
+    use crate::filter::{highlight_imgui, Filter};
+    use crate::{EditResponse, Extent, ImguiPresentable};
 
     // fn label_with_address<T>(label: &str, t: &T) -> String {
     //     format!("{label}##{:p}", std::ptr::addr_of!(t))
@@ -21,6 +23,7 @@ mod imgui_backend {
                     let is_float = type_id == f32_type_id || type_id == f64_type_id;
                     let speed = if is_float { 0.001f32 } else { 1.0f32 };
                     let mut data = *self;
+                    let _highlight = highlight_imgui(ui, Filter::active_matches(self));
                     ui.disabled(true, || {
                         let _ = imgui::Drag::new(&format!("{type_name}##{self:p}"))
                             .speed(speed)
@@ -28,16 +31,30 @@ mod imgui_backend {
                     });
                 }
 
-                fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
+                fn render_component_mut(
+                    &mut self,
+                    ui: &imgui::Ui,
+                    _extent: Extent,
+                ) -> EditResponse {
                     let type_name = std::any::type_name::<$scalar_type>();
                     let type_id = std::any::TypeId::of::<$scalar_type>();
                     let f32_type_id = std::any::TypeId::of::<f32>();
                     let f64_type_id = std::any::TypeId::of::<f64>();
                     let is_float = type_id == f32_type_id || type_id == f64_type_id;
                     let speed = if is_float { 0.001f32 } else { 1.0f32 };
-                    let _ = imgui::Drag::new(&format!("{type_name}##{self:p}"))
+                    let _highlight = highlight_imgui(ui, Filter::active_matches(self));
+                    let changed = imgui::Drag::new(&format!("{type_name}##{self:p}"))
                         .speed(speed)
                         .build(&ui, self);
+                    EditResponse::from_last_imgui_item(ui, changed)
+                }
+
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    filter.matches_display(self)
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
                 }
             }
         };
@@ -71,57 +88,117 @@ mod imgui_backend {
         fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
             let mut data = *self;
             let text = bool_to_string(*self);
+            let _highlight = highlight_imgui(ui, Filter::active_matches(&text));
             ui.disabled(true, || {
                 let _ = ui.checkbox(format!("{text}##{:p}", std::ptr::addr_of!(self)), &mut data);
             });
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
+        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) -> EditResponse {
             let text = bool_to_string(*self);
-            let _ = ui.checkbox(&format!("{text}##{self:p}"), self);
+            let _highlight = highlight_imgui(ui, Filter::active_matches(&text));
+            let changed = ui.checkbox(format!("{text}##{self:p}"), self);
+            EditResponse::from_last_imgui_item(ui, changed)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches(bool_to_string(*self))
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
     impl ImguiPresentable for String {
-        fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-            ui.text(self);
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            self.as_str().render_component(ui, extent);
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) -> EditResponse {
+            let _highlight = highlight_imgui(ui, Filter::active_matches(self));
+            let changed = ui.input_text(&format!("{self}##{self:p}"), self).build();
+            EditResponse::from_last_imgui_item(ui, changed)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches(self)
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-            let _ = ui.input_text(&format!("{self}##{self:p}"), self).build();
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
-    impl ImguiPresentable for &str {
+    impl ImguiPresentable for str {
         fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
+            let _highlight = highlight_imgui(ui, Filter::active_matches(&self));
             ui.text(self);
         }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches(self)
+        }
     }
 
-    // impl ImguiPresentable for &String {
-    //     fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-    //         ui.text(format!("{}##{:p}", self, std::ptr::addr_of!(self)));
-    //     }
-    // }
+    /// A shared reference only allows to change the values with
+    /// interior mutability.
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for &T {
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
 
-    // impl ImguiPresentable for &mut String {
-    //     fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-    //         ui.text(format!("{}##{:p}", self, std::ptr::addr_of!(self)));
-    //     }
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            (**self).render_component(ui, extent);
+        }
 
-    //     fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-    //         let _ = ui
-    //             .input_text(&format!("##{:p}", std::ptr::addr_of!(self)), self)
-    //             .build();
-    //     }
-    // }
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_shared(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_shared(ui, extent)
+        }
+    }
+
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for &mut T {
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
+
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            (**self).render_component(ui, extent);
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_shared(ui, extent)
+        }
+    }
 }
+#[allow(unused)]
 #[cfg(feature = "imgui_backend")]
 pub use imgui_backend::*;
 
 #[cfg(feature = "egui_backend")]
 mod egui_backend {
-    use crate::EguiPresentable;
+    use crate::elements::ElementActions;
+    use crate::filter::{highlight_egui, Filter};
+    use crate::{EditResponse, EguiPresentable};
+
+    /// The default drag speed of a scalar, the same as in the ImGui
+    /// backend.
+    fn speed<T: 'static>() -> f64 {
+        let type_id = std::any::TypeId::of::<T>();
+        if type_id == std::any::TypeId::of::<f32>() || type_id == std::any::TypeId::of::<f64>() {
+            0.001
+        } else {
+            1.0
+        }
+    }
 
     macro_rules! define_for_scalar {
         ($scalar_type: ty) => {
@@ -129,15 +206,35 @@ mod egui_backend {
                 fn render_component(&self, ui: &mut egui::Ui) {
                     let type_name = std::any::type_name::<$scalar_type>();
                     let mut data = *self;
-                    ui.add_enabled_ui(false, |ui: &mut egui::Ui| {
-                        let _ = ui.add(egui::DragValue::new(&mut data).prefix(type_name));
+                    highlight_egui(ui, Filter::active_matches(self), |ui| {
+                        ui.add_enabled_ui(false, |ui: &mut egui::Ui| {
+                            let _ = ui.add(
+                                egui::DragValue::new(&mut data)
+                                    .speed(speed::<$scalar_type>())
+                                    .prefix(type_name),
+                            );
+                        });
                     });
                 }
 
-                fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
                     let type_name = std::any::type_name::<$scalar_type>();
-                    let mut data = *self;
-                    let _ = ui.add(egui::DragValue::new(&mut data).prefix(type_name));
+                    highlight_egui(ui, Filter::active_matches(self), |ui| {
+                        ui.add(
+                            egui::DragValue::new(self)
+                                .speed(speed::<$scalar_type>())
+                                .prefix(type_name),
+                        )
+                    })
+                    .into()
+                }
+
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    filter.matches_display(self)
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
                 }
             }
         };
@@ -165,8 +262,9 @@ mod egui_backend {
             *on = !*on;
             response.mark_changed();
         }
-        response
-            .widget_info(|| egui::WidgetInfo::selected(egui::WidgetType::Checkbox, *on, false, ""));
+        response.widget_info(|| {
+            egui::WidgetInfo::selected(egui::WidgetType::Checkbox, ui.is_enabled(), *on, "")
+        });
 
         if ui.is_rect_visible(rect) {
             let how_on = ui.ctx().animate_bool(response.id, *on);
@@ -197,44 +295,184 @@ mod egui_backend {
             });
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            let _ = bool_switch_ui(ui, self);
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            bool_switch_ui(ui, self).into()
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches_display(self)
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
     impl EguiPresentable for String {
         fn render_component(&self, ui: &mut egui::Ui) {
-            ui.label(self);
+            self.as_str().render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let highlighted = Filter::active_matches(self);
+            highlight_egui(ui, highlighted, |ui| ui.text_edit_singleline(self)).into()
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches(self)
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            let _ = ui.text_edit_singleline(self);
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
-    impl EguiPresentable for &str {
+    impl EguiPresentable for str {
         fn render_component(&self, ui: &mut egui::Ui) {
-            ui.label(*self);
+            highlight_egui(ui, Filter::active_matches(&self), |ui| ui.label(self));
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            filter.matches(self)
         }
     }
 
-    // impl ImguiPresentable for &String {
-    //     fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-    //         ui.text(format!("{}##{:p}", self, std::ptr::addr_of!(self)));
-    //     }
-    // }
+    /// A shared reference only allows to change the values with
+    /// interior mutability.
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for &T {
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
+
+        fn render_component(&self, ui: &mut egui::Ui) {
+            (**self).render_component(ui);
+        }
 
-    // impl ImguiPresentable for &mut String {
-    //     fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-    //         ui.text(format!("{}##{:p}", self, std::ptr::addr_of!(self)));
-    //     }
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
 
-    //     fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-    //         let _ = ui
-    //             .input_text(&format!("##{:p}", std::ptr::addr_of!(self)), self)
-    //             .build();
-    //     }
-    // }
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+    }
+
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for &mut T {
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
+
+        fn render_component(&self, ui: &mut egui::Ui) {
+            (**self).render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+    }
 }
+#[allow(unused)]
 #[cfg(feature = "egui_backend")]
 pub use egui_backend::*;
+
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use crate::tui::{TuiScalar, TuiUi};
+    use crate::{EditResponse, TuiPresentable};
+
+    macro_rules! define_for_scalar {
+        ($scalar_type: ty) => {
+            impl TuiPresentable for $scalar_type {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    let mut data = *self;
+                    ui.disabled(true, |ui| {
+                        let _ = TuiScalar::new(&mut data).show(ui);
+                    });
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    TuiScalar::new(self).show(ui)
+                }
+            }
+        };
+    }
+
+    define_for_scalar!(i8);
+    define_for_scalar!(u8);
+    define_for_scalar!(i16);
+    define_for_scalar!(u16);
+    define_for_scalar!(i32);
+    define_for_scalar!(u32);
+    define_for_scalar!(i64);
+    define_for_scalar!(u64);
+    define_for_scalar!(f32);
+    define_for_scalar!(f64);
+    define_for_scalar!(isize);
+    define_for_scalar!(usize);
+
+    impl TuiPresentable for bool {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            let mut data = *self;
+            ui.disabled(true, |ui| {
+                let _ = ui.checkbox(&mut data, "");
+            });
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            ui.checkbox(self, "")
+        }
+    }
+
+    impl TuiPresentable for String {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            ui.label(self.as_str());
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            ui.text_edit(self)
+        }
+    }
+
+    impl TuiPresentable for str {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            ui.label(self);
+        }
+    }
+
+    /// A shared reference only allows to change the values with
+    /// interior mutability.
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for &T {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            (**self).render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+    }
+
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for &mut T {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            (**self).render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+    }
+}
+#[allow(unused)]
+#[cfg(feature = "ratatui_backend")]
+pub use ratatui_backend::*;
@@ -0,0 +1,643 @@
+//! The editing of the colors.
+//!
+//! The values storing the colors are edited with the color pickers,
+//! like the ImGui `ColorEdit` or the egui `color_edit_button`, rather
+//! than channel by channel. A field is presented as a color with the
+//! `color` attribute, optionally followed by the options describing
+//! how the color is stored:
+//!
+//! ```rust,ignore
+//! #[derive(ImguiPresentation)]
+//! struct Material {
+//!     #[imgui_presentation(color)]
+//!     base: glam::Vec3,
+//!     // Stored in the linear space, above 1.0 if bright.
+//!     #[imgui_presentation(color(linear, hdr))]
+//!     emission: [f32; 3],
+//!     // The alpha channel is kept as it is.
+//!     #[imgui_presentation(color(no_alpha))]
+//!     tint: [u8; 4],
+//! }
+//! ```
+//!
+//! The arrays of three or four `f32` or `u8`, the `glam::Vec3`,
+//! `glam::Vec3A` and `glam::Vec4` and the `egui::Color32` may be
+//! presented as colors, as may any other type implementing the
+//! [`Color`] trait. The [`Rgb`], [`Rgba`] and [`Srgba8`] wrappers are
+//! presented as colors without the attribute. The integer channels
+//! are always stored in the sRGB space within `0..=255`, so the
+//! `linear` and the `hdr` options only apply to the float ones.
+//!
+//! The terminals show the colors as the hexadecimal codes, like
+//! `#ff8000`, which may also be typed in.
+
+/// How the channels of a color are stored and which of those are
+/// edited.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct ColorOptions {
+    /// The alpha channel is edited, if the color has one.
+    pub alpha: bool,
+    /// The float channels may exceed `1.0`, for the high dynamic range
+    /// colors.
+    pub hdr: bool,
+    /// The float channels are stored in the linear space rather than
+    /// in the sRGB one the pickers show those in.
+    pub linear: bool,
+}
+
+impl ColorOptions {
+    /// Returns the options of a color stored in the sRGB space within
+    /// `0.0..=1.0`, the alpha of which is edited.
+    pub const fn new() -> Self {
+        Self {
+            alpha: true,
+            hdr: false,
+            linear: false,
+        }
+    }
+
+    /// Keeps the alpha channel of the color as it is.
+    pub const fn without_alpha(self) -> Self {
+        Self {
+            alpha: false,
+            ..self
+        }
+    }
+
+    /// Allows the float channels to exceed `1.0`.
+    pub const fn with_hdr(self) -> Self {
+        Self { hdr: true, ..self }
+    }
+
+    /// Stores the float channels in the linear space.
+    pub const fn with_linear(self) -> Self {
+        Self {
+            linear: true,
+            ..self
+        }
+    }
+}
+
+impl Default for ColorOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// A value storing a color, which may be edited with a color picker.
+#[diagnostic::on_unimplemented(
+    message = "`{Self}` can't be edited as a color",
+    note = "the arrays of three or four `f32` or `u8`, the `glam` vectors of three or four `f32` and the `egui::Color32` are the colors"
+)]
+pub trait Color {
+    /// The color has an alpha channel.
+    const ALPHA: bool;
+    /// The channels are floats, which may be stored in the linear space
+    /// and exceed `1.0`, rather than the sRGB bytes.
+    const FLOAT: bool;
+
+    /// Returns the red, green, blue and alpha channels as stored,
+    /// the integer ones divided by their maximum. The alpha is `1.0`
+    /// if the color has none.
+    fn to_rgba(&self) -> [f32; 4];
+
+    /// Sets the channels, as returned by [`Color::to_rgba`]. The alpha
+    /// is ignored if the color has none.
+    fn set_rgba(&mut self, rgba: [f32; 4]);
+}
+
+impl Color for [f32; 3] {
+    const ALPHA: bool = false;
+    const FLOAT: bool = true;
+
+    fn to_rgba(&self) -> [f32; 4] {
+        [self[0], self[1], self[2], 1.0]
+    }
+
+    fn set_rgba(&mut self, rgba: [f32; 4]) {
+        *self = [rgba[0], rgba[1], rgba[2]];
+    }
+}
+
+impl Color for [f32; 4] {
+    const ALPHA: bool = true;
+    const FLOAT: bool = true;
+
+    fn to_rgba(&self) -> [f32; 4] {
+        *self
+    }
+
+    fn set_rgba(&mut self, rgba: [f32; 4]) {
+        *self = rgba;
+    }
+}
+
+/// Returns the byte of the channel within `0.0..=1.0`.
+fn to_byte(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+impl Color for [u8; 3] {
+    const ALPHA: bool = false;
+    const FLOAT: bool = false;
+
+    fn to_rgba(&self) -> [f32; 4] {
+        [self[0], self[1], self[2], u8::MAX].map(|channel| f32::from(channel) / 255.0)
+    }
+
+    fn set_rgba(&mut self, rgba: [f32; 4]) {
+        *self = [rgba[0], rgba[1], rgba[2]].map(to_byte);
+    }
+}
+
+impl Color for [u8; 4] {
+    const ALPHA: bool = true;
+    const FLOAT: bool = false;
+
+    fn to_rgba(&self) -> [f32; 4] {
+        self.map(|channel| f32::from(channel) / 255.0)
+    }
+
+    fn set_rgba(&mut self, rgba: [f32; 4]) {
+        *self = rgba.map(to_byte);
+    }
+}
+
+/// An RGB color of the float channels, presented with a color picker.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Rgb(pub [f32; 3]);
+
+/// An RGBA color of the float channels, with the alpha not
+/// premultiplied, presented with a color picker.
+#[derive(Debug, Default, Copy, Clone, PartialEq)]
+pub struct Rgba(pub [f32; 4]);
+
+/// An RGBA color of the bytes in the sRGB space, with the alpha not
+/// premultiplied, presented with a color picker.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Srgba8(pub [u8; 4]);
+
+macro_rules! define_for_wrapper {
+    ($wrapper:ident, $channels:ty) => {
+        impl Color for $wrapper {
+            const ALPHA: bool = <$channels as Color>::ALPHA;
+            const FLOAT: bool = <$channels as Color>::FLOAT;
+
+            fn to_rgba(&self) -> [f32; 4] {
+                self.0.to_rgba()
+            }
+
+            fn set_rgba(&mut self, rgba: [f32; 4]) {
+                self.0.set_rgba(rgba);
+            }
+        }
+
+        impl From<$channels> for $wrapper {
+            fn from(channels: $channels) -> Self {
+                Self(channels)
+            }
+        }
+
+        impl From<$wrapper> for $channels {
+            fn from(color: $wrapper) -> Self {
+                color.0
+            }
+        }
+
+        impl crate::Reflect for $wrapper {
+            fn reflect(&self) -> crate::reflect::ReflectRef<'_> {
+                crate::Reflect::reflect(&self.0)
+            }
+
+            fn reflect_mut(&mut self) -> crate::reflect::ReflectMut<'_> {
+                crate::Reflect::reflect_mut(&mut self.0)
+            }
+        }
+    };
+}
+
+define_for_wrapper!(Rgb, [f32; 3]);
+define_for_wrapper!(Rgba, [f32; 4]);
+define_for_wrapper!(Srgba8, [u8; 4]);
+
+#[cfg(feature = "glam")]
+mod glam_types {
+    use super::Color;
+
+    impl Color for glam::Vec3 {
+        const ALPHA: bool = false;
+        const FLOAT: bool = true;
+
+        fn to_rgba(&self) -> [f32; 4] {
+            self.extend(1.0).to_array()
+        }
+
+        fn set_rgba(&mut self, rgba: [f32; 4]) {
+            *self = glam::Vec4::from_array(rgba).truncate();
+        }
+    }
+
+    impl Color for glam::Vec3A {
+        const ALPHA: bool = false;
+        const FLOAT: bool = true;
+
+        fn to_rgba(&self) -> [f32; 4] {
+            self.extend(1.0).to_array()
+        }
+
+        fn set_rgba(&mut self, rgba: [f32; 4]) {
+            *self = glam::Vec4::from_array(rgba).truncate().into();
+        }
+    }
+
+    impl Color for glam::Vec4 {
+        const ALPHA: bool = true;
+        const FLOAT: bool = true;
+
+        fn to_rgba(&self) -> [f32; 4] {
+            self.to_array()
+        }
+
+        fn set_rgba(&mut self, rgba: [f32; 4]) {
+            *self = glam::Vec4::from_array(rgba);
+        }
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+impl Color for egui::Color32 {
+    const ALPHA: bool = true;
+    const FLOAT: bool = false;
+
+    fn to_rgba(&self) -> [f32; 4] {
+        self.to_srgba_unmultiplied().to_rgba()
+    }
+
+    fn set_rgba(&mut self, rgba: [f32; 4]) {
+        let [r, g, b, a] = rgba.map(to_byte);
+        *self = Self::from_rgba_unmultiplied(r, g, b, a);
+    }
+}
+
+/// Converts a channel from the sRGB space to the linear one. The
+/// conversions are done in `f64`, so that the round trips keep the
+/// channels.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+fn linear_from_srgb(channel: f32) -> f32 {
+    let channel = f64::from(channel);
+    let linear = if channel <= 0.04045 {
+        channel / 12.92
+    } else {
+        ((channel + 0.055) / 1.055).powf(2.4)
+    };
+    linear as f32
+}
+
+/// Converts a channel from the linear space to the sRGB one.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+fn srgb_from_linear(channel: f32) -> f32 {
+    let channel = f64::from(channel);
+    let srgb = if channel <= 0.003_130_8 {
+        channel * 12.92
+    } else {
+        1.055 * channel.powf(1.0 / 2.4) - 0.055
+    };
+    srgb as f32
+}
+
+/// Converts the color channels, leaving the alpha as it is.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+fn convert(rgba: [f32; 4], conversion: fn(f32) -> f32) -> [f32; 4] {
+    [
+        conversion(rgba[0]),
+        conversion(rgba[1]),
+        conversion(rgba[2]),
+        rgba[3],
+    ]
+}
+
+/// Returns the channels of the color in the sRGB space.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+fn to_srgba<C: Color>(color: &C, options: ColorOptions) -> [f32; 4] {
+    match C::FLOAT && options.linear {
+        true => convert(color.to_rgba(), srgb_from_linear),
+        false => color.to_rgba(),
+    }
+}
+
+/// Sets the channels of the color from the ones in the sRGB space.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+fn set_srgba<C: Color>(color: &mut C, srgba: [f32; 4], options: ColorOptions) {
+    match C::FLOAT && options.linear {
+        true => color.set_rgba(convert(srgba, linear_from_srgb)),
+        false => color.set_rgba(srgba),
+    }
+}
+
+/// Returns the options applying to the type of the color, the integer
+/// ones being neither linear nor HDR, to show the copy of its channels.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+fn options_of<C: Color>(options: ColorOptions) -> ColorOptions {
+    ColorOptions {
+        alpha: C::ALPHA && options.alpha,
+        hdr: C::FLOAT && options.hdr,
+        linear: C::FLOAT && options.linear,
+    }
+}
+
+/// Edits the color with an ImGui `ColorEdit`, labelled by the `label`,
+/// returning what the user has done to it.
+#[cfg(feature = "imgui_backend")]
+pub fn edit_imgui<C: Color>(
+    ui: &imgui::Ui,
+    label: &str,
+    color: &mut C,
+    options: ColorOptions,
+) -> crate::EditResponse {
+    let mut srgba = to_srgba(color, options);
+    let mut flags = imgui::ColorEditFlags::empty();
+    if C::FLOAT && options.hdr {
+        flags |= imgui::ColorEditFlags::HDR | imgui::ColorEditFlags::FLOAT;
+    }
+
+    let changed = if C::ALPHA && options.alpha {
+        ui.color_edit4_config(label, &mut srgba)
+            .flags(flags | imgui::ColorEditFlags::ALPHA_BAR)
+            .build()
+    } else {
+        let mut srgb = [srgba[0], srgba[1], srgba[2]];
+        let changed = ui.color_edit3_config(label, &mut srgb).flags(flags).build();
+        srgba[..3].copy_from_slice(&srgb);
+        changed
+    };
+
+    if changed {
+        set_srgba(color, srgba, options);
+    }
+    crate::EditResponse::from_last_imgui_item(ui, changed)
+}
+
+/// Shows the color with a disabled ImGui `ColorEdit`.
+#[cfg(feature = "imgui_backend")]
+pub fn show_imgui<C: Color>(ui: &imgui::Ui, label: &str, color: &C, options: ColorOptions) {
+    let mut rgba = color.to_rgba();
+    ui.disabled(true, || {
+        let _ = edit_imgui(ui, label, &mut rgba, options_of::<C>(options));
+    });
+}
+
+/// Edits the color with an egui `color_edit_button`, followed by the
+/// values of the channels if those may exceed `1.0`, which the picker
+/// doesn't allow. Returns what the user has done to the color.
+#[cfg(feature = "egui_backend")]
+pub fn edit_egui<C: Color>(
+    ui: &mut egui::Ui,
+    color: &mut C,
+    options: ColorOptions,
+) -> crate::EditResponse {
+    let alpha = C::ALPHA && options.alpha;
+
+    ui.horizontal(|ui| {
+        // The egui pickers edit the colors in the linear space.
+        let mut rgba = convert(to_srgba(color, options), linear_from_srgb);
+        let picked = if alpha {
+            ui.color_edit_button_rgba_unmultiplied(&mut rgba)
+        } else {
+            let mut rgb = [rgba[0], rgba[1], rgba[2]];
+            let picked = ui.color_edit_button_rgb(&mut rgb);
+            rgba[..3].copy_from_slice(&rgb);
+            picked
+        };
+        if picked.changed() {
+            set_srgba(color, convert(rgba, srgb_from_linear), options);
+        }
+        let mut response = crate::EditResponse::from(picked);
+
+        if C::FLOAT && options.hdr {
+            let mut rgba = color.to_rgba();
+            let channels = if alpha { 4 } else { 3 };
+            for (index, name) in ["R ", "G ", "B ", "A "]
+                .into_iter()
+                .enumerate()
+                .take(channels)
+            {
+                let limit = if index == 3 { 1.0 } else { f32::INFINITY };
+                response |= ui
+                    .add(
+                        egui::DragValue::new(&mut rgba[index])
+                            .speed(0.01)
+                            .range(0.0..=limit)
+                            .prefix(name),
+                    )
+                    .into();
+            }
+            if response.changed {
+                color.set_rgba(rgba);
+            }
+        }
+
+        response
+    })
+    .inner
+}
+
+/// Shows the color with a disabled egui `color_edit_button`.
+#[cfg(feature = "egui_backend")]
+pub fn show_egui<C: Color>(ui: &mut egui::Ui, color: &C, options: ColorOptions) {
+    let mut rgba = color.to_rgba();
+    ui.add_enabled_ui(false, |ui| {
+        let _ = edit_egui(ui, &mut rgba, options_of::<C>(options));
+    });
+}
+
+/// Returns the hexadecimal code of the color, like `#ff8000`, with the
+/// alpha if it is edited. The channels are clamped to `0.0..=1.0`.
+#[cfg(feature = "ratatui_backend")]
+fn to_hex<C: Color>(color: &C, options: ColorOptions) -> String {
+    let channels = if C::ALPHA && options.alpha { 4 } else { 3 };
+    std::iter::once("#".to_owned())
+        .chain(
+            to_srgba(color, options)
+                .into_iter()
+                .take(channels)
+                .map(|channel| format!("{:02x}", to_byte(channel))),
+        )
+        .collect()
+}
+
+/// Parses the hexadecimal code of a color, with or without the alpha,
+/// into the channels. The alpha is `None` if it is omitted.
+#[cfg(feature = "ratatui_backend")]
+fn parse_hex(code: &str) -> Option<([f32; 3], Option<f32>)> {
+    let code = code.trim().trim_start_matches('#');
+    if !matches!(code.len(), 6 | 8) || !code.is_ascii() {
+        return None;
+    }
+    let channel = |index: usize| {
+        u8::from_str_radix(&code[index * 2..index * 2 + 2], 16)
+            .ok()
+            .map(|byte| f32::from(byte) / 255.0)
+    };
+    let alpha = match code.len() {
+        8 => Some(channel(3)?),
+        _ => None,
+    };
+    Some(([channel(0)?, channel(1)?, channel(2)?], alpha))
+}
+
+/// Edits the color in a terminal as its hexadecimal code, like
+/// `#ff8000`, returning what the user has done to it. The codes which
+/// can't be parsed are ignored.
+#[cfg(feature = "ratatui_backend")]
+pub fn edit_tui<C: Color>(
+    ui: &mut crate::tui::TuiUi<'_>,
+    color: &mut C,
+    options: ColorOptions,
+) -> crate::EditResponse {
+    let mut code = to_hex(color, options);
+    let mut response = ui.text_edit(&mut code);
+    if response.changed {
+        match parse_hex(&code) {
+            Some((rgb, alpha)) => {
+                let mut srgba = to_srgba(color, options);
+                srgba[..3].copy_from_slice(&rgb);
+                if let Some(alpha) = alpha.filter(|_| options.alpha) {
+                    srgba[3] = alpha;
+                }
+                set_srgba(color, srgba, options);
+            }
+            None => response.changed = false,
+        }
+    }
+    response
+}
+
+/// Shows the color in a terminal as its hexadecimal code.
+#[cfg(feature = "ratatui_backend")]
+pub fn show_tui<C: Color>(ui: &mut crate::tui::TuiUi<'_>, color: &C, options: ColorOptions) {
+    ui.label(to_hex(color, options));
+}
+
+macro_rules! define_presentation_for_color {
+    ($color:ty) => {
+        #[cfg(feature = "imgui_backend")]
+        impl crate::ImguiPresentable for $color {
+            fn render_component(&self, ui: &imgui::Ui, _: crate::Extent) {
+                show_imgui(ui, &format!("##{self:p}"), self, ColorOptions::new());
+            }
+
+            fn render_component_mut(
+                &mut self,
+                ui: &imgui::Ui,
+                _: crate::Extent,
+            ) -> crate::EditResponse {
+                edit_imgui(ui, &format!("##{self:p}"), self, ColorOptions::new())
+            }
+
+            fn element_actions() -> crate::elements::ElementActions<Self> {
+                crate::elements::ElementActions::new()
+                    .with_default()
+                    .with_clone()
+            }
+        }
+
+        #[cfg(feature = "egui_backend")]
+        impl crate::EguiPresentable for $color {
+            fn render_component(&self, ui: &mut egui::Ui) {
+                show_egui(ui, self, ColorOptions::new());
+            }
+
+            fn render_component_mut(&mut self, ui: &mut egui::Ui) -> crate::EditResponse {
+                edit_egui(ui, self, ColorOptions::new())
+            }
+
+            fn element_actions() -> crate::elements::ElementActions<Self> {
+                crate::elements::ElementActions::new()
+                    .with_default()
+                    .with_clone()
+            }
+        }
+
+        #[cfg(feature = "ratatui_backend")]
+        impl crate::TuiPresentable for $color {
+            fn render_component(&self, ui: &mut crate::tui::TuiUi<'_>) {
+                show_tui(ui, self, ColorOptions::new());
+            }
+
+            fn render_component_mut(
+                &mut self,
+                ui: &mut crate::tui::TuiUi<'_>,
+            ) -> crate::EditResponse {
+                edit_tui(ui, self, ColorOptions::new())
+            }
+        }
+    };
+}
+
+define_presentation_for_color!(Rgb);
+define_presentation_for_color!(Rgba);
+define_presentation_for_color!(Srgba8);
+#[cfg(feature = "egui_backend")]
+define_presentation_for_color!(egui::Color32);
+
+#[cfg(test)]
+mod tests {
+    use super::{Color, Rgba, Srgba8};
+
+    #[test]
+    fn converts_the_channels() {
+        let mut color = Srgba8([255, 128, 0, 64]);
+        assert_eq!(color.to_rgba()[0], 1.0);
+        color.set_rgba([0.5, 2.0, -1.0, 1.0]);
+        assert_eq!(color, Srgba8([128, 255, 0, 255]));
+
+        let mut rgb = [0.1f32, 0.2, 0.3];
+        rgb.set_rgba([1.0, 0.5, 0.25, 0.0]);
+        assert_eq!(rgb, [1.0, 0.5, 0.25]);
+        assert_eq!(Rgba::from([1.0; 4]).to_rgba(), [1.0; 4]);
+    }
+
+    #[cfg(feature = "ratatui_backend")]
+    #[test]
+    fn parses_the_hexadecimal_codes() {
+        use super::{parse_hex, to_hex, ColorOptions};
+
+        let options = ColorOptions::new();
+        assert_eq!(to_hex(&[255u8, 128, 0, 64], options), "#ff800040");
+        assert_eq!(
+            to_hex(&[255u8, 128, 0, 64], options.without_alpha()),
+            "#ff8000"
+        );
+        assert_eq!(to_hex(&[0.0f32, 0.0, 1.0], options), "#0000ff");
+        // The linear channels are shown in the sRGB space.
+        assert_eq!(
+            to_hex(&[0.215_861f32, 0.0, 0.0], options.with_linear()),
+            "#800000"
+        );
+
+        assert_eq!(parse_hex("#ff0000"), Some(([1.0, 0.0, 0.0], None)));
+        assert_eq!(parse_hex("00ff0080").map(|(_, a)| a.is_some()), Some(true));
+        assert_eq!(parse_hex("#ff00"), None);
+        assert_eq!(parse_hex("#gg0000"), None);
+    }
+}
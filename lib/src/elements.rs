@@ -0,0 +1,342 @@
+//! The editing of the elements of the sequences.
+//!
+//! The mutable presentations of a [`Vec`] and a
+//! [`std::collections::VecDeque`] allow to remove the elements, move
+//! those up and down or drag them to another position. The elements
+//! may also be added to the end of the sequence or inserted before
+//! another one if their type can create a new value, and duplicated if
+//! it can clone one. What the type can do is told by the
+//! `element_actions` method of the presentation traits, which the
+//! derive macros implement by checking whether the type implements the
+//! [`Default`] and the [`Clone`] traits:
+//!
+//! ```rust,ignore
+//! #[derive(Default, Clone, ImguiPresentation)]
+//! struct Object {
+//!     name: String,
+//! }
+//!
+//! #[derive(ImguiPresentation)]
+//! struct Scene {
+//!     // The objects may be added, inserted and duplicated.
+//!     objects: Vec<Object>,
+//! }
+//! ```
+//!
+//! The types implementing the traits manually may return those
+//! actions with [`ElementActions::with_default`] and
+//! [`ElementActions::with_clone`].
+
+/// The actions a sequence may do to its elements, besides removing and
+/// moving those, which are always possible.
+pub struct ElementActions<T> {
+    /// Creates a new element, to add it to the end of the sequence or
+    /// to insert it before another one.
+    pub create: Option<fn() -> T>,
+    /// Creates a copy of an element, to insert it after the original.
+    pub duplicate: Option<fn(&T) -> T>,
+}
+
+impl<T> ElementActions<T> {
+    /// Returns the actions which neither create nor duplicate the
+    /// elements.
+    pub const fn new() -> Self {
+        Self {
+            create: None,
+            duplicate: None,
+        }
+    }
+
+    /// Allows to create the elements with their [`Default`]
+    /// implementation.
+    pub fn with_default(self) -> Self
+    where
+        T: Default,
+    {
+        Self {
+            create: Some(T::default),
+            ..self
+        }
+    }
+
+    /// Allows to duplicate the elements with their [`Clone`]
+    /// implementation.
+    pub fn with_clone(self) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            duplicate: Some(T::clone),
+            ..self
+        }
+    }
+}
+
+impl<T> Default for ElementActions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for ElementActions<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for ElementActions<T> {}
+
+impl<T> std::fmt::Debug for ElementActions<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ElementActions")
+            .field("create", &self.create.is_some())
+            .field("duplicate", &self.duplicate.is_some())
+            .finish()
+    }
+}
+
+/// Picks the element actions for a concrete type at compile time, by
+/// preferring the methods of the traits implemented for the more
+/// referenced probes, which require the [`Default`] or the [`Clone`]
+/// implementation. Used by the derive macros.
+#[doc(hidden)]
+pub mod probe {
+    use std::marker::PhantomData;
+
+    pub struct Probe<T>(PhantomData<T>);
+
+    impl<T> Probe<T> {
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<T> Default for Probe<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub trait ViaDefault<T> {
+        fn create(&self) -> Option<fn() -> T>;
+    }
+
+    impl<T: Default> ViaDefault<T> for &Probe<T> {
+        fn create(&self) -> Option<fn() -> T> {
+            Some(T::default)
+        }
+    }
+
+    pub trait ViaClone<T> {
+        fn duplicate(&self) -> Option<fn(&T) -> T>;
+    }
+
+    impl<T: Clone> ViaClone<T> for &Probe<T> {
+        fn duplicate(&self) -> Option<fn(&T) -> T> {
+            Some(T::clone)
+        }
+    }
+
+    pub trait Unsupported<T> {
+        fn create(&self) -> Option<fn() -> T>;
+        fn duplicate(&self) -> Option<fn(&T) -> T>;
+    }
+
+    impl<T> Unsupported<T> for Probe<T> {
+        fn create(&self) -> Option<fn() -> T> {
+            None
+        }
+
+        fn duplicate(&self) -> Option<fn(&T) -> T> {
+            None
+        }
+    }
+}
+
+/// Returns the [`ElementActions`] of a type, allowing to create the
+/// elements if the type implements [`Default`] and to duplicate those
+/// if it implements [`Clone`]. The implementations are only found for
+/// the concrete types, not for the generic parameters.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! element_actions {
+    ($type: ty) => {{
+        #[allow(unused_imports)]
+        use $crate::elements::probe::{Probe, Unsupported, ViaClone, ViaDefault};
+        $crate::elements::ElementActions::<$type> {
+            create: (&&Probe::<$type>::new()).create(),
+            duplicate: (&&Probe::<$type>::new()).duplicate(),
+        }
+    }};
+}
+
+/// An edit of a sequence requested in its presentation, done once the
+/// elements are rendered.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub(crate) enum SequenceEdit {
+    /// Adds a new element to the end.
+    Push,
+    /// Inserts a new element before the one with the index.
+    InsertBefore(usize),
+    /// Inserts a copy of the element after it.
+    Duplicate(usize),
+    /// Removes the element.
+    Remove(usize),
+    /// Moves the element to another position.
+    Move { from: usize, to: usize },
+    /// Removes all the elements.
+    Clear,
+}
+
+/// A sequence of elements which may be edited in its presentation.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+pub(crate) trait Sequence {
+    type Element;
+
+    fn len(&self) -> usize;
+    fn get(&self, index: usize) -> &Self::Element;
+    fn get_mut(&mut self, index: usize) -> &mut Self::Element;
+    fn insert(&mut self, index: usize, element: Self::Element);
+    fn remove(&mut self, index: usize) -> Self::Element;
+    fn clear(&mut self);
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl<T> Sequence for Vec<T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self[index]
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        self.remove(index)
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl<T> Sequence for std::collections::VecDeque<T> {
+    type Element = T;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn get(&self, index: usize) -> &T {
+        &self[index]
+    }
+
+    fn get_mut(&mut self, index: usize) -> &mut T {
+        &mut self[index]
+    }
+
+    fn insert(&mut self, index: usize, element: T) {
+        self.insert(index, element);
+    }
+
+    fn remove(&mut self, index: usize) -> T {
+        self.remove(index)
+            .expect("The index of an element is valid.")
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl SequenceEdit {
+    /// Applies the edit to the sequence, returning `true` if it has
+    /// changed. The edits the element type doesn't support and the
+    /// ones of the elements no longer there do nothing.
+    pub(crate) fn apply<S: Sequence>(
+        self,
+        sequence: &mut S,
+        actions: ElementActions<S::Element>,
+    ) -> bool {
+        let len = sequence.len();
+        match self {
+            Self::Push => match actions.create {
+                Some(create) => {
+                    sequence.insert(len, create());
+                    true
+                }
+                None => false,
+            },
+            Self::InsertBefore(index) => match actions.create {
+                Some(create) if index <= len => {
+                    sequence.insert(index, create());
+                    true
+                }
+                _ => false,
+            },
+            Self::Duplicate(index) => match actions.duplicate {
+                Some(duplicate) if index < len => {
+                    let copy = duplicate(sequence.get(index));
+                    sequence.insert(index + 1, copy);
+                    true
+                }
+                _ => false,
+            },
+            Self::Remove(index) if index < len => {
+                let _ = sequence.remove(index);
+                true
+            }
+            Self::Move { from, to } if from < len && to < len && from != to => {
+                let element = sequence.remove(from);
+                sequence.insert(to, element);
+                true
+            }
+            Self::Clear => {
+                sequence.clear();
+                len > 0
+            }
+            Self::Remove(_) | Self::Move { .. } => false,
+        }
+    }
+}
+
+#[cfg(all(test, any(feature = "imgui_backend", feature = "egui_backend")))]
+mod tests {
+    use super::{ElementActions, SequenceEdit};
+
+    #[test]
+    fn edits_the_sequences() {
+        let actions = ElementActions::new().with_default().with_clone();
+        let mut sequence = vec![1, 2, 3];
+
+        assert!(SequenceEdit::Move { from: 0, to: 2 }.apply(&mut sequence, actions));
+        assert_eq!(sequence, [2, 3, 1]);
+        assert!(SequenceEdit::Duplicate(1).apply(&mut sequence, actions));
+        assert_eq!(sequence, [2, 3, 3, 1]);
+        assert!(SequenceEdit::InsertBefore(0).apply(&mut sequence, actions));
+        assert!(SequenceEdit::Push.apply(&mut sequence, actions));
+        assert_eq!(sequence, [0, 2, 3, 3, 1, 0]);
+        assert!(SequenceEdit::Remove(5).apply(&mut sequence, actions));
+        assert!(!SequenceEdit::Remove(5).apply(&mut sequence, actions));
+
+        // Without the actions, the elements may only be removed and moved.
+        let mut deque = std::collections::VecDeque::from([1, 2]);
+        assert!(!SequenceEdit::Push.apply(&mut deque, ElementActions::new()));
+        assert!(!SequenceEdit::Duplicate(0).apply(&mut deque, ElementActions::new()));
+        assert!(SequenceEdit::Move { from: 1, to: 0 }.apply(&mut deque, ElementActions::new()));
+        assert_eq!(deque, [2, 1]);
+    }
+}
@@ -0,0 +1,416 @@
+//! The editing of the entries of the maps and the sets.
+//!
+//! The keys of the entries can't be changed in place, as that would
+//! break the order of a [`std::collections::BTreeMap`] or the hashes of
+//! a [`std::collections::HashMap`]. Instead, a key is renamed by editing
+//! its copy and re-inserting the value with it once confirmed, and a
+//! new entry is added by editing its key first. The edited keys are
+//! kept between the frames in an [`EntryEditor`] of the collection.
+
+use std::any::{Any, TypeId};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::hash::Hash;
+
+use crate::frame_state::FrameStates;
+
+thread_local! {
+    /// The editors of the collections with the keys being edited,
+    /// identified by the addresses of the collections and the types of
+    /// the keys. The editors of the collections not rendered during a
+    /// frame are dropped.
+    static EDITORS: RefCell<FrameStates<(usize, TypeId), Box<dyn Any>>> = RefCell::default();
+}
+
+/// Shown when an entry can't be added or renamed.
+const KEY_EXISTS: &str = "An entry with this key already exists.";
+
+/// The keys of a map or a set being edited.
+pub(crate) struct EntryEditor<K> {
+    /// The key of a new entry.
+    pub(crate) new_key: Option<K>,
+    /// The order number of an entry being renamed and its new key.
+    pub(crate) renamed: Option<(usize, K)>,
+    /// Why the last edit couldn't be done.
+    pub(crate) error: Option<&'static str>,
+}
+
+impl<K: 'static> EntryEditor<K> {
+    /// Takes the editor of the collection out of the storage, so that
+    /// the collections nested into the entries may use theirs. The
+    /// editor stays boxed, as the widgets of the keys being edited are
+    /// identified by their addresses, which must be kept between the
+    /// frames.
+    pub(crate) fn take(frame: u64, collection: usize) -> Box<Self> {
+        EDITORS
+            .with(|editors| {
+                editors
+                    .borrow_mut()
+                    .take(frame, &(collection, TypeId::of::<K>()))
+            })
+            .and_then(|editor| editor.downcast().ok())
+            .unwrap_or_else(|| {
+                Box::new(Self {
+                    new_key: None,
+                    renamed: None,
+                    error: None,
+                })
+            })
+    }
+
+    /// Puts the editor back into the storage, unless nothing is being
+    /// edited.
+    pub(crate) fn store(self: Box<Self>, frame: u64, collection: usize) {
+        if self.new_key.is_some() || self.renamed.is_some() {
+            EDITORS.with(|editors| {
+                editors
+                    .borrow_mut()
+                    .insert(frame, (collection, TypeId::of::<K>()), self);
+            });
+        }
+    }
+}
+
+impl<K: Eq> EntryEditor<K> {
+    /// Applies the edit to the collection, returning `true` if it has
+    /// changed. The key of an edit which couldn't be done is kept being
+    /// edited, along with the reason.
+    pub(crate) fn apply<E: Entries<Key = K>>(
+        &mut self,
+        edit: EntryEdit<K>,
+        entries: &mut E,
+        create_value: Option<fn() -> E::Value>,
+    ) -> bool {
+        let renamed = match edit {
+            EntryEdit::Rename { index, .. } => Some(index),
+            _ => None,
+        };
+
+        match edit.apply(entries, create_value) {
+            Ok(changed) => {
+                self.error = None;
+                changed
+            }
+            Err(key) => {
+                self.error = Some(KEY_EXISTS);
+                match renamed {
+                    Some(index) => self.renamed = Some((index, key)),
+                    None => self.new_key = Some(key),
+                }
+                false
+            }
+        }
+    }
+}
+
+/// A map or a set, which is a collection of the entries with the unique
+/// keys. The values of the sets are the unit type.
+pub(crate) trait Entries {
+    type Key: Eq;
+    type Value;
+
+    fn len(&self) -> usize;
+    fn iter_entries(&self) -> impl Iterator<Item = (&Self::Key, &Self::Value)>;
+    fn contains_key(&self, key: &Self::Key) -> bool;
+    fn nth_key(&self, index: usize) -> Option<&Self::Key>;
+    /// Inserts an entry with the key not in the collection yet.
+    fn insert_entry(&mut self, key: Self::Key, value: Self::Value);
+    /// Removes the entry with the order number provided.
+    fn remove_nth(&mut self, index: usize) -> Option<(Self::Key, Self::Value)>;
+    fn clear(&mut self);
+}
+
+impl<K: Ord, V> Entries for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter_entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn nth_key(&self, index: usize) -> Option<&K> {
+        self.keys().nth(index)
+    }
+
+    fn insert_entry(&mut self, key: K, value: V) {
+        let _ = self.insert(key, value);
+    }
+
+    fn remove_nth(&mut self, index: usize) -> Option<(K, V)> {
+        // The key can't be borrowed from the map while removing its
+        // entry, so the others are put back instead.
+        let mut entries: Vec<_> = std::mem::take(self).into_iter().collect();
+        let removed = (index < entries.len()).then(|| entries.remove(index));
+        self.extend(entries);
+        removed
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<K: Eq + Hash, V> Entries for HashMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter_entries(&self) -> impl Iterator<Item = (&K, &V)> {
+        self.iter()
+    }
+
+    fn contains_key(&self, key: &K) -> bool {
+        self.contains_key(key)
+    }
+
+    fn nth_key(&self, index: usize) -> Option<&K> {
+        self.keys().nth(index)
+    }
+
+    fn insert_entry(&mut self, key: K, value: V) {
+        let _ = self.insert(key, value);
+    }
+
+    fn remove_nth(&mut self, index: usize) -> Option<(K, V)> {
+        // The others are put back into the same table, keeping the hasher
+        // and the capacity, so that their order is kept.
+        let mut entries: Vec<_> = self.drain().collect();
+        let removed = (index < entries.len()).then(|| entries.remove(index));
+        self.extend(entries);
+        removed
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Ord> Entries for BTreeSet<T> {
+    type Key = T;
+    type Value = ();
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter_entries(&self) -> impl Iterator<Item = (&T, &())> {
+        self.iter().map(|key| (key, &()))
+    }
+
+    fn contains_key(&self, key: &T) -> bool {
+        self.contains(key)
+    }
+
+    fn nth_key(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    fn insert_entry(&mut self, key: T, _: ()) {
+        let _ = self.insert(key);
+    }
+
+    fn remove_nth(&mut self, index: usize) -> Option<(T, ())> {
+        let mut keys: Vec<_> = std::mem::take(self).into_iter().collect();
+        let removed = (index < keys.len()).then(|| keys.remove(index));
+        self.extend(keys);
+        removed.map(|key| (key, ()))
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Eq + Hash> Entries for HashSet<T> {
+    type Key = T;
+    type Value = ();
+
+    fn len(&self) -> usize {
+        self.len()
+    }
+
+    fn iter_entries(&self) -> impl Iterator<Item = (&T, &())> {
+        self.iter().map(|key| (key, &()))
+    }
+
+    fn contains_key(&self, key: &T) -> bool {
+        self.contains(key)
+    }
+
+    fn nth_key(&self, index: usize) -> Option<&T> {
+        self.iter().nth(index)
+    }
+
+    fn insert_entry(&mut self, key: T, _: ()) {
+        let _ = self.insert(key);
+    }
+
+    fn remove_nth(&mut self, index: usize) -> Option<(T, ())> {
+        let mut keys: Vec<_> = self.drain().collect();
+        let removed = (index < keys.len()).then(|| keys.remove(index));
+        self.extend(keys);
+        removed.map(|key| (key, ()))
+    }
+
+    fn clear(&mut self) {
+        self.clear();
+    }
+}
+
+/// A map, the values of which may be changed in place.
+pub(crate) trait MapEntries: Entries {
+    fn iter_entries_mut(&mut self) -> impl Iterator<Item = (&Self::Key, &mut Self::Value)>;
+}
+
+impl<K: Ord, V> MapEntries for BTreeMap<K, V> {
+    fn iter_entries_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.iter_mut()
+    }
+}
+
+impl<K: Eq + Hash, V> MapEntries for HashMap<K, V> {
+    fn iter_entries_mut(&mut self) -> impl Iterator<Item = (&K, &mut V)> {
+        self.iter_mut()
+    }
+}
+
+/// An edit of a map or a set requested in its presentation, done once
+/// the entries are rendered.
+pub(crate) enum EntryEdit<K> {
+    /// Adds an entry with the key and a new value.
+    Add(K),
+    /// Moves the value of the entry with the order number to the key.
+    Rename { index: usize, key: K },
+    /// Removes the entry with the order number.
+    Remove(usize),
+    /// Removes all the entries.
+    Clear,
+}
+
+impl<K: Eq> EntryEdit<K> {
+    /// Applies the edit to the collection, returning `true` if it has
+    /// changed, or the key back if there is another entry with it.
+    pub(crate) fn apply<E: Entries<Key = K>>(
+        self,
+        entries: &mut E,
+        create_value: Option<fn() -> E::Value>,
+    ) -> Result<bool, K> {
+        match self {
+            Self::Add(key) => {
+                if entries.contains_key(&key) {
+                    return Err(key);
+                }
+                let Some(create_value) = create_value else {
+                    return Ok(false);
+                };
+                entries.insert_entry(key, create_value());
+                Ok(true)
+            }
+            Self::Rename { index, key } => {
+                let Some(old) = entries.nth_key(index) else {
+                    return Ok(false);
+                };
+                if *old == key {
+                    return Ok(false);
+                }
+                if entries.contains_key(&key) {
+                    return Err(key);
+                }
+                let Some((_, value)) = entries.remove_nth(index) else {
+                    return Ok(false);
+                };
+                entries.insert_entry(key, value);
+                Ok(true)
+            }
+            Self::Remove(index) => Ok(entries.remove_nth(index).is_some()),
+            Self::Clear => {
+                let len = entries.len();
+                entries.clear();
+                Ok(len > 0)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::{BTreeMap, HashSet};
+
+    use super::{EntryEdit, EntryEditor};
+
+    #[test]
+    fn edits_the_entries() {
+        let mut map = BTreeMap::from([(1, "a".to_owned()), (2, "b".to_owned())]);
+        let create = Some(String::new as fn() -> String);
+
+        assert_eq!(EntryEdit::Add(3).apply(&mut map, create), Ok(true));
+        assert_eq!(EntryEdit::Add(3).apply(&mut map, create), Err(3));
+        assert_eq!(
+            EntryEdit::Rename { index: 0, key: 5 }.apply(&mut map, create),
+            Ok(true)
+        );
+        assert_eq!(
+            EntryEdit::Rename { index: 0, key: 3 }.apply(&mut map, create),
+            Err(3)
+        );
+        assert_eq!(
+            EntryEdit::Rename { index: 0, key: 2 }.apply(&mut map, create),
+            Ok(false)
+        );
+        assert_eq!(
+            map,
+            BTreeMap::from([(2, "b".to_owned()), (3, String::new()), (5, "a".to_owned())])
+        );
+        assert_eq!(EntryEdit::Remove(1).apply(&mut map, create), Ok(true));
+        assert_eq!(map.keys().collect::<Vec<_>>(), [&2, &5]);
+
+        let mut set = HashSet::from([1]);
+        assert_eq!(
+            EntryEdit::Rename { index: 0, key: 2 }.apply(&mut set, Some(|| ())),
+            Ok(true)
+        );
+        assert_eq!(set, HashSet::from([2]));
+    }
+
+    #[test]
+    fn keeps_only_the_editors_in_use() {
+        let mut editor = EntryEditor::<u32>::take(1, 1);
+        editor.new_key = Some(5);
+        editor.store(1, 1);
+
+        assert!(EntryEditor::<String>::take(2, 1).new_key.is_none());
+        let editor = EntryEditor::<u32>::take(2, 1);
+        assert_eq!(editor.new_key, Some(5));
+        editor.store(2, 1);
+        let mut editor = EntryEditor::<u32>::take(3, 1);
+        editor.new_key = None;
+        editor.store(3, 1);
+        assert!(EntryEditor::<u32>::take(3, 1).new_key.is_none());
+
+        // The editors of the collections not rendered during a frame
+        // are dropped.
+        let mut editor = EntryEditor::<u32>::take(4, 1);
+        editor.new_key = Some(5);
+        editor.store(4, 1);
+        assert!(EntryEditor::<u32>::take(6, 1).new_key.is_none());
+
+        // The key of an entry which couldn't be added is kept.
+        let mut set = HashSet::from([1]);
+        let mut editor = EntryEditor::<u32>::take(6, 2);
+        assert!(!editor.apply(EntryEdit::Add(1), &mut set, Some(|| ())));
+        assert_eq!((editor.new_key, editor.error.is_some()), (Some(1), true));
+        assert!(editor.apply(EntryEdit::Add(2), &mut set, Some(|| ())));
+        assert!(editor.error.is_none());
+    }
+}
@@ -0,0 +1,424 @@
+//! The filtering of the large presentations by a text query.
+//!
+//! Wrapping a value into a [`Filtered`] adds a filter bar above its
+//! presentation, which hides the fields, the elements and the entries
+//! whose names, indices, keys or displayed values don't match the
+//! query typed in. The matches are highlighted, and the collapsed
+//! sections containing those are expanded:
+//!
+//! ```rust,ignore
+//! let mut scene = Filtered::new(scene);
+//! // Every frame:
+//! let _ = scene.render_window_mut(ui, extent);
+//! ```
+//!
+//! The wrapper may also be used for a single collection, by making it
+//! the type of a field, like `objects: Filtered<Vec<Object>>`.
+//!
+//! The values are matched with the `matches_filter` method of the
+//! presentation traits. The types which don't implement it are only
+//! shown when the names of the fields holding those match.
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// The colour of the highlighted text.
+#[cfg(feature = "imgui_backend")]
+const IMGUI_HIGHLIGHT_COLOR: [f32; 4] = [1.0, 0.8, 0.2, 1.0];
+
+/// The colour of the highlighted text.
+#[cfg(feature = "egui_backend")]
+const EGUI_HIGHLIGHT_COLOR: egui::Color32 = egui::Color32::from_rgb(255, 204, 51);
+
+thread_local! {
+    /// The filter applied to the values being rendered.
+    static ACTIVE: RefCell<Option<Rc<Filter>>> = const { RefCell::new(None) };
+}
+
+/// A query the names and the displayed values are matched against. The
+/// matching is case-insensitive.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct Filter {
+    /// The query in lower case.
+    needle: String,
+}
+
+impl Filter {
+    /// Creates a filter matching the texts containing the query.
+    pub fn new(query: &str) -> Self {
+        Self {
+            needle: query.trim().to_lowercase(),
+        }
+    }
+
+    /// Returns `true` if the text contains the query.
+    pub fn matches(&self, text: &str) -> bool {
+        text.to_lowercase().contains(&self.needle)
+    }
+
+    /// Returns `true` if the text the value is displayed as contains the
+    /// query.
+    pub fn matches_display(&self, value: &dyn std::fmt::Display) -> bool {
+        self.matches(&value.to_string())
+    }
+
+    /// Returns the filter applied to the values being rendered, if any.
+    pub fn active() -> Option<Rc<Filter>> {
+        ACTIVE.with(|active| active.borrow().clone())
+    }
+
+    /// Returns `true` if there is a filter applied to the values being
+    /// rendered and the text the value is displayed as matches it.
+    pub fn active_matches(value: &dyn std::fmt::Display) -> bool {
+        ACTIVE.with(|active| {
+            active
+                .borrow()
+                .as_ref()
+                .is_some_and(|filter| filter.matches_display(value))
+        })
+    }
+
+    /// Calls the function, which renders the values, with the filter
+    /// applied, or with no filter applied if `None` is passed. The
+    /// previously applied filter is restored afterwards.
+    pub fn with<R>(filter: Option<Rc<Filter>>, render: impl FnOnce() -> R) -> R {
+        /// Restores the previous filter, even if the rendering panics.
+        struct Restore(Option<Rc<Filter>>);
+
+        impl Drop for Restore {
+            fn drop(&mut self) {
+                let previous = self.0.take();
+                ACTIVE.with(|active| *active.borrow_mut() = previous);
+            }
+        }
+
+        let _restore = Restore(ACTIVE.with(|active| active.replace(filter)));
+        render()
+    }
+}
+
+/// How a nested value, like a field or an element of a collection, is
+/// presented while a filter is applied.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct FilterMatch {
+    /// The value is shown.
+    pub shown: bool,
+    /// The name, the index or the key of the value matches, so it is
+    /// highlighted and shown along with everything nested in it.
+    pub name_matches: bool,
+    /// The value, or some of the values nested in it, matches, so it is
+    /// expanded.
+    pub value_matches: bool,
+}
+
+impl FilterMatch {
+    /// Matches the name and the value against the applied filter. All
+    /// the values are shown if there is no filter applied.
+    pub fn of(
+        name_matches: impl FnOnce(&Filter) -> bool,
+        value_matches: impl FnOnce(&Filter) -> bool,
+    ) -> Self {
+        let Some(filter) = Filter::active() else {
+            return Self {
+                shown: true,
+                ..Default::default()
+            };
+        };
+
+        let name_matches = name_matches(&filter);
+        let value_matches = !name_matches && value_matches(&filter);
+        Self {
+            shown: name_matches || value_matches,
+            name_matches,
+            value_matches,
+        }
+    }
+
+    /// Calls the function, which renders the value. The value the name
+    /// of which matches is rendered without the filter, so that
+    /// everything nested in it is shown.
+    pub fn render<R>(self, render: impl FnOnce() -> R) -> R {
+        if self.name_matches {
+            Filter::with(None, render)
+        } else {
+            render()
+        }
+    }
+}
+
+/// The rows of a collection shown with the applied filter.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+#[derive(Debug)]
+pub(crate) enum ShownRows {
+    /// All the rows of the collection, as there is no filter applied.
+    All(usize),
+    /// The order numbers of the rows shown, along with how those match.
+    Matching(Vec<(usize, FilterMatch)>),
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl ShownRows {
+    /// Matches the rows of the collection against the applied filter.
+    /// The rows aren't visited if there is no filter applied, so that
+    /// the long collections don't slow the frames down.
+    pub(crate) fn of<I: Iterator<Item = FilterMatch>>(
+        len: usize,
+        match_rows: impl FnOnce() -> I,
+    ) -> Self {
+        if Filter::active().is_none() {
+            return Self::All(len);
+        }
+
+        Self::Matching(
+            match_rows()
+                .enumerate()
+                .filter(|(_, filter_match)| filter_match.shown)
+                .collect(),
+        )
+    }
+
+    /// Returns the number of the rows shown.
+    pub(crate) fn len(&self) -> usize {
+        match self {
+            Self::All(len) => *len,
+            Self::Matching(rows) => rows.len(),
+        }
+    }
+
+    /// Returns the order number of the row shown at the position,
+    /// along with how it matches.
+    pub(crate) fn get(&self, position: usize) -> (usize, FilterMatch) {
+        match self {
+            Self::All(_) => (
+                position,
+                FilterMatch {
+                    shown: true,
+                    ..Default::default()
+                },
+            ),
+            Self::Matching(rows) => rows[position],
+        }
+    }
+}
+
+/// Highlights the text of the widgets rendered until the returned
+/// token is dropped, if `highlighted` is `true`.
+#[cfg(feature = "imgui_backend")]
+pub fn highlight_imgui(ui: &imgui::Ui, highlighted: bool) -> Option<imgui::ColorStackToken<'_>> {
+    highlighted.then(|| ui.push_style_color(imgui::StyleColor::Text, IMGUI_HIGHLIGHT_COLOR))
+}
+
+/// Highlights the text of the widgets the function adds, if
+/// `highlighted` is `true`.
+#[cfg(feature = "egui_backend")]
+pub fn highlight_egui<R>(
+    ui: &mut egui::Ui,
+    highlighted: bool,
+    add_contents: impl FnOnce(&mut egui::Ui) -> R,
+) -> R {
+    if !highlighted {
+        return add_contents(ui);
+    }
+
+    ui.scope(|ui| {
+        ui.visuals_mut().override_text_color = Some(EGUI_HIGHLIGHT_COLOR);
+        add_contents(ui)
+    })
+    .inner
+}
+
+/// Returns the text, highlighted if `highlighted` is `true`, like a
+/// header of a collapsing section.
+#[cfg(feature = "egui_backend")]
+pub fn highlighted_egui_text(text: impl Into<String>, highlighted: bool) -> egui::RichText {
+    let text = egui::RichText::new(text);
+    if highlighted {
+        text.color(EGUI_HIGHLIGHT_COLOR)
+    } else {
+        text
+    }
+}
+
+/// A value presented along with a filter bar, hiding the nested values
+/// which don't match the query typed in.
+#[derive(Debug, Default, Clone)]
+pub struct Filtered<T> {
+    value: T,
+    /// The query is changed in the immutable presentations as well.
+    query: RefCell<String>,
+}
+
+impl<T> Filtered<T> {
+    /// Wraps the value, showing all of it until a query is typed in.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            query: RefCell::default(),
+        }
+    }
+
+    /// Sets the query.
+    pub fn with_query(self, query: impl Into<String>) -> Self {
+        self.set_query(query);
+        self
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value, allowing to change it.
+    pub fn value_mut(&mut self) -> &mut T {
+        &mut self.value
+    }
+
+    /// Returns the value, consuming the wrapper.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns the query.
+    pub fn query(&self) -> String {
+        self.query.borrow().clone()
+    }
+
+    /// Replaces the query.
+    pub fn set_query(&self, query: impl Into<String>) {
+        *self.query.borrow_mut() = query.into();
+    }
+
+    /// Returns the filter typed in, or `None` if the query is empty.
+    #[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+    fn filter(&self) -> Option<Rc<Filter>> {
+        let query = self.query.borrow();
+        (!query.trim().is_empty()).then(|| Rc::new(Filter::new(&query)))
+    }
+}
+
+/// Calls the function, which renders the value, with the filter
+/// applied. Without a filter the one applied by the outer values, if
+/// any, is kept.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+fn with_filter<R>(filter: Option<Rc<Filter>>, render: impl FnOnce() -> R) -> R {
+    match filter {
+        Some(filter) => Filter::with(Some(filter), render),
+        None => render(),
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_backend {
+    use super::{with_filter, Filter, Filtered};
+    use crate::{EditResponse, Extent, ImguiPresentable};
+
+    impl<T> Filtered<T> {
+        fn render_imgui_filter_bar(&self, ui: &imgui::Ui) {
+            let mut query = self.query.borrow_mut();
+            let _ = ui
+                .input_text(format!("##filter{self:p}"), &mut query)
+                .hint("Filter")
+                .build();
+        }
+
+        fn imgui_window<'ui>(ui: &'ui imgui::Ui) -> imgui::Window<'ui, 'ui, &'static str> {
+            ui.window(std::any::type_name::<T>())
+                .resizable(true)
+                .collapsible(true)
+                .bg_alpha(0.7f32)
+                .position([0.0, 0.0], imgui::Condition::FirstUseEver)
+        }
+    }
+
+    impl<T: ImguiPresentable> ImguiPresentable for Filtered<T> {
+        fn render_window(&self, ui: &imgui::Ui, extent: Extent) {
+            Self::imgui_window(ui).build(|| self.render_component(ui, extent));
+        }
+
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            self.render_imgui_filter_bar(ui);
+            with_filter(self.filter(), || self.value.render_component(ui, extent));
+        }
+
+        fn render_window_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            Self::imgui_window(ui)
+                .build(|| self.render_component_mut(ui, extent))
+                .unwrap_or_default()
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.render_imgui_filter_bar(ui);
+            with_filter(self.filter(), || {
+                self.value.render_component_mut(ui, extent)
+            })
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.value.matches_filter(filter)
+        }
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_backend {
+    use super::{with_filter, Filter, Filtered};
+    use crate::{EditResponse, EguiPresentable};
+
+    impl<T> Filtered<T> {
+        fn render_egui_filter_bar(&self, ui: &mut egui::Ui) {
+            let mut query = self.query.borrow_mut();
+            let _ = ui.add(egui::TextEdit::singleline(&mut *query).hint_text("Filter"));
+        }
+    }
+
+    impl<T: EguiPresentable> EguiPresentable for Filtered<T> {
+        fn render_window(&self, context: &egui::Context) {
+            egui::Window::new(std::any::type_name::<T>())
+                .show(context, |ui| self.render_component(ui));
+        }
+
+        fn render_component(&self, ui: &mut egui::Ui) {
+            self.render_egui_filter_bar(ui);
+            with_filter(self.filter(), || self.value.render_component(ui));
+        }
+
+        fn render_window_mut(&mut self, context: &egui::Context) -> EditResponse {
+            egui::Window::new(std::any::type_name::<T>())
+                .show(context, |ui| self.render_component_mut(ui))
+                .and_then(|inner| inner.inner)
+                .unwrap_or_default()
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.render_egui_filter_bar(ui);
+            with_filter(self.filter(), || self.value.render_component_mut(ui))
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.value.matches_filter(filter)
+        }
+    }
+}
+
+/// The terminal presentation isn't filtered, so only the value is
+/// presented.
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use super::Filtered;
+    use crate::tui::TuiUi;
+    use crate::{EditResponse, TuiPresentable};
+
+    impl<T: TuiPresentable> TuiPresentable for Filtered<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            self.value.render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.value.render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.value.render_component_shared(ui)
+        }
+    }
+}
@@ -0,0 +1,129 @@
+//! The state of the widgets kept between the frames.
+//!
+//! The widgets are identified by the addresses of their values or by
+//! the ids of the backends, which aren't reused once the values are
+//! gone, so the states not used during a frame are dropped.
+
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// The states of the widgets, by their keys, which are dropped unless
+/// used during the previous frame.
+#[derive(Debug)]
+pub(crate) struct FrameStates<K, V> {
+    /// The states, along with the frames those were last used in.
+    states: HashMap<K, (V, u64)>,
+    /// The frame the states were last used in.
+    frame: u64,
+}
+
+impl<K, V> Default for FrameStates<K, V> {
+    fn default() -> Self {
+        Self {
+            states: HashMap::new(),
+            frame: 0,
+        }
+    }
+}
+
+impl<K: Eq + Hash, V> FrameStates<K, V> {
+    /// Returns the state of the widget, if it was kept.
+    #[cfg(feature = "imgui_backend")]
+    pub(crate) fn get(&mut self, frame: u64, key: &K) -> Option<&V> {
+        self.advance(frame);
+        self.states.get(key).map(|(state, _)| state)
+    }
+
+    /// Takes the state of the widget out, so that the widgets nested
+    /// into it may use theirs while it is in use.
+    pub(crate) fn take(&mut self, frame: u64, key: &K) -> Option<V> {
+        self.advance(frame);
+        self.states.remove(key).map(|(state, _)| state)
+    }
+
+    /// Keeps the state of the widget until the next frame.
+    pub(crate) fn insert(&mut self, frame: u64, key: K, state: V) {
+        self.advance(frame);
+        let _ = self.states.insert(key, (state, frame));
+    }
+
+    /// Drops the states not used during the previous frame, once
+    /// another one starts, even if nothing was used in the frames between.
+    fn advance(&mut self, frame: u64) {
+        if frame != self.frame {
+            self.states
+                .retain(|_, (_, used)| used.saturating_add(1) >= frame);
+            self.frame = frame;
+        }
+    }
+
+    /// Returns the number of the states kept.
+    #[cfg(test)]
+    fn len(&self) -> usize {
+        self.states.len()
+    }
+}
+
+/// The user interfaces of the backends, which number their frames.
+pub trait Frame {
+    /// Returns the number of the current frame.
+    fn frame(&self) -> u64;
+}
+
+#[cfg(feature = "imgui_backend")]
+impl Frame for imgui::Ui {
+    fn frame(&self) -> u64 {
+        imgui_frame(self)
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+impl Frame for egui::Ui {
+    fn frame(&self) -> u64 {
+        egui_frame(self)
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+impl Frame for crate::tui::TuiUi<'_> {
+    fn frame(&self) -> u64 {
+        self.frame()
+    }
+}
+
+/// Returns the number of the current ImGui frame.
+#[cfg(feature = "imgui_backend")]
+pub(crate) fn imgui_frame(ui: &imgui::Ui) -> u64 {
+    u64::try_from(ui.frame_count()).unwrap_or_default()
+}
+
+/// Returns the number of the current egui pass, of which there may be
+/// several during a frame.
+#[cfg(feature = "egui_backend")]
+pub(crate) fn egui_frame(ui: &egui::Ui) -> u64 {
+    ui.ctx().cumulative_pass_nr()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FrameStates;
+
+    #[test]
+    fn drops_the_states_not_used_during_a_frame() {
+        let mut states = FrameStates::default();
+        states.insert(1, "pager", 3);
+        states.insert(1, "editor", 5);
+
+        assert_eq!(states.take(2, &"pager"), Some(3));
+        states.insert(2, "pager", 4);
+        // The editor wasn't used during the second frame.
+        assert_eq!(states.len(), 2);
+        assert_eq!(states.take(3, &"editor"), None);
+        assert_eq!(states.len(), 1);
+
+        // The states taken out and not put back are gone.
+        assert_eq!(states.take(3, &"pager"), Some(4));
+        assert_eq!(states.take(4, &"pager"), None);
+        assert_eq!(states.len(), 0);
+    }
+}
@@ -1,13 +1,186 @@
-//! Provides the implementations of the [`ImguiPresentable`] and/or the
-//! [`EguiPresentable`] trait(s) for the [`glam`] crate types.
+//! Provides the implementations of the [`ImguiPresentable`], the
+//! [`EguiPresentable`] and/or the [`TuiPresentable`] trait(s) for the
+//! [`glam`] crate types.
+
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+mod quaternion {
+    use glam::{DQuat, DVec4, EulerRot};
+
+    /// The rotation orders a quaternion may be edited in, as Euler
+    /// angles, along with the axes of those angles.
+    pub(super) const EULER_ORDERS: [(EulerRot, &str); 6] = [
+        (EulerRot::XYZ, "XYZ"),
+        (EulerRot::XZY, "XZY"),
+        (EulerRot::YXZ, "YXZ"),
+        (EulerRot::YZX, "YZX"),
+        (EulerRot::ZXY, "ZXY"),
+        (EulerRot::ZYX, "ZYX"),
+    ];
+
+    /// The quaternions of both the precisions, which are edited as the
+    /// double-precision ones.
+    pub(super) trait Quaternion: Copy {
+        fn to_dquat(self) -> DQuat;
+        fn from_dquat(rotation: DQuat) -> Self;
+    }
+
+    impl Quaternion for glam::Quat {
+        fn to_dquat(self) -> DQuat {
+            self.as_dquat()
+        }
+
+        fn from_dquat(rotation: DQuat) -> Self {
+            rotation.as_quat()
+        }
+    }
+
+    impl Quaternion for DQuat {
+        fn to_dquat(self) -> DQuat {
+            self
+        }
+
+        fn from_dquat(rotation: DQuat) -> Self {
+            rotation
+        }
+    }
+
+    /// The state of the editor of a quaternion, kept between the frames.
+    #[derive(Debug, Default, Copy, Clone, PartialEq)]
+    pub(super) struct QuaternionEditor {
+        /// The quaternion is edited as Euler angles, rather than as its
+        /// components.
+        pub(super) euler: bool,
+        /// The index of the rotation order in [`EULER_ORDERS`].
+        pub(super) order: usize,
+        /// The angles last entered, in degrees, along with the rotation
+        /// those have produced. While the rotation stays the same,
+        /// these are shown instead of the angles computed from it, as
+        /// the computed ones may flip to an equivalent set in the
+        /// middle of an edit.
+        pub(super) last_edit: Option<([f64; 3], DQuat)>,
+    }
+
+    impl QuaternionEditor {
+        /// Returns the axes of the Euler angles, in the rotation order.
+        pub(super) fn axes(&self) -> &'static str {
+            EULER_ORDERS[self.order].1
+        }
+
+        /// Returns the Euler angles of the rotation, in degrees.
+        pub(super) fn angles<Q: Quaternion>(&self, rotation: Q) -> [f64; 3] {
+            let rotation = rotation.to_dquat();
+            match self.last_edit {
+                Some((angles, edited)) if edited == rotation => angles,
+                _ => {
+                    let (a, b, c) = rotation.to_euler(EULER_ORDERS[self.order].0);
+                    [a, b, c].map(f64::to_degrees)
+                }
+            }
+        }
+
+        /// Sets the rotation from the Euler angles, in degrees.
+        pub(super) fn set_angles<Q: Quaternion>(&mut self, rotation: &mut Q, angles: [f64; 3]) {
+            let [a, b, c] = angles.map(f64::to_radians);
+            let order = EULER_ORDERS[self.order].0;
+            *rotation = Q::from_dquat(DQuat::from_euler(order, a, b, c).normalize());
+            self.last_edit = Some((angles, rotation.to_dquat()));
+        }
+    }
+
+    /// Sets the rotation from its components, normalising those. The
+    /// components of a zero length are ignored, as they don't describe
+    /// any rotation.
+    pub(super) fn set_components<Q: Quaternion>(rotation: &mut Q, components: [f64; 4]) {
+        if let Some(components) = DVec4::from_array(components).try_normalize() {
+            *rotation = Q::from_dquat(DQuat::from_vec4(components));
+        }
+    }
+}
+
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+mod affine {
+    use super::quaternion::Quaternion;
+    use glam::DQuat;
+
+    /// The transforms, which are edited as their scale, rotation and
+    /// translation.
+    pub(super) trait Affine {
+        type Vector;
+        type Rotation: Quaternion;
+
+        /// Returns the scale, the rotation and the translation of the
+        /// transform. The rotation of a transform scaled to zero along
+        /// any of the axes can't be told, so it is the identity then,
+        /// rather than the NaN the decomposition produces.
+        fn decompose(&self) -> (Self::Vector, Self::Rotation, Self::Vector);
+
+        /// Sets the transform from its scale, rotation and translation,
+        /// unless those don't produce a finite one.
+        fn recompose(
+            &mut self,
+            scale: Self::Vector,
+            rotation: Self::Rotation,
+            translation: Self::Vector,
+        );
+    }
+
+    macro_rules! define_for_affine {
+        ($affine_type:ty, $vector_type:ty, $rotation_type:ty) => {
+            impl Affine for $affine_type {
+                type Vector = $vector_type;
+                type Rotation = $rotation_type;
+
+                fn decompose(&self) -> ($vector_type, $rotation_type, $vector_type) {
+                    let (scale, rotation, translation) = self.to_scale_rotation_translation();
+                    let rotation = if rotation.is_finite() {
+                        rotation
+                    } else {
+                        <$rotation_type>::from_dquat(DQuat::IDENTITY)
+                    };
+                    (scale, rotation, translation)
+                }
+
+                fn recompose(
+                    &mut self,
+                    scale: $vector_type,
+                    rotation: $rotation_type,
+                    translation: $vector_type,
+                ) {
+                    let transform =
+                        Self::from_scale_rotation_translation(scale, rotation, translation);
+                    if transform.is_finite() {
+                        *self = transform;
+                    }
+                }
+            }
+        };
+    }
+
+    define_for_affine!(glam::Affine3A, glam::Vec3, glam::Quat);
+    define_for_affine!(glam::DAffine3, glam::DVec3, glam::DQuat);
+}
 
 #[cfg(feature = "imgui_backend")]
 mod imgui_backend {
-    use crate::{Extent, ImguiPresentable};
+    use super::affine::Affine;
+    use super::quaternion::{set_components, Quaternion, QuaternionEditor, EULER_ORDERS};
+    use crate::elements::ElementActions;
+    use crate::frame_state::{imgui_frame, FrameStates};
+    use crate::{EditResponse, Extent, ImguiPresentable};
+    use std::cell::RefCell;
 
     impl ImguiPresentable for glam::Vec4 {
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -19,23 +192,29 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.z as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.z as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.w as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.w as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 table.end();
             }
+
+            response
         }
 
         fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -61,11 +240,16 @@ mod imgui_backend {
                 table.end();
             }
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     impl ImguiPresentable for glam::Vec3 {
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -76,20 +260,25 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.z as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.z as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 table.end();
             }
+
+            response
         }
 
         fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -111,11 +300,16 @@ mod imgui_backend {
                 table.end();
             }
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     impl ImguiPresentable for glam::Vec2 {
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -125,17 +319,21 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.x as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 ui.table_next_column();
-                (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                response |=
+                    (&mut self.y as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
 
                 table.end();
             }
+
+            response
         }
 
         fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -153,6 +351,10 @@ mod imgui_backend {
                 table.end();
             }
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     fn create_vec4_for_mat4(ui: &imgui::Ui, vec: &glam::Vec4, prefix: &str) {
@@ -180,23 +382,33 @@ mod imgui_backend {
         });
     }
 
-    fn create_vec4_for_mat4_mut(ui: &imgui::Ui, vec: &mut glam::Vec4, prefix: &str) {
-        let _ = {
-            imgui::Drag::new(&format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)))
-                .build(ui, &mut vec.x)
+    fn create_vec4_for_mat4_mut(
+        ui: &imgui::Ui,
+        vec: &mut glam::Vec4,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= {
+            let changed = imgui::Drag::new(&format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)))
+                .build(ui, &mut vec.x);
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            imgui::Drag::new(&format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.y)))
-                .build(ui, &mut vec.y)
+        response |= {
+            let changed = imgui::Drag::new(&format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.y)))
+                .build(ui, &mut vec.y);
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            imgui::Drag::new(&format!("{prefix}_2##{:p}", std::ptr::addr_of!(vec.z)))
-                .build(ui, &mut vec.z)
+        response |= {
+            let changed = imgui::Drag::new(&format!("{prefix}_2##{:p}", std::ptr::addr_of!(vec.z)))
+                .build(ui, &mut vec.z);
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            imgui::Drag::new(&format!("{prefix}_3##{:p}", std::ptr::addr_of!(vec.w)))
-                .build(ui, &mut vec.w)
+        response |= {
+            let changed = imgui::Drag::new(&format!("{prefix}_3##{:p}", std::ptr::addr_of!(vec.w)))
+                .build(ui, &mut vec.w);
+            EditResponse::from_last_imgui_item(ui, changed)
         };
+        response
     }
 
     fn create_vec3_for_mat3(ui: &imgui::Ui, vec: &glam::Vec3, prefix: &str) {
@@ -229,28 +441,40 @@ mod imgui_backend {
         };
     }
 
-    fn create_vec3_for_mat3_mut(ui: &imgui::Ui, vec: &mut glam::Vec3, prefix: &str) {
-        let _ = {
-            ui.input_float(
-                &format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)),
-                &mut vec.x,
-            )
-            .build()
+    fn create_vec3_for_mat3_mut(
+        ui: &imgui::Ui,
+        vec: &mut glam::Vec3,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= {
+            let changed = ui
+                .input_float(
+                    &format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)),
+                    &mut vec.x,
+                )
+                .build();
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            ui.input_float(
-                &format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.x)),
-                &mut vec.y,
-            )
-            .build()
+        response |= {
+            let changed = ui
+                .input_float(
+                    &format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.x)),
+                    &mut vec.y,
+                )
+                .build();
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            ui.input_float(
-                &format!("{prefix}_2##{:p}", std::ptr::addr_of!(vec.x)),
-                &mut vec.z,
-            )
-            .build()
+        response |= {
+            let changed = ui
+                .input_float(
+                    &format!("{prefix}_2##{:p}", std::ptr::addr_of!(vec.x)),
+                    &mut vec.z,
+                )
+                .build();
+            EditResponse::from_last_imgui_item(ui, changed)
         };
+        response
     }
 
     fn create_vec2_for_mat2(ui: &imgui::Ui, vec: &glam::Vec2, prefix: &str) {
@@ -274,26 +498,36 @@ mod imgui_backend {
         };
     }
 
-    fn create_vec2_for_mat2_mut(ui: &imgui::Ui, vec: &mut glam::Vec2, prefix: &str) {
-        let _ = {
-            ui.input_float(
-                &format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)),
-                &mut vec.x,
-            )
-            .build()
+    fn create_vec2_for_mat2_mut(
+        ui: &imgui::Ui,
+        vec: &mut glam::Vec2,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= {
+            let changed = ui
+                .input_float(
+                    &format!("{prefix}_0##{:p}", std::ptr::addr_of!(vec.x)),
+                    &mut vec.x,
+                )
+                .build();
+            EditResponse::from_last_imgui_item(ui, changed)
         };
-        let _ = {
-            ui.input_float(
-                &format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.x)),
-                &mut vec.y,
-            )
-            .build()
+        response |= {
+            let changed = ui
+                .input_float(
+                    &format!("{prefix}_1##{:p}", std::ptr::addr_of!(vec.x)),
+                    &mut vec.y,
+                )
+                .build();
+            EditResponse::from_last_imgui_item(ui, changed)
         };
+        response
     }
 
     impl ImguiPresentable for glam::Mat4 {
         fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -328,8 +562,9 @@ mod imgui_backend {
             }
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -341,22 +576,23 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                create_vec4_for_mat4_mut(ui, &mut self.x_axis, "x");
+                response |= create_vec4_for_mat4_mut(ui, &mut self.x_axis, "x");
 
                 ui.table_next_column();
-                create_vec4_for_mat4_mut(ui, &mut self.y_axis, "y");
+                response |= create_vec4_for_mat4_mut(ui, &mut self.y_axis, "y");
 
                 ui.table_next_column();
-                create_vec4_for_mat4_mut(ui, &mut self.z_axis, "z");
+                response |= create_vec4_for_mat4_mut(ui, &mut self.z_axis, "z");
 
                 ui.table_next_column();
-                create_vec4_for_mat4_mut(ui, &mut self.w_axis, "w");
+                response |= create_vec4_for_mat4_mut(ui, &mut self.w_axis, "w");
 
                 table.end();
             }
 
             if ui.button(format!("Identity##{self:p}")) {
                 *self = Self::IDENTITY;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -367,6 +603,7 @@ mod imgui_backend {
 
             if ui.button(format!("Zero##{self:p}")) {
                 *self = Self::ZERO;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -377,6 +614,7 @@ mod imgui_backend {
 
             if ui.button(format!("NaN##{self:p}")) {
                 *self = Self::NAN;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -392,12 +630,18 @@ mod imgui_backend {
             if ui.is_item_hovered() {
                 ui.tooltip_text("Copies the debug representation to clipboard.");
             }
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
     impl ImguiPresentable for glam::Mat3 {
         fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -428,8 +672,9 @@ mod imgui_backend {
             }
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -440,19 +685,20 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                create_vec3_for_mat3_mut(ui, &mut self.x_axis, "x");
+                response |= create_vec3_for_mat3_mut(ui, &mut self.x_axis, "x");
 
                 ui.table_next_column();
-                create_vec3_for_mat3_mut(ui, &mut self.y_axis, "y");
+                response |= create_vec3_for_mat3_mut(ui, &mut self.y_axis, "y");
 
                 ui.table_next_column();
-                create_vec3_for_mat3_mut(ui, &mut self.z_axis, "z");
+                response |= create_vec3_for_mat3_mut(ui, &mut self.z_axis, "z");
 
                 table.end();
             }
 
             if ui.button(format!("Identity##{self:p}")) {
                 *self = Self::IDENTITY;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -463,6 +709,7 @@ mod imgui_backend {
 
             if ui.button(format!("Zero##{self:p}")) {
                 *self = Self::ZERO;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -473,6 +720,7 @@ mod imgui_backend {
 
             if ui.button(format!("NaN##{self:p}")) {
                 *self = Self::NAN;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -488,12 +736,18 @@ mod imgui_backend {
             if ui.is_item_hovered() {
                 ui.tooltip_text("Copies the debug representation to clipboard.");
             }
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
     impl ImguiPresentable for glam::Mat2 {
         fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+            let _id = ui.push_id(format!("##{self:p}"));
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -520,8 +774,9 @@ mod imgui_backend {
             }
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) {
-            let _id = ui.push_id(&format!("##{self:p}"));
+        fn render_component_mut(&mut self, ui: &imgui::Ui, _extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut response = EditResponse::default();
 
             if let Some(table) = ui.begin_table_header(
                 "values",
@@ -531,16 +786,17 @@ mod imgui_backend {
                 ],
             ) {
                 ui.table_next_column();
-                create_vec2_for_mat2_mut(ui, &mut self.x_axis, "x");
+                response |= create_vec2_for_mat2_mut(ui, &mut self.x_axis, "x");
 
                 ui.table_next_column();
-                create_vec2_for_mat2_mut(ui, &mut self.y_axis, "y");
+                response |= create_vec2_for_mat2_mut(ui, &mut self.y_axis, "y");
 
                 table.end();
             }
 
             if ui.button(format!("Identity##{self:p}")) {
                 *self = Self::IDENTITY;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -551,6 +807,7 @@ mod imgui_backend {
 
             if ui.button(format!("Zero##{self:p}")) {
                 *self = Self::ZERO;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -561,6 +818,7 @@ mod imgui_backend {
 
             if ui.button(format!("NaN##{self:p}")) {
                 *self = Self::NAN;
+                response |= EditResponse::changed(true);
             }
 
             if ui.is_item_hovered() {
@@ -576,20 +834,227 @@ mod imgui_backend {
             if ui.is_item_hovered() {
                 ui.tooltip_text("Copies the debug representation to clipboard.");
             }
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
+    }
+
+    macro_rules! define_for_vector {
+        ($vector_type:ty, $($field:ident)+) => {
+            impl ImguiPresentable for $vector_type {
+                fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    let mut response = EditResponse::default();
+
+                    if let Some(table) = ui.begin_table_header(
+                        "values",
+                        [$(imgui::TableColumnSetup::new(stringify!($field))),+],
+                    ) {
+                        $(
+                            ui.table_next_column();
+                            response |= (&mut self.$field as &mut dyn ImguiPresentable)
+                                .render_component_mut(ui, extent);
+                        )+
+
+                        table.end();
+                    }
+
+                    response
+                }
+
+                fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+                    let _id = ui.push_id(format!("##{self:p}"));
+
+                    if let Some(table) = ui.begin_table_header(
+                        "values",
+                        [$(imgui::TableColumnSetup::new(stringify!($field))),+],
+                    ) {
+                        $(
+                            ui.table_next_column();
+                            (&self.$field as &dyn ImguiPresentable).render_component(ui, extent);
+                        )+
+
+                        table.end();
+                    }
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
+    }
+
+    define_for_vector!(glam::Vec3A, x y z);
+    define_for_vector!(glam::DVec2, x y);
+    define_for_vector!(glam::DVec3, x y z);
+    define_for_vector!(glam::DVec4, x y z w);
+    define_for_vector!(glam::IVec2, x y);
+    define_for_vector!(glam::IVec3, x y z);
+    define_for_vector!(glam::IVec4, x y z w);
+    define_for_vector!(glam::UVec2, x y);
+    define_for_vector!(glam::UVec3, x y z);
+    define_for_vector!(glam::UVec4, x y z w);
+    define_for_vector!(glam::BVec2, x y);
+    define_for_vector!(glam::BVec3, x y z);
+    define_for_vector!(glam::BVec4, x y z w);
+
+    thread_local! {
+        /// The states of the quaternion editors, by their ImGui ids.
+        static QUATERNION_EDITORS: RefCell<FrameStates<imgui::Id, QuaternionEditor>> =
+            RefCell::default();
+    }
+
+    /// Renders the editor of a quaternion. Unless it is `editable`,
+    /// only the way the quaternion is presented may be changed.
+    fn render_quaternion<Q: Quaternion>(
+        ui: &imgui::Ui,
+        rotation: &mut Q,
+        editable: bool,
+    ) -> EditResponse {
+        let frame = imgui_frame(ui);
+        let key = ui.new_id_str("quaternion");
+        let mut editor = QUATERNION_EDITORS
+            .with_borrow_mut(|editors| editors.get(frame, &key).copied())
+            .unwrap_or_default();
+        let mut response = EditResponse::default();
+
+        ui.checkbox("Euler angles", &mut editor.euler);
+        if editor.euler {
+            ui.same_line();
+            let orders = EULER_ORDERS.map(|(_, axes)| axes);
+            if ui.combo_simple_string("Order", &mut editor.order, &orders) {
+                editor.last_edit = None;
+            }
         }
+
+        ui.disabled(!editable, || {
+            if editor.euler {
+                let mut angles = editor.angles(*rotation);
+                let changed = imgui::Drag::new(format!("{}##angles", editor.axes()))
+                    .speed(0.5)
+                    .display_format("%.2f°")
+                    .build_array(ui, &mut angles);
+                response = EditResponse::from_last_imgui_item(ui, changed);
+                if changed {
+                    editor.set_angles(rotation, angles);
+                }
+            } else {
+                let mut components = rotation.to_dquat().to_array();
+                let changed = imgui::Drag::new("xyzw##components")
+                    .speed(0.01)
+                    .range(-1.0, 1.0)
+                    .build_array(ui, &mut components);
+                response = EditResponse::from_last_imgui_item(ui, changed);
+                if changed {
+                    set_components(rotation, components);
+                }
+            }
+        });
+
+        QUATERNION_EDITORS.with_borrow_mut(|editors| editors.insert(frame, key, editor));
+        response
+    }
+
+    macro_rules! define_for_quaternion {
+        ($quaternion_type:ty) => {
+            /// The quaternion is either edited as its components, which
+            /// are normalised afterwards, or as the Euler angles.
+            impl ImguiPresentable for $quaternion_type {
+                fn render_component(&self, ui: &imgui::Ui, _extent: Extent) {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    let mut rotation = *self;
+                    let _ = render_quaternion(ui, &mut rotation, false);
+                }
+
+                fn render_component_mut(
+                    &mut self,
+                    ui: &imgui::Ui,
+                    _extent: Extent,
+                ) -> EditResponse {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    render_quaternion(ui, self, true)
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
     }
+
+    define_for_quaternion!(glam::Quat);
+    define_for_quaternion!(glam::DQuat);
+
+    macro_rules! define_for_affine {
+        ($affine_type:ty) => {
+            /// The transform is edited as its translation, rotation and
+            /// scale, so any shear is lost once it is changed.
+            impl ImguiPresentable for $affine_type {
+                fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    let (scale, rotation, translation) = self.decompose();
+
+                    ui.text("translation");
+                    (&translation as &dyn ImguiPresentable).render_component(ui, extent);
+                    ui.text("rotation");
+                    (&rotation as &dyn ImguiPresentable).render_component(ui, extent);
+                    ui.text("scale");
+                    (&scale as &dyn ImguiPresentable).render_component(ui, extent);
+                }
+
+                fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    let (mut scale, mut rotation, mut translation) = self.decompose();
+                    let mut response = EditResponse::default();
+
+                    ui.text("translation");
+                    response |= (&mut translation as &mut dyn ImguiPresentable)
+                        .render_component_mut(ui, extent);
+                    ui.text("rotation");
+                    response |= (&mut rotation as &mut dyn ImguiPresentable)
+                        .render_component_mut(ui, extent);
+                    ui.text("scale");
+                    response |=
+                        (&mut scale as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+
+                    if response.changed {
+                        self.recompose(scale, rotation, translation);
+                    }
+
+                    response
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
+    }
+
+    define_for_affine!(glam::Affine3A);
+    define_for_affine!(glam::DAffine3);
 }
 
+#[allow(unused)]
 #[cfg(feature = "imgui_backend")]
 pub use imgui_backend::*;
 
 #[cfg(feature = "egui_backend")]
 mod egui_backend {
-    use crate::EguiPresentable;
+    use super::affine::Affine;
+    use super::quaternion::{set_components, Quaternion, QuaternionEditor, EULER_ORDERS};
+    use crate::elements::ElementActions;
+    use crate::{EditResponse, EguiPresentable};
     use egui_extras::{Column, TableBuilder};
 
     impl EguiPresentable for glam::Vec4 {
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 4);
@@ -613,19 +1078,25 @@ mod egui_backend {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
                         row.col(|ui| {
-                            (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.z as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.z as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.w as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.w as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                     });
                 });
+
+            response
         }
 
         fn render_component(&self, ui: &mut egui::Ui) {
@@ -658,10 +1129,15 @@ mod egui_backend {
                     });
                 });
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     impl EguiPresentable for glam::Vec3 {
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 3);
@@ -682,16 +1158,21 @@ mod egui_backend {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
                         row.col(|ui| {
-                            (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.z as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.z as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                     });
                 });
+
+            response
         }
 
         fn render_component(&self, ui: &mut egui::Ui) {
@@ -720,10 +1201,15 @@ mod egui_backend {
                     });
                 });
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     impl EguiPresentable for glam::Vec2 {
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 2);
@@ -741,13 +1227,17 @@ mod egui_backend {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
                         row.col(|ui| {
-                            (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.x as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                         row.col(|ui| {
-                            (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui)
+                            response |=
+                                (&mut self.y as &mut dyn EguiPresentable).render_component_mut(ui);
                         });
                     });
                 });
+
+            response
         }
 
         fn render_component(&self, ui: &mut egui::Ui) {
@@ -772,6 +1262,10 @@ mod egui_backend {
                     });
                 });
         }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
+        }
     }
 
     fn create_vec4_for_mat4(ui: &mut egui::Ui, vec: &glam::Vec4, prefix: &str) {
@@ -787,11 +1281,25 @@ mod egui_backend {
         });
     }
 
-    fn create_vec4_for_mat4_mut(ui: &mut egui::Ui, vec: &mut glam::Vec4, prefix: &str) {
-        let _ = ui.add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.z).prefix(format!("{prefix}_2")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.w).prefix(format!("{prefix}_3")));
+    fn create_vec4_for_mat4_mut(
+        ui: &mut egui::Ui,
+        vec: &mut glam::Vec4,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.z).prefix(format!("{prefix}_2")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.w).prefix(format!("{prefix}_3")))
+            .into();
+        response
     }
 
     fn create_vec3_for_mat3(ui: &mut egui::Ui, vec: &glam::Vec3, prefix: &str) {
@@ -805,10 +1313,22 @@ mod egui_backend {
         });
     }
 
-    fn create_vec3_for_mat3_mut(ui: &mut egui::Ui, vec: &mut glam::Vec3, prefix: &str) {
-        let _ = ui.add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.z).prefix(format!("{prefix}_2")));
+    fn create_vec3_for_mat3_mut(
+        ui: &mut egui::Ui,
+        vec: &mut glam::Vec3,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.z).prefix(format!("{prefix}_2")))
+            .into();
+        response
     }
 
     fn create_vec2_for_mat2(ui: &mut egui::Ui, vec: &glam::Vec2, prefix: &str) {
@@ -820,9 +1340,19 @@ mod egui_backend {
         });
     }
 
-    fn create_vec2_for_mat2_mut(ui: &mut egui::Ui, vec: &mut glam::Vec2, prefix: &str) {
-        let _ = ui.add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")));
-        let _ = ui.add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")));
+    fn create_vec2_for_mat2_mut(
+        ui: &mut egui::Ui,
+        vec: &mut glam::Vec2,
+        prefix: &str,
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.x).prefix(format!("{prefix}_0")))
+            .into();
+        response |= ui
+            .add(egui::DragValue::new(&mut vec.y).prefix(format!("{prefix}_1")))
+            .into();
+        response
     }
 
     impl EguiPresentable for glam::Mat4 {
@@ -888,7 +1418,8 @@ mod egui_backend {
             // }
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 4);
@@ -911,10 +1442,18 @@ mod egui_backend {
                 .body(|mut body| {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
-                        row.col(|ui| create_vec4_for_mat4_mut(ui, &mut self.x_axis, "x"));
-                        row.col(|ui| create_vec4_for_mat4_mut(ui, &mut self.y_axis, "y"));
-                        row.col(|ui| create_vec4_for_mat4_mut(ui, &mut self.z_axis, "z"));
-                        row.col(|ui| create_vec4_for_mat4_mut(ui, &mut self.w_axis, "w"));
+                        row.col(|ui| {
+                            response |= create_vec4_for_mat4_mut(ui, &mut self.x_axis, "x")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec4_for_mat4_mut(ui, &mut self.y_axis, "y")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec4_for_mat4_mut(ui, &mut self.z_axis, "z")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec4_for_mat4_mut(ui, &mut self.w_axis, "w")
+                        });
                     });
                 });
 
@@ -925,6 +1464,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::IDENTITY;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -933,6 +1473,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::ZERO;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -941,6 +1482,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::NAN;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -951,6 +1493,12 @@ mod egui_backend {
                     // not implemented since egui doesn't have a clipboard API.
                 }
             });
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
@@ -990,7 +1538,8 @@ mod egui_backend {
             // }
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 3);
@@ -1010,9 +1559,15 @@ mod egui_backend {
                 .body(|mut body| {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
-                        row.col(|ui| create_vec3_for_mat3_mut(ui, &mut self.x_axis, "x"));
-                        row.col(|ui| create_vec3_for_mat3_mut(ui, &mut self.y_axis, "y"));
-                        row.col(|ui| create_vec3_for_mat3_mut(ui, &mut self.z_axis, "z"));
+                        row.col(|ui| {
+                            response |= create_vec3_for_mat3_mut(ui, &mut self.x_axis, "x")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec3_for_mat3_mut(ui, &mut self.y_axis, "y")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec3_for_mat3_mut(ui, &mut self.z_axis, "z")
+                        });
                     });
                 });
 
@@ -1023,6 +1578,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::IDENTITY;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1031,6 +1587,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::ZERO;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1039,6 +1596,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::NAN;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1049,6 +1607,12 @@ mod egui_backend {
                     // not implemented since egui doesn't have a clipboard API.
                 }
             });
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
 
@@ -1084,7 +1648,8 @@ mod egui_backend {
             // }
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut response = EditResponse::default();
             let table = TableBuilder::new(ui)
                 .striped(true)
                 .columns(Column::auto(), 2);
@@ -1101,8 +1666,12 @@ mod egui_backend {
                 .body(|mut body| {
                     body.row(20.0f32, |mut row| {
                         // ui.separator();
-                        row.col(|ui| create_vec2_for_mat2_mut(ui, &mut self.x_axis, "x"));
-                        row.col(|ui| create_vec2_for_mat2_mut(ui, &mut self.y_axis, "y"));
+                        row.col(|ui| {
+                            response |= create_vec2_for_mat2_mut(ui, &mut self.x_axis, "x")
+                        });
+                        row.col(|ui| {
+                            response |= create_vec2_for_mat2_mut(ui, &mut self.y_axis, "y")
+                        });
                     });
                 });
 
@@ -1113,6 +1682,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::IDENTITY;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1121,6 +1691,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::ZERO;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1129,6 +1700,7 @@ mod egui_backend {
                     .clicked()
                 {
                     *self = Self::NAN;
+                    response |= EditResponse::changed(true);
                 }
 
                 if ui
@@ -1139,13 +1711,410 @@ mod egui_backend {
                     // not implemented since egui doesn't have a clipboard API.
                 }
             });
+
+            response
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default().with_clone()
         }
     }
+
+    macro_rules! define_for_vector {
+        ($vector_type:ty, $($field:ident)+) => {
+            impl EguiPresentable for $vector_type {
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+                    let mut response = EditResponse::default();
+                    let table = TableBuilder::new(ui)
+                        .striped(true)
+                        .columns(Column::auto(), [$(stringify!($field)),+].len());
+
+                    table
+                        .header(20.0f32, |mut header| {
+                            $(
+                                header.col(|ui| {
+                                    ui.strong(stringify!($field));
+                                });
+                            )+
+                        })
+                        .body(|mut body| {
+                            body.row(20.0f32, |mut row| {
+                                $(
+                                    row.col(|ui| {
+                                        response |= (&mut self.$field as &mut dyn EguiPresentable)
+                                            .render_component_mut(ui);
+                                    });
+                                )+
+                            });
+                        });
+
+                    response
+                }
+
+                fn render_component(&self, ui: &mut egui::Ui) {
+                    let table = TableBuilder::new(ui)
+                        .striped(true)
+                        .columns(Column::auto(), [$(stringify!($field)),+].len());
+
+                    table
+                        .header(20.0f32, |mut header| {
+                            $(
+                                header.col(|ui| {
+                                    ui.strong(stringify!($field));
+                                });
+                            )+
+                        })
+                        .body(|mut body| {
+                            body.row(20.0f32, |mut row| {
+                                $(
+                                    row.col(|ui| {
+                                        (&self.$field as &dyn EguiPresentable).render_component(ui)
+                                    });
+                                )+
+                            });
+                        });
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
+    }
+
+    define_for_vector!(glam::Vec3A, x y z);
+    define_for_vector!(glam::DVec2, x y);
+    define_for_vector!(glam::DVec3, x y z);
+    define_for_vector!(glam::DVec4, x y z w);
+    define_for_vector!(glam::IVec2, x y);
+    define_for_vector!(glam::IVec3, x y z);
+    define_for_vector!(glam::IVec4, x y z w);
+    define_for_vector!(glam::UVec2, x y);
+    define_for_vector!(glam::UVec3, x y z);
+    define_for_vector!(glam::UVec4, x y z w);
+    define_for_vector!(glam::BVec2, x y);
+    define_for_vector!(glam::BVec3, x y z);
+    define_for_vector!(glam::BVec4, x y z w);
+
+    /// Renders the editor of a quaternion. Unless it is `editable`,
+    /// only the way the quaternion is presented may be changed.
+    fn render_quaternion<Q: Quaternion>(
+        ui: &mut egui::Ui,
+        rotation: &mut Q,
+        editable: bool,
+    ) -> EditResponse {
+        let id = ui.id().with("quaternion");
+        let mut editor: QuaternionEditor = ui.data(|data| data.get_temp(id)).unwrap_or_default();
+        let mut response = EditResponse::default();
+
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut editor.euler, "Euler angles");
+            if editor.euler {
+                let order = editor.order;
+                egui::ComboBox::new(id.with("order"), "Order")
+                    .selected_text(editor.axes())
+                    .show_ui(ui, |ui| {
+                        for (i, (_, axes)) in EULER_ORDERS.iter().enumerate() {
+                            ui.selectable_value(&mut editor.order, i, *axes);
+                        }
+                    });
+                if editor.order != order {
+                    editor.last_edit = None;
+                }
+            }
+        });
+
+        ui.add_enabled_ui(editable, |ui| {
+            ui.horizontal(|ui| {
+                if editor.euler {
+                    let mut angles = editor.angles(*rotation);
+                    for (angle, axis) in angles.iter_mut().zip(editor.axes().chars()) {
+                        response |= ui
+                            .add(
+                                egui::DragValue::new(angle)
+                                    .speed(0.5)
+                                    .prefix(format!("{axis}: "))
+                                    .suffix("°"),
+                            )
+                            .into();
+                    }
+                    if response.changed {
+                        editor.set_angles(rotation, angles);
+                    }
+                } else {
+                    let mut components = rotation.to_dquat().to_array();
+                    for (component, axis) in components.iter_mut().zip("xyzw".chars()) {
+                        response |= ui
+                            .add(
+                                egui::DragValue::new(component)
+                                    .speed(0.01)
+                                    .range(-1.0..=1.0)
+                                    .prefix(format!("{axis}: ")),
+                            )
+                            .into();
+                    }
+                    if response.changed {
+                        set_components(rotation, components);
+                    }
+                }
+            });
+        });
+
+        ui.data_mut(|data| data.insert_temp(id, editor));
+        response
+    }
+
+    macro_rules! define_for_quaternion {
+        ($quaternion_type:ty) => {
+            /// The quaternion is either edited as its components, which
+            /// are normalised afterwards, or as the Euler angles.
+            impl EguiPresentable for $quaternion_type {
+                fn render_component(&self, ui: &mut egui::Ui) {
+                    let mut rotation = *self;
+                    let _ = render_quaternion(ui, &mut rotation, false);
+                }
+
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+                    render_quaternion(ui, self, true)
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
+    }
+
+    define_for_quaternion!(glam::Quat);
+    define_for_quaternion!(glam::DQuat);
+
+    macro_rules! define_for_affine {
+        ($affine_type:ty) => {
+            /// The transform is edited as its translation, rotation and
+            /// scale, so any shear is lost once it is changed.
+            impl EguiPresentable for $affine_type {
+                fn render_component(&self, ui: &mut egui::Ui) {
+                    let (scale, rotation, translation) = self.decompose();
+
+                    ui.strong("translation");
+                    ui.push_id("translation", |ui| {
+                        (&translation as &dyn EguiPresentable).render_component(ui)
+                    });
+                    ui.strong("rotation");
+                    ui.push_id("rotation", |ui| {
+                        (&rotation as &dyn EguiPresentable).render_component(ui)
+                    });
+                    ui.strong("scale");
+                    ui.push_id("scale", |ui| {
+                        (&scale as &dyn EguiPresentable).render_component(ui)
+                    });
+                }
+
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+                    let (mut scale, mut rotation, mut translation) = self.decompose();
+                    let mut response = EditResponse::default();
+
+                    ui.strong("translation");
+                    response |= ui
+                        .push_id("translation", |ui| {
+                            (&mut translation as &mut dyn EguiPresentable).render_component_mut(ui)
+                        })
+                        .inner;
+                    ui.strong("rotation");
+                    response |= ui
+                        .push_id("rotation", |ui| {
+                            (&mut rotation as &mut dyn EguiPresentable).render_component_mut(ui)
+                        })
+                        .inner;
+                    ui.strong("scale");
+                    response |= ui
+                        .push_id("scale", |ui| {
+                            (&mut scale as &mut dyn EguiPresentable).render_component_mut(ui)
+                        })
+                        .inner;
+
+                    if response.changed {
+                        self.recompose(scale, rotation, translation);
+                    }
+
+                    response
+                }
+
+                fn element_actions() -> ElementActions<Self> {
+                    ElementActions::new().with_default().with_clone()
+                }
+            }
+        };
+    }
+
+    define_for_affine!(glam::Affine3A);
+    define_for_affine!(glam::DAffine3);
 }
 
+#[allow(unused)]
 #[cfg(feature = "egui_backend")]
 pub use egui_backend::*;
 
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use super::affine::Affine;
+    use super::quaternion::{set_components, Quaternion, QuaternionEditor, EULER_ORDERS};
+    use crate::tui::{TuiScalar, TuiUi};
+    use crate::{EditResponse, TuiPresentable};
+
+    macro_rules! define_for_vector {
+        ($vector_type:ty, $($field:ident)+) => {
+            impl TuiPresentable for $vector_type {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    $(
+                        ui.field(stringify!($field), |ui| self.$field.render_component(ui));
+                    )+
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    let mut response = EditResponse::default();
+                    $(
+                        response |= ui.field(stringify!($field), |ui| {
+                            self.$field.render_component_mut(ui)
+                        });
+                    )+
+                    response
+                }
+            }
+        };
+    }
+
+    define_for_vector!(glam::Vec2, x y);
+    define_for_vector!(glam::Vec3, x y z);
+    define_for_vector!(glam::Vec3A, x y z);
+    define_for_vector!(glam::Vec4, x y z w);
+    define_for_vector!(glam::DVec2, x y);
+    define_for_vector!(glam::DVec3, x y z);
+    define_for_vector!(glam::DVec4, x y z w);
+    define_for_vector!(glam::IVec2, x y);
+    define_for_vector!(glam::IVec3, x y z);
+    define_for_vector!(glam::IVec4, x y z w);
+    define_for_vector!(glam::UVec2, x y);
+    define_for_vector!(glam::UVec3, x y z);
+    define_for_vector!(glam::UVec4, x y z w);
+    define_for_vector!(glam::BVec2, x y);
+    define_for_vector!(glam::BVec3, x y z);
+    define_for_vector!(glam::BVec4, x y z w);
+    define_for_vector!(glam::Mat2, x_axis y_axis);
+    define_for_vector!(glam::Mat3, x_axis y_axis z_axis);
+    define_for_vector!(glam::Mat4, x_axis y_axis z_axis w_axis);
+
+    /// Renders the editor of a quaternion. Unless it is `editable`,
+    /// only the way the quaternion is presented may be changed.
+    fn render_quaternion<Q: Quaternion>(
+        ui: &mut TuiUi<'_>,
+        rotation: &mut Q,
+        editable: bool,
+    ) -> EditResponse {
+        let mut editor: QuaternionEditor = ui.data("quaternion");
+        let mut response = EditResponse::default();
+
+        let _ = ui.checkbox(&mut editor.euler, "Euler angles");
+        if editor.euler {
+            let orders = EULER_ORDERS.map(|(_, axes)| axes);
+            if ui
+                .field("order", |ui| ui.choice(&mut editor.order, &orders))
+                .changed
+            {
+                editor.last_edit = None;
+            }
+        }
+
+        ui.disabled(!editable, |ui| {
+            if editor.euler {
+                let mut angles = editor.angles(*rotation);
+                for (angle, axis) in angles.iter_mut().zip(editor.axes().chars()) {
+                    response |= ui.field(&axis.to_string(), |ui| {
+                        TuiScalar::new(angle).step(1.0).suffix("°").show(ui)
+                    });
+                }
+                if response.changed {
+                    editor.set_angles(rotation, angles);
+                }
+            } else {
+                let mut components = rotation.to_dquat().to_array();
+                for (component, axis) in components.iter_mut().zip("xyzw".chars()) {
+                    response |= ui.field(&axis.to_string(), |ui| {
+                        TuiScalar::new(component)
+                            .step(0.01)
+                            .range(-1.0, 1.0)
+                            .show(ui)
+                    });
+                }
+                if response.changed {
+                    set_components(rotation, components);
+                }
+            }
+        });
+
+        ui.set_data("quaternion", editor);
+        response
+    }
+
+    macro_rules! define_for_quaternion {
+        ($quaternion_type:ty) => {
+            /// The quaternion is either edited as its components, which
+            /// are normalised afterwards, or as the Euler angles.
+            impl TuiPresentable for $quaternion_type {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    let mut rotation = *self;
+                    let _ = render_quaternion(ui, &mut rotation, false);
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    render_quaternion(ui, self, true)
+                }
+            }
+        };
+    }
+
+    define_for_quaternion!(glam::Quat);
+    define_for_quaternion!(glam::DQuat);
+
+    macro_rules! define_for_affine {
+        ($affine_type:ty) => {
+            /// The transform is edited as its translation, rotation and
+            /// scale, so any shear is lost once it is changed.
+            impl TuiPresentable for $affine_type {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    let (scale, rotation, translation) = self.decompose();
+
+                    ui.field("translation", |ui| translation.render_component(ui));
+                    ui.field("rotation", |ui| rotation.render_component(ui));
+                    ui.field("scale", |ui| scale.render_component(ui));
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    let (mut scale, mut rotation, mut translation) = self.decompose();
+                    let mut response = EditResponse::default();
+
+                    response |= ui.field("translation", |ui| translation.render_component_mut(ui));
+                    response |= ui.field("rotation", |ui| rotation.render_component_mut(ui));
+                    response |= ui.field("scale", |ui| scale.render_component_mut(ui));
+
+                    if response.changed {
+                        self.recompose(scale, rotation, translation);
+                    }
+
+                    response
+                }
+            }
+        };
+    }
+
+    define_for_affine!(glam::Affine3A);
+    define_for_affine!(glam::DAffine3);
+}
+
+#[allow(unused)]
+#[cfg(feature = "ratatui_backend")]
+pub use ratatui_backend::*;
+
 #[cfg(test)]
 mod tests {
     use crate::ImguiPresentable;
@@ -1160,5 +2129,60 @@ mod tests {
         implements_imgui(&glam::Vec4::default());
         implements_imgui(&glam::Vec3::default());
         implements_imgui(&glam::Vec2::default());
+        implements_imgui(&glam::Vec3A::default());
+        implements_imgui(&glam::DVec3::default());
+        implements_imgui(&glam::IVec2::default());
+        implements_imgui(&glam::UVec3::default());
+        implements_imgui(&glam::BVec3::default());
+        implements_imgui(&glam::Quat::IDENTITY);
+        implements_imgui(&glam::DQuat::IDENTITY);
+        implements_imgui(&glam::Affine3A::IDENTITY);
+        implements_imgui(&glam::DAffine3::IDENTITY);
+    }
+
+    #[test]
+    fn quaternions_are_edited_as_euler_angles() {
+        use super::quaternion::{set_components, QuaternionEditor, EULER_ORDERS};
+
+        let mut editor = QuaternionEditor {
+            euler: true,
+            order: EULER_ORDERS
+                .iter()
+                .position(|(order, _)| *order == glam::EulerRot::YXZ)
+                .unwrap(),
+            last_edit: None,
+        };
+        let mut rotation = glam::Quat::IDENTITY;
+        editor.set_angles(&mut rotation, [90.0, 0.0, 0.0]);
+        assert!(rotation.is_normalized());
+        assert!(rotation.abs_diff_eq(glam::Quat::from_rotation_y(90f32.to_radians()), 1e-6));
+        assert_eq!(editor.angles(rotation), [90.0, 0.0, 0.0]);
+
+        // The angles entered are kept, rather than recomputed.
+        editor.set_angles(&mut rotation, [180.0, 180.0, 0.0]);
+        assert_eq!(editor.angles(rotation), [180.0, 180.0, 0.0]);
+
+        let mut rotation = glam::DQuat::IDENTITY;
+        set_components(&mut rotation, [0.0, 0.0, 0.0, 0.0]);
+        assert_eq!(rotation, glam::DQuat::IDENTITY);
+        set_components(&mut rotation, [0.0, 2.0, 0.0, 2.0]);
+        assert!(rotation.is_normalized());
+        assert!(rotation.abs_diff_eq(glam::DQuat::from_rotation_y(90f64.to_radians()), 1e-9));
+    }
+
+    #[test]
+    fn transforms_scaled_to_zero_stay_finite() {
+        use super::affine::Affine;
+
+        let mut transform = glam::Affine3A::from_scale(glam::Vec3::new(1.0, 0.0, 1.0));
+        let (scale, rotation, translation) = transform.decompose();
+        assert_eq!(rotation, glam::Quat::IDENTITY);
+
+        transform.recompose(scale, rotation, translation + glam::Vec3::X);
+        assert!(transform.is_finite());
+        assert_eq!(transform.translation, glam::Vec3A::X);
+
+        transform.recompose(scale, glam::Quat::NAN, translation);
+        assert_eq!(transform.translation, glam::Vec3A::X);
     }
 }
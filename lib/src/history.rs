@@ -0,0 +1,390 @@
+//! The undo and redo history of the values edited in the mutable
+//! presentations.
+//!
+//! Wrapping a value into an [`EditHistory`] records every edit the
+//! user makes in its mutable presentation, which may then be undone
+//! and redone with `Ctrl+Z` and `Ctrl+Shift+Z` in ImGui and egui, or
+//! with the `TuiKey::Undo` and `TuiKey::Redo` keys in the terminal:
+//!
+//! ```rust,ignore
+//! let mut scene = EditHistory::new(scene);
+//! // Every frame:
+//! let _ = scene.render_window_mut(ui, extent);
+//! ```
+//!
+//! The continuous edits, like dragging a value or typing text, are
+//! recorded as a single entry once those are finished (see
+//! [`EditResponse::edit_finished`]).
+
+use crate::EditResponse;
+
+/// An edit which can be reverted and applied again. Recording the
+/// edits instead of the copies of the whole value allows to keep the
+/// history of the values which are expensive or impossible to clone.
+pub trait Edit<T> {
+    /// Reverts the edit of the value.
+    fn undo(&mut self, value: &mut T);
+
+    /// Applies the edit to the value.
+    fn redo(&mut self, value: &mut T);
+}
+
+/// An entry of the history.
+enum Entry<T> {
+    /// The copy of the value, which becomes the current value once
+    /// the entry is undone or redone, while the current value takes its
+    /// place.
+    Snapshot(T),
+    /// The reversible edit.
+    Edit(Box<dyn Edit<T>>),
+}
+
+/// A value along with the history of the edits made to it.
+///
+/// The edits made in the presentations are recorded as the snapshots
+/// of the value, so those require the value to be [`Clone`], and
+/// [`PartialEq`] to skip the edits leaving it unchanged. A single copy
+/// is made per recorded edit. The other edits may be recorded
+/// with [`EditHistory::apply`] and [`EditHistory::modify`].
+pub struct EditHistory<T> {
+    value: T,
+    undo: Vec<Entry<T>>,
+    redo: Vec<Entry<T>>,
+    /// The copy of the value taken before the next edit in the
+    /// presentation, or `None` if it has to be taken again.
+    committed: Option<T>,
+    /// An edit in the presentation has started, but hasn't finished
+    /// yet.
+    editing: bool,
+    limit: usize,
+}
+
+impl<T: std::fmt::Debug> std::fmt::Debug for EditHistory<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("EditHistory")
+            .field("value", &self.value)
+            .field("undo", &self.undo.len())
+            .field("redo", &self.redo.len())
+            .field("editing", &self.editing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Default> Default for EditHistory<T> {
+    fn default() -> Self {
+        Self::new(T::default())
+    }
+}
+
+impl<T> EditHistory<T> {
+    /// The number of the entries kept by default.
+    pub const DEFAULT_LIMIT: usize = 100;
+
+    /// Creates an empty history of the value.
+    pub fn new(value: T) -> Self {
+        Self {
+            value,
+            undo: Vec::new(),
+            redo: Vec::new(),
+            committed: None,
+            editing: false,
+            limit: Self::DEFAULT_LIMIT,
+        }
+    }
+
+    /// Sets the number of the entries kept, dropping the oldest ones
+    /// once it is exceeded.
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = limit;
+        self
+    }
+
+    /// Returns the value.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Returns the value, consuming the history.
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// Returns `true` if there is an entry to undo.
+    pub fn can_undo(&self) -> bool {
+        self.editing || !self.undo.is_empty()
+    }
+
+    /// Returns `true` if there is an entry to redo.
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    /// Forgets all the entries.
+    pub fn clear(&mut self) {
+        self.undo.clear();
+        self.redo.clear();
+        self.committed = None;
+        self.editing = false;
+    }
+
+    /// Applies the edit to the value and records it.
+    pub fn apply(&mut self, mut edit: impl Edit<T> + 'static) {
+        self.finish_edit();
+        edit.redo(&mut self.value);
+        self.push(Entry::Edit(Box::new(edit)));
+        self.committed = None;
+    }
+
+    /// Reverts the last recorded edit. Returns `false` if there is
+    /// nothing to undo.
+    pub fn undo(&mut self) -> bool {
+        self.finish_edit();
+        let Some(entry) = self.undo.pop() else {
+            return false;
+        };
+        let entry = self.swap(entry, |edit, value| edit.undo(value));
+        self.redo.push(entry);
+        true
+    }
+
+    /// Applies the last reverted edit again. Returns `false` if there
+    /// is nothing to redo.
+    pub fn redo(&mut self) -> bool {
+        self.finish_edit();
+        let Some(entry) = self.redo.pop() else {
+            return false;
+        };
+        let entry = self.swap(entry, |edit, value| edit.redo(value));
+        self.undo.push(entry);
+        true
+    }
+
+    /// Undoes or redoes the edit, returning the response telling
+    /// whether the value has been changed.
+    fn undo_or_redo(&mut self, redo: bool) -> EditResponse {
+        EditResponse::changed(if redo { self.redo() } else { self.undo() })
+    }
+
+    /// Replaces the value with the snapshot, or reverts or applies the
+    /// edit, returning the entry doing the opposite.
+    fn swap(&mut self, entry: Entry<T>, apply: impl FnOnce(&mut dyn Edit<T>, &mut T)) -> Entry<T> {
+        self.committed = None;
+        match entry {
+            Entry::Snapshot(snapshot) => {
+                Entry::Snapshot(std::mem::replace(&mut self.value, snapshot))
+            }
+            Entry::Edit(mut edit) => {
+                apply(edit.as_mut(), &mut self.value);
+                Entry::Edit(edit)
+            }
+        }
+    }
+
+    /// Records the edit in the presentation which hasn't finished yet,
+    /// like when the value is undone while being dragged.
+    fn finish_edit(&mut self) {
+        if !std::mem::take(&mut self.editing) {
+            return;
+        }
+        if let Some(before) = self.committed.take() {
+            self.push(Entry::Snapshot(before));
+        }
+    }
+
+    fn push(&mut self, entry: Entry<T>) {
+        self.redo.clear();
+        self.undo.push(entry);
+        if self.undo.len() > self.limit {
+            let _ = self.undo.remove(0);
+        }
+    }
+}
+
+impl<T: Clone> EditHistory<T> {
+    /// Changes the value with the function provided, recording a
+    /// snapshot of it.
+    pub fn modify<R>(&mut self, modify: impl FnOnce(&mut T) -> R) -> R {
+        self.finish_edit();
+        let before = self.value.clone();
+        let result = modify(&mut self.value);
+        self.push(Entry::Snapshot(before));
+        self.committed = None;
+        result
+    }
+}
+
+impl<T: Clone + PartialEq> EditHistory<T> {
+    /// Presents the value with the function provided, recording the
+    /// edit once the returned response tells it has finished. The
+    /// changes made during the consecutive frames of a continuous edit
+    /// are recorded as a single entry.
+    pub fn record(&mut self, present: impl FnOnce(&mut T) -> EditResponse) -> EditResponse {
+        if self.committed.is_none() {
+            self.committed = Some(self.value.clone());
+        }
+
+        let response = present(&mut self.value);
+        // The clicked methods may have changed the value.
+        if response.changed || response.clicked {
+            self.editing = true;
+        }
+        if response.edit_finished || !response.active {
+            // The edits leaving the value as it was, like clicking a
+            // button which doesn't change it, aren't recorded.
+            if self.committed.as_ref() == Some(&self.value) {
+                self.editing = false;
+            }
+            self.finish_edit();
+        }
+
+        response
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_backend {
+    use super::EditHistory;
+    use crate::filter::Filter;
+    use crate::{EditResponse, Extent, ImguiPresentable};
+
+    impl<T> EditHistory<T> {
+        /// Handles the undo and redo shortcuts, unless a text is being
+        /// typed in, as the text inputs have their own history.
+        fn handle_imgui_shortcuts(&mut self, ui: &imgui::Ui) -> EditResponse {
+            let io = ui.io();
+            // The Command key is used instead of Ctrl on macOS.
+            let command = if io.config_mac_os_behaviors {
+                io.key_super
+            } else {
+                io.key_ctrl
+            };
+            if command && !io.want_text_input && ui.is_key_pressed_no_repeat(imgui::Key::Z) {
+                self.undo_or_redo(io.key_shift)
+            } else {
+                EditResponse::default()
+            }
+        }
+    }
+
+    impl<T: ImguiPresentable + Clone + PartialEq> ImguiPresentable for EditHistory<T> {
+        fn render_window(&self, ui: &imgui::Ui, extent: Extent) {
+            self.value.render_window(ui, extent);
+        }
+
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            self.value.render_component(ui, extent);
+        }
+
+        fn render_window_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.handle_imgui_shortcuts(ui)
+                | self.record(|value| value.render_window_mut(ui, extent))
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.handle_imgui_shortcuts(ui)
+                | self.record(|value| value.render_component_mut(ui, extent))
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.value.matches_filter(filter)
+        }
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_backend {
+    use super::EditHistory;
+    use crate::filter::Filter;
+    use crate::{EditResponse, EguiPresentable};
+
+    impl<T> EditHistory<T> {
+        /// Handles the undo and redo shortcuts, unless a text is being
+        /// typed in, as the text inputs have their own history.
+        fn handle_egui_shortcuts(&mut self, context: &egui::Context) -> EditResponse {
+            if context.wants_keyboard_input() {
+                return EditResponse::default();
+            }
+
+            let shortcut = |modifiers| egui::KeyboardShortcut::new(modifiers, egui::Key::Z);
+            // The extra Shift is ignored when matching the shortcuts,
+            // so the redo one is checked first.
+            let redo = shortcut(egui::Modifiers::COMMAND | egui::Modifiers::SHIFT);
+            let undo = shortcut(egui::Modifiers::COMMAND);
+            if context.input_mut(|i| i.consume_shortcut(&redo)) {
+                self.undo_or_redo(true)
+            } else if context.input_mut(|i| i.consume_shortcut(&undo)) {
+                self.undo_or_redo(false)
+            } else {
+                EditResponse::default()
+            }
+        }
+    }
+
+    impl<T: EguiPresentable + Clone + PartialEq> EguiPresentable for EditHistory<T> {
+        fn render_window(&self, context: &egui::Context) {
+            self.value.render_window(context);
+        }
+
+        fn render_component(&self, ui: &mut egui::Ui) {
+            self.value.render_component(ui);
+        }
+
+        fn render_window_mut(&mut self, context: &egui::Context) -> EditResponse {
+            self.handle_egui_shortcuts(context)
+                | self.record(|value| value.render_window_mut(context))
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.handle_egui_shortcuts(&ui.ctx().clone())
+                | self.record(|value| value.render_component_mut(ui))
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.value.matches_filter(filter)
+        }
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use super::EditHistory;
+    use crate::tui::{TuiKey, TuiState, TuiUi};
+    use crate::{EditResponse, TuiPresentable};
+
+    impl<T: TuiPresentable + Clone + PartialEq> TuiPresentable for EditHistory<T> {
+        fn render_window(
+            &self,
+            frame: &mut ratatui::Frame<'_>,
+            area: ratatui::layout::Rect,
+            state: &mut TuiState,
+        ) {
+            self.value.render_window(frame, area, state);
+        }
+
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            self.value.render_component(ui);
+        }
+
+        fn render_window_mut(
+            &mut self,
+            frame: &mut ratatui::Frame<'_>,
+            area: ratatui::layout::Rect,
+            state: &mut TuiState,
+        ) -> EditResponse {
+            // The key is taken from the frame, so that only one key is
+            // handled per frame, as usual.
+            let mut ui = TuiUi::new(state);
+            let response = self.render_component_mut(&mut ui);
+            ui.show(frame, area, std::any::type_name::<T>());
+            response
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            let response = match ui.take_history_key() {
+                Some(key) => self.undo_or_redo(key == TuiKey::Redo),
+                None => EditResponse::default(),
+            };
+            response | self.record(|value| value.render_component_mut(ui))
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! The layouts of the fields of the derived structs.
+//!
+//! The fields may be put into the collapsible sections with the `group`
+//! attribute and into the tabs with the `tab` attribute, reordered with
+//! `order`, separated with `separator` and put on the same line with
+//! `same_line`. The `layout = "grid"` attribute of a struct shows the
+//! names and the values of its fields as the two columns of a table:
+//!
+//! ```rust,ignore
+//! #[derive(ImguiPresentation)]
+//! #[imgui_presentation(layout = "grid")]
+//! struct Config {
+//!     #[imgui_presentation(group = "Physics")]
+//!     gravity: f32,
+//!     #[imgui_presentation(tab = "Rendering")]
+//!     fov: f32,
+//! }
+//! ```
+//!
+//! The `flatten` attribute of a field shows the fields of its value
+//! among the fields of the owner, like the serde's one, and the `inline`
+//! attribute shows the field and the fields of its value on a single
+//! row, which suits the small structs. Those structs are marked
+//! `inline` too, so that only they have the code for it generated:
+//!
+//! ```rust,ignore
+//! #[derive(ImguiPresentation)]
+//! struct Object {
+//!     #[imgui_presentation(flatten)]
+//!     common: Common,
+//!     #[imgui_presentation(inline)]
+//!     transform: Transform,
+//! }
+//!
+//! #[derive(ImguiPresentation)]
+//! #[imgui_presentation(inline)]
+//! struct Transform {
+//!     x: f32,
+//!     y: f32,
+//! }
+//! ```
+//!
+//! The single field of a newtype, like `struct Meters(f32)`, is
+//! flattened unless it is inline.
+//!
+//! ImGui has the tab bars of its own, the functions here add those to
+//! the other backends.
+
+use std::cell::Cell;
+
+/// Implemented by the derived structs with the `inline` attribute, which
+/// may be shown on a single row by the fields with the `inline`
+/// attribute. The other structs don't have the code for it generated.
+pub trait Inline {}
+
+thread_local! {
+    /// Whether the struct rendered next is shown on a single row.
+    static INLINE: Cell<bool> = const { Cell::new(false) };
+}
+
+/// Calls the function, which renders a derived struct of the type `T`,
+/// showing the fields of the struct on a single row. Only the struct
+/// rendered first is, not the ones nested into its fields.
+pub fn with_inline<T: Inline + ?Sized, R>(render: impl FnOnce() -> R) -> R {
+    /// Restores the previous state, even if the rendering panics.
+    struct Restore(bool);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            INLINE.with(|inline| inline.set(self.0));
+        }
+    }
+
+    let _restore = Restore(INLINE.with(|inline| inline.replace(true)));
+    render()
+}
+
+/// Returns whether the struct being rendered is shown on a single row,
+/// so that the structs nested into it aren't. Called by the derived
+/// presentations.
+pub fn take_inline() -> bool {
+    INLINE.with(Cell::take)
+}
+
+/// Shows the tabs as the selectable labels in a row and returns the
+/// index of the one selected, which is remembered by the identifier
+/// provided.
+#[cfg(feature = "egui_backend")]
+pub fn tab_bar_egui(ui: &mut egui::Ui, id_salt: impl std::hash::Hash, tabs: &[&str]) -> usize {
+    let id = ui.make_persistent_id(id_salt);
+    let last = tabs.len().saturating_sub(1);
+    let mut selected = ui
+        .data(|data| data.get_temp::<usize>(id))
+        .unwrap_or_default()
+        .min(last);
+    let _ = ui.horizontal(|ui| {
+        for (index, tab) in tabs.iter().enumerate() {
+            let _ = ui.selectable_value(&mut selected, index, *tab);
+        }
+    });
+    let _ = ui.separator();
+    ui.data_mut(|data| data.insert_temp(id, selected));
+    selected
+}
+
+/// Shows the tabs as a choice and returns the index of the one
+/// selected, which is remembered by the name provided.
+#[cfg(feature = "ratatui_backend")]
+pub fn tab_bar_tui(ui: &mut crate::tui::TuiUi<'_>, name: &str, tabs: &[&str]) -> usize {
+    let last = tabs.len().saturating_sub(1);
+    let mut selected = ui.data::<usize>(name).min(last);
+    // Switching the tabs doesn't change the value.
+    let _ = ui.choice(&mut selected, tabs);
+    ui.set_data(name, selected);
+    selected
+}
@@ -1,10 +1,10 @@
 //! Imgui Presentable.
 //!
 //! Here are the main interfaces which allow the users to render enums
-//! or structs in ImGui or egui effortlessly.
+//! or structs in ImGui, egui or a terminal (via ratatui) effortlessly.
 //!
-//! To switch between the gui backends (ImGui and egui), use the
-//! corresponding crate features. Both the features can be enabled
+//! To switch between the gui backends (ImGui, egui and ratatui), use
+//! the corresponding crate features. All the features can be enabled
 //! simultaneously, if necessary.
 //!
 //! The crate already provides some implementations for the basic Rust
@@ -13,12 +13,78 @@
 //! For the collections, the trait is implemented automatically if the
 //! element type of the collection implements the [`ImguiPresentable`]
 //! or the [`EguiPresentable`] trait itself.
+//!
+//! The mutable presentations return an [`EditResponse`], telling
+//! whether the user has changed the value during the frame, which
+//! allows to, for example, mark a document dirty or push an undo step
+//! without comparing the whole objects every frame. The
+//! [`EditHistory`] does the latter, providing the undo and redo for any
+//! presentable value.
+//!
+//! The large presentations may be searched through by wrapping those
+//! into a [`Filtered`], which adds a filter bar hiding everything not
+//! matching the query typed in.
+//!
+//! The elements of the sequences may be added, duplicated and reordered
+//! in the mutable presentations, as far as their types allow, see the
+//! [`elements`] module. The entries of the maps and the sets may be
+//! added, removed and renamed in the same way, the keys being edited
+//! aside and re-inserted once confirmed.
+//!
+//! The colors stored in the arrays, the vectors or the wrappers like
+//! [`Rgb`] are edited with the color pickers, see the [`color`] module.
+//!
+//! With the `serde` feature, the fields of the derived presentations
+//! may be copied and pasted as JSON or RON, and the windows save and
+//! load the presets, see the `serialization` module.
+//!
+//! The fields may be validated with the functions and the built-in
+//! checks, the errors being shown next to those, see the [`validation`]
+//! module.
+//!
+//! The fields of the derived structs may be grouped, put into the tabs,
+//! reordered, shown as a table, flattened into their owners and shown
+//! on a single row, see the [`layout`] module.
+//!
+//! The long collections only lay out the rows visible on the screen,
+//! and may be split into pages, see the [`pagination`] module.
 
 pub use imgui_presentable_derive::*;
 mod basic_types;
+pub mod color;
+pub mod elements;
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+mod entries;
+pub mod filter;
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+mod frame_state;
 #[cfg(feature = "glam")]
 mod glam_types;
+pub mod history;
+pub mod layout;
+pub mod pagination;
+pub mod path;
+pub mod reflect;
+mod response;
+#[cfg(feature = "serde")]
+pub mod serialization;
 mod std_types;
+#[cfg(feature = "testing")]
+pub mod testing;
+#[cfg(feature = "ratatui_backend")]
+pub mod tui;
+pub mod validation;
+
+pub use color::{Rgb, Rgba, Srgba8};
+pub use filter::Filtered;
+pub use history::EditHistory;
+pub use reflect::Reflect;
+pub use response::EditResponse;
+pub use validation::Validate;
 
 /// The width and height of the viewport used by ImGUI.
 #[cfg(feature = "imgui_backend")]
@@ -62,26 +128,74 @@ pub trait ImguiPresentable {
     /// This is the default implementation. The code may be overriden
     /// and changed to some other window creation code, depending on
     /// the code generation options used.
-    fn render_window_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
+    fn render_window_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
         ui.window(std::any::type_name::<Self>())
             .resizable(true)
             .collapsible(true)
             .bg_alpha(0.7f32)
             .position([0.0, 0.0], imgui::Condition::FirstUseEver)
             .menu_bar(false)
-            .build(|| self.render_component_mut(ui, extent));
+            .build(|| self.render_component_mut(ui, extent))
+            .unwrap_or_default()
     }
 
     /// Renders the implementor as a sub-component allowing to change
-    /// the values.
+    /// the values. Returns what the user has done to the value during
+    /// this frame.
     ///
     /// # Note
     ///
     /// If not re-implemented, the default implementation shows the
     /// immutable UI.
-    fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
+    fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
         // ui.text("This struct doesn't provide a mutable ui.");
         self.render_component(ui, extent);
+        EditResponse::default()
+    }
+
+    /// Renders the implementor as a sub-component allowing to change
+    /// the values through a shared reference. This is only possible
+    /// for the types with interior mutability, like
+    /// [`std::cell::RefCell`] or [`std::sync::RwLock`], and is what
+    /// allows to edit the values behind an [`std::rc::Rc`] or an
+    /// [`std::sync::Arc`].
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the default implementation shows the
+    /// immutable UI.
+    fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+        self.render_component(ui, extent);
+        EditResponse::default()
+    }
+
+    /// Returns `true` if the value, or any of the values nested in it,
+    /// is displayed as a text matching the filter, so that the field
+    /// holding the value is shown while the filter is applied (see
+    /// [`Filtered`]).
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the value never matches, so it is only
+    /// shown when the name of the field holding it does.
+    fn matches_filter(&self, filter: &filter::Filter) -> bool {
+        let _ = filter;
+        false
+    }
+
+    /// Returns what the sequences of the values may do to their
+    /// elements besides removing and moving those, like adding the new
+    /// ones (see [`elements`]).
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the elements may neither be created nor
+    /// duplicated.
+    fn element_actions() -> elements::ElementActions<Self>
+    where
+        Self: Sized,
+    {
+        elements::ElementActions::new()
     }
 }
 
@@ -101,19 +215,133 @@ pub trait EguiPresentable {
 
     /// Renders the implementor as a stand-alone window allowing to
     /// change the values.
-    fn render_window_mut(&mut self, context: &egui::Context) {
+    fn render_window_mut(&mut self, context: &egui::Context) -> EditResponse {
         egui::Window::new(std::any::type_name::<Self>())
-            .show(context, |ui| self.render_component_mut(ui));
+            .show(context, |ui| self.render_component_mut(ui))
+            .and_then(|inner| inner.inner)
+            .unwrap_or_default()
+    }
+
+    /// Renders the implementor as a sub-component allowing to change
+    /// the values. Returns what the user has done to the value during
+    /// this frame.
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the default implementation shows the
+    /// immutable UI.
+    fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+        self.render_component(ui);
+        EditResponse::default()
+    }
+
+    /// Renders the implementor as a sub-component allowing to change
+    /// the values through a shared reference. This is only possible
+    /// for the types with interior mutability, like
+    /// [`std::cell::RefCell`] or [`std::sync::RwLock`], and is what
+    /// allows to edit the values behind an [`std::rc::Rc`] or an
+    /// [`std::sync::Arc`].
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the default implementation shows the
+    /// immutable UI.
+    fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+        self.render_component(ui);
+        EditResponse::default()
+    }
+
+    /// Returns `true` if the value, or any of the values nested in it,
+    /// is displayed as a text matching the filter, so that the field
+    /// holding the value is shown while the filter is applied (see
+    /// [`Filtered`]).
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the value never matches, so it is only
+    /// shown when the name of the field holding it does.
+    fn matches_filter(&self, filter: &filter::Filter) -> bool {
+        let _ = filter;
+        false
+    }
+
+    /// Returns what the sequences of the values may do to their
+    /// elements besides removing and moving those, like adding the new
+    /// ones (see [`elements`]).
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the elements may neither be created nor
+    /// duplicated.
+    fn element_actions() -> elements::ElementActions<Self>
+    where
+        Self: Sized,
+    {
+        elements::ElementActions::new()
+    }
+}
+
+/// Allows the implementing object to be rendered in a terminal, using
+/// the [`tui`] user interface drawn with ratatui.
+#[cfg(feature = "ratatui_backend")]
+pub trait TuiPresentable {
+    /// Renders the implementor into the area of the frame, not
+    /// allowing to change the values.
+    fn render_window(
+        &self,
+        frame: &mut ratatui::Frame<'_>,
+        area: ratatui::layout::Rect,
+        state: &mut tui::TuiState,
+    ) {
+        let mut ui = tui::TuiUi::new(state);
+        self.render_component(&mut ui);
+        ui.show(frame, area, std::any::type_name::<Self>());
+    }
+
+    /// Renders the implementor as a sub-component not allowing to
+    /// change the values.
+    fn render_component(&self, ui: &mut tui::TuiUi<'_>);
+
+    /// Renders the implementor into the area of the frame, allowing to
+    /// change the values.
+    fn render_window_mut(
+        &mut self,
+        frame: &mut ratatui::Frame<'_>,
+        area: ratatui::layout::Rect,
+        state: &mut tui::TuiState,
+    ) -> EditResponse {
+        let mut ui = tui::TuiUi::new(state);
+        let response = self.render_component_mut(&mut ui);
+        ui.show(frame, area, std::any::type_name::<Self>());
+        response
+    }
+
+    /// Renders the implementor as a sub-component allowing to change
+    /// the values. Returns what the user has done to the value during
+    /// this frame.
+    ///
+    /// # Note
+    ///
+    /// If not re-implemented, the default implementation shows the
+    /// immutable UI.
+    fn render_component_mut(&mut self, ui: &mut tui::TuiUi<'_>) -> EditResponse {
+        self.render_component(ui);
+        EditResponse::default()
     }
 
     /// Renders the implementor as a sub-component allowing to change
-    /// the values.
+    /// the values through a shared reference. This is only possible
+    /// for the types with interior mutability, like
+    /// [`std::cell::RefCell`] or [`std::sync::RwLock`], and is what
+    /// allows to edit the values behind an [`std::rc::Rc`] or an
+    /// [`std::sync::Arc`].
     ///
     /// # Note
     ///
     /// If not re-implemented, the default implementation shows the
     /// immutable UI.
-    fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+    fn render_component_shared(&self, ui: &mut tui::TuiUi<'_>) -> EditResponse {
         self.render_component(ui);
+        EditResponse::default()
     }
 }
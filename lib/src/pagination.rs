@@ -0,0 +1,320 @@
+//! The rendering of the large collections.
+//!
+//! Only the rows of a [`Vec`], a [`std::collections::HashMap`] or a
+//! [`std::collections::BTreeMap`] visible within the scrolled area are
+//! laid out, so that the collections with many thousands of elements
+//! don't slow the frames down.
+//!
+//! The collections may also be split into pages, showing a page
+//! selector like "rows 0–999 of 120 000" above the rows. The size of
+//! the pages is set with the `page_size` attribute of a field:
+//!
+//! ```rust,ignore
+//! #[derive(ImguiPresentation)]
+//! struct Scene {
+//!     #[imgui_presentation(page_size = 1000)]
+//!     objects: Vec<Object>,
+//! }
+//! ```
+//!
+//! Or, when implementing the presentation manually, with
+//! [`with_page_size`].
+
+use std::cell::Cell;
+use std::ops::Range;
+
+thread_local! {
+    /// The size of the pages of the collection rendered next.
+    static PAGE_SIZE: Cell<Option<usize>> = const { Cell::new(None) };
+}
+
+/// Calls the function, which renders a collection, splitting the
+/// collection into the pages of the size provided. Only the collection
+/// rendered first is split, not the ones nested into its elements.
+pub fn with_page_size<R>(page_size: usize, render: impl FnOnce() -> R) -> R {
+    /// Restores the previous page size, even if the rendering panics.
+    struct Restore(Option<usize>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            PAGE_SIZE.with(|size| size.set(self.0));
+        }
+    }
+
+    let _restore = Restore(PAGE_SIZE.with(|size| size.replace(Some(page_size.max(1)))));
+    render()
+}
+
+/// Returns the size of the pages the collection being rendered is
+/// split into, if it is, so that the collections nested into it aren't.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+pub(crate) fn take_page_size() -> Option<usize> {
+    PAGE_SIZE.with(Cell::take)
+}
+
+/// Walks an iterator over a collection to the rows rendered, which
+/// are rendered in the ascending order, so that the collections which
+/// can't be indexed, like the maps, aren't collected every frame.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+pub(crate) struct RowCursor<I> {
+    iter: I,
+    /// The order number of the item returned next by the iterator.
+    next: usize,
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl<I: Iterator> RowCursor<I> {
+    pub(crate) fn new(iter: I) -> Self {
+        Self { iter, next: 0 }
+    }
+
+    /// Returns the item with the order number, unless it precedes the
+    /// items returned before.
+    pub(crate) fn nth(&mut self, index: usize) -> Option<I::Item> {
+        let item = self.iter.nth(index.checked_sub(self.next)?);
+        self.next = index + 1;
+        item
+    }
+}
+
+/// A page of the rows of a collection.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Page {
+    /// The order number of the page.
+    pub index: usize,
+    /// The maximum number of the rows on a page.
+    pub size: usize,
+    /// The number of the rows of the collection.
+    pub len: usize,
+}
+
+impl Page {
+    /// Returns the page with the order number provided, or the last
+    /// page if there are fewer pages.
+    pub fn new(index: usize, size: usize, len: usize) -> Self {
+        let size = size.max(1);
+        let mut page = Self { index, size, len };
+        page.index = index.min(page.count() - 1);
+        page
+    }
+
+    /// Returns the number of the pages, which is at least one.
+    pub fn count(&self) -> usize {
+        self.len.div_ceil(self.size).max(1)
+    }
+
+    /// Returns the rows of the collection shown on the page.
+    pub fn rows(&self) -> Range<usize> {
+        let start = (self.index * self.size).min(self.len);
+        start..(start + self.size).min(self.len)
+    }
+
+    /// Returns `true` if there is a page before this one.
+    pub fn has_previous(&self) -> bool {
+        self.index > 0
+    }
+
+    /// Returns `true` if there is a page after this one.
+    pub fn has_next(&self) -> bool {
+        self.index + 1 < self.count()
+    }
+}
+
+/// Shows the page like "rows 0–999 of 120 000".
+impl std::fmt::Display for Page {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let rows = self.rows();
+        write!(
+            f,
+            "rows {}–{} of {}",
+            group_digits(rows.start),
+            group_digits(rows.end.saturating_sub(1)),
+            group_digits(self.len)
+        )
+    }
+}
+
+/// Returns the number with its digits grouped by three, like
+/// "120 000".
+fn group_digits(number: usize) -> String {
+    let digits = number.to_string();
+    let mut grouped = String::with_capacity(digits.len() + digits.len() / 3);
+    // The leading group takes the digits which don't make a full one.
+    let (leading, rest) = digits.split_at((digits.len() - 1) % 3 + 1);
+    grouped.push_str(leading);
+    for start in (0..rest.len()).step_by(3) {
+        grouped.push(' ');
+        grouped.push_str(&rest[start..start + 3]);
+    }
+    grouped
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_backend {
+    use std::cell::RefCell;
+    use std::ops::Range;
+
+    use super::Page;
+    use crate::frame_state::{imgui_frame, FrameStates};
+
+    thread_local! {
+        /// The pages shown, by the addresses of the collections.
+        static PAGES: RefCell<FrameStates<usize, usize>> = RefCell::default();
+
+        /// Whether any of the rows of the collections was expanded
+        /// during the last frame, by the addresses of the collections.
+        static EXPANDED: RefCell<FrameStates<usize, bool>> = RefCell::default();
+    }
+
+    /// Renders the page selector of the collection, if it is split
+    /// into pages and doesn't fit into one, and returns the rows shown.
+    /// The page shown is kept between the frames, identified by the
+    /// address of the collection.
+    pub(crate) fn render_imgui_pager<T>(
+        ui: &imgui::Ui,
+        collection: *const T,
+        page_size: Option<usize>,
+        len: usize,
+    ) -> Range<usize> {
+        let Some(page_size) = page_size.filter(|size| len > *size) else {
+            return 0..len;
+        };
+
+        let frame = imgui_frame(ui);
+        let key = collection as usize;
+        let stored = PAGES.with_borrow_mut(|pages| pages.get(frame, &key).copied());
+        let mut page = Page::new(stored.unwrap_or_default(), page_size, len);
+
+        let _id = ui.push_id(format!("##pager{collection:p}"));
+        ui.disabled(!page.has_previous(), || {
+            if ui.arrow_button("##previous", imgui::Direction::Left) {
+                page.index -= 1;
+            }
+        });
+        ui.same_line();
+        ui.disabled(!page.has_next(), || {
+            if ui.arrow_button("##next", imgui::Direction::Right) {
+                page.index += 1;
+            }
+        });
+        ui.same_line();
+        ui.text(page.to_string());
+
+        PAGES.with_borrow_mut(|pages| pages.insert(frame, key, page.index));
+        page.rows()
+    }
+
+    /// Renders only the rows visible within the scrolled area, which
+    /// must be of the same height to be skipped. The function renders a
+    /// row and returns `true` if it is expanded, like an open header of
+    /// an element, in which case all the rows are rendered during the
+    /// next frame.
+    pub(crate) fn clip_imgui_rows<T>(
+        ui: &imgui::Ui,
+        collection: *const T,
+        rows: Range<usize>,
+        mut render_row: impl FnMut(usize) -> bool,
+    ) {
+        let frame = imgui_frame(ui);
+        let key = collection as usize;
+        let was_expanded = EXPANDED.with_borrow_mut(|expanded| expanded.get(frame, &key).copied());
+        let mut is_expanded = false;
+
+        if was_expanded.unwrap_or_default() {
+            rows.for_each(|row| is_expanded |= render_row(row));
+        } else {
+            let count = i32::try_from(rows.len()).unwrap_or(i32::MAX);
+            imgui::ListClipper::new(count)
+                .begin(ui)
+                .iter()
+                .for_each(|row| is_expanded |= render_row(rows.start + row as usize));
+        }
+
+        EXPANDED.with_borrow_mut(|expanded| expanded.insert(frame, key, is_expanded));
+    }
+
+    /// Renders a cell of a table row, returning `true` if it is taller
+    /// than a single widget, like a struct shown in it.
+    pub(crate) fn render_imgui_cell<R>(ui: &imgui::Ui, render: impl FnOnce() -> R) -> (R, bool) {
+        ui.table_next_column();
+        let top = ui.cursor_pos()[1];
+        let result = render();
+        let expanded = ui.cursor_pos()[1] - top > ui.frame_height_with_spacing() + 0.5;
+        (result, expanded)
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+pub(crate) use imgui_backend::*;
+
+#[cfg(feature = "egui_backend")]
+mod egui_backend {
+    use std::ops::Range;
+
+    use super::Page;
+
+    /// Renders the page selector of the collection, if it is split
+    /// into pages and doesn't fit into one, and returns the rows shown.
+    /// The page shown is kept in the memory of the context, identified
+    /// by the current `Ui`.
+    pub(crate) fn render_egui_pager(
+        ui: &mut egui::Ui,
+        page_size: Option<usize>,
+        len: usize,
+    ) -> Range<usize> {
+        let Some(page_size) = page_size.filter(|size| len > *size) else {
+            return 0..len;
+        };
+
+        let id = ui.id().with("page");
+        let stored = ui
+            .data(|data| data.get_temp::<usize>(id))
+            .unwrap_or_default();
+        let mut page = Page::new(stored, page_size, len);
+
+        ui.horizontal(|ui| {
+            if ui
+                .add_enabled(page.has_previous(), egui::Button::new("◀"))
+                .on_hover_text("Shows the previous page.")
+                .clicked()
+            {
+                page.index -= 1;
+            }
+            if ui
+                .add_enabled(page.has_next(), egui::Button::new("▶"))
+                .on_hover_text("Shows the next page.")
+                .clicked()
+            {
+                page.index += 1;
+            }
+            ui.label(page.to_string());
+        });
+
+        ui.data_mut(|data| data.insert_temp(id, page.index));
+        page.rows()
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+pub(crate) use egui_backend::*;
+
+#[cfg(test)]
+mod tests {
+    use super::Page;
+
+    #[test]
+    fn splits_the_rows_into_pages() {
+        let page = Page::new(0, 1000, 120_000);
+        assert_eq!(page.count(), 120);
+        assert_eq!(page.rows(), 0..1000);
+        assert_eq!(page.to_string(), "rows 0–999 of 120 000");
+
+        let last = Page::new(500, 1000, 1500);
+        assert_eq!(last.index, 1);
+        assert_eq!(last.rows(), 1000..1500);
+        assert!(last.has_previous() && !last.has_next());
+
+        let empty = Page::new(3, 0, 0);
+        assert_eq!((empty.index, empty.count(), empty.rows()), (0, 1, 0..0));
+    }
+}
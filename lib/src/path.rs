@@ -0,0 +1,368 @@
+//! The addressing of the nested values by their paths, like
+//! `"scene.objects[3].position.x"`.
+//!
+//! A path consists of the segments, each of which looks into the value
+//! found by the previous ones:
+//!
+//! - `name` (separated with a dot from the previous segment) selects a
+//!   field of a struct, or a field of the current variant of an enum.
+//!   The fields of the tuple structs are named by their order numbers,
+//!   like `0`.
+//! - `[3]` selects an element of a list (like a [`Vec`], an array or a
+//!   tuple) or an entry of a map the key of which is shown as `3`.
+//! - `["key"]` selects an entry of a map by its key.
+//!
+//! The paths are resolved using the [`Reflect`] implementations, see
+//! [`Reflect::get_field`], [`Reflect::get_field_mut`] and
+//! [`Reflect::set_field`].
+
+use crate::reflect::{Reflect, ReflectMut, ReflectRef, Value};
+
+/// The reason a path couldn't be resolved or a value couldn't be set.
+#[derive(Debug, Clone, PartialEq)]
+pub enum PathError {
+    /// The path is malformed. Contains the byte offset of the error.
+    Syntax(usize),
+    /// There is no field with the name in the value of the type.
+    NoField {
+        /// The name of the field.
+        field: String,
+        /// The name of the type of the value.
+        type_name: &'static str,
+    },
+    /// There is no element with the index in the list.
+    NoElement {
+        /// The index of the element.
+        index: usize,
+        /// The number of the elements in the list.
+        len: usize,
+    },
+    /// There is no entry with the key in the map.
+    NoEntry(String),
+    /// The value of the type can't be looked into with the segment, as
+    /// it isn't a struct, an enum, a list or a map, or it is borrowed
+    /// elsewhere.
+    NotNested(&'static str),
+    /// The value at the path may only be looked at.
+    ReadOnly(String),
+    /// The field is of a type which doesn't implement [`Reflect`], so
+    /// its value can't be reached.
+    NotReflected(String),
+    /// The value can't be converted into the type of the value at the
+    /// path.
+    InvalidValue {
+        /// The value which couldn't be set.
+        value: Value,
+        /// The name of the type of the value at the path.
+        type_name: &'static str,
+    },
+}
+
+impl std::fmt::Display for PathError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Syntax(offset) => write!(f, "The path is malformed at {offset}."),
+            Self::NoField { field, type_name } => {
+                write!(f, "There is no field `{field}` in {type_name}.")
+            }
+            Self::NoElement { index, len } => {
+                write!(
+                    f,
+                    "The index {index} is out of bounds, the length is {len}."
+                )
+            }
+            Self::NoEntry(key) => write!(f, "There is no entry with the key `{key}`."),
+            Self::NotNested(type_name) => write!(f, "{type_name} can't be looked into."),
+            Self::ReadOnly(segment) => write!(f, "`{segment}` is read-only."),
+            Self::NotReflected(segment) => write!(f, "`{segment}` isn't reflected."),
+            Self::InvalidValue { value, type_name } => {
+                write!(f, "`{value}` can't be converted into {type_name}.")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PathError {}
+
+/// A single segment of a path.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment<'a> {
+    /// A field of a struct or an enum variant.
+    Field(&'a str),
+    /// An element of a list or the key of an entry of a map.
+    Index(String),
+}
+
+impl std::fmt::Display for Segment<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Field(name) => name.fmt(f),
+            Self::Index(key) => write!(f, "[{key}]"),
+        }
+    }
+}
+
+/// Splits the path into the segments.
+fn parse(path: &str) -> Result<Vec<Segment<'_>>, PathError> {
+    let mut segments = Vec::new();
+    let mut rest = path;
+    let offset = |rest: &str| path.len() - rest.len();
+
+    while !rest.is_empty() {
+        if let Some(index) = rest.strip_prefix('[') {
+            let (key, after) = match index.strip_prefix('"') {
+                Some(quoted) => {
+                    let end = quoted.find('"').ok_or(PathError::Syntax(offset(index)))?;
+                    let after = quoted[end + 1..]
+                        .strip_prefix(']')
+                        .ok_or(PathError::Syntax(offset(&quoted[end + 1..])))?;
+                    (&quoted[..end], after)
+                }
+                None => {
+                    let end = index.find(']').ok_or(PathError::Syntax(offset(index)))?;
+                    let key = index[..end].trim();
+                    if key.is_empty() {
+                        return Err(PathError::Syntax(offset(index)));
+                    }
+                    (key, &index[end + 1..])
+                }
+            };
+            segments.push(Segment::Index(key.to_owned()));
+            rest = after;
+        } else {
+            // The first field isn't preceded by a dot.
+            let field = match rest.strip_prefix('.') {
+                Some(field) => field,
+                None if segments.is_empty() => rest,
+                None => return Err(PathError::Syntax(offset(rest))),
+            };
+            let end = field.find(['.', '[']).unwrap_or(field.len());
+            let name = field[..end].trim();
+            if name.is_empty() {
+                return Err(PathError::Syntax(offset(field)));
+            }
+            segments.push(Segment::Field(name));
+            rest = &field[end..];
+        }
+    }
+
+    Ok(segments)
+}
+
+/// Returns `true` if the key of a map entry is written as the text.
+fn key_matches(key: &dyn Reflect, text: &str) -> bool {
+    match key.reflect() {
+        ReflectRef::Value(value) => value.to_string() == text,
+        _ => false,
+    }
+}
+
+/// Looks into the value with the segment.
+fn step<'a>(
+    value: ReflectRef<'a>,
+    segment: &Segment<'_>,
+    type_name: &'static str,
+) -> Result<&'a dyn Reflect, PathError> {
+    let no_field = |field: &str| PathError::NoField {
+        field: field.to_owned(),
+        type_name,
+    };
+    let not_reflected = || PathError::NotReflected(segment.to_string());
+
+    match (value, segment) {
+        (ReflectRef::Struct(strukt), Segment::Field(name)) => {
+            let index = strukt
+                .info()
+                .fields
+                .iter()
+                .position(|f| f.name == *name)
+                .ok_or_else(|| no_field(name))?;
+            strukt.field(index).ok_or_else(not_reflected)
+        }
+        (ReflectRef::Enum(enumm), Segment::Field(name)) => {
+            let index = enumm
+                .variant()
+                .fields
+                .iter()
+                .position(|f| f.name == *name)
+                .ok_or_else(|| no_field(name))?;
+            enumm.field(index).ok_or_else(not_reflected)
+        }
+        (ReflectRef::List(list), Segment::Index(index)) => {
+            let index = index
+                .parse()
+                .map_err(|_| PathError::NoEntry(index.clone()))?;
+            list.get(index).ok_or(PathError::NoElement {
+                index,
+                len: list.len(),
+            })
+        }
+        (ReflectRef::Map(map), Segment::Index(key)) => (0..map.len())
+            .filter_map(|i| map.get_index(i))
+            .find(|(k, _)| key_matches(*k, key))
+            .map(|(_, v)| v)
+            .ok_or_else(|| PathError::NoEntry(key.clone())),
+        _ => Err(PathError::NotNested(type_name)),
+    }
+}
+
+/// Looks into the value with the segment, allowing to change the value
+/// found.
+fn step_mut<'a>(
+    value: ReflectMut<'a>,
+    segment: &Segment<'_>,
+    type_name: &'static str,
+) -> Result<&'a mut dyn Reflect, PathError> {
+    let no_field = |field: &str| PathError::NoField {
+        field: field.to_owned(),
+        type_name,
+    };
+    let read_only = || PathError::ReadOnly(segment.to_string());
+    let not_reflected = || PathError::NotReflected(segment.to_string());
+
+    match (value, segment) {
+        (ReflectMut::Struct(strukt), Segment::Field(name)) => {
+            let index = strukt
+                .info()
+                .fields
+                .iter()
+                .position(|f| f.name == *name)
+                .ok_or_else(|| no_field(name))?;
+            if strukt.field(index).is_none() {
+                return Err(not_reflected());
+            }
+            strukt.field_mut(index).ok_or_else(read_only)
+        }
+        (ReflectMut::Enum(enumm), Segment::Field(name)) => {
+            let index = enumm
+                .variant()
+                .fields
+                .iter()
+                .position(|f| f.name == *name)
+                .ok_or_else(|| no_field(name))?;
+            if enumm.field(index).is_none() {
+                return Err(not_reflected());
+            }
+            enumm.field_mut(index).ok_or_else(read_only)
+        }
+        (ReflectMut::List(list), Segment::Index(index)) => {
+            let index = index
+                .parse()
+                .map_err(|_| PathError::NoEntry(index.clone()))?;
+            let len = list.len();
+            if index >= len {
+                return Err(PathError::NoElement { index, len });
+            }
+            list.get_mut(index).ok_or_else(read_only)
+        }
+        (ReflectMut::Map(map), Segment::Index(key)) => {
+            let index = (0..map.len())
+                .find(|i| map.get_index(*i).is_some_and(|(k, _)| key_matches(k, key)))
+                .ok_or_else(|| PathError::NoEntry(key.clone()))?;
+            map.get_index_mut(index)
+                .map(|(_, v)| v)
+                .ok_or_else(|| PathError::NoEntry(key.clone()))
+        }
+        (ReflectMut::Opaque, _) => Err(read_only()),
+        _ => Err(PathError::NotNested(type_name)),
+    }
+}
+
+/// Resolves the path within the value.
+pub(crate) fn get<'a>(
+    value: ReflectRef<'a>,
+    type_name: &'static str,
+    path: &str,
+) -> Result<ReflectRef<'a>, PathError> {
+    let segments = parse(path)?;
+    let mut current = (value, type_name);
+    for segment in &segments {
+        let next = step(current.0, segment, current.1)?;
+        current = (next.reflect(), next.type_name());
+    }
+    Ok(current.0)
+}
+
+/// Resolves the path within the value, allowing to change the value
+/// found, which is returned along with the name of its type.
+pub(crate) fn get_mut<'a>(
+    value: ReflectMut<'a>,
+    type_name: &'static str,
+    path: &str,
+) -> Result<(ReflectMut<'a>, &'static str), PathError> {
+    let segments = parse(path)?;
+    let mut current = (value, type_name);
+    for segment in &segments {
+        let next = step_mut(current.0, segment, current.1)?;
+        let type_name = next.type_name();
+        current = (next.reflect_mut(), type_name);
+    }
+    Ok(current)
+}
+
+/// Replaces the value at the path with the dynamic one. The enums are
+/// set to the variant named by a string.
+pub(crate) fn set(
+    root: ReflectMut<'_>,
+    type_name: &'static str,
+    path: &str,
+    value: Value,
+) -> Result<(), PathError> {
+    let (target, type_name) = get_mut(root, type_name, path)?;
+    let invalid = |value| PathError::InvalidValue { value, type_name };
+
+    match target {
+        ReflectMut::Value(mut target) => target.set(value).map_err(invalid),
+        ReflectMut::Enum(enumm) => {
+            let Value::String(name) = &value else {
+                return Err(invalid(value));
+            };
+            let index = enumm
+                .info()
+                .variants
+                .iter()
+                .position(|v| v.name == name || v.title == name);
+            match index {
+                Some(index) if enumm.set_variant(index) => Ok(()),
+                Some(_) => Err(PathError::ReadOnly(name.clone())),
+                None => Err(invalid(value)),
+            }
+        }
+        ReflectMut::Opaque => Err(PathError::ReadOnly(type_name.to_owned())),
+        _ => Err(invalid(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{parse, PathError, Segment};
+
+    #[test]
+    fn parses_the_paths() {
+        assert_eq!(parse(""), Ok(vec![]));
+        assert_eq!(
+            parse("data.objects[3].position.x"),
+            Ok(vec![
+                Segment::Field("data"),
+                Segment::Field("objects"),
+                Segment::Index("3".to_owned()),
+                Segment::Field("position"),
+                Segment::Field("x"),
+            ])
+        );
+        assert_eq!(
+            parse("[\"a.b[c]\"][0].1"),
+            Ok(vec![
+                Segment::Index("a.b[c]".to_owned()),
+                Segment::Index("0".to_owned()),
+                Segment::Field("1"),
+            ])
+        );
+
+        assert_eq!(parse("a..b"), Err(PathError::Syntax(2)));
+        assert_eq!(parse("a[1"), Err(PathError::Syntax(2)));
+        assert_eq!(parse("a[]"), Err(PathError::Syntax(2)));
+        assert_eq!(parse("a[1]b"), Err(PathError::Syntax(4)));
+        assert_eq!(parse("a[\"b\"c]"), Err(PathError::Syntax(5)));
+    }
+}
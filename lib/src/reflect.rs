@@ -0,0 +1,1158 @@
+//! The backend-agnostic description of the values.
+//!
+//! Unlike the presentation traits, which render the values in a
+//! particular user interface, the [`Reflect`] trait only describes the
+//! structure of a value: the fields of the structs, the variants of the
+//! enums, the elements of the collections and the primitive values,
+//! along with the names, the documentation and the presentation
+//! attributes of the fields. This allows to walk and change any value
+//! without knowing its type, and so to build the new user interfaces
+//! and the other tooling without changing the derive macro:
+//!
+//! ```rust,ignore
+//! fn print(value: &dyn Reflect, depth: usize) {
+//!     match value.reflect() {
+//!         ReflectRef::Struct(s) => {
+//!             for (field, value) in s.info().fields.iter().zip(s.fields()) {
+//!                 println!("{:depth$}{}:", "", field.title);
+//!                 print(value, depth + 2);
+//!             }
+//!         }
+//!         ReflectRef::Value(v) => println!("{:depth$}{v}", ""),
+//!         _ => println!("{:depth$}...", ""),
+//!     }
+//! }
+//! ```
+//!
+//! The trait is implemented for the basic Rust types, the standard
+//! collections and the [`glam`] types, and for the structs and the
+//! enums deriving `ImguiPresentation`, from the same
+//! `#[imgui_presentation]` attributes as their presentations. The
+//! fields of the types not implementing [`Reflect`], like the trait
+//! objects, are left out of the views.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError, RwLock};
+
+use crate::path::{self, PathError};
+
+/// A value which describes its own structure.
+pub trait Reflect {
+    /// Returns the name of the type of the value.
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<Self>()
+    }
+
+    /// Returns the view of the value, allowing to look into it.
+    fn reflect(&self) -> ReflectRef<'_>;
+
+    /// Returns the view of the value, allowing to change it.
+    fn reflect_mut(&mut self) -> ReflectMut<'_>;
+
+    /// Returns the view of the value nested at the [path],
+    /// like `"objects[3].position.x"`. The empty path refers to the
+    /// value itself.
+    fn get_field(&self, path: &str) -> Result<ReflectRef<'_>, PathError> {
+        path::get(self.reflect(), self.type_name(), path)
+    }
+
+    /// Returns the view of the value nested at the [path],
+    /// allowing to change it.
+    fn get_field_mut(&mut self, path: &str) -> Result<ReflectMut<'_>, PathError> {
+        let type_name = self.type_name();
+        path::get_mut(self.reflect_mut(), type_name, path).map(|(value, _)| value)
+    }
+
+    /// Sets the primitive value nested at the [path],
+    /// converting the value provided into its type, or selects the
+    /// variant of the enum at the path by its name or title:
+    ///
+    /// ```rust,ignore
+    /// scene.set_field("objects[3].position.x", 1.5.into())?;
+    /// scene.set_field("objects[3].name", "Lamp".into())?;
+    /// scene.set_field("objects[3].visible", "true".into())?;
+    /// scene.set_field("objects[3].shape", "Sphere".into())?;
+    /// ```
+    fn set_field(&mut self, path: &str, value: Value) -> Result<(), PathError> {
+        let type_name = self.type_name();
+        path::set(self.reflect_mut(), type_name, path, value)
+    }
+}
+
+/// The view of a value, allowing to look into it.
+#[derive(Copy, Clone)]
+pub enum ReflectRef<'a> {
+    /// A struct with named or numbered fields.
+    Struct(&'a dyn Struct),
+    /// An enum, the current variant of which may carry data.
+    Enum(&'a dyn Enum),
+    /// A sequence of elements, like a [`Vec`], an array or a set.
+    List(&'a dyn List),
+    /// A collection of the key-value pairs.
+    Map(&'a dyn Map),
+    /// A primitive value.
+    Value(ValueRef<'a>),
+    /// A value which can't be looked into, like the one borrowed
+    /// elsewhere.
+    Opaque,
+}
+
+/// The view of a value, allowing to change it.
+pub enum ReflectMut<'a> {
+    /// A struct with named or numbered fields.
+    Struct(&'a mut dyn Struct),
+    /// An enum, the current variant of which may carry data.
+    Enum(&'a mut dyn Enum),
+    /// A sequence of elements, like a [`Vec`], an array or a set.
+    List(&'a mut dyn List),
+    /// A collection of the key-value pairs.
+    Map(&'a mut dyn Map),
+    /// A primitive value.
+    Value(ValueMut<'a>),
+    /// A value which can't be changed, like the one behind a shared
+    /// reference.
+    Opaque,
+}
+
+/// The presentation attributes of a field.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct FieldInfo {
+    /// The identifier of the field, or its order number for the tuple
+    /// structs.
+    pub name: &'static str,
+    /// The name of the field shown to the user, which is the
+    /// identifier unless the field is renamed.
+    pub title: &'static str,
+    /// The tooltip or the documentation of the field.
+    pub docs: Option<&'static str>,
+    /// Whether the field may only be looked at.
+    pub readonly: bool,
+    /// The display format of a scalar (printf syntax).
+    pub format: Option<&'static str>,
+    /// The text shown before a scalar.
+    pub prefix: Option<&'static str>,
+    /// The text shown after a scalar.
+    pub suffix: Option<&'static str>,
+    /// The speed of the change of a scalar when dragging.
+    pub speed: Option<f32>,
+}
+
+impl FieldInfo {
+    /// Creates the description of a field with no attributes.
+    pub const fn new(name: &'static str) -> Self {
+        Self {
+            name,
+            title: name,
+            docs: None,
+            readonly: false,
+            format: None,
+            prefix: None,
+            suffix: None,
+            speed: None,
+        }
+    }
+
+    /// Sets the name shown to the user.
+    pub const fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Sets the tooltip or the documentation.
+    pub const fn docs(mut self, docs: &'static str) -> Self {
+        self.docs = Some(docs);
+        self
+    }
+
+    /// Makes the field read-only.
+    pub const fn readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+
+    /// Sets the display format of a scalar.
+    pub const fn format(mut self, format: &'static str) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets the text shown before a scalar.
+    pub const fn prefix(mut self, prefix: &'static str) -> Self {
+        self.prefix = Some(prefix);
+        self
+    }
+
+    /// Sets the text shown after a scalar.
+    pub const fn suffix(mut self, suffix: &'static str) -> Self {
+        self.suffix = Some(suffix);
+        self
+    }
+
+    /// Sets the speed of the change of a scalar when dragging.
+    pub const fn speed(mut self, speed: f32) -> Self {
+        self.speed = Some(speed);
+        self
+    }
+}
+
+/// The description of a struct.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct StructInfo {
+    /// The identifier of the struct.
+    pub name: &'static str,
+    /// The name of the struct shown to the user.
+    pub title: &'static str,
+    /// The tooltip or the documentation of the struct.
+    pub docs: Option<&'static str>,
+    /// Whether the struct may only be looked at.
+    pub readonly: bool,
+    /// The fields of the struct, excluding the skipped ones.
+    pub fields: &'static [FieldInfo],
+}
+
+impl StructInfo {
+    /// Creates the description of a struct with the fields provided.
+    pub const fn new(name: &'static str, fields: &'static [FieldInfo]) -> Self {
+        Self {
+            name,
+            title: name,
+            docs: None,
+            readonly: false,
+            fields,
+        }
+    }
+
+    /// Sets the name shown to the user.
+    pub const fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Sets the tooltip or the documentation.
+    pub const fn docs(mut self, docs: &'static str) -> Self {
+        self.docs = Some(docs);
+        self
+    }
+
+    /// Makes the struct read-only.
+    pub const fn readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+}
+
+/// The description of a variant of an enum.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct VariantInfo {
+    /// The identifier of the variant.
+    pub name: &'static str,
+    /// The name of the variant shown to the user.
+    pub title: &'static str,
+    /// The tooltip or the documentation of the variant.
+    pub docs: Option<&'static str>,
+    /// The fields of the variant, excluding the skipped ones.
+    pub fields: &'static [FieldInfo],
+}
+
+impl VariantInfo {
+    /// Creates the description of a variant with the fields provided.
+    pub const fn new(name: &'static str, fields: &'static [FieldInfo]) -> Self {
+        Self {
+            name,
+            title: name,
+            docs: None,
+            fields,
+        }
+    }
+
+    /// Sets the name shown to the user.
+    pub const fn title(mut self, title: &'static str) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// Sets the tooltip or the documentation.
+    pub const fn docs(mut self, docs: &'static str) -> Self {
+        self.docs = Some(docs);
+        self
+    }
+}
+
+/// The description of an enum.
+#[derive(Debug, Copy, Clone, PartialEq)]
+#[non_exhaustive]
+pub struct EnumInfo {
+    /// The identifier of the enum.
+    pub name: &'static str,
+    /// The tooltip or the documentation of the enum.
+    pub docs: Option<&'static str>,
+    /// Whether the enum may only be looked at.
+    pub readonly: bool,
+    /// The variants of the enum.
+    pub variants: &'static [VariantInfo],
+}
+
+impl EnumInfo {
+    /// Creates the description of an enum with the variants provided.
+    pub const fn new(name: &'static str, variants: &'static [VariantInfo]) -> Self {
+        Self {
+            name,
+            docs: None,
+            readonly: false,
+            variants,
+        }
+    }
+
+    /// Sets the tooltip or the documentation.
+    pub const fn docs(mut self, docs: &'static str) -> Self {
+        self.docs = Some(docs);
+        self
+    }
+
+    /// Makes the enum read-only.
+    pub const fn readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+}
+
+/// A struct, the fields of which are accessed by their indices in
+/// [`StructInfo::fields`].
+pub trait Struct {
+    /// Returns the description of the struct.
+    fn info(&self) -> &'static StructInfo;
+
+    /// Returns the field with the index provided.
+    fn field(&self, index: usize) -> Option<&dyn Reflect>;
+
+    /// Returns the field with the index provided, allowing to change
+    /// it. Returns `None` for the read-only fields.
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect>;
+
+    /// Returns the range of the values the scalar field with the index
+    /// provided may have. Unlike the rest of the description, the range
+    /// may depend on the value, so it is evaluated on every call.
+    fn field_range(&self, index: usize) -> Option<(f64, f64)> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the field with the identifier provided.
+    fn field_by_name(&self, name: &str) -> Option<&dyn Reflect> {
+        let index = self.info().fields.iter().position(|f| f.name == name)?;
+        self.field(index)
+    }
+
+    /// Returns the field with the identifier provided, allowing to
+    /// change it.
+    fn field_by_name_mut(&mut self, name: &str) -> Option<&mut dyn Reflect> {
+        let index = self.info().fields.iter().position(|f| f.name == name)?;
+        self.field_mut(index)
+    }
+}
+
+impl<'a> dyn Struct + 'a {
+    /// Returns an iterator over the fields, in the order of
+    /// [`StructInfo::fields`], leaving out those which aren't reflected.
+    pub fn fields(&self) -> impl Iterator<Item = &dyn Reflect> + '_ {
+        (0..self.info().fields.len()).filter_map(|i| self.field(i))
+    }
+}
+
+/// An enum, the fields of the current variant of which are accessed by
+/// their indices in [`VariantInfo::fields`].
+pub trait Enum {
+    /// Returns the description of the enum.
+    fn info(&self) -> &'static EnumInfo;
+
+    /// Returns the index of the current variant in
+    /// [`EnumInfo::variants`].
+    fn variant_index(&self) -> usize;
+
+    /// Replaces the value with the variant with the index provided,
+    /// created the same way as when it is selected in the user
+    /// interface. The value is left as is if it already is the variant.
+    /// Returns `false` if the variant can't be created.
+    fn set_variant(&mut self, index: usize) -> bool;
+
+    /// Returns the field of the current variant with the index
+    /// provided.
+    fn field(&self, index: usize) -> Option<&dyn Reflect>;
+
+    /// Returns the field of the current variant with the index
+    /// provided, allowing to change it. Returns `None` for the
+    /// read-only fields.
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect>;
+
+    /// Returns the range of the values the scalar field of the current
+    /// variant with the index provided may have, see
+    /// [`Struct::field_range`].
+    fn field_range(&self, index: usize) -> Option<(f64, f64)> {
+        let _ = index;
+        None
+    }
+
+    /// Returns the description of the current variant.
+    fn variant(&self) -> &'static VariantInfo {
+        &self.info().variants[self.variant_index()]
+    }
+}
+
+/// A sequence of elements.
+pub trait List {
+    /// Returns the number of the elements.
+    fn len(&self) -> usize;
+
+    /// Returns the element with the index provided.
+    fn get(&self, index: usize) -> Option<&dyn Reflect>;
+
+    /// Returns the element with the index provided, allowing to change
+    /// it. The elements of the sets can't be changed, as that would
+    /// break the ordering or the hashing of those.
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect>;
+
+    /// Returns `true` if there are no elements.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// A collection of the key-value pairs, accessed by the indices in the
+/// iteration order of the collection.
+pub trait Map {
+    /// Returns the number of the entries.
+    fn len(&self) -> usize;
+
+    /// Returns the key and the value of the entry with the index
+    /// provided.
+    fn get_index(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)>;
+
+    /// Returns the key and the value of the entry with the index
+    /// provided, allowing to change the value.
+    fn get_index_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)>;
+
+    /// Returns `true` if there are no entries.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+macro_rules! define_values {
+    ($($variant:ident($value_type:ty)),+ $(,)?) => {
+        /// A primitive value.
+        #[derive(Debug, Copy, Clone, PartialEq)]
+        pub enum ValueRef<'a> {
+            $(
+                #[doc = concat!("A `", stringify!($value_type), "`.")]
+                $variant(&'a $value_type),
+            )+
+            /// A string.
+            String(&'a str),
+        }
+
+        /// A primitive value, which may be changed.
+        #[derive(Debug, PartialEq)]
+        pub enum ValueMut<'a> {
+            $(
+                #[doc = concat!("A `", stringify!($value_type), "`.")]
+                $variant(&'a mut $value_type),
+            )+
+            /// A string.
+            String(&'a mut String),
+        }
+
+        impl ValueMut<'_> {
+            /// Returns the view of the value, not allowing to change
+            /// it.
+            pub fn as_ref(&self) -> ValueRef<'_> {
+                match self {
+                    $(Self::$variant(value) => ValueRef::$variant(value),)+
+                    Self::String(value) => ValueRef::String(value),
+                }
+            }
+
+            /// Replaces the value with the dynamic one, converting it
+            /// to the type of this value. The strings are parsed, and
+            /// the numbers are only converted if they fit into the
+            /// type. Returns the dynamic value back if it can't be
+            /// converted.
+            pub fn set(&mut self, value: Value) -> Result<(), Value> {
+                match self {
+                    $(
+                        Self::$variant(target) => {
+                            **target = FromValue::from_value(&value).ok_or(value)?;
+                        }
+                    )+
+                    Self::String(target) => **target = value.to_string(),
+                }
+                Ok(())
+            }
+        }
+
+        impl From<ValueRef<'_>> for Value {
+            fn from(value: ValueRef<'_>) -> Self {
+                match value {
+                    $(ValueRef::$variant(value) => Self::from(*value),)+
+                    ValueRef::String(value) => Self::String(value.to_owned()),
+                }
+            }
+        }
+
+        impl std::fmt::Display for ValueRef<'_> {
+            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+                match self {
+                    $(Self::$variant(value) => value.fmt(f),)+
+                    Self::String(value) => value.fmt(f),
+                }
+            }
+        }
+
+        $(
+            impl Reflect for $value_type {
+                fn reflect(&self) -> ReflectRef<'_> {
+                    ReflectRef::Value(ValueRef::$variant(self))
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Value(ValueMut::$variant(self))
+                }
+            }
+        )+
+    };
+}
+
+/// An owned primitive value of any type, used to set the values of
+/// which the types aren't known, like the ones entered by the user
+/// into a console.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    /// A boolean.
+    Bool(bool),
+    /// An integer of any size.
+    Integer(i128),
+    /// A floating-point number.
+    Float(f64),
+    /// A string, which is parsed when a value of another type is set
+    /// with it.
+    String(String),
+}
+
+impl std::fmt::Display for Value {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Bool(value) => value.fmt(f),
+            Self::Integer(value) => value.fmt(f),
+            Self::Float(value) => value.fmt(f),
+            Self::String(value) => value.fmt(f),
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Self::Bool(value)
+    }
+}
+
+impl From<f32> for Value {
+    fn from(value: f32) -> Self {
+        Self::Float(f64::from(value))
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Self::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Self::String(value.to_owned())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Self::String(value)
+    }
+}
+
+/// The conversion of the dynamic values into the primitive ones.
+trait FromValue: Sized {
+    fn from_value(value: &Value) -> Option<Self>;
+}
+
+impl FromValue for bool {
+    fn from_value(value: &Value) -> Option<Self> {
+        match value {
+            Value::Bool(value) => Some(*value),
+            Value::String(value) => value.trim().parse().ok(),
+            _ => None,
+        }
+    }
+}
+
+macro_rules! define_value_for_integer {
+    ($($integer_type:ty)*) => {
+        $(
+            impl From<$integer_type> for Value {
+                fn from(value: $integer_type) -> Self {
+                    Self::Integer(value as i128)
+                }
+            }
+
+            impl FromValue for $integer_type {
+                fn from_value(value: &Value) -> Option<Self> {
+                    match value {
+                        Value::Integer(value) => Self::try_from(*value).ok(),
+                        Value::Float(value) if value.fract() == 0.0 => {
+                            Self::try_from(*value as i128).ok()
+                        }
+                        Value::String(value) => value.trim().parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_value_for_integer!(i8 u8 i16 u16 i32 u32 i64 u64 isize usize);
+
+macro_rules! define_value_for_float {
+    ($($float_type:ty)*) => {
+        $(
+            impl FromValue for $float_type {
+                fn from_value(value: &Value) -> Option<Self> {
+                    match value {
+                        Value::Integer(value) => Some(*value as $float_type),
+                        Value::Float(value) => Some(*value as $float_type),
+                        Value::String(value) => value.trim().parse().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_value_for_float!(f32 f64);
+
+define_values!(
+    Bool(bool),
+    I8(i8),
+    U8(u8),
+    I16(i16),
+    U16(u16),
+    I32(i32),
+    U32(u32),
+    I64(i64),
+    U64(u64),
+    Isize(isize),
+    Usize(usize),
+    F32(f32),
+    F64(f64),
+);
+
+impl Reflect for String {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Value(ValueRef::String(self))
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Value(ValueMut::String(self))
+    }
+}
+
+impl Reflect for str {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Value(ValueRef::String(self))
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque
+    }
+}
+
+/// A shared reference doesn't allow to change the value.
+impl<T: Reflect + ?Sized> Reflect for &T {
+    fn type_name(&self) -> &'static str {
+        (**self).type_name()
+    }
+
+    fn reflect(&self) -> ReflectRef<'_> {
+        (**self).reflect()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Opaque
+    }
+}
+
+macro_rules! define_for_pointer {
+    ($($pointer:ident)*) => {
+        $(
+            impl<T: Reflect + ?Sized> Reflect for $pointer<T> {
+                fn type_name(&self) -> &'static str {
+                    (**self).type_name()
+                }
+
+                fn reflect(&self) -> ReflectRef<'_> {
+                    (**self).reflect()
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    (**self).reflect_mut()
+                }
+            }
+        )*
+    };
+}
+
+define_for_pointer!(Box);
+
+impl<T: Reflect + ?Sized> Reflect for &mut T {
+    fn type_name(&self) -> &'static str {
+        (**self).type_name()
+    }
+
+    fn reflect(&self) -> ReflectRef<'_> {
+        (**self).reflect()
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        (**self).reflect_mut()
+    }
+}
+
+macro_rules! define_for_shared_pointer {
+    ($($pointer:ident)*) => {
+        $(
+            /// The value may only be changed when this is the only
+            /// pointer to it.
+            impl<T: Reflect + ?Sized> Reflect for $pointer<T> {
+                fn type_name(&self) -> &'static str {
+                    (**self).type_name()
+                }
+
+                fn reflect(&self) -> ReflectRef<'_> {
+                    (**self).reflect()
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    match $pointer::get_mut(self) {
+                        Some(value) => value.reflect_mut(),
+                        None => ReflectMut::Opaque,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+define_for_shared_pointer!(Rc Arc);
+
+/// The values with interior mutability may only be looked into through
+/// a unique reference, as the borrows and the locks can't outlive the
+/// views.
+impl<T: Reflect + Copy> Reflect for Cell<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.get_mut().reflect_mut()
+    }
+}
+
+impl<T: Reflect + ?Sized> Reflect for RefCell<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.get_mut().reflect_mut()
+    }
+}
+
+impl<T: Reflect + ?Sized> Reflect for Mutex<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .reflect_mut()
+    }
+}
+
+impl<T: Reflect + ?Sized> Reflect for RwLock<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Opaque
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        self.get_mut()
+            .unwrap_or_else(PoisonError::into_inner)
+            .reflect_mut()
+    }
+}
+
+impl<T: Reflect> Reflect for Vec<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<T: Reflect> List for Vec<T> {
+    fn len(&self) -> usize {
+        self.as_slice().len()
+    }
+
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice().get(index).map(|e| e as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.as_mut_slice()
+            .get_mut(index)
+            .map(|e| e as &mut dyn Reflect)
+    }
+}
+
+impl<T: Reflect, const N: usize> Reflect for [T; N] {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::List(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::List(self)
+    }
+}
+
+impl<T: Reflect, const N: usize> List for [T; N] {
+    fn len(&self) -> usize {
+        N
+    }
+
+    fn get(&self, index: usize) -> Option<&dyn Reflect> {
+        self.as_slice().get(index).map(|e| e as &dyn Reflect)
+    }
+
+    fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        self.as_mut_slice()
+            .get_mut(index)
+            .map(|e| e as &mut dyn Reflect)
+    }
+}
+
+macro_rules! define_for_tuple {
+    ($($element:ident $index:tt),+) => {
+        impl<$($element: Reflect),+> Reflect for ($($element,)+) {
+            fn reflect(&self) -> ReflectRef<'_> {
+                ReflectRef::List(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::List(self)
+            }
+        }
+
+        impl<$($element: Reflect),+> List for ($($element,)+) {
+            fn len(&self) -> usize {
+                [$($index),+].len()
+            }
+
+            fn get(&self, index: usize) -> Option<&dyn Reflect> {
+                match index {
+                    $($index => Some(&self.$index),)+
+                    _ => None,
+                }
+            }
+
+            fn get_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                match index {
+                    $($index => Some(&mut self.$index),)+
+                    _ => None,
+                }
+            }
+        }
+    };
+}
+
+define_for_tuple!(A 0);
+define_for_tuple!(A 0, B 1);
+define_for_tuple!(A 0, B 1, C 2);
+define_for_tuple!(A 0, B 1, C 2, D 3);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+macro_rules! define_for_set {
+    ($set:ident $(, $bound:path)?) => {
+        impl<T: Reflect $(+ $bound)?> Reflect for $set<T> {
+            fn reflect(&self) -> ReflectRef<'_> {
+                ReflectRef::List(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::List(self)
+            }
+        }
+
+        impl<T: Reflect $(+ $bound)?> List for $set<T> {
+            fn len(&self) -> usize {
+                self.iter().len()
+            }
+
+            fn get(&self, index: usize) -> Option<&dyn Reflect> {
+                self.iter().nth(index).map(|e| e as &dyn Reflect)
+            }
+
+            fn get_mut(&mut self, _index: usize) -> Option<&mut dyn Reflect> {
+                None
+            }
+        }
+    };
+}
+
+define_for_set!(BTreeSet);
+define_for_set!(HashSet);
+
+macro_rules! define_for_map {
+    ($map:ident) => {
+        impl<K: Reflect, V: Reflect> Reflect for $map<K, V> {
+            fn reflect(&self) -> ReflectRef<'_> {
+                ReflectRef::Map(self)
+            }
+
+            fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                ReflectMut::Map(self)
+            }
+        }
+
+        impl<K: Reflect, V: Reflect> Map for $map<K, V> {
+            fn len(&self) -> usize {
+                self.iter().len()
+            }
+
+            fn get_index(&self, index: usize) -> Option<(&dyn Reflect, &dyn Reflect)> {
+                self.iter()
+                    .nth(index)
+                    .map(|(k, v)| (k as &dyn Reflect, v as &dyn Reflect))
+            }
+
+            fn get_index_mut(&mut self, index: usize) -> Option<(&dyn Reflect, &mut dyn Reflect)> {
+                self.iter_mut()
+                    .nth(index)
+                    .map(|(k, v)| (k as &dyn Reflect, v as &mut dyn Reflect))
+            }
+        }
+    };
+}
+
+define_for_map!(BTreeMap);
+define_for_map!(HashMap);
+
+impl<T: Reflect + Default> Reflect for Option<T> {
+    fn reflect(&self) -> ReflectRef<'_> {
+        ReflectRef::Enum(self)
+    }
+
+    fn reflect_mut(&mut self) -> ReflectMut<'_> {
+        ReflectMut::Enum(self)
+    }
+}
+
+/// The value of the `Some` variant is created with the [`Default`]
+/// implementation, the same way as in the user interface.
+impl<T: Reflect + Default> Enum for Option<T> {
+    fn info(&self) -> &'static EnumInfo {
+        static INFO: EnumInfo = EnumInfo::new(
+            "Option",
+            &[
+                VariantInfo::new("None", &[]),
+                VariantInfo::new("Some", &[FieldInfo::new("0")]),
+            ],
+        );
+        &INFO
+    }
+
+    fn variant_index(&self) -> usize {
+        usize::from(self.is_some())
+    }
+
+    fn set_variant(&mut self, index: usize) -> bool {
+        match index {
+            0 => *self = None,
+            1 => {
+                let _ = self.get_or_insert_with(T::default);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    fn field(&self, index: usize) -> Option<&dyn Reflect> {
+        match index {
+            0 => self.as_ref().map(|v| v as &dyn Reflect),
+            _ => None,
+        }
+    }
+
+    fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+        match index {
+            0 => self.as_mut().map(|v| v as &mut dyn Reflect),
+            _ => None,
+        }
+    }
+}
+
+/// The specialisation by the auto-referencing, which picks the
+/// reflection of a field if its type implements [`Reflect`]. Used by
+/// the derive macros.
+#[doc(hidden)]
+pub mod probe {
+    use super::Reflect;
+    pub use crate::elements::probe::Probe;
+
+    pub trait ViaReflect<T> {
+        fn reflect_field<'a>(&self, value: &'a T) -> Option<&'a dyn Reflect>;
+        fn reflect_field_mut<'a>(&self, value: &'a mut T) -> Option<&'a mut dyn Reflect>;
+    }
+
+    impl<T: Reflect> ViaReflect<T> for &Probe<T> {
+        fn reflect_field<'a>(&self, value: &'a T) -> Option<&'a dyn Reflect> {
+            Some(value)
+        }
+
+        fn reflect_field_mut<'a>(&self, value: &'a mut T) -> Option<&'a mut dyn Reflect> {
+            Some(value)
+        }
+    }
+
+    pub trait Unsupported<T> {
+        fn reflect_field<'a>(&self, value: &'a T) -> Option<&'a dyn Reflect>;
+        fn reflect_field_mut<'a>(&self, value: &'a mut T) -> Option<&'a mut dyn Reflect>;
+    }
+
+    impl<T> Unsupported<T> for Probe<T> {
+        fn reflect_field<'a>(&self, _: &'a T) -> Option<&'a dyn Reflect> {
+            None
+        }
+
+        fn reflect_field_mut<'a>(&self, _: &'a mut T) -> Option<&'a mut dyn Reflect> {
+            None
+        }
+    }
+}
+
+/// Returns the value of the type as a `&dyn Reflect` if the type
+/// implements [`Reflect`]. The implementations are only found for the
+/// concrete types and the generic parameters bound by [`Reflect`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! reflect_nested {
+    ($type: ty, $value: expr) => {{
+        #[allow(unused_imports)]
+        use $crate::reflect::probe::{Probe, Unsupported, ViaReflect};
+        (&&Probe::<$type>::new()).reflect_field($value)
+    }};
+}
+
+/// Returns the value of the type as a `&mut dyn Reflect` if the type
+/// implements [`Reflect`], see [`reflect_nested`].
+#[doc(hidden)]
+#[macro_export]
+macro_rules! reflect_nested_mut {
+    ($type: ty, $value: expr) => {{
+        #[allow(unused_imports)]
+        use $crate::reflect::probe::{Probe, Unsupported, ViaReflect};
+        (&&Probe::<$type>::new()).reflect_field_mut($value)
+    }};
+}
+
+#[cfg(feature = "glam")]
+mod glam_types {
+    use super::*;
+
+    macro_rules! define_for_glam_struct {
+        ($glam_type:ty, $($field:ident)+) => {
+            impl Reflect for $glam_type {
+                fn reflect(&self) -> ReflectRef<'_> {
+                    ReflectRef::Struct(self)
+                }
+
+                fn reflect_mut(&mut self) -> ReflectMut<'_> {
+                    ReflectMut::Struct(self)
+                }
+            }
+
+            impl Struct for $glam_type {
+                fn info(&self) -> &'static StructInfo {
+                    static INFO: StructInfo = StructInfo::new(
+                        stringify!($glam_type),
+                        &[$(FieldInfo::new(stringify!($field)),)+],
+                    );
+                    &INFO
+                }
+
+                fn field(&self, index: usize) -> Option<&dyn Reflect> {
+                    match self.info().fields.get(index)?.name {
+                        $(stringify!($field) => Some(&self.$field),)+
+                        _ => None,
+                    }
+                }
+
+                fn field_mut(&mut self, index: usize) -> Option<&mut dyn Reflect> {
+                    match self.info().fields.get(index)?.name {
+                        $(stringify!($field) => Some(&mut self.$field),)+
+                        _ => None,
+                    }
+                }
+            }
+        };
+    }
+
+    define_for_glam_struct!(glam::Vec2, x y);
+    define_for_glam_struct!(glam::Vec3, x y z);
+    define_for_glam_struct!(glam::Vec3A, x y z);
+    define_for_glam_struct!(glam::Vec4, x y z w);
+    define_for_glam_struct!(glam::DVec2, x y);
+    define_for_glam_struct!(glam::DVec3, x y z);
+    define_for_glam_struct!(glam::DVec4, x y z w);
+    define_for_glam_struct!(glam::IVec2, x y);
+    define_for_glam_struct!(glam::IVec3, x y z);
+    define_for_glam_struct!(glam::IVec4, x y z w);
+    define_for_glam_struct!(glam::UVec2, x y);
+    define_for_glam_struct!(glam::UVec3, x y z);
+    define_for_glam_struct!(glam::UVec4, x y z w);
+    define_for_glam_struct!(glam::BVec2, x y);
+    define_for_glam_struct!(glam::BVec3, x y z);
+    define_for_glam_struct!(glam::BVec4, x y z w);
+    define_for_glam_struct!(glam::Mat2, x_axis y_axis);
+    define_for_glam_struct!(glam::Mat3, x_axis y_axis z_axis);
+    define_for_glam_struct!(glam::Mat3A, x_axis y_axis z_axis);
+    define_for_glam_struct!(glam::Mat4, x_axis y_axis z_axis w_axis);
+    define_for_glam_struct!(glam::DMat3, x_axis y_axis z_axis);
+    define_for_glam_struct!(glam::Quat, x y z w);
+    define_for_glam_struct!(glam::DQuat, x y z w);
+    define_for_glam_struct!(glam::Affine3A, matrix3 translation);
+    define_for_glam_struct!(glam::DAffine3, matrix3 translation);
+}
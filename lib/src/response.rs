@@ -0,0 +1,149 @@
+//! The report of what has happened to a value during a single frame of
+//! its mutable presentation.
+
+/// Describes what the user did to a value while it was rendered
+/// using the `render_component_mut` or `render_window_mut` methods of
+/// the [`crate::ImguiPresentable`] or the [`crate::EguiPresentable`]
+/// traits.
+///
+/// The responses of the nested values are combined using the `|`
+/// operator, so that the response of a struct tells whether any of its
+/// fields was changed.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct EditResponse {
+    /// The value was changed during this frame.
+    pub changed: bool,
+    /// An edit of the value has been finished during this frame. For
+    /// the continuous edits (like dragging a value or typing text),
+    /// this is only set once the user has released the widget, so
+    /// it is the right moment to, for example, push an undo step.
+    pub edit_finished: bool,
+    /// A widget presenting the value is being interacted with.
+    pub active: bool,
+    /// A button of the value, like the one calling a method of it, was
+    /// clicked during this frame. The method may or may not have
+    /// changed the value, so it isn't reported as changed.
+    pub clicked: bool,
+}
+
+impl EditResponse {
+    /// Creates a response of an immediate, one-shot edit, like pushing
+    /// a button or removing an element from a collection.
+    pub fn changed(changed: bool) -> Self {
+        Self {
+            changed,
+            edit_finished: changed,
+            active: false,
+            clicked: false,
+        }
+    }
+
+    /// Creates a response of clicking a button of the value, like the
+    /// one calling a method of it.
+    pub fn clicked(clicked: bool) -> Self {
+        Self {
+            clicked,
+            ..Self::default()
+        }
+    }
+
+    /// Returns `true` if anything has happened to the value.
+    pub fn any(&self) -> bool {
+        self.changed || self.edit_finished || self.active || self.clicked
+    }
+
+    /// Creates the response from the state of the last ImGui item
+    /// submitted, given whether the widget reported a change.
+    #[cfg(feature = "imgui_backend")]
+    pub fn from_last_imgui_item(ui: &imgui::Ui, changed: bool) -> Self {
+        let active = ui.is_item_active();
+        Self {
+            changed,
+            edit_finished: ui.is_item_deactivated_after_edit() || (changed && !active),
+            active,
+            clicked: false,
+        }
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+impl From<&egui::Response> for EditResponse {
+    fn from(response: &egui::Response) -> Self {
+        let changed = response.changed();
+        let active = response.dragged() || response.has_focus();
+        Self {
+            changed,
+            edit_finished: response.drag_stopped() || response.lost_focus() || (changed && !active),
+            active,
+            clicked: false,
+        }
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+impl From<egui::Response> for EditResponse {
+    fn from(response: egui::Response) -> Self {
+        Self::from(&response)
+    }
+}
+
+impl std::ops::BitOr for EditResponse {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        Self {
+            changed: self.changed || rhs.changed,
+            edit_finished: self.edit_finished || rhs.edit_finished,
+            active: self.active || rhs.active,
+            clicked: self.clicked || rhs.clicked,
+        }
+    }
+}
+
+impl std::ops::BitOrAssign for EditResponse {
+    fn bitor_assign(&mut self, rhs: Self) {
+        *self = *self | rhs;
+    }
+}
+
+impl std::iter::FromIterator<EditResponse> for EditResponse {
+    fn from_iter<I: IntoIterator<Item = EditResponse>>(iter: I) -> Self {
+        iter.into_iter().fold(Self::default(), |a, b| a | b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::EditResponse;
+
+    #[test]
+    fn responses_are_combined() {
+        let untouched = EditResponse::default();
+        let dragging = EditResponse {
+            changed: true,
+            edit_finished: false,
+            active: true,
+            clicked: false,
+        };
+        let removed = EditResponse::changed(true);
+        let clicked = EditResponse::clicked(true);
+
+        assert!(!untouched.any());
+        assert_eq!(untouched | untouched, untouched);
+        assert_eq!(
+            dragging | removed,
+            EditResponse {
+                changed: true,
+                edit_finished: true,
+                active: true,
+                clicked: false,
+            }
+        );
+        assert_eq!(
+            [untouched, removed].into_iter().collect::<EditResponse>(),
+            removed
+        );
+        assert!(clicked.any() && !clicked.changed);
+        assert!((removed | clicked).clicked);
+    }
+}
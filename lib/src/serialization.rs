@@ -0,0 +1,787 @@
+//! The copying, the pasting and the presets of the values, as JSON or
+//! RON, enabled by the `serde` feature.
+//!
+//! Every field of a derived presentation has a context menu copying its
+//! value to the clipboard as JSON or RON, and, in the mutable
+//! presentations, pasting a value copied before. The windows of the
+//! derived types have the "Save preset…" and "Load preset…" buttons,
+//! which save the whole value to a file, as JSON if the file has the
+//! `.json` extension and as RON otherwise, and load it back.
+//!
+//! The values are only copied if their types implement
+//! [`serde::Serialize`], and pasted or loaded if those implement
+//! [`serde::de::DeserializeOwned`]. As with the [`crate::elements`]
+//! actions, the implementations are only found for the concrete types,
+//! not for the generic parameters. The pasted text may be either JSON
+//! or RON.
+//!
+//! ImGui uses the clipboard of its platform backend. As egui can't read
+//! the clipboard, the values are pasted from the text last copied by
+//! the presentations or pasted into the application with the system
+//! shortcut. The ratatui backend has no clipboard.
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+use std::cell::RefCell;
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+use std::collections::HashMap;
+use std::path::Path;
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+
+/// The text formats the values are copied and saved in.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum Format {
+    /// The JSON, written indented.
+    Json,
+    /// The Rusty Object Notation, written indented.
+    Ron,
+}
+
+impl Format {
+    /// All the formats, in the order those are offered.
+    pub const ALL: [Self; 2] = [Self::Json, Self::Ron];
+
+    /// Returns the name of the format, like "JSON".
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Json => "JSON",
+            Self::Ron => "RON",
+        }
+    }
+
+    /// Returns the format of the file, which is JSON if the file has
+    /// the `.json` extension and RON otherwise.
+    pub fn of_path(path: &Path) -> Self {
+        match path.extension() {
+            Some(extension) if extension.eq_ignore_ascii_case("json") => Self::Json,
+            _ => Self::Ron,
+        }
+    }
+}
+
+/// The reason a value couldn't be copied, pasted, saved or loaded.
+#[derive(Debug)]
+pub enum SerializationError {
+    /// The value couldn't be converted to or from JSON.
+    Json(serde_json::Error),
+    /// The value couldn't be converted to RON.
+    Ron(ron::Error),
+    /// The text is neither JSON nor RON describing a value of the type.
+    /// Contains the error of the RON parser.
+    Syntax(ron::error::SpannedError),
+    /// The preset file couldn't be read or written.
+    Io(std::io::Error),
+}
+
+impl std::fmt::Display for SerializationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Json(error) => write!(f, "JSON: {error}."),
+            Self::Ron(error) => write!(f, "RON: {error}."),
+            Self::Syntax(error) => write!(f, "Neither JSON nor RON: {error}."),
+            Self::Io(error) => write!(f, "{error}."),
+        }
+    }
+}
+
+impl std::error::Error for SerializationError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::Json(error) => Some(error),
+            Self::Ron(error) => Some(error),
+            Self::Syntax(error) => Some(error),
+            Self::Io(error) => Some(error),
+        }
+    }
+}
+
+impl From<std::io::Error> for SerializationError {
+    fn from(error: std::io::Error) -> Self {
+        Self::Io(error)
+    }
+}
+
+/// Returns the text of the value in the format.
+pub fn to_string<T: Serialize + ?Sized>(
+    value: &T,
+    format: Format,
+) -> Result<String, SerializationError> {
+    match format {
+        Format::Json => serde_json::to_string_pretty(value).map_err(SerializationError::Json),
+        Format::Ron => ron::ser::to_string_pretty(value, ron::ser::PrettyConfig::default())
+            .map_err(SerializationError::Ron),
+    }
+}
+
+/// Parses the value from its text, which may be either JSON or RON.
+/// The JSON error is reported if the text is JSON of another value,
+/// and the RON one otherwise.
+pub fn from_str<T: DeserializeOwned>(text: &str) -> Result<T, SerializationError> {
+    let json = match serde_json::from_str(text) {
+        Ok(value) => return Ok(value),
+        Err(error) => error,
+    };
+    ron::from_str(text).map_err(|ron| {
+        if json.is_data() {
+            SerializationError::Json(json)
+        } else {
+            SerializationError::Syntax(ron)
+        }
+    })
+}
+
+/// Saves the value to the file, as JSON if the file has the `.json`
+/// extension and as RON otherwise.
+pub fn save_preset<T: Serialize + ?Sized>(
+    value: &T,
+    path: impl AsRef<Path>,
+) -> Result<(), SerializationError> {
+    let path = path.as_ref();
+    std::fs::write(path, to_string(value, Format::of_path(path))?)?;
+    Ok(())
+}
+
+/// Loads the value from the file, which may be either JSON or RON.
+pub fn load_preset<T: DeserializeOwned>(path: impl AsRef<Path>) -> Result<T, SerializationError> {
+    from_str(&std::fs::read_to_string(path)?)
+}
+
+/// Returns the text of a value in the format.
+pub type SerializeFn<T> = fn(&T, Format) -> Result<String, SerializationError>;
+
+/// Parses a value from JSON or RON.
+pub type DeserializeFn<T> = fn(&str) -> Result<T, SerializationError>;
+
+/// What may be done to the values of a type in its presentations, as
+/// far as the type implements the serde traits.
+pub struct SerdeActions<T> {
+    /// Returns the text of a value in the format, to copy or save it.
+    pub serialize: Option<SerializeFn<T>>,
+    /// Parses a value from JSON or RON, to paste or load it.
+    pub deserialize: Option<DeserializeFn<T>>,
+}
+
+impl<T> SerdeActions<T> {
+    /// Returns the actions which neither copy nor paste the values.
+    pub const fn new() -> Self {
+        Self {
+            serialize: None,
+            deserialize: None,
+        }
+    }
+
+    /// Allows to copy and save the values.
+    pub fn with_serialize(self) -> Self
+    where
+        T: Serialize,
+    {
+        Self {
+            serialize: Some(to_string::<T>),
+            ..self
+        }
+    }
+
+    /// Allows to paste and load the values.
+    pub fn with_deserialize(self) -> Self
+    where
+        T: DeserializeOwned,
+    {
+        Self {
+            deserialize: Some(from_str::<T>),
+            ..self
+        }
+    }
+
+    /// Returns `true` if the values may neither be copied nor pasted.
+    pub fn is_empty(&self) -> bool {
+        self.serialize.is_none() && self.deserialize.is_none()
+    }
+}
+
+impl<T> Default for SerdeActions<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Clone for SerdeActions<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for SerdeActions<T> {}
+
+impl<T> std::fmt::Debug for SerdeActions<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SerdeActions")
+            .field("serialize", &self.serialize.is_some())
+            .field("deserialize", &self.deserialize.is_some())
+            .finish()
+    }
+}
+
+/// Picks the serde actions for a concrete type at compile time, the
+/// same way as [`crate::elements::probe`] does. Used by the derive
+/// macros.
+#[doc(hidden)]
+pub mod probe {
+    use serde::de::DeserializeOwned;
+    use serde::Serialize;
+
+    use super::{DeserializeFn, SerializeFn};
+    pub use crate::elements::probe::Probe;
+
+    pub trait ViaSerialize<T> {
+        fn serialize(&self) -> Option<SerializeFn<T>>;
+    }
+
+    impl<T: Serialize> ViaSerialize<T> for &Probe<T> {
+        fn serialize(&self) -> Option<SerializeFn<T>> {
+            Some(super::to_string::<T>)
+        }
+    }
+
+    pub trait ViaDeserialize<T> {
+        fn deserialize(&self) -> Option<DeserializeFn<T>>;
+    }
+
+    impl<T: DeserializeOwned> ViaDeserialize<T> for &Probe<T> {
+        fn deserialize(&self) -> Option<DeserializeFn<T>> {
+            Some(super::from_str::<T>)
+        }
+    }
+
+    pub trait Unsupported<T> {
+        fn serialize(&self) -> Option<SerializeFn<T>>;
+        fn deserialize(&self) -> Option<DeserializeFn<T>>;
+    }
+
+    impl<T> Unsupported<T> for Probe<T> {
+        fn serialize(&self) -> Option<SerializeFn<T>> {
+            None
+        }
+
+        fn deserialize(&self) -> Option<DeserializeFn<T>> {
+            None
+        }
+    }
+}
+
+/// Returns the [`SerdeActions`] of a type, allowing to copy the values
+/// if the type implements [`serde::Serialize`] and to paste those if it
+/// implements [`serde::de::DeserializeOwned`]. The implementations are
+/// only found for the concrete types, not for the generic parameters.
+///
+/// The probing ignores the lifetimes, so the types borrowing the data,
+/// which never implement [`serde::de::DeserializeOwned`], are probed
+/// with `borrowed` and are only copied.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! serde_actions {
+    ($type: ty, borrowed) => {{
+        #[allow(unused_imports)]
+        use $crate::serialization::probe::{Probe, Unsupported, ViaSerialize};
+        $crate::serialization::SerdeActions::<$type> {
+            serialize: (&&Probe::<$type>::new()).serialize(),
+            deserialize: None,
+        }
+    }};
+    ($type: ty) => {{
+        #[allow(unused_imports)]
+        use $crate::serialization::probe::{Probe, Unsupported, ViaDeserialize, ViaSerialize};
+        $crate::serialization::SerdeActions::<$type> {
+            serialize: (&&Probe::<$type>::new()).serialize(),
+            deserialize: (&&Probe::<$type>::new()).deserialize(),
+        }
+    }};
+}
+
+/// The title of the menu item pasting the value.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+const PASTE: &str = "Paste";
+
+/// Returns the title of the menu item copying the value in the format.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+fn copy_title(format: Format) -> String {
+    format!("Copy as {}", format.name())
+}
+
+thread_local! {
+    /// The text last copied or pasted into an egui application, which
+    /// is what the values are pasted from, as egui can't read the
+    /// clipboard.
+    #[cfg(feature = "egui_backend")]
+    static EGUI_CLIPBOARD: RefCell<Option<String>> = const { RefCell::new(None) };
+
+    /// The dialogs of the presets being saved or loaded, identified by
+    /// the addresses of the values.
+    #[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+    static PRESET_DIALOGS: RefCell<HashMap<usize, PresetDialog>> = RefCell::default();
+}
+
+/// Whether a preset is being saved or loaded.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum PresetAction {
+    Save,
+    Load,
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl PresetAction {
+    /// Returns the title of the button confirming the action.
+    fn title(self) -> &'static str {
+        match self {
+            Self::Save => "Save",
+            Self::Load => "Load",
+        }
+    }
+}
+
+/// The state of the preset dialog of a window, kept between the
+/// frames. The path is kept after the dialog is closed, so that the
+/// same preset may be saved or loaded again.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+#[derive(Debug, Default)]
+struct PresetDialog {
+    /// What is being done, if the dialog is open.
+    action: Option<PresetAction>,
+    /// The path of the preset file.
+    path: String,
+    /// Why the last action couldn't be done.
+    error: Option<String>,
+}
+
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+impl PresetDialog {
+    /// Takes the dialog of the value out of the storage, so that the
+    /// windows rendered within the value's one may use theirs.
+    fn take(value: usize) -> Self {
+        PRESET_DIALOGS
+            .with(|dialogs| dialogs.borrow_mut().remove(&value))
+            .unwrap_or_default()
+    }
+
+    /// Puts the dialog back into the storage.
+    fn store(self, value: usize) {
+        PRESET_DIALOGS.with(|dialogs| {
+            let _ = dialogs.borrow_mut().insert(value, self);
+        });
+    }
+
+    /// Opens the dialog for the action, or closes it if it is already
+    /// open for it.
+    fn toggle(&mut self, action: PresetAction) {
+        self.action = (self.action != Some(action)).then_some(action);
+        self.error = None;
+    }
+
+    /// Does the action of the dialog, returning the value loaded, if
+    /// it is loaded. The dialog is closed unless the action fails.
+    fn confirm<T>(&mut self, value: &T, actions: SerdeActions<T>) -> Option<T> {
+        let path = Path::new(self.path.trim());
+        let result = match (self.action, actions.serialize, actions.deserialize) {
+            (Some(PresetAction::Save), Some(serialize), _) => {
+                serialize(value, Format::of_path(path))
+                    .and_then(|text| Ok(std::fs::write(path, text)?))
+                    .map(|()| None)
+            }
+            (Some(PresetAction::Load), _, Some(deserialize)) => std::fs::read_to_string(path)
+                .map_err(SerializationError::from)
+                .and_then(|text| deserialize(&text))
+                .map(Some),
+            _ => Ok(None),
+        };
+
+        match result {
+            Ok(loaded) => {
+                self.action = None;
+                self.error = None;
+                loaded
+            }
+            Err(error) => {
+                self.error = Some(error.to_string());
+                None
+            }
+        }
+    }
+}
+
+/// Returns the address identifying the value.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+fn address_of<T>(value: &T) -> usize {
+    std::ptr::from_ref(value) as usize
+}
+
+/// Adds the context menu of the last item, copying the value, if its
+/// type implements [`serde::Serialize`].
+#[cfg(feature = "imgui_backend")]
+pub fn context_menu_imgui<T>(ui: &imgui::Ui, value: &T, actions: SerdeActions<T>) {
+    let _ = menu_imgui(
+        ui,
+        value,
+        SerdeActions {
+            deserialize: None,
+            ..actions
+        },
+    );
+}
+
+/// Adds the context menu of the last item, copying the value and
+/// pasting another one, as far as its type implements the serde
+/// traits. Returns what the user has done to the value.
+#[cfg(feature = "imgui_backend")]
+pub fn context_menu_imgui_mut<T>(
+    ui: &imgui::Ui,
+    value: &mut T,
+    actions: SerdeActions<T>,
+) -> crate::EditResponse {
+    match menu_imgui(ui, value, actions) {
+        Some(pasted) => {
+            *value = pasted;
+            crate::EditResponse::changed(true)
+        }
+        None => crate::EditResponse::default(),
+    }
+}
+
+/// Adds the context menu of the last item, returning the value pasted.
+/// The menu items which can't be used are disabled, telling why once
+/// hovered.
+#[cfg(feature = "imgui_backend")]
+fn menu_imgui<T>(ui: &imgui::Ui, value: &T, actions: SerdeActions<T>) -> Option<T> {
+    if actions.is_empty() {
+        return None;
+    }
+    let _popup = ui.begin_popup_context_item()?;
+
+    let explain = |error: &SerializationError| {
+        if ui.is_item_hovered_with_flags(imgui::ItemHoveredFlags::ALLOW_WHEN_DISABLED) {
+            ui.tooltip_text(error.to_string());
+        }
+    };
+
+    if let Some(serialize) = actions.serialize {
+        for format in Format::ALL {
+            let text = serialize(value, format);
+            let clicked = ui
+                .menu_item_config(copy_title(format))
+                .enabled(text.is_ok())
+                .build();
+            match text {
+                Ok(text) if clicked => ui.set_clipboard_text(text),
+                Ok(_) => {}
+                Err(error) => explain(&error),
+            }
+        }
+    }
+
+    let deserialize = actions.deserialize?;
+    let pasted = ui.clipboard_text().map(|text| deserialize(&text));
+    let clicked = ui
+        .menu_item_config(PASTE)
+        .enabled(matches!(pasted, Some(Ok(_))))
+        .build();
+    match pasted? {
+        Ok(pasted) if clicked => Some(pasted),
+        Ok(_) => None,
+        Err(error) => {
+            explain(&error);
+            None
+        }
+    }
+}
+
+/// Adds the "Save preset…" button of a window, if the type of the value
+/// implements [`serde::Serialize`].
+#[cfg(feature = "imgui_backend")]
+pub fn presets_imgui<T>(ui: &imgui::Ui, value: &T, actions: SerdeActions<T>) {
+    let _ = preset_dialog_imgui(
+        ui,
+        value,
+        SerdeActions {
+            deserialize: None,
+            ..actions
+        },
+    );
+}
+
+/// Adds the "Save preset…" and the "Load preset…" buttons of a window,
+/// as far as the type of the value implements the serde traits.
+/// Returns what the user has done to the value.
+#[cfg(feature = "imgui_backend")]
+pub fn presets_imgui_mut<T>(
+    ui: &imgui::Ui,
+    value: &mut T,
+    actions: SerdeActions<T>,
+) -> crate::EditResponse {
+    match preset_dialog_imgui(ui, value, actions) {
+        Some(loaded) => {
+            *value = loaded;
+            crate::EditResponse::changed(true)
+        }
+        None => crate::EditResponse::default(),
+    }
+}
+
+/// Adds the preset buttons and, once one is pushed, the path of the
+/// preset file with the button confirming the action below those.
+/// Returns the value loaded.
+#[cfg(feature = "imgui_backend")]
+fn preset_dialog_imgui<T>(ui: &imgui::Ui, value: &T, actions: SerdeActions<T>) -> Option<T> {
+    if actions.is_empty() {
+        return None;
+    }
+    let address = address_of(value);
+    let _id = ui.push_id(format!("presets##{address:x}"));
+    let mut dialog = PresetDialog::take(address);
+
+    if actions.serialize.is_some() && ui.button("Save preset…") {
+        dialog.toggle(PresetAction::Save);
+    }
+    if actions.deserialize.is_some() {
+        if actions.serialize.is_some() {
+            ui.same_line();
+        }
+        if ui.button("Load preset…") {
+            dialog.toggle(PresetAction::Load);
+        }
+    }
+
+    let mut loaded = None;
+    if let Some(action) = dialog.action {
+        ui.text("Preset file:");
+        ui.same_line();
+        let _ = ui.input_text("##path", &mut dialog.path).build();
+        ui.same_line();
+        if ui.button(action.title()) {
+            loaded = dialog.confirm(value, actions);
+        }
+        ui.same_line();
+        if ui.button("Cancel") {
+            dialog.action = None;
+            dialog.error = None;
+        }
+        if let Some(error) = &dialog.error {
+            ui.text_colored([1.0, 0.3, 0.3, 1.0], error);
+        }
+    }
+
+    dialog.store(address);
+    loaded
+}
+
+/// Remembers the text pasted into the application during this frame,
+/// to paste the values from it.
+#[cfg(feature = "egui_backend")]
+fn remember_pasted_egui(context: &egui::Context) {
+    let pasted = context.input(|input| {
+        input.events.iter().rev().find_map(|event| match event {
+            egui::Event::Paste(text) => Some(text.clone()),
+            _ => None,
+        })
+    });
+    if let Some(text) = pasted {
+        EGUI_CLIPBOARD.with(|clipboard| *clipboard.borrow_mut() = Some(text));
+    }
+}
+
+/// Adds the context menu of the widget, copying the value, if its type
+/// implements [`serde::Serialize`].
+#[cfg(feature = "egui_backend")]
+pub fn context_menu_egui<T>(response: &egui::Response, value: &T, actions: SerdeActions<T>) {
+    let _ = menu_egui(
+        response,
+        value,
+        SerdeActions {
+            deserialize: None,
+            ..actions
+        },
+    );
+}
+
+/// Adds the context menu of the widget, copying the value and pasting
+/// another one, as far as its type implements the serde traits.
+/// Returns what the user has done to the value.
+#[cfg(feature = "egui_backend")]
+pub fn context_menu_egui_mut<T>(
+    response: &egui::Response,
+    value: &mut T,
+    actions: SerdeActions<T>,
+) -> crate::EditResponse {
+    match menu_egui(response, value, actions) {
+        Some(pasted) => {
+            *value = pasted;
+            crate::EditResponse::changed(true)
+        }
+        None => crate::EditResponse::default(),
+    }
+}
+
+/// Adds the context menu of the widget, returning the value pasted. The
+/// menu items which can't be used are disabled, telling why once
+/// hovered.
+#[cfg(feature = "egui_backend")]
+fn menu_egui<T>(response: &egui::Response, value: &T, actions: SerdeActions<T>) -> Option<T> {
+    remember_pasted_egui(&response.ctx);
+    if actions.is_empty() {
+        return None;
+    }
+
+    let mut pasted = None;
+    let _ = response.context_menu(|ui| {
+        if let Some(serialize) = actions.serialize {
+            for format in Format::ALL {
+                let text = serialize(value, format);
+                let item = ui.add_enabled(text.is_ok(), egui::Button::new(copy_title(format)));
+                match text {
+                    Ok(text) if item.clicked() => {
+                        ui.ctx().copy_text(text.clone());
+                        EGUI_CLIPBOARD.with(|clipboard| *clipboard.borrow_mut() = Some(text));
+                        ui.close();
+                    }
+                    Ok(_) => {}
+                    Err(error) => {
+                        let _ = item.on_disabled_hover_text(error.to_string());
+                    }
+                }
+            }
+        }
+
+        let Some(deserialize) = actions.deserialize else {
+            return;
+        };
+        let parsed =
+            EGUI_CLIPBOARD.with(|clipboard| clipboard.borrow().as_deref().map(deserialize));
+        let item = ui.add_enabled(matches!(parsed, Some(Ok(_))), egui::Button::new(PASTE));
+        match parsed {
+            Some(Ok(value)) if item.clicked() => {
+                pasted = Some(value);
+                ui.close();
+            }
+            Some(Err(error)) => {
+                let _ = item.on_disabled_hover_text(error.to_string());
+            }
+            None => {
+                let _ = item.on_disabled_hover_text("Nothing has been copied yet.");
+            }
+            Some(Ok(_)) => {}
+        }
+    });
+    pasted
+}
+
+/// Adds the "Save preset…" button of a window, if the type of the value
+/// implements [`serde::Serialize`].
+#[cfg(feature = "egui_backend")]
+pub fn presets_egui<T>(ui: &mut egui::Ui, value: &T, actions: SerdeActions<T>) {
+    let _ = preset_dialog_egui(
+        ui,
+        value,
+        SerdeActions {
+            deserialize: None,
+            ..actions
+        },
+    );
+}
+
+/// Adds the "Save preset…" and the "Load preset…" buttons of a window,
+/// as far as the type of the value implements the serde traits.
+/// Returns what the user has done to the value.
+#[cfg(feature = "egui_backend")]
+pub fn presets_egui_mut<T>(
+    ui: &mut egui::Ui,
+    value: &mut T,
+    actions: SerdeActions<T>,
+) -> crate::EditResponse {
+    match preset_dialog_egui(ui, value, actions) {
+        Some(loaded) => {
+            *value = loaded;
+            crate::EditResponse::changed(true)
+        }
+        None => crate::EditResponse::default(),
+    }
+}
+
+/// Adds the preset buttons and, once one is pushed, the path of the
+/// preset file with the button confirming the action below those.
+/// Returns the value loaded.
+#[cfg(feature = "egui_backend")]
+fn preset_dialog_egui<T>(ui: &mut egui::Ui, value: &T, actions: SerdeActions<T>) -> Option<T> {
+    if actions.is_empty() {
+        return None;
+    }
+    let address = address_of(value);
+    let mut dialog = PresetDialog::take(address);
+
+    ui.horizontal(|ui| {
+        if actions.serialize.is_some() && ui.button("Save preset…").clicked() {
+            dialog.toggle(PresetAction::Save);
+        }
+        if actions.deserialize.is_some() && ui.button("Load preset…").clicked() {
+            dialog.toggle(PresetAction::Load);
+        }
+    });
+
+    let mut loaded = None;
+    if let Some(action) = dialog.action {
+        ui.horizontal(|ui| {
+            let _ = ui.label("Preset file:");
+            let _ = ui.text_edit_singleline(&mut dialog.path);
+            if ui.button(action.title()).clicked() {
+                loaded = dialog.confirm(value, actions);
+            }
+            if ui.button("Cancel").clicked() {
+                dialog.action = None;
+                dialog.error = None;
+            }
+        });
+        if let Some(error) = &dialog.error {
+            let _ = ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+    }
+
+    dialog.store(address);
+    loaded
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use super::{from_str, to_string, Format, SerializationError};
+
+    #[test]
+    fn converts_the_values() {
+        let value = BTreeMap::from([("a".to_owned(), vec![1, 2])]);
+        let json = to_string(&value, Format::Json).unwrap();
+        let ron = to_string(&value, Format::Ron).unwrap();
+        assert_ne!(json, ron);
+
+        // Both the formats are pasted.
+        assert_eq!(
+            from_str::<BTreeMap<String, Vec<u32>>>(&json).unwrap(),
+            value
+        );
+        assert_eq!(from_str::<BTreeMap<String, Vec<u32>>>(&ron).unwrap(), value);
+
+        // The JSON of another value is reported as such.
+        assert!(matches!(
+            from_str::<Vec<u32>>("{\"a\": 1}"),
+            Err(SerializationError::Json(_))
+        ));
+        assert!(matches!(
+            from_str::<Vec<u32>>("(a: "),
+            Err(SerializationError::Syntax(_))
+        ));
+    }
+
+    #[test]
+    fn picks_the_format_of_the_file() {
+        assert_eq!(Format::of_path("preset.JSON".as_ref()), Format::Json);
+        assert_eq!(Format::of_path("preset.ron".as_ref()), Format::Ron);
+        assert_eq!(Format::of_path("preset".as_ref()), Format::Ron);
+    }
+}
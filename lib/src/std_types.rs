@@ -1,187 +1,497 @@
-//! This module provides impementations of the [`ImguiPresentable`]
-//! and/or the [`EguiPresentable`] trait for the commonly used standard
-//! library types of Rust.
-
-use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+//! This module provides impementations of the [`ImguiPresentable`],
+//! the [`EguiPresentable`] and/or the [`TuiPresentable`] trait for the
+//! commonly used standard library types of Rust.
+
+use std::cell::{Cell, RefCell};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+use std::hash::Hash;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, PoisonError, RwLock, TryLockError, TryLockResult};
+
+/// The maximum number of the elements of an array or a tuple shown in
+/// a single row.
+#[cfg(any(feature = "imgui_backend", feature = "egui_backend"))]
+const INLINE_COLUMNS: usize = 4;
+
+/// Shown instead of a value which is already borrowed or locked
+/// elsewhere, as waiting for it would deadlock the UI.
+const LOCKED_PLACEHOLDER: &str = "locked";
+
+/// Returns the guard of a lock if it could be acquired without
+/// blocking. A poisoned lock is still acquired, as the panic of another
+/// thread shouldn't prevent looking at the value.
+fn try_acquire<G>(result: TryLockResult<G>) -> Option<G> {
+    match result {
+        Ok(guard) => Some(guard),
+        Err(TryLockError::Poisoned(error)) => Some(error.into_inner()),
+        Err(TryLockError::WouldBlock) => None,
+    }
+}
 
 #[cfg(feature = "imgui_backend")]
 mod imgui_backend {
     use super::*;
-    use crate::{Extent, ImguiPresentable};
-
-    impl<T: ImguiPresentable> ImguiPresentable for Vec<T> {
-        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let type_name = std::any::type_name::<T>();
-            let _id = ui.push_id(format!("##{self:p}"));
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [imgui::TableColumnSetup::new(&format!(
-                    "objects ({type_name}): {}",
-                    self.len()
-                ))],
-            ) {
+    use crate::elements::{ElementActions, Sequence, SequenceEdit};
+    use crate::entries::{Entries, EntryEdit, EntryEditor, MapEntries};
+    use crate::filter::{highlight_imgui, Filter, FilterMatch, ShownRows};
+    use crate::frame_state::imgui_frame;
+    use crate::pagination::{
+        clip_imgui_rows, render_imgui_cell, render_imgui_pager, take_page_size, RowCursor,
+    };
+    use crate::{EditResponse, Extent, ImguiPresentable};
+
+    /// The name of the drag and drop payloads of the sequence elements.
+    const ELEMENT_PAYLOAD: &str = "sequence_element";
+
+    /// Renders the elements of a sequence shown with the applied filter,
+    /// the ones on the page shown and visible within the scrolled area.
+    fn render_sequence<S>(ui: &imgui::Ui, extent: Extent, sequence: &S)
+    where
+        S: Sequence,
+        S::Element: ImguiPresentable,
+    {
+        let type_name = std::any::type_name::<S::Element>();
+        let page_size = take_page_size();
+        let _id = ui.push_id(format!("##{:p}", std::ptr::from_ref(sequence)));
+        let shown = match_elements(sequence);
+        let rows = render_imgui_pager(ui, sequence, page_size, shown.len());
+
+        if let Some(table) = ui.begin_table_header(
+            "objects",
+            [imgui::TableColumnSetup::new(&format!(
+                "objects ({type_name}): {}",
+                sequence.len()
+            ))],
+        ) {
+            clip_imgui_rows(ui, sequence, rows, |row| {
+                let (i, filter_match) = shown.get(row);
+                let o = sequence.get(i);
+
+                ui.table_next_row();
                 ui.table_next_column();
-
-                self.iter().enumerate().for_each(|(i, o)| {
-                    ui.separator();
+                let (opened, condition) = if filter_match.value_matches {
+                    (true, imgui::Condition::Always)
+                } else {
+                    (false, imgui::Condition::FirstUseEver)
+                };
+                let node = {
+                    let _highlight = highlight_imgui(ui, filter_match.name_matches);
                     ui.tree_node_config(&format!("{i}: {type_name}##{o:p}"))
-                        .opened(false, imgui::Condition::FirstUseEver)
+                        .opened(opened, condition)
                         .framed(true)
-                        .build(|| {
-                            (o as &dyn ImguiPresentable).render_component(ui, extent);
-                        });
-                });
+                        .push()
+                };
+                let expanded = node.is_some();
+                if let Some(_node) = node {
+                    filter_match.render(|| {
+                        (o as &dyn ImguiPresentable).render_component(ui, extent);
+                    });
+                }
+                expanded
+            });
 
-                table.end();
-            }
+            table.end();
         }
+    }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let type_name = std::any::type_name::<T>();
-            let _id = ui.push_id(format!("##{self:p}"));
-            // let filter = ui.input_scalar("Filter index", )
-
-            if let Some(table) = ui.begin_table_header_with_flags(
-                "objects",
-                [imgui::TableColumnSetup::new(&format!(
-                    "objects ({type_name}): {}",
-                    self.len()
-                ))],
-                imgui::TableFlags::BORDERS | imgui::TableFlags::ROW_BG,
-            ) {
+    /// Renders the elements of a sequence like [`render_sequence`] does,
+    /// allowing to change, add, remove and reorder those.
+    fn render_sequence_mut<S>(ui: &imgui::Ui, extent: Extent, sequence: &mut S) -> EditResponse
+    where
+        S: Sequence,
+        S::Element: ImguiPresentable,
+    {
+        let type_name = std::any::type_name::<S::Element>();
+        let actions = S::Element::element_actions();
+        let page_size = take_page_size();
+        let sequence_ptr = std::ptr::from_ref(sequence);
+        let sequence_id = sequence_ptr as usize;
+        let _id = ui.push_id(format!("##{sequence_id:x}"));
+        let mut response = EditResponse::default();
+        let shown = match_elements(sequence);
+        let rows = render_imgui_pager(ui, sequence_ptr, page_size, shown.len());
+        let len = sequence.len();
+        let mut edit = None;
+
+        if let Some(table) = ui.begin_table_header_with_flags(
+            "objects",
+            [imgui::TableColumnSetup::new(&format!(
+                "objects ({type_name}): {len}"
+            ))],
+            imgui::TableFlags::BORDERS | imgui::TableFlags::ROW_BG,
+        ) {
+            clip_imgui_rows(ui, sequence_ptr, rows, |row| {
+                let (i, filter_match) = shown.get(row);
+                let o = sequence.get_mut(i);
+
+                ui.table_next_row();
                 ui.table_next_column();
 
-                let mut to_delete = None;
-                self.iter_mut().enumerate().for_each(|(i, o)| {
-                    let mut is_not_deleted = true;
-                    if ui.collapsing_header_with_close_button(
-                        format!("{i}: {type_name}##{o:p}"),
-                        imgui::TreeNodeFlags::FRAMED,
-                        &mut is_not_deleted,
-                    ) {
-                        ui.indent();
-                        (o as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
-                        ui.unindent();
-                    }
+                let mut is_not_deleted = true;
+                let opened = render_header_with_close_button(
+                    ui,
+                    &format!("{i}: {type_name}##{:p}", std::ptr::from_ref(o)),
+                    filter_match,
+                    &mut is_not_deleted,
+                );
+                if let Some(element_edit) = render_element_actions(ui, sequence_id, i, len, actions)
+                {
+                    edit = Some(element_edit);
+                }
 
-                    if !is_not_deleted {
-                        to_delete = Some(i);
-                    }
-                });
+                if opened {
+                    ui.indent();
+                    response |= filter_match.render(|| {
+                        (o as &mut dyn ImguiPresentable).render_component_mut(ui, extent)
+                    });
+                    ui.unindent();
+                }
 
-                if let Some(index) = to_delete {
-                    let _ = self.remove(index);
+                if !is_not_deleted {
+                    edit = Some(SequenceEdit::Remove(i));
                 }
+                opened
+            });
 
-                table.end();
+            table.end();
+        }
+
+        if actions.create.is_some() {
+            if ui.button("Add new") {
+                edit = Some(SequenceEdit::Push);
+            }
+
+            if ui.is_item_hovered() {
+                ui.tooltip_text("Adds a new element to the end.");
             }
 
-            // if ui.button("Add new") {
-            //     self.push()
-            // }
+            ui.same_line();
+        }
+
+        if ui.button("Clear") {
+            edit = Some(SequenceEdit::Clear);
+        }
+
+        if ui.is_item_hovered() {
+            ui.tooltip_text("Removes all the elements.");
+        }
+
+        if let Some(edit) = edit {
+            response |= EditResponse::changed(edit.apply(sequence, actions));
+        }
 
-            // if ui.is_item_hovered() {
-            //     ui.tooltip_text("Adds a new element to the end of the vector by cloning the last object in it.");
-            // }
+        response
+    }
 
-            // ui.same_line();
+    /// Makes the header of an element just rendered a source and a
+    /// target of dragging the elements of the same sequence around, and
+    /// opens the menu of the actions on the element with the right
+    /// click. Returns the edit requested.
+    fn render_element_actions<T>(
+        ui: &imgui::Ui,
+        sequence_id: usize,
+        index: usize,
+        len: usize,
+        actions: ElementActions<T>,
+    ) -> Option<SequenceEdit> {
+        let mut edit = None;
+
+        if let Some(_tooltip) = ui
+            .drag_drop_source_config(ELEMENT_PAYLOAD)
+            .begin_payload((sequence_id, index))
+        {
+            ui.text(format!("Moving the element {index}"));
+        }
 
-            if ui.button("Clear") {
-                self.clear();
+        if let Some(target) = ui.drag_drop_target() {
+            if let Some(Ok(payload)) = target
+                .accept_payload::<(usize, usize), _>(ELEMENT_PAYLOAD, imgui::DragDropFlags::empty())
+            {
+                let (source_id, from) = payload.data;
+                if source_id == sequence_id {
+                    edit = Some(SequenceEdit::Move { from, to: index });
+                }
             }
 
-            if ui.is_item_hovered() {
-                ui.tooltip_text("Clears the vector.");
+            target.pop();
+        }
+
+        if let Some(_popup) = ui.begin_popup_context_item() {
+            if actions.create.is_some() && ui.menu_item("Insert before") {
+                edit = Some(SequenceEdit::InsertBefore(index));
+            }
+            if actions.duplicate.is_some() && ui.menu_item("Duplicate") {
+                edit = Some(SequenceEdit::Duplicate(index));
+            }
+            if ui.menu_item_config("Move up").enabled(index > 0).build() {
+                edit = Some(SequenceEdit::Move {
+                    from: index,
+                    to: index - 1,
+                });
+            }
+            if ui
+                .menu_item_config("Move down")
+                .enabled(index + 1 < len)
+                .build()
+            {
+                edit = Some(SequenceEdit::Move {
+                    from: index,
+                    to: index + 1,
+                });
+            }
+            if ui.menu_item("Remove") {
+                edit = Some(SequenceEdit::Remove(index));
             }
         }
+
+        edit
     }
 
-    impl<T: ImguiPresentable + Ord> ImguiPresentable for BTreeSet<T> {
+    impl<T: ImguiPresentable> ImguiPresentable for Vec<T> {
         fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let type_name = std::any::type_name::<T>();
-            let _id = ui.push_id(format!("##{self:p}"));
+            render_sequence(ui, extent, self);
+        }
 
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [imgui::TableColumnSetup::new(&format!(
-                    "objects ({type_name}): {}",
-                    self.len()
-                ))],
-            ) {
-                ui.table_next_column();
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_sequence_mut(ui, extent, self)
+        }
 
-                self.iter().for_each(|o| {
-                    (o as &dyn ImguiPresentable).render_component(ui, extent);
-                });
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
 
-                table.end();
-            }
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
         }
+    }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let type_name = std::any::type_name::<T>();
-            let _id = ui.push_id(format!("##{self:p}"));
+    impl<T: ImguiPresentable> ImguiPresentable for VecDeque<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            render_sequence(ui, extent, self);
+        }
 
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [imgui::TableColumnSetup::new(&format!(
-                    "objects ({type_name}): {}",
-                    self.len()
-                ))],
-            ) {
-                ui.table_next_column();
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_sequence_mut(ui, extent, self)
+        }
 
-                // let to_delete = self.iter().enumerate().find(|(i, o)| {
-                //     let mut is_not_deleted = true;
-                //     if ui.collapsing_header_with_close_button(
-                //         format!("{i}: {type_name}##{:p}", std::ptr::addr_of!(o)),
-                //         imgui::TreeNodeFlags::FRAMED,
-                //         &mut is_not_deleted,
-                //     ) {
-                //         ui.indent();
-                //         o.render_component(ui, extent);
-                //         ui.unindent();
-                //     }
-
-                //     !is_not_deleted
-                // });
-
-                // if let Some(object) = to_delete {
-                //     let _ = self.remove(object.1);
-                // }
-
-                let mut index = 0usize;
-                self.retain(|o| {
-                    let mut is_not_deleted = true;
-                    if ui.collapsing_header_with_close_button(
-                        format!("{index}: {type_name}##{o:p}"),
-                        imgui::TreeNodeFlags::FRAMED,
-                        &mut is_not_deleted,
-                    ) {
-                        ui.indent();
-                        (o as &dyn ImguiPresentable).render_component(ui, extent);
-                        ui.unindent();
-                    }
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
 
-                    index += 1;
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
+        }
+    }
 
-                    is_not_deleted
-                });
+    /// Returns the elements of a sequence shown with the applied filter,
+    /// matched by their indices and by the values.
+    fn match_elements<S>(sequence: &S) -> ShownRows
+    where
+        S: Sequence,
+        S::Element: ImguiPresentable,
+    {
+        ShownRows::of(sequence.len(), || {
+            (0..sequence.len()).map(|i| {
+                FilterMatch::of(
+                    |filter| filter.matches(&i.to_string()),
+                    |filter| sequence.get(i).matches_filter(filter),
+                )
+            })
+        })
+    }
 
-                table.end();
+    /// Renders the header of an element of a collection, which may be
+    /// closed to remove the element, highlighting and expanding it as
+    /// the filter tells. Returns `true` if the header is open.
+    fn render_header_with_close_button(
+        ui: &imgui::Ui,
+        label: &str,
+        filter_match: FilterMatch,
+        is_not_deleted: &mut bool,
+    ) -> bool {
+        // The headers of the matching elements are separate ones, open
+        // by default, so that the matches inside those are seen.
+        let label = if filter_match.value_matches {
+            format!("{label}-match")
+        } else {
+            label.to_owned()
+        };
+        let _highlight = highlight_imgui(ui, filter_match.name_matches);
+        imgui::CollapsingHeader::new(&label)
+            .flags(imgui::TreeNodeFlags::FRAMED)
+            .default_open(filter_match.value_matches)
+            .build_with_close_button(ui, is_not_deleted)
+    }
+
+    /// Renders the editor of the key an entry of a map or a set is
+    /// renamed to, returning the edit once the renaming is confirmed.
+    fn render_renamed_key<K: ImguiPresentable>(
+        ui: &imgui::Ui,
+        extent: Extent,
+        editor: &mut EntryEditor<K>,
+    ) -> Option<EntryEdit<K>> {
+        let _id = ui.push_id("renamed");
+        ui.text("Rename to:");
+        ui.same_line();
+        if let Some((_, key)) = editor.renamed.as_mut() {
+            let _ = (key as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+        }
+
+        if ui.small_button("Rename") {
+            return editor
+                .renamed
+                .take()
+                .map(|(index, key)| EntryEdit::Rename { index, key });
+        }
+
+        ui.same_line();
+        if ui.small_button("Cancel") {
+            editor.renamed = None;
+            editor.error = None;
+        }
+
+        None
+    }
+
+    /// Renders the buttons adding an entry to a map or a set and
+    /// removing all the entries, along with the editor of the key of the
+    /// new entry if it is being added. Returns the edit requested.
+    fn render_entry_buttons<K: ImguiPresentable>(
+        ui: &imgui::Ui,
+        extent: Extent,
+        editor: &mut EntryEditor<K>,
+        create: Option<fn() -> K>,
+    ) -> Option<EntryEdit<K>> {
+        let mut edit = None;
+
+        if let Some(key) = editor.new_key.as_mut() {
+            let _id = ui.push_id("new");
+            ui.text("New key:");
+            ui.same_line();
+            let _ = (key as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+
+            if ui.button("Add") {
+                edit = editor.new_key.take().map(EntryEdit::Add);
             }
 
-            if ui.button("Clear") {
-                self.clear();
+            ui.same_line();
+            if ui.button("Cancel") {
+                editor.new_key = None;
+                editor.error = None;
+            }
+        } else if let Some(create) = create {
+            if ui.button("Add entry") {
+                editor.new_key = Some(create());
+                editor.error = None;
             }
 
             if ui.is_item_hovered() {
-                ui.tooltip_text("Clears the set.");
+                ui.tooltip_text("Adds an entry with a new key.");
+            }
+        }
+
+        if create.is_some() {
+            ui.same_line();
+        }
+
+        if ui.button("Clear") {
+            edit = Some(EntryEdit::Clear);
+        }
+
+        if ui.is_item_hovered() {
+            ui.tooltip_text("Removes all the entries.");
+        }
+
+        if let Some(error) = editor.error {
+            ui.text_colored([1.0, 0.3, 0.3, 1.0], error);
+        }
+
+        edit
+    }
+
+    /// Renders the elements of a set shown with the applied filter,
+    /// allowing to add, rename and remove those.
+    fn render_set_mut<S>(ui: &imgui::Ui, extent: Extent, set: &mut S) -> EditResponse
+    where
+        S: Entries<Value = ()>,
+        S::Key: ImguiPresentable + 'static,
+    {
+        let type_name = std::any::type_name::<S::Key>();
+        let actions = S::Key::element_actions();
+        let set_id = std::ptr::from_ref(set) as usize;
+        let _id = ui.push_id(format!("##{set_id:x}"));
+        let mut editor = EntryEditor::take(imgui_frame(ui), set_id);
+        let mut edit = None;
+
+        if let Some(table) = ui.begin_table_header(
+            "objects",
+            [imgui::TableColumnSetup::new(&format!(
+                "objects ({type_name}): {}",
+                set.len()
+            ))],
+        ) {
+            ui.table_next_column();
+
+            for (index, (o, ())) in set.iter_entries().enumerate() {
+                let filter_match = FilterMatch::of(|_| false, |filter| o.matches_filter(filter));
+                if !filter_match.shown {
+                    continue;
+                }
+
+                let _id = ui.push_id_usize(index);
+                if editor
+                    .renamed
+                    .as_ref()
+                    .is_some_and(|(renamed, _)| *renamed == index)
+                {
+                    if let Some(renamed) = render_renamed_key(ui, extent, &mut editor) {
+                        edit = Some(renamed);
+                    }
+                    continue;
+                }
+
+                let mut is_not_deleted = true;
+                let opened = render_header_with_close_button(
+                    ui,
+                    &format!("{index}: {type_name}##{o:p}"),
+                    filter_match,
+                    &mut is_not_deleted,
+                );
+
+                if let Some(duplicate) = actions.duplicate {
+                    if let Some(_popup) = ui.begin_popup_context_item() {
+                        if ui.menu_item("Rename") {
+                            editor.renamed = Some((index, duplicate(o)));
+                            editor.error = None;
+                        }
+                    }
+                }
+
+                if opened {
+                    ui.indent();
+                    (o as &dyn ImguiPresentable).render_component(ui, extent);
+                    ui.unindent();
+                }
+
+                if !is_not_deleted {
+                    edit = Some(EntryEdit::Remove(index));
+                }
             }
+
+            table.end();
         }
+
+        if let Some(entry_edit) = render_entry_buttons(ui, extent, &mut editor, actions.create) {
+            edit = Some(entry_edit);
+        }
+        let changed = edit.is_some_and(|edit| editor.apply(edit, set, Some(|| ())));
+        editor.store(imgui_frame(ui), set_id);
+
+        EditResponse::changed(changed)
     }
 
-    impl<T: ImguiPresentable> ImguiPresentable for HashSet<T> {
+    impl<T: ImguiPresentable + Ord + 'static> ImguiPresentable for BTreeSet<T> {
         fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
             let type_name = std::any::type_name::<T>();
             let _id = ui.push_id(format!("##{self:p}"));
@@ -195,15 +505,27 @@ mod imgui_backend {
             ) {
                 ui.table_next_column();
 
-                self.iter().for_each(|o| {
-                    (o as &dyn ImguiPresentable).render_component(ui, extent);
-                });
+                self.iter()
+                    .filter(|o| FilterMatch::of(|_| false, |filter| o.matches_filter(filter)).shown)
+                    .for_each(|o| {
+                        (o as &dyn ImguiPresentable).render_component(ui, extent);
+                    });
 
                 table.end();
             }
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_set_mut(ui, extent, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
+    }
+
+    impl<T: ImguiPresentable + Eq + Hash + 'static> ImguiPresentable for HashSet<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
             let type_name = std::any::type_name::<T>();
             let _id = ui.push_id(format!("##{self:p}"));
 
@@ -216,156 +538,209 @@ mod imgui_backend {
             ) {
                 ui.table_next_column();
 
-                let mut index = 0usize;
-                self.retain(|o| {
-                    let mut is_not_deleted = true;
-                    if ui.collapsing_header_with_close_button(
-                        format!("{index}: {type_name}##{o:p}"),
-                        imgui::TreeNodeFlags::FRAMED,
-                        &mut is_not_deleted,
-                    ) {
-                        ui.indent();
+                self.iter()
+                    .filter(|o| FilterMatch::of(|_| false, |filter| o.matches_filter(filter)).shown)
+                    .for_each(|o| {
                         (o as &dyn ImguiPresentable).render_component(ui, extent);
-                        ui.unindent();
-                    }
-
-                    index += 1;
-
-                    is_not_deleted
-                });
+                    });
 
                 table.end();
             }
+        }
 
-            if ui.button("Clear") {
-                self.clear();
-            }
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_set_mut(ui, extent, self)
+        }
 
-            if ui.is_item_hovered() {
-                ui.tooltip_text("Clears the set.");
-            }
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
         }
     }
 
-    impl<K: ImguiPresentable, V: ImguiPresentable> ImguiPresentable for BTreeMap<K, V> {
-        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(format!("##{self:p}"));
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [
-                    imgui::TableColumnSetup::new("#"),
-                    imgui::TableColumnSetup::new("key"),
-                    imgui::TableColumnSetup::new("value"),
-                ],
-            ) {
-                self.iter().enumerate().for_each(|(i, (k, v))| {
-                    ui.table_next_column();
-                    (&i as &dyn ImguiPresentable).render_component(ui, extent);
-
-                    ui.table_next_column();
-                    (k as &dyn ImguiPresentable).render_component(ui, extent);
-
-                    ui.table_next_column();
-                    (v as &dyn ImguiPresentable).render_component(ui, extent);
-                });
-
-                table.end();
-            }
-        }
+    /// Returns the entries of a map shown with the applied filter,
+    /// matched by the keys and by the values.
+    fn match_entries<M>(map: &M) -> ShownRows
+    where
+        M: Entries,
+        M::Key: ImguiPresentable,
+        M::Value: ImguiPresentable,
+    {
+        ShownRows::of(map.len(), || {
+            map.iter_entries().map(|(key, value)| {
+                FilterMatch::of(
+                    |filter| key.matches_filter(filter),
+                    |filter| value.matches_filter(filter),
+                )
+            })
+        })
+    }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(format!("##{self:p}"));
+    /// Begins the table the entries of a map are rendered in.
+    fn begin_map_table(ui: &imgui::Ui) -> Option<imgui::TableToken<'_>> {
+        ui.begin_table_header(
+            "objects",
+            [
+                imgui::TableColumnSetup::new("#"),
+                imgui::TableColumnSetup::new("key"),
+                imgui::TableColumnSetup::new("value"),
+            ],
+        )
+    }
 
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [
-                    imgui::TableColumnSetup::new("#"),
-                    imgui::TableColumnSetup::new("key"),
-                    imgui::TableColumnSetup::new("value"),
-                ],
-            ) {
-                self.iter_mut().enumerate().for_each(|(i, (k, v))| {
-                    ui.table_next_column();
-                    (&i as &dyn ImguiPresentable).render_component(ui, extent);
+    /// Renders the entries of a map shown with the applied filter, the
+    /// ones on the page shown and visible within the scrolled area.
+    fn render_map<M>(ui: &imgui::Ui, extent: Extent, map: &M)
+    where
+        M: Entries,
+        M::Key: ImguiPresentable,
+        M::Value: ImguiPresentable,
+    {
+        let page_size = take_page_size();
+        let _id = ui.push_id(format!("##{:p}", std::ptr::from_ref(map)));
+        let shown = match_entries(map);
+        let rows = render_imgui_pager(ui, map, page_size, shown.len());
+
+        if let Some(table) = begin_map_table(ui) {
+            let mut entries = RowCursor::new(map.iter_entries());
+            clip_imgui_rows(ui, map, rows, |row| {
+                let (i, filter_match) = shown.get(row);
+                let Some((k, v)) = entries.nth(i) else {
+                    return false;
+                };
+
+                ui.table_next_row();
+                ui.table_next_column();
+                (&i as &dyn ImguiPresentable).render_component(ui, extent);
 
-                    ui.table_next_column();
+                let ((), key_expanded) = render_imgui_cell(ui, || {
                     (k as &dyn ImguiPresentable).render_component(ui, extent);
-
-                    ui.table_next_column();
-                    (v as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
                 });
 
-                table.end();
-            }
-
-            if ui.button(format!("Clear##{self:p}")) {
-                self.clear();
-            }
+                let ((), value_expanded) = render_imgui_cell(ui, || {
+                    filter_match.render(|| {
+                        (v as &dyn ImguiPresentable).render_component(ui, extent);
+                    });
+                });
+                key_expanded || value_expanded
+            });
 
-            if ui.is_item_hovered() {
-                ui.tooltip_text("Clears the map.");
-            }
+            table.end();
         }
     }
 
-    impl<K: ImguiPresentable, V: ImguiPresentable> ImguiPresentable for HashMap<K, V> {
-        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(format!("##{self:p}"));
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [
-                    imgui::TableColumnSetup::new("#"),
-                    imgui::TableColumnSetup::new("key"),
-                    imgui::TableColumnSetup::new("value"),
-                ],
-            ) {
-                self.iter().enumerate().for_each(|(i, (k, v))| {
-                    ui.table_next_column();
-                    (&i as &dyn ImguiPresentable).render_component(ui, extent);
+    /// Renders the entries of a map like [`render_map`] does, allowing
+    /// to change the values, to add and remove the entries and to rename
+    /// their keys.
+    fn render_map_mut<M>(ui: &imgui::Ui, extent: Extent, map: &mut M) -> EditResponse
+    where
+        M: MapEntries,
+        M::Key: ImguiPresentable + 'static,
+        M::Value: ImguiPresentable,
+    {
+        let page_size = take_page_size();
+        let key_actions = M::Key::element_actions();
+        let create_value = M::Value::element_actions().create;
+        let map_ptr = std::ptr::from_ref(map);
+        let _id = ui.push_id(format!("##{map_ptr:p}"));
+        let mut editor = EntryEditor::take(imgui_frame(ui), map_ptr as usize);
+        let mut response = EditResponse::default();
+        let mut edit = None;
+        let shown = match_entries(map);
+        let rows = render_imgui_pager(ui, map_ptr, page_size, shown.len());
+
+        if let Some(table) = begin_map_table(ui) {
+            let mut entries = RowCursor::new(map.iter_entries_mut());
+            clip_imgui_rows(ui, map_ptr, rows, |row| {
+                let (i, filter_match) = shown.get(row);
+                let Some((k, v)) = entries.nth(i) else {
+                    return false;
+                };
+                let _id = ui.push_id_usize(i);
+
+                ui.table_next_row();
+                ui.table_next_column();
+                (&i as &dyn ImguiPresentable).render_component(ui, extent);
+
+                let ((), key_expanded) = render_imgui_cell(ui, || {
+                    if editor
+                        .renamed
+                        .as_ref()
+                        .is_some_and(|(renamed, _)| *renamed == i)
+                    {
+                        if let Some(renamed) = render_renamed_key(ui, extent, &mut editor) {
+                            edit = Some(renamed);
+                        }
+                    } else {
+                        (k as &dyn ImguiPresentable).render_component(ui, extent);
+
+                        if let Some(duplicate) = key_actions.duplicate {
+                            ui.same_line();
+                            if ui.small_button("Rename") {
+                                editor.renamed = Some((i, duplicate(k)));
+                                editor.error = None;
+                            }
+                        }
 
-                    ui.table_next_column();
-                    (k as &dyn ImguiPresentable).render_component(ui, extent);
+                        ui.same_line();
+                        if ui.small_button("X") {
+                            edit = Some(EntryEdit::Remove(i));
+                        }
+                    }
+                });
 
-                    ui.table_next_column();
-                    (v as &dyn ImguiPresentable).render_component(ui, extent);
+                let (value_response, value_expanded) = render_imgui_cell(ui, || {
+                    filter_match.render(|| {
+                        (v as &mut dyn ImguiPresentable).render_component_mut(ui, extent)
+                    })
                 });
+                response |= value_response;
+                key_expanded || value_expanded
+            });
 
-                table.end();
-            }
+            table.end();
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
-            let _id = ui.push_id(format!("##{self:p}"));
+        let create = key_actions.create.filter(|_| create_value.is_some());
+        if let Some(entry_edit) = render_entry_buttons(ui, extent, &mut editor, create) {
+            edit = Some(entry_edit);
+        }
+        if let Some(edit) = edit {
+            response |= EditResponse::changed(editor.apply(edit, map, create_value));
+        }
+        editor.store(imgui_frame(ui), map_ptr as usize);
 
-            if let Some(table) = ui.begin_table_header(
-                "objects",
-                [
-                    imgui::TableColumnSetup::new("#"),
-                    imgui::TableColumnSetup::new("key"),
-                    imgui::TableColumnSetup::new("value"),
-                ],
-            ) {
-                self.iter_mut().enumerate().for_each(|(i, (k, v))| {
-                    ui.table_next_column();
-                    (&i as &dyn ImguiPresentable).render_component(ui, extent);
+        response
+    }
 
-                    ui.table_next_column();
-                    (k as &dyn ImguiPresentable).render_component(ui, extent);
+    impl<K: ImguiPresentable + Ord + 'static, V: ImguiPresentable> ImguiPresentable for BTreeMap<K, V> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            render_map(ui, extent, self);
+        }
 
-                    ui.table_next_column();
-                    (v as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
-                });
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_map_mut(ui, extent, self)
+        }
 
-                table.end();
-            }
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter()
+                .any(|(k, v)| k.matches_filter(filter) || v.matches_filter(filter))
+        }
+    }
 
-            if ui.button(format!("Clear##{self:p}")) {
-                self.clear();
-            }
+    impl<K: ImguiPresentable + Eq + Hash + 'static, V: ImguiPresentable> ImguiPresentable
+        for HashMap<K, V>
+    {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            render_map(ui, extent, self);
+        }
 
-            if ui.is_item_hovered() {
-                ui.tooltip_text("Clears the map.");
-            }
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            render_map_mut(ui, extent, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter()
+                .any(|(k, v)| k.matches_filter(filter) || v.matches_filter(filter))
         }
     }
 
@@ -393,12 +768,13 @@ mod imgui_backend {
             });
         }
 
-        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) {
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
             let type_name = std::any::type_name::<T>();
             let _id = ui.push_id(format!("##{self:p}"));
             let had_value = self.is_some();
             let mut has_value = had_value;
             let checked = ui.checkbox(format!("Has value ({type_name})##{self:p}"), &mut has_value);
+            let mut response = EditResponse::from_last_imgui_item(ui, checked);
             if checked || has_value {
                 if !had_value {
                     let _ = self.insert(T::default());
@@ -407,609 +783,1683 @@ mod imgui_backend {
                 }
 
                 if let Some(value) = self.as_mut() {
-                    (value as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
+                    response |=
+                        (value as &mut dyn ImguiPresentable).render_component_mut(ui, extent);
                 }
             }
+
+            response
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.as_ref()
+                .is_some_and(|value| value.matches_filter(filter))
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
         }
     }
-}
 
-#[allow(unused)]
-#[cfg(feature = "imgui_backend")]
-pub use imgui_backend::*;
+    /// Renders the elements of an array or a tuple in compact rows of
+    /// a table.
+    fn render_inline(ui: &imgui::Ui, extent: Extent, elements: &[&dyn ImguiPresentable]) {
+        if elements.is_empty() {
+            return;
+        }
 
-#[cfg(feature = "egui_backend")]
-mod egui_backend {
-    use super::*;
-    use crate::EguiPresentable;
-    use egui_extras::{Column, TableBuilder};
+        if let Some(table) = ui.begin_table("elements", elements.len().min(INLINE_COLUMNS)) {
+            elements.iter().enumerate().for_each(|(i, element)| {
+                ui.table_next_column();
+                let _id = ui.push_id_usize(i);
+                element.render_component(ui, extent);
+            });
 
-    impl<T: EguiPresentable> EguiPresentable for Vec<T> {
+            table.end();
+        }
+    }
+
+    /// Renders the elements of an array or a tuple in compact rows of
+    /// a table, allowing to change those.
+    fn render_inline_mut(
+        ui: &imgui::Ui,
+        extent: Extent,
+        elements: &mut [&mut dyn ImguiPresentable],
+    ) -> EditResponse {
+        let mut response = EditResponse::default();
+        if elements.is_empty() {
+            return response;
+        }
+
+        if let Some(table) = ui.begin_table("elements", elements.len().min(INLINE_COLUMNS)) {
+            elements.iter_mut().enumerate().for_each(|(i, element)| {
+                ui.table_next_column();
+                let _id = ui.push_id_usize(i);
+                response |= element.render_component_mut(ui, extent);
+            });
+
+            table.end();
+        }
+
+        response
+    }
+
+    impl<T: ImguiPresentable, const N: usize> ImguiPresentable for [T; N] {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let elements: Vec<&dyn ImguiPresentable> =
+                self.iter().map(|e| e as &dyn ImguiPresentable).collect();
+            render_inline(ui, extent, &elements);
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            let _id = ui.push_id(format!("##{self:p}"));
+            let mut elements: Vec<&mut dyn ImguiPresentable> = self
+                .iter_mut()
+                .map(|e| e as &mut dyn ImguiPresentable)
+                .collect();
+            render_inline_mut(ui, extent, &mut elements)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|e| e.matches_filter(filter))
+        }
+    }
+
+    macro_rules! define_for_tuple {
+        ($($element:ident $index:tt),+) => {
+            impl<$($element: ImguiPresentable),+> ImguiPresentable for ($($element,)+) {
+                fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    render_inline(ui, extent, &[$(&self.$index as &dyn ImguiPresentable),+]);
+                }
+
+                fn render_component_mut(
+                    &mut self,
+                    ui: &imgui::Ui,
+                    extent: Extent,
+                ) -> EditResponse {
+                    let _id = ui.push_id(format!("##{self:p}"));
+                    render_inline_mut(
+                        ui,
+                        extent,
+                        &mut [$(&mut self.$index as &mut dyn ImguiPresentable),+],
+                    )
+                }
+
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    false $(|| self.$index.matches_filter(filter))+
+                }
+            }
+        };
+    }
+
+    define_for_tuple!(A 0);
+    define_for_tuple!(A 0, B 1);
+    define_for_tuple!(A 0, B 1, C 2);
+    define_for_tuple!(A 0, B 1, C 2, D 3);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for Box<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            (**self).render_component(ui, extent);
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            (**self).render_component_shared(ui, extent)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
+    }
+
+    macro_rules! define_for_shared_pointer {
+        ($pointer:ident) => {
+            /// The value is only changed in place when this is the
+            /// only pointer to it, otherwise only the values with
+            /// interior mutability can be changed.
+            impl<T: ImguiPresentable + ?Sized> ImguiPresentable for $pointer<T> {
+                fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+                    (**self).render_component(ui, extent);
+                }
+
+                fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+                    match $pointer::get_mut(self) {
+                        Some(value) => value.render_component_mut(ui, extent),
+                        None => (**self).render_component_shared(ui, extent),
+                    }
+                }
+
+                fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+                    (**self).render_component_shared(ui, extent)
+                }
+
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    (**self).matches_filter(filter)
+                }
+            }
+        };
+    }
+
+    define_for_shared_pointer!(Rc);
+    define_for_shared_pointer!(Arc);
+
+    /// Shows that the value can't be accessed during this frame.
+    fn render_locked(ui: &imgui::Ui) {
+        ui.text_disabled(LOCKED_PLACEHOLDER);
+    }
+
+    impl<T: ImguiPresentable + Copy> ImguiPresentable for Cell<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            self.get().render_component(ui, extent);
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.get_mut().render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            let mut value = self.get();
+            let response = value.render_component_mut(ui, extent);
+            if response.changed {
+                self.set(value);
+            }
+            response
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.get().matches_filter(filter)
+        }
+    }
+
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for RefCell<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            match self.try_borrow() {
+                Ok(value) => value.render_component(ui, extent),
+                Err(_) => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.get_mut().render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            match self.try_borrow_mut() {
+                Ok(mut value) => value.render_component_mut(ui, extent),
+                Err(_) => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.try_borrow()
+                .is_ok_and(|value| value.matches_filter(filter))
+        }
+    }
+
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for Mutex<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            match try_acquire(self.try_lock()) {
+                Some(value) => value.render_component(ui, extent),
+                None => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            match try_acquire(self.try_lock()) {
+                Some(mut value) => value.render_component_mut(ui, extent),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            try_acquire(self.try_lock()).is_some_and(|value| value.matches_filter(filter))
+        }
+    }
+
+    impl<T: ImguiPresentable + ?Sized> ImguiPresentable for RwLock<T> {
+        fn render_component(&self, ui: &imgui::Ui, extent: Extent) {
+            match try_acquire(self.try_read()) {
+                Some(value) => value.render_component(ui, extent),
+                None => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui, extent)
+        }
+
+        fn render_component_shared(&self, ui: &imgui::Ui, extent: Extent) -> EditResponse {
+            match try_acquire(self.try_write()) {
+                Some(mut value) => value.render_component_mut(ui, extent),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            try_acquire(self.try_read()).is_some_and(|value| value.matches_filter(filter))
+        }
+    }
+}
+
+#[allow(unused)]
+#[cfg(feature = "imgui_backend")]
+pub use imgui_backend::*;
+
+#[cfg(feature = "egui_backend")]
+mod egui_backend {
+    use super::*;
+    use crate::elements::{ElementActions, Sequence, SequenceEdit};
+    use crate::entries::{Entries, EntryEdit, EntryEditor, MapEntries};
+    use crate::filter::{highlighted_egui_text, Filter, FilterMatch, ShownRows};
+    use crate::frame_state::egui_frame;
+    use crate::pagination::{render_egui_pager, take_page_size, RowCursor};
+    use crate::{EditResponse, EguiPresentable};
+    use egui_extras::{Column, TableBuilder};
+
+    /// Returns the entries of a map shown with the applied filter,
+    /// matched by the keys and by the values.
+    fn match_entries<M>(map: &M) -> ShownRows
+    where
+        M: Entries,
+        M::Key: EguiPresentable,
+        M::Value: EguiPresentable,
+    {
+        ShownRows::of(map.len(), || {
+            map.iter_entries().map(|(key, value)| {
+                FilterMatch::of(
+                    |filter| key.matches_filter(filter),
+                    |filter| value.matches_filter(filter),
+                )
+            })
+        })
+    }
+
+    /// Returns the elements of a sequence shown with the applied filter,
+    /// matched by their indices and by the values.
+    fn match_elements<S>(sequence: &S) -> ShownRows
+    where
+        S: Sequence,
+        S::Element: EguiPresentable,
+    {
+        ShownRows::of(sequence.len(), || {
+            (0..sequence.len()).map(|index| {
+                FilterMatch::of(
+                    |filter| filter.matches(&index.to_string()),
+                    |filter| sequence.get(index).matches_filter(filter),
+                )
+            })
+        })
+    }
+
+    /// Returns the elements of a set shown with the applied filter, along
+    /// with their order numbers. Those are only matched by the values.
+    fn match_set_elements<'a, T: EguiPresentable + 'a>(
+        elements: impl Iterator<Item = &'a T>,
+    ) -> Vec<(usize, &'a T, FilterMatch)> {
+        elements
+            .enumerate()
+            .map(|(index, o)| {
+                let filter_match = FilterMatch::of(|_| false, |filter| o.matches_filter(filter));
+                (index, o, filter_match)
+            })
+            .filter(|(.., filter_match)| filter_match.shown)
+            .collect()
+    }
+
+    /// Returns the header of a collapsing section showing an element of a
+    /// collection, highlighted and expanded as the filter tells.
+    fn element_header(title: String, filter_match: FilterMatch) -> egui::CollapsingHeader {
+        egui::CollapsingHeader::new(highlighted_egui_text(title, filter_match.name_matches))
+            .open(filter_match.value_matches.then_some(true))
+    }
+
+    /// Renders the elements of a sequence shown with the applied filter,
+    /// the ones on the page shown and visible within the scrolled area.
+    fn render_sequence<S>(ui: &mut egui::Ui, sequence: &S)
+    where
+        S: Sequence,
+        S::Element: EguiPresentable,
+    {
+        let type_name = std::any::type_name::<S::Element>();
+        let page_size = take_page_size();
+        let shown = match_elements(sequence);
+        let rows = render_egui_pager(ui, page_size, shown.len());
+        let table = TableBuilder::new(ui)
+            .striped(true)
+            .columns(Column::auto(), 1);
+
+        table
+            .header(20.0f32, |mut header| {
+                header.col(|ui| {
+                    ui.strong(format!("objects ({type_name}): {}", sequence.len()));
+                });
+            })
+            .body(|body| {
+                body.rows(20.0f32, rows.len(), |mut row| {
+                    let (index, filter_match) = shown.get(rows.start + row.index());
+                    let o = sequence.get(index);
+                    row.col(|ui| {
+                        element_header(format!("{index}: {type_name}"), filter_match).show(
+                            ui,
+                            |ui| {
+                                filter_match
+                                    .render(|| (o as &dyn EguiPresentable).render_component(ui));
+                            },
+                        );
+                    });
+                });
+            });
+    }
+
+    /// Renders the elements of a sequence like [`render_sequence`] does,
+    /// allowing to change, add, remove and reorder those.
+    fn render_sequence_mut<S>(ui: &mut egui::Ui, sequence: &mut S) -> EditResponse
+    where
+        S: Sequence,
+        S::Element: EguiPresentable,
+    {
+        let type_name = std::any::type_name::<S::Element>();
+        let actions = S::Element::element_actions();
+        let page_size = take_page_size();
+        let sequence_id = ui.id().with("sequence");
+        let len = sequence.len();
+        let shown = match_elements(sequence);
+        let rows = render_egui_pager(ui, page_size, shown.len());
+        let table = TableBuilder::new(ui)
+            .striped(true)
+            .columns(Column::auto(), 1);
+
+        let mut edit = None;
+        let mut response = EditResponse::default();
+
+        table
+            .header(20.0f32, |mut header| {
+                header.col(|ui| {
+                    ui.strong(format!("objects ({type_name}): {len}"));
+                });
+            })
+            .body(|body| {
+                body.rows(20.0f32, rows.len(), |mut row| {
+                    let (index, filter_match) = shown.get(rows.start + row.index());
+                    row.col(|ui| {
+                        let row = ui.horizontal(|ui| {
+                            let _ = ui
+                                .dnd_drag_source(
+                                    sequence_id.with(index),
+                                    (sequence_id, index),
+                                    |ui| ui.label("☰"),
+                                )
+                                .response
+                                .on_hover_text("Drag to move the element.");
+
+                            element_header(format!("{index}: {type_name}"), filter_match).show(
+                                ui,
+                                |ui| {
+                                    response |= filter_match.render(|| {
+                                        (sequence.get_mut(index) as &mut dyn EguiPresentable)
+                                            .render_component_mut(ui)
+                                    });
+                                },
+                            );
+
+                            if let Some(element_edit) =
+                                render_element_actions(ui, index, len, actions)
+                            {
+                                edit = Some(element_edit);
+                            }
+                        });
+
+                        if let Some(payload) =
+                            row.response.dnd_release_payload::<(egui::Id, usize)>()
+                        {
+                            let (source_id, from) = *payload;
+                            if source_id == sequence_id {
+                                edit = Some(SequenceEdit::Move { from, to: index });
+                            }
+                        }
+                    });
+                });
+            });
+
+        ui.horizontal(|ui| {
+            if actions.create.is_some()
+                && ui
+                    .button("Add new")
+                    .on_hover_text("Adds a new element to the end.")
+                    .clicked()
+            {
+                edit = Some(SequenceEdit::Push);
+            }
+
+            let clear = ui
+                .button("Clear")
+                .on_hover_text("Removes all the elements.");
+            if clear.clicked() {
+                edit = Some(SequenceEdit::Clear);
+            }
+        });
+
+        if let Some(edit) = edit {
+            response |= EditResponse::changed(edit.apply(sequence, actions));
+        }
+
+        response
+    }
+
+    /// Renders the buttons of the actions on an element of a sequence,
+    /// returning the edit requested.
+    fn render_element_actions<T>(
+        ui: &mut egui::Ui,
+        index: usize,
+        len: usize,
+        actions: ElementActions<T>,
+    ) -> Option<SequenceEdit> {
+        let mut edit = None;
+
+        let up = ui
+            .add_enabled(index > 0, egui::Button::new("⏶"))
+            .on_hover_text("Moves the element up.");
+        if up.clicked() {
+            edit = Some(SequenceEdit::Move {
+                from: index,
+                to: index - 1,
+            });
+        }
+
+        let down = ui
+            .add_enabled(index + 1 < len, egui::Button::new("⏷"))
+            .on_hover_text("Moves the element down.");
+        if down.clicked() {
+            edit = Some(SequenceEdit::Move {
+                from: index,
+                to: index + 1,
+            });
+        }
+
+        if actions.create.is_some()
+            && ui
+                .button("+")
+                .on_hover_text("Inserts a new element before this one.")
+                .clicked()
+        {
+            edit = Some(SequenceEdit::InsertBefore(index));
+        }
+
+        if actions.duplicate.is_some()
+            && ui
+                .button("🗐")
+                .on_hover_text("Duplicates the element.")
+                .clicked()
+        {
+            edit = Some(SequenceEdit::Duplicate(index));
+        }
+
+        if ui
+            .button("X")
+            .on_hover_text("Removes the element.")
+            .clicked()
+        {
+            edit = Some(SequenceEdit::Remove(index));
+        }
+
+        edit
+    }
+
+    impl<T: EguiPresentable> EguiPresentable for Vec<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_sequence(ui, self);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_sequence_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
+        }
+    }
+
+    impl<T: EguiPresentable> EguiPresentable for VecDeque<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_sequence(ui, self);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_sequence_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
+        }
+    }
+
+    /// Renders the editor of the key an entry of a map or a set is
+    /// renamed to, returning the edit once the renaming is confirmed.
+    fn render_renamed_key<K: EguiPresentable>(
+        ui: &mut egui::Ui,
+        editor: &mut EntryEditor<K>,
+    ) -> Option<EntryEdit<K>> {
+        let mut edit = None;
+
+        ui.horizontal(|ui| {
+            ui.label("Rename to:");
+            if let Some((_, key)) = editor.renamed.as_mut() {
+                let _ = (key as &mut dyn EguiPresentable).render_component_mut(ui);
+            }
+
+            if ui.button("Rename").clicked() {
+                edit = editor
+                    .renamed
+                    .take()
+                    .map(|(index, key)| EntryEdit::Rename { index, key });
+            }
+
+            if ui.button("Cancel").clicked() {
+                editor.renamed = None;
+                editor.error = None;
+            }
+        });
+
+        edit
+    }
+
+    /// Renders the buttons of the actions on an entry of a map or a set,
+    /// returning the edit requested.
+    fn render_key_actions<K>(
+        ui: &mut egui::Ui,
+        index: usize,
+        key: &K,
+        editor: &mut EntryEditor<K>,
+        duplicate: Option<fn(&K) -> K>,
+    ) -> Option<EntryEdit<K>> {
+        if let Some(duplicate) = duplicate {
+            if ui.button("✏").on_hover_text("Renames the entry.").clicked() {
+                editor.renamed = Some((index, duplicate(key)));
+                editor.error = None;
+            }
+        }
+
+        ui.button("X")
+            .on_hover_text("Removes the entry.")
+            .clicked()
+            .then_some(EntryEdit::Remove(index))
+    }
+
+    /// Renders the buttons adding an entry to a map or a set and
+    /// removing all the entries, along with the editor of the key of the
+    /// new entry if it is being added. Returns the edit requested.
+    fn render_entry_buttons<K: EguiPresentable>(
+        ui: &mut egui::Ui,
+        editor: &mut EntryEditor<K>,
+        create: Option<fn() -> K>,
+    ) -> Option<EntryEdit<K>> {
+        let mut edit = None;
+
+        ui.horizontal(|ui| {
+            if let Some(key) = editor.new_key.as_mut() {
+                ui.label("New key:");
+                let _ = (key as &mut dyn EguiPresentable).render_component_mut(ui);
+
+                if ui.button("Add").clicked() {
+                    edit = editor.new_key.take().map(EntryEdit::Add);
+                }
+
+                if ui.button("Cancel").clicked() {
+                    editor.new_key = None;
+                    editor.error = None;
+                }
+            } else if let Some(create) = create {
+                let add = ui
+                    .button("Add entry")
+                    .on_hover_text("Adds an entry with a new key.");
+                if add.clicked() {
+                    editor.new_key = Some(create());
+                    editor.error = None;
+                }
+            }
+
+            let clear = ui.button("Clear").on_hover_text("Removes all the entries.");
+            if clear.clicked() {
+                edit = Some(EntryEdit::Clear);
+            }
+        });
+
+        if let Some(error) = editor.error {
+            ui.colored_label(ui.visuals().error_fg_color, error);
+        }
+
+        edit
+    }
+
+    /// Renders the elements of a set shown with the applied filter.
+    fn render_set<'a, T: EguiPresentable + 'a>(
+        ui: &mut egui::Ui,
+        len: usize,
+        elements: impl Iterator<Item = &'a T>,
+    ) {
+        let type_name = std::any::type_name::<T>();
+        let table = TableBuilder::new(ui)
+            .striped(true)
+            .columns(Column::auto(), 1);
+
+        table
+            .header(20.0f32, |mut header| {
+                header.col(|ui| {
+                    ui.strong(format!("objects ({type_name}): {len}"));
+                });
+            })
+            .body(|body| {
+                let elements = match_set_elements(elements);
+                body.rows(20.0f32, elements.len(), |mut row| {
+                    let (index, o, filter_match) = elements[row.index()];
+                    row.col(|ui| {
+                        element_header(format!("{index}: {type_name}"), filter_match)
+                            .show(ui, |ui| (o as &dyn EguiPresentable).render_component(ui));
+                    });
+                });
+            });
+    }
+
+    /// Renders the elements of a set like [`render_set`] does, allowing
+    /// to add, rename and remove those.
+    fn render_set_mut<S>(ui: &mut egui::Ui, set: &mut S) -> EditResponse
+    where
+        S: Entries<Value = ()>,
+        S::Key: EguiPresentable + 'static,
+    {
+        let type_name = std::any::type_name::<S::Key>();
+        let actions = S::Key::element_actions();
+        let set_id = std::ptr::from_ref(set) as usize;
+        let mut editor = EntryEditor::take(egui_frame(ui), set_id);
+        let mut edit = None;
+        let elements = match_set_elements(set.iter_entries().map(|(o, ())| o));
+        let table = TableBuilder::new(ui)
+            .striped(true)
+            .columns(Column::auto(), 1);
+
+        table
+            .header(20.0f32, |mut header| {
+                header.col(|ui| {
+                    ui.strong(format!("objects ({type_name}): {}", set.len()));
+                });
+            })
+            .body(|body| {
+                body.rows(20.0f32, elements.len(), |mut row| {
+                    let (index, o, filter_match) = elements[row.index()];
+                    row.col(|ui| {
+                        if editor
+                            .renamed
+                            .as_ref()
+                            .is_some_and(|(renamed, _)| *renamed == index)
+                        {
+                            if let Some(renamed) = render_renamed_key(ui, &mut editor) {
+                                edit = Some(renamed);
+                            }
+                            return;
+                        }
+
+                        ui.horizontal(|ui| {
+                            element_header(format!("{index}: {type_name}"), filter_match)
+                                .show(ui, |ui| (o as &dyn EguiPresentable).render_component(ui));
+
+                            if let Some(entry_edit) =
+                                render_key_actions(ui, index, o, &mut editor, actions.duplicate)
+                            {
+                                edit = Some(entry_edit);
+                            }
+                        });
+                    });
+                });
+            });
+
+        if let Some(entry_edit) = render_entry_buttons(ui, &mut editor, actions.create) {
+            edit = Some(entry_edit);
+        }
+        let changed = edit.is_some_and(|edit| editor.apply(edit, set, Some(|| ())));
+        editor.store(egui_frame(ui), set_id);
+
+        EditResponse::changed(changed)
+    }
+
+    impl<T: EguiPresentable + Ord + 'static> EguiPresentable for BTreeSet<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_set(ui, self.len(), self.iter());
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_set_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
+    }
+
+    impl<T: EguiPresentable + Eq + Hash + 'static> EguiPresentable for HashSet<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_set(ui, self.len(), self.iter());
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_set_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|o| o.matches_filter(filter))
+        }
+    }
+
+    /// Renders the header of the table the entries of a map are
+    /// rendered in.
+    fn map_table(ui: &mut egui::Ui) -> egui_extras::Table<'_> {
+        TableBuilder::new(ui)
+            .striped(true)
+            .columns(Column::auto(), 3)
+            .header(20.0f32, |mut header| {
+                header.col(|ui| {
+                    ui.strong("#");
+                });
+                header.col(|ui| {
+                    ui.strong("key");
+                });
+                header.col(|ui| {
+                    ui.strong("value");
+                });
+            })
+    }
+
+    /// Renders the entries of a map shown with the applied filter, the
+    /// ones on the page shown and visible within the scrolled area.
+    fn render_map<M>(ui: &mut egui::Ui, map: &M)
+    where
+        M: Entries,
+        M::Key: EguiPresentable,
+        M::Value: EguiPresentable,
+    {
+        let page_size = take_page_size();
+        let shown = match_entries(map);
+        let rows = render_egui_pager(ui, page_size, shown.len());
+
+        map_table(ui).body(|body| {
+            let mut entries = RowCursor::new(map.iter_entries());
+            body.rows(20.0f32, rows.len(), |mut row| {
+                let (i, filter_match) = shown.get(rows.start + row.index());
+                let Some((k, v)) = entries.nth(i) else {
+                    return;
+                };
+                row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
+                row.col(|ui| (k as &dyn EguiPresentable).render_component(ui));
+                row.col(|ui| {
+                    filter_match.render(|| (v as &dyn EguiPresentable).render_component(ui));
+                });
+            });
+        });
+    }
+
+    /// Renders the entries of a map like [`render_map`] does, allowing
+    /// to change the values, to add and remove the entries and to rename
+    /// their keys.
+    fn render_map_mut<M>(ui: &mut egui::Ui, map: &mut M) -> EditResponse
+    where
+        M: MapEntries,
+        M::Key: EguiPresentable + 'static,
+        M::Value: EguiPresentable,
+    {
+        let page_size = take_page_size();
+        let key_actions = M::Key::element_actions();
+        let create_value = M::Value::element_actions().create;
+        let map_id = std::ptr::from_ref(map) as usize;
+        let mut editor = EntryEditor::take(egui_frame(ui), map_id);
+        let mut response = EditResponse::default();
+        let mut edit = None;
+        let shown = match_entries(map);
+        let rows = render_egui_pager(ui, page_size, shown.len());
+
+        map_table(ui).body(|body| {
+            let mut entries = RowCursor::new(map.iter_entries_mut());
+            body.rows(20.0f32, rows.len(), |mut row| {
+                let (i, filter_match) = shown.get(rows.start + row.index());
+                let Some((k, v)) = entries.nth(i) else {
+                    return;
+                };
+                row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
+                row.col(|ui| {
+                    if editor
+                        .renamed
+                        .as_ref()
+                        .is_some_and(|(renamed, _)| *renamed == i)
+                    {
+                        if let Some(renamed) = render_renamed_key(ui, &mut editor) {
+                            edit = Some(renamed);
+                        }
+                        return;
+                    }
+
+                    ui.horizontal(|ui| {
+                        (k as &dyn EguiPresentable).render_component(ui);
+
+                        if let Some(entry_edit) =
+                            render_key_actions(ui, i, k, &mut editor, key_actions.duplicate)
+                        {
+                            edit = Some(entry_edit);
+                        }
+                    });
+                });
+                row.col(|ui| {
+                    response |= filter_match
+                        .render(|| (v as &mut dyn EguiPresentable).render_component_mut(ui));
+                });
+            });
+        });
+
+        let create = key_actions.create.filter(|_| create_value.is_some());
+        if let Some(entry_edit) = render_entry_buttons(ui, &mut editor, create) {
+            edit = Some(entry_edit);
+        }
+        if let Some(edit) = edit {
+            response |= EditResponse::changed(editor.apply(edit, map, create_value));
+        }
+        editor.store(egui_frame(ui), map_id);
+
+        response
+    }
+
+    impl<K: EguiPresentable + Ord + 'static, V: EguiPresentable> EguiPresentable for BTreeMap<K, V> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_map(ui, self);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_map_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter()
+                .any(|(k, v)| k.matches_filter(filter) || v.matches_filter(filter))
+        }
+    }
+
+    impl<K: EguiPresentable + Eq + Hash + 'static, V: EguiPresentable> EguiPresentable
+        for HashMap<K, V>
+    {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            render_map(ui, self);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            render_map_mut(ui, self)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter()
+                .any(|(k, v)| k.matches_filter(filter) || v.matches_filter(filter))
+        }
+    }
+
+    impl<T: EguiPresentable + Default> EguiPresentable for Option<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            let type_name = std::any::type_name::<T>();
+            let mut has_value = self.is_some();
+
+            ui.add_enabled_ui(false, |ui| {
+                let checked = ui
+                    .checkbox(&mut has_value, format!("Has value ({type_name})"))
+                    .changed();
+
+                if checked || has_value {
+                    match self.as_ref() {
+                        Some(value) => (value as &dyn EguiPresentable).render_component(ui),
+                        None => {
+                            let temp = T::default();
+                            (&temp as &dyn EguiPresentable).render_component(ui);
+                        }
+                    }
+                }
+            });
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let type_name = std::any::type_name::<T>();
+            let had_value = self.is_some();
+            let mut has_value = had_value;
+            let checkbox = ui.checkbox(&mut has_value, format!("Has value ({type_name})"));
+            let checked = checkbox.changed();
+            let mut response = EditResponse::from(checkbox);
+            if checked || has_value {
+                if !had_value {
+                    let _ = self.insert(T::default());
+                } else if !has_value {
+                    let _ = self.take();
+                }
+
+                if let Some(value) = self.as_mut() {
+                    response |= (value as &mut dyn EguiPresentable).render_component_mut(ui);
+                }
+            }
+
+            response
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.as_ref()
+                .is_some_and(|value| value.matches_filter(filter))
+        }
+
+        fn element_actions() -> ElementActions<Self> {
+            ElementActions::new().with_default()
+        }
+    }
+
+    /// Renders the elements of an array or a tuple in compact rows of
+    /// a grid. The grid is identified by its position within the
+    /// parent, rather than the address of the value, as the state of
+    /// an egui grid is kept across the frames, while the value may
+    /// move in between.
+    fn render_inline(ui: &mut egui::Ui, elements: &[&dyn EguiPresentable]) {
+        egui::Grid::new(ui.next_auto_id()).show(ui, |ui| {
+            elements.iter().enumerate().for_each(|(i, element)| {
+                ui.push_id(i, |ui| element.render_component(ui));
+                if (i + 1) % INLINE_COLUMNS == 0 {
+                    ui.end_row();
+                }
+            });
+        });
+    }
+
+    /// Renders the elements of an array or a tuple in compact rows of
+    /// a grid, allowing to change those.
+    fn render_inline_mut(
+        ui: &mut egui::Ui,
+        elements: &mut [&mut dyn EguiPresentable],
+    ) -> EditResponse {
+        egui::Grid::new(ui.next_auto_id())
+            .show(ui, |ui| {
+                elements
+                    .iter_mut()
+                    .enumerate()
+                    .map(|(i, element)| {
+                        let response = ui.push_id(i, |ui| element.render_component_mut(ui)).inner;
+                        if (i + 1) % INLINE_COLUMNS == 0 {
+                            ui.end_row();
+                        }
+                        response
+                    })
+                    .collect()
+            })
+            .inner
+    }
+
+    impl<T: EguiPresentable, const N: usize> EguiPresentable for [T; N] {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            let elements: Vec<&dyn EguiPresentable> =
+                self.iter().map(|e| e as &dyn EguiPresentable).collect();
+            render_inline(ui, &elements);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            let mut elements: Vec<&mut dyn EguiPresentable> = self
+                .iter_mut()
+                .map(|e| e as &mut dyn EguiPresentable)
+                .collect();
+            render_inline_mut(ui, &mut elements)
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.iter().any(|e| e.matches_filter(filter))
+        }
+    }
+
+    macro_rules! define_for_tuple {
+        ($($element:ident $index:tt),+) => {
+            impl<$($element: EguiPresentable),+> EguiPresentable for ($($element,)+) {
+                fn render_component(&self, ui: &mut egui::Ui) {
+                    render_inline(ui, &[$(&self.$index as &dyn EguiPresentable),+]);
+                }
+
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+                    render_inline_mut(
+                        ui,
+                        &mut [$(&mut self.$index as &mut dyn EguiPresentable),+],
+                    )
+                }
+
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    false $(|| self.$index.matches_filter(filter))+
+                }
+            }
+        };
+    }
+
+    define_for_tuple!(A 0);
+    define_for_tuple!(A 0, B 1);
+    define_for_tuple!(A 0, B 1, C 2);
+    define_for_tuple!(A 0, B 1, C 2, D 3);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for Box<T> {
         fn render_component(&self, ui: &mut egui::Ui) {
-            let type_name = std::any::type_name::<T>();
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 1);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong(format!("objects ({type_name}): {}", self.len()));
-                    });
-                })
-                .body(|body| {
-                    body.rows(20.0f32, self.len(), |mut row| {
-                        let row_index = row.index();
-                        // ui.separator();
-                        row.col(|ui| {
-                            ui.collapsing(format!("{row_index}: {type_name}"), |ui| {
-                                (&self[row_index] as &dyn EguiPresentable).render_component(ui);
-                            });
-                        });
-                    });
-                });
+            (**self).render_component(ui);
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            // let type_name = std::any::type_name::<T>();
-            // let _id = ui.push_id(&format!("##{self:p}"));
-            // // let filter = ui.input_scalar("Filter index", )
-
-            // if let Some(table) = ui.begin_table_header_with_flags(
-            //     "objects",
-            //     [imgui::TableColumnSetup::new(&format!(
-            //         "objects ({type_name}): {}",
-            //         self.len()
-            //     ))],
-            //     imgui::TableFlags::BORDERS | imgui::TableFlags::ROW_BG,
-            // ) {
-            //     ui.table_next_column();
-
-            //     let mut to_delete = None;
-            //     self.iter_mut().enumerate().for_each(|(i, o)| {
-            //         let mut is_not_deleted = true;
-            //         if ui.collapsing_header_with_close_button(
-            //             format!("{i}: {type_name}##{o:p}"),
-            //             imgui::TreeNodeFlags::FRAMED,
-            //             &mut is_not_deleted,
-            //         ) {
-            //             ui.indent();
-            //             o.render_component_mut(ui, extent);
-            //             ui.unindent();
-            //         }
-
-            //         if !is_not_deleted {
-            //             to_delete = Some(i);
-            //         }
-            //     });
-
-            //     if let Some(index) = to_delete {
-            //         let _ = self.remove(index);
-            //     }
-
-            //     table.end();
-            // }
-            let type_name = std::any::type_name::<T>();
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 1);
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
 
-            let mut to_delete = None;
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            (**self).matches_filter(filter)
+        }
+    }
 
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong(format!("objects ({type_name}): {}", self.len()));
-                    });
-                })
-                .body(|body| {
-                    body.rows(20.0f32, self.len(), |mut row| {
-                        let row_index = row.index();
-                        // ui.separator();
-                        row.col(|ui| {
-                            ui.horizontal(|ui| {
-                                ui.collapsing(format!("{row_index}: {type_name}"), |ui| {
-                                    (&mut self[row_index] as &mut dyn EguiPresentable)
-                                        .render_component_mut(ui);
-                                });
-
-                                if ui.button("X").clicked() {
-                                    to_delete = Some(row_index);
-                                }
-                            });
-                        });
-                    });
-                });
+    macro_rules! define_for_shared_pointer {
+        ($pointer:ident) => {
+            /// The value is only changed in place when this is the
+            /// only pointer to it, otherwise only the values with
+            /// interior mutability can be changed.
+            impl<T: EguiPresentable + ?Sized> EguiPresentable for $pointer<T> {
+                fn render_component(&self, ui: &mut egui::Ui) {
+                    (**self).render_component(ui);
+                }
+
+                fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+                    match $pointer::get_mut(self) {
+                        Some(value) => value.render_component_mut(ui),
+                        None => (**self).render_component_shared(ui),
+                    }
+                }
+
+                fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+                    (**self).render_component_shared(ui)
+                }
 
-            if let Some(index) = to_delete {
-                self.remove(index);
+                fn matches_filter(&self, filter: &Filter) -> bool {
+                    (**self).matches_filter(filter)
+                }
             }
+        };
+    }
+
+    define_for_shared_pointer!(Rc);
+    define_for_shared_pointer!(Arc);
+
+    /// Shows that the value can't be accessed during this frame.
+    fn render_locked(ui: &mut egui::Ui) {
+        ui.weak(LOCKED_PLACEHOLDER);
+    }
+
+    impl<T: EguiPresentable + Copy> EguiPresentable for Cell<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            self.get().render_component(ui);
+        }
 
-            let response = ui.button("Clear").on_hover_text("Clears the vector.");
-            if response.clicked() {
-                self.clear();
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.get_mut().render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            let mut value = self.get();
+            let response = value.render_component_mut(ui);
+            if response.changed {
+                self.set(value);
             }
+            response
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.get().matches_filter(filter)
         }
     }
 
-    impl<T: EguiPresentable + Ord> EguiPresentable for BTreeSet<T> {
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for RefCell<T> {
         fn render_component(&self, ui: &mut egui::Ui) {
-            let type_name = std::any::type_name::<T>();
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 1);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong(format!("objects ({type_name}): {}", self.len()));
-                    });
-                })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter();
-                    body.rows(20.0f32, len, |mut row| {
-                        let row_index = row.index();
-                        // ui.separator();
-                        row.col(|ui| {
-                            ui.collapsing(format!("{row_index}: {type_name}"), |ui| {
-                                if let Some(o) = iter.next() {
-                                    (o as &dyn EguiPresentable).render_component(ui);
-                                }
-                            });
-                        });
-                    });
-                });
+            match self.try_borrow() {
+                Ok(value) => value.render_component(ui),
+                Err(_) => render_locked(ui),
+            }
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            let type_name = std::any::type_name::<T>();
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 1);
-
-            let mut to_delete = None;
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.get_mut().render_component_mut(ui)
+        }
 
-            let len = self.len();
-            let mut iter = self.iter();
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            match self.try_borrow_mut() {
+                Ok(mut value) => value.render_component_mut(ui),
+                Err(_) => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
 
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong(format!("objects ({type_name}): {}", self.len()));
-                    });
-                })
-                .body(move |body| {
-                    body.rows(20.0f32, len, |mut row| {
-                        let row_index = row.index();
-                        row.col(|ui| {
-                            ui.horizontal(|ui| {
-                                ui.collapsing(format!("{row_index}: {type_name}"), |ui| {
-                                    if let Some(o) = iter.next() {
-                                        (o as &dyn EguiPresentable).render_component(ui);
-
-                                        if ui.button("X").clicked() {
-                                            to_delete = Some(o);
-                                        }
-                                    }
-                                });
-                            });
-                        });
-                    });
-                });
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            self.try_borrow()
+                .is_ok_and(|value| value.matches_filter(filter))
+        }
+    }
 
-            if let Some(index) = to_delete {
-                self.remove(index);
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for Mutex<T> {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            match try_acquire(self.try_lock()) {
+                Some(value) => value.render_component(ui),
+                None => render_locked(ui),
             }
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui)
+        }
 
-            let response = ui.button("Clear").on_hover_text("Clears the set.");
-            if response.clicked() {
-                self.clear();
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            match try_acquire(self.try_lock()) {
+                Some(mut value) => value.render_component_mut(ui),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
             }
         }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            try_acquire(self.try_lock()).is_some_and(|value| value.matches_filter(filter))
+        }
     }
 
-    impl<T: EguiPresentable> EguiPresentable for HashSet<T> {
+    impl<T: EguiPresentable + ?Sized> EguiPresentable for RwLock<T> {
         fn render_component(&self, ui: &mut egui::Ui) {
-            // let type_name = std::any::type_name::<T>();
-            // let _id = ui.push_id(&format!("##{self:p}"));
-
-            // if let Some(table) = ui.begin_table_header(
-            //     "objects",
-            //     [imgui::TableColumnSetup::new(&format!(
-            //         "objects ({type_name}): {}",
-            //         self.len()
-            //     ))],
-            // ) {
-            //     ui.table_next_column();
-
-            //     self.iter().for_each(|o| {
-            //         o.render_component(ui, extent);
-            //     });
-
-            //     table.end();
-            // }
-            let type_name = std::any::type_name::<T>();
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 1);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong(format!("objects ({type_name}): {}", self.len()));
-                    });
+            match try_acquire(self.try_read()) {
+                Some(value) => value.render_component(ui),
+                None => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut egui::Ui) -> EditResponse {
+            match try_acquire(self.try_write()) {
+                Some(mut value) => value.render_component_mut(ui),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+
+        fn matches_filter(&self, filter: &Filter) -> bool {
+            try_acquire(self.try_read()).is_some_and(|value| value.matches_filter(filter))
+        }
+    }
+}
+
+#[allow(unused)]
+#[cfg(feature = "egui_backend")]
+pub use egui_backend::*;
+
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use super::*;
+    use crate::tui::TuiUi;
+    use crate::{EditResponse, TuiPresentable};
+
+    /// Renders the elements of a collection as the fields labelled by
+    /// their indices.
+    fn render_elements<'a>(
+        ui: &mut TuiUi<'_>,
+        elements: impl Iterator<Item = &'a (dyn TuiPresentable + 'a)>,
+    ) {
+        elements.enumerate().for_each(|(i, element)| {
+            ui.field(&i.to_string(), |ui| element.render_component(ui));
+        });
+    }
+
+    /// Renders the entries of a map as the fields labelled by their
+    /// indices, with the key and the value nested.
+    fn render_entries<'a>(
+        ui: &mut TuiUi<'_>,
+        entries: impl Iterator<Item = (&'a (dyn TuiPresentable + 'a), &'a (dyn TuiPresentable + 'a))>,
+    ) {
+        entries.enumerate().for_each(|(i, (k, v))| {
+            ui.field(&i.to_string(), |ui| {
+                ui.field("key", |ui| k.render_component(ui));
+                ui.field("value", |ui| v.render_component(ui));
+            });
+        });
+    }
+
+    /// Renders the entries of a map as the fields labelled by their
+    /// indices, allowing to change the values.
+    fn render_entries_mut<'a>(
+        ui: &mut TuiUi<'_>,
+        entries: impl Iterator<
+            Item = (
+                &'a (dyn TuiPresentable + 'a),
+                &'a mut (dyn TuiPresentable + 'a),
+            ),
+        >,
+    ) -> EditResponse {
+        entries
+            .enumerate()
+            .map(|(i, (k, v))| {
+                ui.field(&i.to_string(), |ui| {
+                    ui.field("key", |ui| k.render_component(ui));
+                    ui.field("value", |ui| v.render_component_mut(ui))
                 })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter();
-                    body.rows(20.0f32, len, |mut row| {
-                        let row_index = row.index();
-                        // ui.separator();
-                        row.col(|ui| {
-                            ui.collapsing(format!("{row_index}: {type_name}"), |ui| {
-                                if let Some(o) = iter.next() {
-                                    (o as &dyn EguiPresentable).render_component(ui);
+            })
+            .collect()
+    }
+
+    macro_rules! define_for_sequence {
+        ($sequence: ident) => {
+            impl<T: TuiPresentable> TuiPresentable for $sequence<T> {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    let type_name = std::any::type_name::<T>();
+                    ui.collapsing(&format!("objects ({type_name}): {}", self.len()), |ui| {
+                        render_elements(ui, self.iter().map(|o| o as &dyn TuiPresentable));
+                    });
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    let type_name = std::any::type_name::<T>();
+                    let label = format!("objects ({type_name}): {}", self.len());
+                    ui.collapsing(&label, |ui| {
+                        let mut to_delete = None;
+                        let mut response = EditResponse::default();
+
+                        self.iter_mut().enumerate().for_each(|(i, o)| {
+                            ui.field(&i.to_string(), |ui| {
+                                response |= o.render_component_mut(ui);
+                                if ui.button("Remove") {
+                                    to_delete = Some(i);
                                 }
                             });
                         });
-                    });
-                });
-        }
 
-        // TODO uncomment and fix
-        // fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-        // let type_name = std::any::type_name::<T>();
-        // let _id = ui.push_id(&format!("##{self:p}"));
-
-        // if let Some(table) = ui.begin_table_header(
-        //     "objects",
-        //     [imgui::TableColumnSetup::new(&format!(
-        //         "objects ({type_name}): {}",
-        //         self.len()
-        //     ))],
-        // ) {
-        //     ui.table_next_column();
-
-        //     let mut index = 0usize;
-        //     self.retain(|o| {
-        //         let mut is_not_deleted = true;
-        //         if ui.collapsing_header_with_close_button(
-        //             format!("{index}: {type_name}##{o:p}"),
-        //             imgui::TreeNodeFlags::FRAMED,
-        //             &mut is_not_deleted,
-        //         ) {
-        //             ui.indent();
-        //             o.render_component(ui, extent);
-        //             ui.unindent();
-        //         }
-
-        //         index += 1;
-
-        //         is_not_deleted
-        //     });
-
-        //     table.end();
-        // }
-
-        // if ui.button("Clear") {
-        //     self.clear();
-        // }
-
-        // if ui.is_item_hovered() {
-        //     ui.tooltip_text("Clears the set.");
-        // }
-
-        // EGUI START
-        // let type_name = std::any::type_name::<T>();
-        // let mut table = TableBuilder::new(ui)
-        //     .striped(true)
-        //     .columns(Column::auto(), 1);
-
-        // table
-        //     .header(20.0f32, |mut header| {
-        //         header.col(|ui| {
-        //             ui.strong(format!("objects ({type_name}): {}", self.len()));
-        //         });
-        //     })
-        //     .body(|mut body| {
-        //         let len = self.len();
-        //         let mut iter = self.iter();
-        //         body.rows(20.0f32, len, |row_index, mut row| {
-        //             ui.horizontal(|mut ui| {
-        //                 ui.collapsing(format!("{row_index}: {type_name}"), |mut ui| {
-        //                     if let Some(o) = iter.next() {
-        //                         o.render_component_mut(ui);
-
-        //                         if ui.button("X").clicked() {
-        //                             to_delete = Some(o);
-        //                         }
-        //                     }
-        //                 });
-        //             });
-        //         });
-        //     });
-
-        // let response = ui.button("Clear").on_hover_text("Clears the set.");
-        // if response.clicked() {
-        //     self.clear();
-        // }
-        // }
-    }
-
-    impl<K: EguiPresentable, V: EguiPresentable> EguiPresentable for BTreeMap<K, V> {
-        fn render_component(&self, ui: &mut egui::Ui) {
-            // if let Some(table) = ui.begin_table_header(
-            //     "objects",
-            //     [
-            //         imgui::TableColumnSetup::new("#"),
-            //         imgui::TableColumnSetup::new("key"),
-            //         imgui::TableColumnSetup::new("value"),
-            //     ],
-            // ) {
-            //     self.iter().enumerate().for_each(|(i, (k, v))| {
-            //         ui.table_next_column();
-            //         i.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         k.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         v.render_component(ui, extent);
-            //     });
-
-            //     table.end();
-            // }
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 3);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong("#");
-                    });
-                    header.col(|ui| {
-                        ui.strong("key");
-                    });
-                    header.col(|ui| {
-                        ui.strong("value");
-                    });
-                })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter().enumerate();
-                    body.rows(20.0f32, len, |mut row| {
-                        // ui.separator();
-                        if let Some((i, (k, v))) = iter.next() {
-                            row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (k as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (v as &dyn EguiPresentable).render_component(ui));
+                        if let Some(index) = to_delete {
+                            let _ = self.remove(index);
+                            response |= EditResponse::changed(true);
                         }
-                    });
-                });
-        }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            // let _id = ui.push_id(&format!("##{self:p}"));
-
-            // if let Some(table) = ui.begin_table_header(
-            //     "objects",
-            //     [
-            //         imgui::TableColumnSetup::new("#"),
-            //         imgui::TableColumnSetup::new("key"),
-            //         imgui::TableColumnSetup::new("value"),
-            //     ],
-            // ) {
-            //     self.iter_mut().enumerate().for_each(|(i, (k, v))| {
-            //         ui.table_next_column();
-            //         i.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         k.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         v.render_component_mut(ui, extent);
-            //     });
-
-            //     table.end();
-            // }
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 3);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong("#");
-                    });
-                    header.col(|ui| {
-                        ui.strong("key");
-                    });
-                    header.col(|ui| {
-                        ui.strong("value");
-                    });
-                })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter_mut().enumerate();
-                    body.rows(20.0f32, len, |mut row| {
-                        // ui.separator();
-                        if let Some((i, (k, v))) = iter.next() {
-                            row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (k as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (v as &mut dyn EguiPresentable).render_component_mut(ui));
+                        ui.hint("Removes all the elements.");
+                        if ui.button("Clear") {
+                            self.clear();
+                            response |= EditResponse::changed(true);
                         }
-                    });
-                });
 
-            let response = ui.button("Clear").on_hover_text("Clears the set.");
-            if response.clicked() {
-                self.clear();
+                        response
+                    })
+                }
             }
-        }
+        };
     }
 
-    impl<K: EguiPresentable, V: EguiPresentable> EguiPresentable for HashMap<K, V> {
-        fn render_component(&self, ui: &mut egui::Ui) {
-            // let _id = ui.push_id(&format!("##{self:p}"));
-            // if let Some(table) = ui.begin_table_header(
-            //     "objects",
-            //     [
-            //         imgui::TableColumnSetup::new("#"),
-            //         imgui::TableColumnSetup::new("key"),
-            //         imgui::TableColumnSetup::new("value"),
-            //     ],
-            // ) {
-            //     self.iter().enumerate().for_each(|(i, (k, v))| {
-            //         ui.table_next_column();
-            //         i.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         k.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         v.render_component(ui, extent);
-            //     });
-
-            //     table.end();
-            // }
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 3);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong("#");
-                    });
-                    header.col(|ui| {
-                        ui.strong("key");
-                    });
-                    header.col(|ui| {
-                        ui.strong("value");
-                    });
-                })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter().enumerate();
-                    body.rows(20.0f32, len, |mut row| {
-                        // ui.separator();
-                        if let Some((i, (k, v))) = iter.next() {
-                            row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (k as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (v as &dyn EguiPresentable).render_component(ui));
+    define_for_sequence!(Vec);
+    define_for_sequence!(VecDeque);
+
+    impl<T: TuiPresentable + Ord> TuiPresentable for BTreeSet<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            let type_name = std::any::type_name::<T>();
+            ui.collapsing(&format!("objects ({type_name}): {}", self.len()), |ui| {
+                render_elements(ui, self.iter().map(|o| o as &dyn TuiPresentable));
+            });
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            let type_name = std::any::type_name::<T>();
+            let label = format!("objects ({type_name}): {}", self.len());
+            ui.collapsing(&label, |ui| {
+                let mut to_delete = None;
+
+                self.iter().enumerate().for_each(|(i, o)| {
+                    ui.field(&i.to_string(), |ui| {
+                        o.render_component(ui);
+                        if ui.button("Remove") {
+                            to_delete = Some(i);
                         }
                     });
                 });
-        }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
-            // let _id = ui.push_id(&format!("##{self:p}"));
-
-            // if let Some(table) = ui.begin_table_header(
-            //     "objects",
-            //     [
-            //         imgui::TableColumnSetup::new("#"),
-            //         imgui::TableColumnSetup::new("key"),
-            //         imgui::TableColumnSetup::new("value"),
-            //     ],
-            // ) {
-            //     self.iter_mut().enumerate().for_each(|(i, (k, v))| {
-            //         ui.table_next_column();
-            //         i.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         k.render_component(ui, extent);
-
-            //         ui.table_next_column();
-            //         v.render_component_mut(ui, extent);
-            //     });
-
-            //     table.end();
-            // }
-
-            // if ui.button(format!("Clear##{self:p}")) {
-            //     self.clear();
-            // }
-
-            // if ui.is_item_hovered() {
-            //     ui.tooltip_text("Clears the map.");
-            // }
-            let table = TableBuilder::new(ui)
-                .striped(true)
-                .columns(Column::auto(), 3);
-
-            table
-                .header(20.0f32, |mut header| {
-                    header.col(|ui| {
-                        ui.strong("#");
-                    });
-                    header.col(|ui| {
-                        ui.strong("key");
+                let mut changed = false;
+                if let Some(index) = to_delete {
+                    let mut i = 0;
+                    self.retain(|_| {
+                        i += 1;
+                        i != index + 1
                     });
-                    header.col(|ui| {
-                        ui.strong("value");
+                    changed = true;
+                }
+
+                ui.hint("Clears the set.");
+                if ui.button("Clear") {
+                    self.clear();
+                    changed = true;
+                }
+
+                EditResponse::changed(changed)
+            })
+        }
+    }
+
+    impl<T: TuiPresentable> TuiPresentable for HashSet<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            let type_name = std::any::type_name::<T>();
+            ui.collapsing(&format!("objects ({type_name}): {}", self.len()), |ui| {
+                render_elements(ui, self.iter().map(|o| o as &dyn TuiPresentable));
+            });
+        }
+    }
+
+    macro_rules! define_for_map {
+        ($map:ident $(, $bound:path)?) => {
+            impl<K: TuiPresentable $(+ $bound)?, V: TuiPresentable> TuiPresentable for $map<K, V> {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    ui.collapsing(&format!("entries: {}", self.len()), |ui| {
+                        render_entries(
+                            ui,
+                            self.iter()
+                                .map(|(k, v)| (k as &dyn TuiPresentable, v as &dyn TuiPresentable)),
+                        );
                     });
-                })
-                .body(|body| {
-                    let len = self.len();
-                    let mut iter = self.iter_mut().enumerate();
-                    body.rows(20.0f32, len, |mut row| {
-                        // ui.separator();
-                        if let Some((i, (k, v))) = iter.next() {
-                            row.col(|ui| (&i as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (k as &dyn EguiPresentable).render_component(ui));
-                            row.col(|ui| (v as &mut dyn EguiPresentable).render_component_mut(ui));
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    let label = format!("entries: {}", self.len());
+                    ui.collapsing(&label, |ui| {
+                        let mut response = render_entries_mut(
+                            ui,
+                            self.iter_mut().map(|(k, v)| {
+                                (k as &dyn TuiPresentable, v as &mut dyn TuiPresentable)
+                            }),
+                        );
+
+                        ui.hint("Clears the map.");
+                        if ui.button("Clear") {
+                            self.clear();
+                            response |= EditResponse::changed(true);
                         }
-                    });
-                });
 
-            let response = ui.button("Clear").on_hover_text("Clears the set.");
-            if response.clicked() {
-                self.clear();
+                        response
+                    })
+                }
             }
-        }
+        };
     }
 
-    impl<T: EguiPresentable + Default> EguiPresentable for Option<T> {
-        fn render_component(&self, ui: &mut egui::Ui) {
+    define_for_map!(BTreeMap);
+    define_for_map!(HashMap);
+
+    impl<T: TuiPresentable + Default> TuiPresentable for Option<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
             let type_name = std::any::type_name::<T>();
             let mut has_value = self.is_some();
 
-            ui.add_enabled_ui(false, |ui| {
-                let checked = ui
-                    .checkbox(&mut has_value, format!("Has value ({type_name})"))
-                    .changed();
-
-                if checked || has_value {
-                    match self.as_ref() {
-                        Some(value) => (value as &dyn EguiPresentable).render_component(ui),
-                        None => {
-                            let temp = T::default();
-                            (&temp as &dyn EguiPresentable).render_component(ui);
-                        }
-                    }
+            ui.disabled(true, |ui| {
+                let _ = ui.checkbox(&mut has_value, &format!("Has value ({type_name})"));
+                if let Some(value) = self.as_ref() {
+                    value.render_component(ui);
                 }
             });
         }
 
-        fn render_component_mut(&mut self, ui: &mut egui::Ui) {
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
             let type_name = std::any::type_name::<T>();
-            let had_value = self.is_some();
-            let mut has_value = had_value;
-            let checked = ui
-                .checkbox(&mut has_value, format!("Has value ({type_name})"))
-                .changed();
-            if checked || has_value {
-                if !had_value {
-                    let _ = self.insert(T::default());
-                } else if !has_value {
-                    let _ = self.take();
+            let mut has_value = self.is_some();
+            let mut response = ui.checkbox(&mut has_value, &format!("Has value ({type_name})"));
+            if response.changed {
+                *self = has_value.then(T::default);
+            }
+
+            if let Some(value) = self.as_mut() {
+                response |= value.render_component_mut(ui);
+            }
+
+            response
+        }
+    }
+
+    /// Renders the elements of an array or a tuple allowing to change
+    /// those.
+    fn render_elements_mut(
+        ui: &mut TuiUi<'_>,
+        elements: &mut [&mut dyn TuiPresentable],
+    ) -> EditResponse {
+        elements
+            .iter_mut()
+            .enumerate()
+            .map(|(i, element)| ui.field(&i.to_string(), |ui| element.render_component_mut(ui)))
+            .collect()
+    }
+
+    impl<T: TuiPresentable, const N: usize> TuiPresentable for [T; N] {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            render_elements(ui, self.iter().map(|e| e as &dyn TuiPresentable));
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            let mut elements: Vec<&mut dyn TuiPresentable> = self
+                .iter_mut()
+                .map(|e| e as &mut dyn TuiPresentable)
+                .collect();
+            render_elements_mut(ui, &mut elements)
+        }
+    }
+
+    macro_rules! define_for_tuple {
+        ($($element:ident $index:tt),+) => {
+            impl<$($element: TuiPresentable),+> TuiPresentable for ($($element,)+) {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    render_elements(ui, [$(&self.$index as &dyn TuiPresentable),+].into_iter());
                 }
 
-                if let Some(value) = self.as_mut() {
-                    (value as &mut dyn EguiPresentable).render_component_mut(ui);
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    render_elements_mut(
+                        ui,
+                        &mut [$(&mut self.$index as &mut dyn TuiPresentable),+],
+                    )
+                }
+            }
+        };
+    }
+
+    define_for_tuple!(A 0);
+    define_for_tuple!(A 0, B 1);
+    define_for_tuple!(A 0, B 1, C 2);
+    define_for_tuple!(A 0, B 1, C 2, D 3);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+    define_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for Box<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            (**self).render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            (**self).render_component_shared(ui)
+        }
+    }
+
+    macro_rules! define_for_shared_pointer {
+        ($pointer:ident) => {
+            /// The value is only changed in place when this is the
+            /// only pointer to it, otherwise only the values with
+            /// interior mutability can be changed.
+            impl<T: TuiPresentable + ?Sized> TuiPresentable for $pointer<T> {
+                fn render_component(&self, ui: &mut TuiUi<'_>) {
+                    (**self).render_component(ui);
+                }
+
+                fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    match $pointer::get_mut(self) {
+                        Some(value) => value.render_component_mut(ui),
+                        None => (**self).render_component_shared(ui),
+                    }
+                }
+
+                fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+                    (**self).render_component_shared(ui)
+                }
+            }
+        };
+    }
+
+    define_for_shared_pointer!(Rc);
+    define_for_shared_pointer!(Arc);
+
+    /// Shows that the value can't be accessed during this frame.
+    fn render_locked(ui: &mut TuiUi<'_>) {
+        ui.weak(LOCKED_PLACEHOLDER);
+    }
+
+    impl<T: TuiPresentable + Copy> TuiPresentable for Cell<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            self.get().render_component(ui);
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.get_mut().render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            let mut value = self.get();
+            let response = value.render_component_mut(ui);
+            if response.changed {
+                self.set(value);
+            }
+            response
+        }
+    }
+
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for RefCell<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            match self.try_borrow() {
+                Ok(value) => value.render_component(ui),
+                Err(_) => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.get_mut().render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            match self.try_borrow_mut() {
+                Ok(mut value) => value.render_component_mut(ui),
+                Err(_) => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+    }
+
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for Mutex<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            match try_acquire(self.try_lock()) {
+                Some(value) => value.render_component(ui),
+                None => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            match try_acquire(self.try_lock()) {
+                Some(mut value) => value.render_component_mut(ui),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
+                }
+            }
+        }
+    }
+
+    impl<T: TuiPresentable + ?Sized> TuiPresentable for RwLock<T> {
+        fn render_component(&self, ui: &mut TuiUi<'_>) {
+            match try_acquire(self.try_read()) {
+                Some(value) => value.render_component(ui),
+                None => render_locked(ui),
+            }
+        }
+
+        fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+            self.get_mut()
+                .unwrap_or_else(PoisonError::into_inner)
+                .render_component_mut(ui)
+        }
+
+        fn render_component_shared(&self, ui: &mut TuiUi<'_>) -> EditResponse {
+            match try_acquire(self.try_write()) {
+                Some(mut value) => value.render_component_mut(ui),
+                None => {
+                    render_locked(ui);
+                    EditResponse::default()
                 }
             }
         }
@@ -1017,5 +2467,5 @@ mod egui_backend {
 }
 
 #[allow(unused)]
-#[cfg(feature = "egui_backend")]
-pub use egui_backend::*;
+#[cfg(feature = "ratatui_backend")]
+pub use ratatui_backend::*;
@@ -0,0 +1,1611 @@
+//! Headless harnesses for testing the presentations.
+//!
+//! The harnesses render a value the same way an application would, but
+//! without a window, a renderer or a GPU, so that the inspector panels
+//! can be regression-tested in CI.
+//!
+//! The [`EguiHarness`] captures the widget tree of every frame and
+//! allows to simulate the interactions with the widgets by their
+//! labels:
+//!
+//! ```rust,ignore
+//! let mut harness = EguiHarness::new(Car::default());
+//! harness.drag("speed", 3.0);
+//! harness.click("Reload");
+//! assert_eq!(harness.value().speed, 3.0);
+//! ```
+//!
+//! Dear ImGui doesn't expose the widgets it has submitted, so the
+//! [`ImguiHarness`] reads those back from the glyphs and the frames in
+//! the draw data, and simulates the interactions by moving the mouse
+//! and pressing the keys, with the same methods.
+//!
+//! The [`TuiHarness`] draws into a ratatui
+//! [`ratatui::backend::TestBackend`], allowing to press the keys and to
+//! look at the lines drawn.
+//!
+//! The module requires the `testing` feature.
+
+/// The kind of a captured widget.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum WidgetKind {
+    /// A container of other widgets, like the root of the tree.
+    Group,
+    /// A window.
+    Window,
+    /// A static text.
+    Label,
+    /// A clickable button, including the headers of the collapsible
+    /// sections.
+    Button,
+    /// A checkbox or a toggle switch.
+    Checkbox,
+    /// A radio button.
+    RadioButton,
+    /// A draggable numeric value.
+    DragValue,
+    /// A slider.
+    Slider,
+    /// A text input.
+    TextEdit,
+    /// A combo box.
+    ComboBox,
+    /// Anything else.
+    Other,
+}
+
+/// The value shown by a captured widget.
+#[derive(Debug, Clone, PartialEq)]
+pub enum WidgetValue {
+    /// A numeric value, like the one of a drag value or a slider.
+    Number(f64),
+    /// A text, like the one of a text input.
+    Text(String),
+    /// The state of a checkbox.
+    Bool(bool),
+}
+
+/// A widget captured during a frame, along with its children.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Widget {
+    /// The kind of the widget.
+    pub kind: WidgetKind,
+    /// The label of the widget, or the text of a [`WidgetKind::Label`].
+    pub label: Option<String>,
+    /// The value the widget shows.
+    pub value: Option<WidgetValue>,
+    /// Whether the widget can be interacted with.
+    pub enabled: bool,
+    /// The widgets nested in this one, in the order those were added.
+    pub children: Vec<Widget>,
+}
+
+impl Widget {
+    /// Returns an iterator over this widget and all the nested ones,
+    /// in the order those were added to the user interface.
+    pub fn iter(&self) -> impl Iterator<Item = &Widget> {
+        let mut stack = vec![self];
+        std::iter::from_fn(move || {
+            let widget = stack.pop()?;
+            stack.extend(widget.children.iter().rev());
+            Some(widget)
+        })
+    }
+
+    /// Returns the first widget with the label provided.
+    pub fn find(&self, label: &str) -> Option<&Widget> {
+        self.iter().find(|w| w.label.as_deref() == Some(label))
+    }
+}
+
+impl std::fmt::Display for Widget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        fn write_widget(
+            widget: &Widget,
+            depth: usize,
+            f: &mut std::fmt::Formatter<'_>,
+        ) -> std::fmt::Result {
+            write!(f, "{:indent$}{:?}", "", widget.kind, indent = depth * 2)?;
+            if let Some(label) = &widget.label {
+                write!(f, " {label:?}")?;
+            }
+            match &widget.value {
+                Some(WidgetValue::Number(n)) => write!(f, " = {n}")?,
+                Some(WidgetValue::Text(s)) => write!(f, " = {s:?}")?,
+                Some(WidgetValue::Bool(b)) => write!(f, " = {b}")?,
+                None => {}
+            }
+            if !widget.enabled {
+                write!(f, " (disabled)")?;
+            }
+            writeln!(f)?;
+            widget
+                .children
+                .iter()
+                .try_for_each(|child| write_widget(child, depth + 1, f))
+        }
+
+        write_widget(self, 0, f)
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_backend {
+    use super::{Widget, WidgetKind, WidgetValue};
+    use crate::{EditResponse, EguiPresentable};
+    use egui::accesskit;
+
+    /// The size of the screen the harness renders to.
+    const SCREEN_SIZE: egui::Vec2 = egui::vec2(1024.0, 4096.0);
+
+    /// Renders a value using its [`EguiPresentable`] implementation in
+    /// a headless [`egui::Context`], capturing the widget tree of every
+    /// frame.
+    ///
+    /// All the collapsible sections, popups and tooltips are always
+    /// shown, so the whole presentation is captured at once. The
+    /// widgets are looked up by their labels; the labels of the fields
+    /// of the derived presentations are the headers of their sections,
+    /// so the value widget of a field is the first one following the
+    /// header.
+    ///
+    /// The methods simulating the interactions panic if there is no
+    /// suitable widget, as they are meant to be used in tests.
+    pub struct EguiHarness<T> {
+        context: egui::Context,
+        /// Boxed, as the presentations identify the widgets by the
+        /// addresses of the values, which must not change when the
+        /// harness is moved.
+        value: Box<T>,
+        mutable: bool,
+        /// Whether the value is rendered in its own window instead of
+        /// the central panel.
+        window: bool,
+        time: f64,
+        events: Vec<egui::Event>,
+        tree: Widget,
+        /// The accesskit identifiers of the widgets, in the order of
+        /// [`Widget::iter`].
+        node_ids: Vec<accesskit::NodeId>,
+        /// The screen rectangles of the widgets, in the same order.
+        node_rects: Vec<Option<egui::Rect>>,
+    }
+
+    impl<T: EguiPresentable> EguiHarness<T> {
+        /// Creates a harness rendering the mutable presentation of the
+        /// value, and runs the first frame.
+        pub fn new(value: T) -> Self {
+            Self::with_mutability(value, true, false)
+        }
+
+        /// Creates a harness rendering the immutable presentation of
+        /// the value, and runs the first frame.
+        pub fn new_readonly(value: T) -> Self {
+            Self::with_mutability(value, false, false)
+        }
+
+        /// Creates a harness rendering the mutable presentation of the
+        /// value in its window, with the menu bar, and runs the first
+        /// frame.
+        pub fn new_window(value: T) -> Self {
+            Self::with_mutability(value, true, true)
+        }
+
+        fn with_mutability(value: T, mutable: bool, window: bool) -> Self {
+            let value = Box::new(value);
+            let context = egui::Context::default();
+            context.enable_accesskit();
+            context.memory_mut(|memory| memory.set_everything_is_visible(true));
+
+            let mut harness = Self {
+                context,
+                value,
+                mutable,
+                window,
+                time: 0.0,
+                events: Vec::new(),
+                tree: Widget {
+                    kind: WidgetKind::Group,
+                    label: None,
+                    value: None,
+                    enabled: true,
+                    children: Vec::new(),
+                },
+                node_ids: Vec::new(),
+                node_rects: Vec::new(),
+            };
+            // The first frame is only used to lay the widgets out.
+            let _ = harness.run();
+            let _ = harness.run();
+            harness
+        }
+
+        /// Runs a single frame, processing the simulated input, and
+        /// returns what the user has done to the value.
+        pub fn run(&mut self) -> EditResponse {
+            let input = egui::RawInput {
+                screen_rect: Some(egui::Rect::from_min_size(egui::Pos2::ZERO, SCREEN_SIZE)),
+                time: Some(self.time),
+                events: std::mem::take(&mut self.events),
+                ..Default::default()
+            };
+            self.time += 1.0 / 60.0;
+
+            let mut response = EditResponse::default();
+            let value = self.value.as_mut();
+            let mutable = self.mutable;
+            let window = self.window;
+            // The frame may be run again if a pass is discarded, like the
+            // first pass of a grid, while the edits of every pass count.
+            let output = self.context.clone().run(input, |context| {
+                if window {
+                    response |= value.render_window_mut(context);
+                    return;
+                }
+                egui::CentralPanel::default().show(context, |ui| {
+                    if mutable {
+                        response |= value.render_component_mut(ui);
+                    } else {
+                        value.render_component(ui);
+                    }
+                });
+            });
+
+            if let Some(update) = output.platform_output.accesskit_update {
+                self.capture(update);
+            }
+
+            response
+        }
+
+        /// Returns the widget tree captured during the last frame.
+        pub fn tree(&self) -> &Widget {
+            &self.tree
+        }
+
+        /// Returns the value presented.
+        pub fn value(&self) -> &T {
+            &self.value
+        }
+
+        /// Returns the value presented, allowing to change it between
+        /// the frames.
+        pub fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        /// Returns the value presented, consuming the harness.
+        pub fn into_inner(self) -> T {
+            *self.value
+        }
+
+        /// Returns the context used for rendering.
+        pub fn context(&self) -> &egui::Context {
+            &self.context
+        }
+
+        /// Queues a raw input event for the next frame.
+        pub fn push_event(&mut self, event: egui::Event) {
+            self.events.push(event);
+        }
+
+        /// Clicks the widget with the label provided, like a button,
+        /// a checkbox or a header of a section.
+        pub fn click(&mut self, label: &str) -> EditResponse {
+            let index = self.find_labelled(label, |w| {
+                !matches!(w.kind, WidgetKind::Label | WidgetKind::Group)
+            });
+            self.request(index, accesskit::Action::Click, None);
+            self.settle()
+        }
+
+        /// Clicks the widget with the label provided with the secondary
+        /// mouse button, like the header of a section to open its
+        /// context menu.
+        pub fn secondary_click(&mut self, label: &str) -> EditResponse {
+            let index = self.find_labelled(label, |w| {
+                !matches!(w.kind, WidgetKind::Label | WidgetKind::Group)
+            });
+            let position = self.node_rects[index]
+                .unwrap_or_else(|| panic!("The widget {label:?} has no bounds."))
+                .center();
+            self.events.push(egui::Event::PointerMoved(position));
+            let mut response = self.run();
+
+            for pressed in [true, false] {
+                self.events.push(egui::Event::PointerButton {
+                    pos: position,
+                    button: egui::PointerButton::Secondary,
+                    pressed,
+                    modifiers: egui::Modifiers::NONE,
+                });
+            }
+            response |= self.settle();
+            response
+        }
+
+        /// Toggles the checkbox labelled or following the label
+        /// provided.
+        pub fn toggle(&mut self, label: &str) -> EditResponse {
+            let index = self.find_following(label, |w| w.kind == WidgetKind::Checkbox);
+            self.request(index, accesskit::Action::Click, None);
+            self.settle()
+        }
+
+        /// Drags the numeric value labelled or following the label
+        /// provided to the value.
+        pub fn drag(&mut self, label: &str, value: f64) -> EditResponse {
+            let index = self.find_following(label, |w| {
+                matches!(w.kind, WidgetKind::DragValue | WidgetKind::Slider)
+            });
+            self.request(
+                index,
+                accesskit::Action::SetValue,
+                Some(accesskit::ActionData::NumericValue(value)),
+            );
+            self.settle()
+        }
+
+        /// Replaces the text of the text input labelled or following
+        /// the label provided, as if the user has typed it in and then
+        /// left the input.
+        pub fn type_text(&mut self, label: &str, text: &str) -> EditResponse {
+            let index = self.find_following(label, |w| w.kind == WidgetKind::TextEdit);
+            self.request(index, accesskit::Action::Focus, None);
+            let mut response = self.run();
+
+            self.events.push(egui::Event::Key {
+                key: egui::Key::A,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::COMMAND,
+            });
+            self.events.push(egui::Event::Text(text.to_owned()));
+            response |= self.run();
+
+            self.events.push(egui::Event::Key {
+                key: egui::Key::Escape,
+                physical_key: None,
+                pressed: true,
+                repeat: false,
+                modifiers: egui::Modifiers::NONE,
+            });
+            response | self.settle()
+        }
+
+        /// Selects the option of the combo box labelled or following
+        /// the label provided.
+        pub fn select(&mut self, label: &str, option: &str) -> EditResponse {
+            let combo_box = self.find_following(label, |w| w.kind == WidgetKind::ComboBox);
+            let index = self
+                .tree
+                .iter()
+                .enumerate()
+                .skip(combo_box + 1)
+                .find(|(_, w)| w.kind == WidgetKind::Button && w.label.as_deref() == Some(option))
+                .map(|(i, _)| i)
+                .unwrap_or_else(|| panic!("No option {option:?} in the combo box {label:?}."));
+            self.request(index, accesskit::Action::Click, None);
+            self.settle()
+        }
+
+        /// Runs the frame processing the requests and another one to
+        /// capture the updated widgets.
+        fn settle(&mut self) -> EditResponse {
+            self.run() | self.run()
+        }
+
+        fn request(
+            &mut self,
+            index: usize,
+            action: accesskit::Action,
+            data: Option<accesskit::ActionData>,
+        ) {
+            self.events.push(egui::Event::AccessKitActionRequest(
+                accesskit::ActionRequest {
+                    action,
+                    target: self.node_ids[index],
+                    data,
+                },
+            ));
+        }
+
+        /// Returns the index of the enabled widget with the label
+        /// provided.
+        fn find_labelled(&self, label: &str, predicate: impl Fn(&Widget) -> bool) -> usize {
+            self.tree
+                .iter()
+                .position(|w| w.enabled && w.label.as_deref() == Some(label) && predicate(w))
+                .unwrap_or_else(|| panic!("No widget labelled {label:?}:\n{}", self.tree))
+        }
+
+        /// Returns the index of the first enabled widget matching the
+        /// predicate, which either has the label provided or follows
+        /// the widget with the label provided.
+        fn find_following(&self, label: &str, predicate: impl Fn(&Widget) -> bool) -> usize {
+            let start = self
+                .tree
+                .iter()
+                .position(|w| w.label.as_deref() == Some(label))
+                .unwrap_or_else(|| panic!("No widget labelled {label:?}:\n{}", self.tree));
+            self.tree
+                .iter()
+                .enumerate()
+                .skip(start)
+                .find(|(_, w)| w.enabled && predicate(w))
+                .map(|(i, _)| i)
+                .unwrap_or_else(|| panic!("No suitable widget for {label:?}:\n{}", self.tree))
+        }
+
+        fn capture(&mut self, update: accesskit::TreeUpdate) {
+            let Some(tree) = update.tree else {
+                return;
+            };
+            let nodes: std::collections::HashMap<_, _> = update.nodes.into_iter().collect();
+
+            fn convert(
+                id: accesskit::NodeId,
+                nodes: &std::collections::HashMap<accesskit::NodeId, accesskit::Node>,
+                node_ids: &mut Vec<(accesskit::NodeId, Option<egui::Rect>)>,
+            ) -> Option<Widget> {
+                use accesskit::{Role, Toggled};
+
+                let node = nodes.get(&id)?;
+                let kind = match node.role() {
+                    // The rows of the text inputs.
+                    Role::TextRun => return None,
+                    Role::GenericContainer => WidgetKind::Group,
+                    Role::Window => WidgetKind::Window,
+                    Role::Label => WidgetKind::Label,
+                    Role::Button => WidgetKind::Button,
+                    Role::CheckBox => WidgetKind::Checkbox,
+                    Role::RadioButton => WidgetKind::RadioButton,
+                    Role::SpinButton => WidgetKind::DragValue,
+                    Role::Slider => WidgetKind::Slider,
+                    Role::TextInput | Role::MultilineTextInput | Role::PasswordInput => {
+                        WidgetKind::TextEdit
+                    }
+                    Role::ComboBox => WidgetKind::ComboBox,
+                    _ => WidgetKind::Other,
+                };
+                let (label, value) = match kind {
+                    WidgetKind::Label => (node.value(), None),
+                    WidgetKind::DragValue | WidgetKind::Slider => {
+                        (node.label(), node.numeric_value().map(WidgetValue::Number))
+                    }
+                    WidgetKind::TextEdit => (
+                        node.label(),
+                        Some(WidgetValue::Text(
+                            node.value().unwrap_or_default().to_owned(),
+                        )),
+                    ),
+                    WidgetKind::Checkbox | WidgetKind::RadioButton => (
+                        node.label(),
+                        node.toggled()
+                            .map(|toggled| WidgetValue::Bool(toggled == Toggled::True)),
+                    ),
+                    _ => (node.label(), None),
+                };
+
+                let rect = node.bounds().map(|bounds| {
+                    egui::Rect::from_min_max(
+                        egui::pos2(bounds.x0 as f32, bounds.y0 as f32),
+                        egui::pos2(bounds.x1 as f32, bounds.y1 as f32),
+                    )
+                });
+                node_ids.push((id, rect));
+                let children = node
+                    .children()
+                    .iter()
+                    .filter_map(|child| convert(*child, nodes, node_ids))
+                    .collect();
+
+                Some(Widget {
+                    kind,
+                    label: label.filter(|l| !l.is_empty()).map(ToOwned::to_owned),
+                    value,
+                    enabled: !node.is_disabled(),
+                    children,
+                })
+            }
+
+            let mut node_ids = Vec::new();
+            if let Some(root) = convert(tree.root, &nodes, &mut node_ids) {
+                self.tree = root;
+                (self.node_ids, self.node_rects) = node_ids.into_iter().unzip();
+            }
+        }
+    }
+}
+
+#[allow(unused)]
+#[cfg(feature = "egui_backend")]
+pub use egui_backend::*;
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_backend {
+    use std::collections::HashMap;
+
+    use imgui::StyleColor;
+
+    use super::{Widget, WidgetKind, WidgetValue};
+    use crate::{EditResponse, Extent, ImguiPresentable};
+
+    /// Dear ImGui allows only one context at a time, so the harnesses
+    /// created by the tests running in parallel wait for each other.
+    static CONTEXT_LOCK: std::sync::Mutex<()> = std::sync::Mutex::new(());
+
+    /// The distance between the characters drawn to learn the glyphs.
+    const GLYPH_CELL: f32 = 40.0;
+
+    /// The characters drawn to learn the glyphs, the ones the default
+    /// font has.
+    fn known_characters() -> impl Iterator<Item = char> {
+        (0x21u8..0x7f).chain(0xa1..=0xff).map(char::from)
+    }
+
+    /// How a character of the font is drawn.
+    #[derive(Debug, Copy, Clone)]
+    struct GlyphMetrics {
+        character: char,
+        /// The offset of the quad of the glyph from the position of the
+        /// text.
+        offset: [f32; 2],
+        /// The distance to the next character.
+        advance: f32,
+    }
+
+    /// Returns the draw lists of the frame, if any.
+    fn draw_lists(draw_data: &imgui::DrawData) -> impl Iterator<Item = &imgui::DrawList> {
+        // The list of the draw lists is null when there is nothing to
+        // draw, which the iterator doesn't expect.
+        (draw_data.draw_lists_count() > 0)
+            .then(|| draw_data.draw_lists())
+            .into_iter()
+            .flatten()
+    }
+
+    /// A rectangle, as the minimum and the maximum corners.
+    type Rect = [f32; 4];
+
+    fn contains(outer: Rect, inner: Rect) -> bool {
+        outer[0] <= inner[0] + 0.5
+            && outer[1] <= inner[1] + 0.5
+            && outer[2] >= inner[2] - 0.5
+            && outer[3] >= inner[3] - 0.5
+    }
+
+    fn overlap_vertically(a: Rect, b: Rect) -> bool {
+        a[1] < b[3] && b[1] < a[3]
+    }
+
+    fn center(rect: Rect) -> [f32; 2] {
+        [(rect[0] + rect[2]) / 2.0, (rect[1] + rect[3]) / 2.0]
+    }
+
+    fn area(rect: Rect) -> f32 {
+        (rect[2] - rect[0]) * (rect[3] - rect[1])
+    }
+
+    /// A text drawn in a single line.
+    #[derive(Debug, Clone)]
+    struct Run {
+        text: String,
+        rect: Rect,
+        alpha: u8,
+        /// The order of the first glyph among the primitives drawn.
+        order: usize,
+    }
+
+    /// A primitive other than a glyph.
+    #[derive(Debug, Copy, Clone)]
+    struct Shape {
+        rect: Rect,
+        color: [u8; 4],
+        /// Whether the shape is a filled rectangle, rather than a part
+        /// of a polygon, like an arrow or a check mark.
+        rectangle: bool,
+        /// The center of mass and the area, telling the directions of
+        /// the arrows apart from their antialiased edges.
+        centroid: [f32; 2],
+        area: f32,
+        order: usize,
+    }
+
+    /// The glyphs and the shapes drawn in a window.
+    #[derive(Debug, Default)]
+    struct Primitives {
+        runs: Vec<Run>,
+        shapes: Vec<Shape>,
+    }
+
+    /// Reads the text and the shapes back from the draw data, knowing
+    /// how the glyphs of the font are drawn.
+    struct Reader {
+        /// The glyphs by the texture coordinates of their quads.
+        glyphs: HashMap<[u32; 2], GlyphMetrics>,
+        space: f32,
+    }
+
+    impl Reader {
+        fn read(&self, list: &imgui::DrawList) -> Primitives {
+            let vertices = list.vtx_buffer();
+            let indices = list.idx_buffer();
+            let mut primitives = Primitives::default();
+            let mut order = 0;
+            // The pen position after the last glyph of the current run.
+            let mut pen: Option<[f32; 2]> = None;
+
+            for command in list.commands() {
+                let imgui::DrawCmd::Elements { count, cmd_params } = command else {
+                    continue;
+                };
+                let indices = &indices[cmd_params.idx_offset..cmd_params.idx_offset + count];
+                let vertex = |i: imgui::DrawIdx| vertices[cmd_params.vtx_offset + usize::from(i)];
+                let mut i = 0;
+                while i + 3 <= indices.len() {
+                    order += 1;
+                    let quad = indices.get(i..i + 6).filter(|q| {
+                        let a = q[0];
+                        q[1] == a + 1
+                            && q[2] == a + 2
+                            && q[3] == a
+                            && q[4] == a + 2
+                            && q[5] == a + 3
+                    });
+                    let Some(quad) = quad else {
+                        let corners = [
+                            vertex(indices[i]),
+                            vertex(indices[i + 1]),
+                            vertex(indices[i + 2]),
+                        ];
+                        i += 3;
+                        pen = None;
+                        let xs = corners.map(|v| v.pos[0]);
+                        let ys = corners.map(|v| v.pos[1]);
+                        primitives.shapes.push(Shape {
+                            rect: [
+                                xs.iter().copied().fold(f32::MAX, f32::min),
+                                ys.iter().copied().fold(f32::MAX, f32::min),
+                                xs.iter().copied().fold(f32::MIN, f32::max),
+                                ys.iter().copied().fold(f32::MIN, f32::max),
+                            ],
+                            centroid: [xs.iter().sum::<f32>() / 3.0, ys.iter().sum::<f32>() / 3.0],
+                            area: ((xs[1] - xs[0]) * (ys[2] - ys[0])
+                                - (xs[2] - xs[0]) * (ys[1] - ys[0]))
+                                .abs()
+                                / 2.0,
+                            color: corners
+                                .iter()
+                                .map(|v| v.col)
+                                .max_by_key(|c| c[3])
+                                .unwrap_or_default(),
+                            rectangle: false,
+                            order,
+                        });
+                        continue;
+                    };
+                    i += 6;
+                    let (min, max) = (vertex(quad[0]), vertex(quad[2]));
+                    let rect = [min.pos[0], min.pos[1], max.pos[0], max.pos[1]];
+
+                    let glyph = (min.uv != max.uv)
+                        .then(|| self.glyphs.get(&[min.uv[0].to_bits(), min.uv[1].to_bits()]))
+                        .flatten();
+                    let Some(glyph) = glyph else {
+                        pen = None;
+                        primitives.shapes.push(Shape {
+                            rect,
+                            color: min.col,
+                            rectangle: min.uv == max.uv,
+                            centroid: center(rect),
+                            area: area(rect),
+                            order,
+                        });
+                        continue;
+                    };
+
+                    let position = [rect[0] - glyph.offset[0], rect[1] - glyph.offset[1]];
+                    // The glyphs continue the run if those follow its
+                    // last one on the same line, possibly after a space.
+                    let spaces = pen
+                        .filter(|pen| (pen[1] - position[1]).abs() < 0.5)
+                        .map(|pen| ((position[0] - pen[0]) / self.space).round())
+                        .filter(|spaces| (0.0..=1.0).contains(spaces));
+                    match (spaces, primitives.runs.last_mut()) {
+                        (Some(spaces), Some(run)) => {
+                            if spaces > 0.0 {
+                                run.text.push(' ');
+                            }
+                            run.text.push(glyph.character);
+                            run.rect[2] = position[0] + glyph.advance;
+                        }
+                        _ => primitives.runs.push(Run {
+                            text: glyph.character.to_string(),
+                            rect: [
+                                position[0],
+                                position[1],
+                                position[0] + glyph.advance,
+                                position[1] + glyph.offset[1].max(0.0) + (rect[3] - rect[1]),
+                            ],
+                            alpha: min.col[3],
+                            order,
+                        }),
+                    }
+                    if let Some(run) = primitives.runs.last_mut() {
+                        run.rect[3] = run.rect[3].max(rect[3]);
+                    }
+                    pen = Some([position[0] + glyph.advance, position[1]]);
+                }
+            }
+
+            primitives
+        }
+    }
+
+    /// The colors of the style, to tell the widgets apart by their
+    /// frames.
+    struct Palette {
+        colors: Vec<(StyleColor, [u8; 4])>,
+        disabled_alpha: f32,
+    }
+
+    impl Palette {
+        fn new(style: &imgui::Style) -> Self {
+            let colors = StyleColor::VARIANTS
+                .iter()
+                .map(|&color| {
+                    (
+                        color,
+                        style[color].map(|c| (c.clamp(0.0, 1.0) * 255.0).round() as u8),
+                    )
+                })
+                .collect();
+            Self {
+                colors,
+                disabled_alpha: style.disabled_alpha,
+            }
+        }
+
+        /// Returns `true` if the shape has any of the colors, possibly
+        /// faded as the disabled widgets are.
+        fn is(&self, shape: &Shape, colors: &[StyleColor]) -> bool {
+            let [r, g, b, a] = shape.color;
+            self.colors.iter().any(|(color, rgba)| {
+                let faded = (f32::from(rgba[3]) * self.disabled_alpha).round() as u8;
+                colors.contains(color) && rgba[..3] == [r, g, b] && (rgba[3] == a || faded == a)
+            })
+        }
+
+        /// Returns `true` if the shape is only of a faded color.
+        fn is_faded(&self, shape: &Shape) -> bool {
+            !self.colors.iter().any(|(_, rgba)| *rgba == shape.color)
+        }
+    }
+
+    const BUTTON: &[StyleColor] = &[
+        StyleColor::Button,
+        StyleColor::ButtonHovered,
+        StyleColor::ButtonActive,
+        StyleColor::Header,
+        StyleColor::HeaderHovered,
+        StyleColor::HeaderActive,
+    ];
+    const FRAME: &[StyleColor] = &[
+        StyleColor::FrameBg,
+        StyleColor::FrameBgHovered,
+        StyleColor::FrameBgActive,
+    ];
+    const TITLE: &[StyleColor] = &[
+        StyleColor::TitleBg,
+        StyleColor::TitleBgActive,
+        StyleColor::TitleBgCollapsed,
+    ];
+    const GRAB: &[StyleColor] = &[StyleColor::SliderGrab, StyleColor::SliderGrabActive];
+
+    /// Tells the widgets apart by the shapes drawn around their texts,
+    /// returning those along with their rectangles.
+    fn recognize(
+        primitives: &Primitives,
+        palette: &Palette,
+        inner_spacing: f32,
+    ) -> (Widget, Vec<Rect>) {
+        let mut window = Widget {
+            kind: WidgetKind::Window,
+            label: None,
+            value: None,
+            enabled: true,
+            children: Vec::new(),
+        };
+        let mut rects = Vec::new();
+        let rectangles: Vec<&Shape> = primitives.shapes.iter().filter(|s| s.rectangle).collect();
+        // The arrow buttons draw no text either, so are named after the
+        // directions of their arrows, unlike the ones of the combo boxes
+        // which follow their frames.
+        let arrows: Vec<(&Shape, &Shape)> = rectangles
+            .iter()
+            .filter(|s| {
+                palette.is(s, BUTTON)
+                    && (s.rect[2] - s.rect[0] - (s.rect[3] - s.rect[1])).abs() <= 1.0
+                    && !rectangles.iter().any(|f| {
+                        palette.is(f, FRAME)
+                            && (f.rect[2] - s.rect[0]).abs() < 0.5
+                            && overlap_vertically(f.rect, s.rect)
+                    })
+            })
+            .filter_map(|s| {
+                let arrow = primitives
+                    .shapes
+                    .iter()
+                    .filter(|a| {
+                        !a.rectangle
+                            && a.order > s.order
+                            && palette.is(a, &[StyleColor::Text])
+                            && contains(s.rect, a.rect)
+                    })
+                    .max_by(|a, b| a.area.total_cmp(&b.area))?;
+                Some((*s, arrow))
+            })
+            .collect();
+        let in_arrow_button = |shape: &Shape| {
+            arrows
+                .iter()
+                .any(|(button, _)| contains(button.rect, shape.rect))
+        };
+        // The empty text inputs draw no text, so are found by their
+        // frames, which the checkboxes draw as squares.
+        let mut runs = primitives.runs.clone();
+        runs.extend(
+            rectangles
+                .iter()
+                .filter(|s| {
+                    palette.is(s, FRAME)
+                        && (s.rect[2] - s.rect[0] - (s.rect[3] - s.rect[1])).abs() > 1.0
+                })
+                .filter(|s| !primitives.runs.iter().any(|run| contains(s.rect, run.rect)))
+                .map(|s| Run {
+                    text: String::new(),
+                    rect: [
+                        s.rect[0] + 1.0,
+                        s.rect[1] + 1.0,
+                        s.rect[0] + 2.0,
+                        s.rect[3] - 1.0,
+                    ],
+                    alpha: if palette.is_faded(s) { 0 } else { u8::MAX },
+                    order: s.order + 1,
+                }),
+        );
+        runs.extend(arrows.iter().map(|(button, arrow)| {
+            let [x, y] = center(arrow.rect);
+            let text = match (arrow.centroid[0] - x, arrow.centroid[1] - y) {
+                (dx, dy) if dx.abs() < dy.abs() && dy < 0.0 => "▼",
+                (dx, dy) if dx.abs() < dy.abs() => "▲",
+                (dx, _) if dx < 0.0 => "▶",
+                _ => "◀",
+            };
+            Run {
+                text: text.to_owned(),
+                rect: arrow.rect,
+                alpha: if palette.is_faded(button) { 0 } else { u8::MAX },
+                order: button.order + 1,
+            }
+        }));
+        runs.sort_by_key(|run| run.order);
+
+        // The frame drawn last behind the text, which is the innermost.
+        // The texts may be clipped at the right edges of the frames.
+        let frame_of = |run: &Run| {
+            rectangles
+                .iter()
+                .filter(|s| {
+                    let start = [run.rect[0], run.rect[1], run.rect[0] + 1.0, run.rect[3]];
+                    s.order < run.order && contains(s.rect, start)
+                })
+                .filter(|s| palette.is(s, BUTTON) || palette.is(s, FRAME) || palette.is(s, TITLE))
+                .max_by_key(|s| s.order)
+                .copied()
+        };
+        // The texts drawn one after another in the same frame are a
+        // single value, like the channels of the colors prefixed with
+        // their names and padded with the spaces.
+        let runs = runs.into_iter().fold(Vec::<Run>::new(), |mut runs, run| {
+            let frame = frame_of(&run).filter(|frame| palette.is(frame, FRAME));
+            match runs.last_mut() {
+                Some(last)
+                    if frame.is_some()
+                        && frame.map(|f| f.order) == frame_of(last).map(|f| f.order)
+                        && overlap_vertically(last.rect, run.rect) =>
+                {
+                    last.text.push(' ');
+                    last.text.push_str(&run.text);
+                    last.rect[2] = run.rect[2];
+                }
+                _ => runs.push(run),
+            }
+            runs
+        });
+        let mut consumed = vec![false; runs.len()];
+        // The label ImGui draws to the right of the frame.
+        let label_of = |frame: Rect, consumed: &[bool]| {
+            runs.iter().enumerate().find(|(i, run)| {
+                !consumed[*i]
+                    && overlap_vertically(run.rect, frame)
+                    && run.rect[0] >= frame[2] - 0.5
+                    && run.rect[0] <= frame[2] + inner_spacing + 1.0
+            })
+        };
+
+        for (index, run) in runs.iter().enumerate() {
+            if consumed[index] {
+                continue;
+            }
+            consumed[index] = true;
+            let enabled = run.alpha == u8::MAX;
+            let mut widget = Widget {
+                kind: WidgetKind::Label,
+                label: Some(run.text.clone()),
+                value: None,
+                enabled,
+                children: Vec::new(),
+            };
+            let mut rect = run.rect;
+
+            match frame_of(run) {
+                Some(frame) if palette.is(frame, TITLE) => {
+                    window.label = Some(run.text.clone());
+                    continue;
+                }
+                Some(frame) if palette.is(frame, BUTTON) => {
+                    // The combo boxes draw the arrow as a button next to
+                    // the frame of the value.
+                    widget.kind = WidgetKind::Button;
+                    rect = frame.rect;
+                }
+                Some(frame) => {
+                    let inside = |colors| {
+                        rectangles.iter().any(|s| {
+                            s.order > frame.order
+                                && palette.is(s, colors)
+                                && overlap_vertically(s.rect, frame.rect)
+                                && s.rect[0] >= frame.rect[0] - 0.5
+                                && s.rect[0] <= frame.rect[2] + 0.5
+                                && s.rect[2] <= frame.rect[2] + frame.rect[3] - frame.rect[1]
+                        })
+                    };
+                    let centered = (center(run.rect)[0] - center(frame.rect)[0]).abs() < 1.5;
+                    widget.kind = if inside(GRAB) {
+                        WidgetKind::Slider
+                    } else if inside(&BUTTON[..3]) {
+                        WidgetKind::ComboBox
+                    } else if centered {
+                        WidgetKind::DragValue
+                    } else {
+                        WidgetKind::TextEdit
+                    };
+                    widget.value = Some(match run.text.parse() {
+                        Ok(number) if widget.kind != WidgetKind::TextEdit => {
+                            WidgetValue::Number(number)
+                        }
+                        _ => WidgetValue::Text(run.text.clone()),
+                    });
+                    // The combo box spans its arrow too.
+                    let mut frame_rect = frame.rect;
+                    if let Some(arrow) = rectangles.iter().find(|s| {
+                        s.order > frame.order
+                            && palette.is(s, &BUTTON[..3])
+                            && (s.rect[0] - frame.rect[2]).abs() < 0.5
+                            && overlap_vertically(s.rect, frame.rect)
+                    }) {
+                        frame_rect[2] = arrow.rect[2];
+                    }
+                    widget.label = label_of(frame_rect, &consumed).map(|(i, run)| {
+                        consumed[i] = true;
+                        run.text.clone()
+                    });
+                    rect = frame_rect;
+                }
+                None => {
+                    // The checkboxes draw a square frame to the left of
+                    // the label, and the tree nodes an arrow.
+                    let left = |s: &&Shape| {
+                        s.order < run.order
+                            && overlap_vertically(s.rect, run.rect)
+                            && s.rect[2] <= run.rect[0] + 0.5
+                            && s.rect[2]
+                                >= run.rect[0] - inner_spacing - (run.rect[3] - run.rect[1])
+                    };
+                    if let Some(square) = rectangles
+                        .iter()
+                        .find(|s| left(s) && palette.is(s, FRAME) && !in_arrow_button(s))
+                    {
+                        let checked = primitives.shapes.iter().any(|s| {
+                            s.order > square.order
+                                && palette.is(s, &[StyleColor::CheckMark])
+                                && contains(square.rect, s.rect)
+                        });
+                        widget.kind = WidgetKind::Checkbox;
+                        widget.value = Some(WidgetValue::Bool(checked));
+                        rect = square.rect;
+                    } else if primitives.shapes.iter().any(|s| {
+                        !s.rectangle
+                            && left(&s)
+                            && palette.is(s, &[StyleColor::Text])
+                            && !in_arrow_button(s)
+                    }) {
+                        widget.kind = WidgetKind::Button;
+                    }
+                }
+            }
+
+            window.children.push(widget);
+            rects.push(rect);
+        }
+
+        (window, rects)
+    }
+
+    /// Renders a value using its [`ImguiPresentable`] implementation in
+    /// a headless [`imgui::Context`], with no renderer attached, reading
+    /// the widgets back from the draw data of every frame.
+    ///
+    /// The texts are read from the glyphs drawn, and the kinds of the
+    /// widgets are told apart by the colors of the frames drawn around
+    /// or next to them, so the style must not be changed. Every window
+    /// drawn, including the popups, is a [`WidgetKind::Window`] of the
+    /// tree, titled with the text of its title bar. The labels ImGui
+    /// draws to the right of the frames are the labels of the widgets,
+    /// like `f32` of a drag value of a number, and the texts in the
+    /// frames are their values. The arrow buttons are labelled after
+    /// the directions of their arrows, like `▶`. The fields of the
+    /// derived presentations are the headers of their sections or the
+    /// names of their rows, so the value widget of a field is the one
+    /// on its row, or else the first one below the header.
+    ///
+    /// The methods simulating the interactions move the mouse over the
+    /// widgets and press the keys, and panic if there is no suitable
+    /// widget, as they are meant to be used in tests.
+    ///
+    /// Only one harness may exist at a time, the others block until it
+    /// is dropped. No other ImGui context may be created while the
+    /// harness exists.
+    pub struct ImguiHarness<T> {
+        context: imgui::Context,
+        /// Boxed, as the presentations identify the widgets by the
+        /// addresses of the values, which must not change when the
+        /// harness is moved.
+        value: Box<T>,
+        extent: Extent,
+        reader: Reader,
+        palette: Palette,
+        tree: Widget,
+        /// The screen rectangles of the widgets, in the order of
+        /// [`Widget::iter`].
+        rects: Vec<Option<Rect>>,
+        // Must be dropped after the context.
+        _lock: std::sync::MutexGuard<'static, ()>,
+    }
+
+    impl<T: ImguiPresentable> ImguiHarness<T> {
+        /// Creates a harness for the value, and runs the first frames
+        /// of the mutable presentation.
+        pub fn new(value: T) -> Self {
+            let lock = CONTEXT_LOCK
+                .lock()
+                .unwrap_or_else(|poisoned| poisoned.into_inner());
+
+            let extent = Extent {
+                width: 1024,
+                height: 4096,
+            };
+            let mut context = imgui::Context::create();
+            context.set_ini_filename(None);
+            context.io_mut().display_size = [f32::from(extent.width), f32::from(extent.height)];
+            // Every event is processed in the frame it is sent before.
+            context.io_mut().config_input_trickle_event_queue = false;
+            let _ = context.fonts().build_rgba32_texture();
+            // The windows fit their contents during their first frames
+            // only, and the widgets take a part of the width of the
+            // windows, so those are made large enough to show the
+            // labels and the widgets added later.
+            context.style_mut().window_min_size = [
+                f32::from(extent.width) / 2.0,
+                f32::from(extent.height) / 2.0,
+            ];
+
+            let reader = Self::learn_glyphs(&mut context);
+            let palette = Palette::new(context.style());
+            let mut harness = Self {
+                context,
+                value: Box::new(value),
+                extent,
+                reader,
+                palette,
+                tree: Widget {
+                    kind: WidgetKind::Group,
+                    label: None,
+                    value: None,
+                    enabled: true,
+                    children: Vec::new(),
+                },
+                rects: Vec::new(),
+                _lock: lock,
+            };
+            // The new windows are only laid out during their first
+            // frame.
+            let _ = harness.run();
+            let _ = harness.run();
+            harness
+        }
+
+        /// Draws every character of the font at the known positions,
+        /// to recognize the glyphs drawn by the presentations.
+        fn learn_glyphs(context: &mut imgui::Context) -> Reader {
+            let columns = 16;
+            let ui = context.new_frame();
+            let space = ui.calc_text_size(" ")[0];
+            let mut metrics = Vec::new();
+            for (i, character) in known_characters().enumerate() {
+                let position = [
+                    (i % columns) as f32 * GLYPH_CELL,
+                    (i / columns) as f32 * GLYPH_CELL,
+                ];
+                let text = character.to_string();
+                ui.get_foreground_draw_list()
+                    .add_text(position, imgui::ImColor32::WHITE, &text);
+                metrics.push((position, character, ui.calc_text_size(&text)[0]));
+            }
+
+            let mut glyphs = HashMap::new();
+            for list in draw_lists(context.render()) {
+                let vertices = list.vtx_buffer();
+                for quad in list.idx_buffer().chunks_exact(6) {
+                    let (min, max) = (
+                        vertices[usize::from(quad[0])],
+                        vertices[usize::from(quad[2])],
+                    );
+                    let cell = (min.pos[1] / GLYPH_CELL) as usize * columns
+                        + (min.pos[0] / GLYPH_CELL) as usize;
+                    let Some(&(position, character, advance)) = metrics.get(cell) else {
+                        continue;
+                    };
+                    // The characters the font doesn't have are drawn
+                    // as the same fallback glyph, which is only known
+                    // as the first of those.
+                    let _ = glyphs
+                        .entry([min.uv[0].to_bits(), min.uv[1].to_bits()])
+                        .or_insert(GlyphMetrics {
+                            character,
+                            offset: [min.pos[0] - position[0], min.pos[1] - position[1]],
+                            advance,
+                        });
+                    debug_assert!(min.uv != max.uv);
+                }
+            }
+
+            Reader { glyphs, space }
+        }
+
+        /// Runs a single frame of the mutable presentation and returns
+        /// what the user has done to the value.
+        pub fn run(&mut self) -> EditResponse {
+            self.context.io_mut().delta_time = 1.0 / 60.0;
+            let ui = self.context.new_frame();
+            let response = self.value.as_mut().render_window_mut(ui, self.extent);
+            self.capture();
+            response
+        }
+
+        /// Runs a single frame of the immutable presentation.
+        pub fn run_readonly(&mut self) {
+            self.context.io_mut().delta_time = 1.0 / 60.0;
+            let ui = self.context.new_frame();
+            self.value.as_ref().render_window(ui, self.extent);
+            self.capture();
+        }
+
+        /// Returns the widget tree read back from the last frame.
+        pub fn tree(&self) -> &Widget {
+            &self.tree
+        }
+
+        /// Returns the input and output state of the context, allowing
+        /// to simulate the input for the next frame.
+        pub fn io_mut(&mut self) -> &mut imgui::Io {
+            self.context.io_mut()
+        }
+
+        /// Returns the value presented.
+        pub fn value(&self) -> &T {
+            &self.value
+        }
+
+        /// Returns the value presented, allowing to change it between
+        /// the frames.
+        pub fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        /// Returns the value presented, consuming the harness.
+        pub fn into_inner(self) -> T {
+            *self.value
+        }
+
+        /// Clicks the widget with the label provided, like a button,
+        /// a checkbox or a header of a section.
+        pub fn click(&mut self, label: &str) -> EditResponse {
+            let index = self.find_labelled(label, |w| {
+                !matches!(w.kind, WidgetKind::Group | WidgetKind::Window)
+            });
+            let response = self.click_at(index, imgui::MouseButton::Left, false);
+            response | self.settle()
+        }
+
+        /// Clicks the widget with the label provided with the secondary
+        /// mouse button, like the header of a section to open its
+        /// context menu.
+        pub fn secondary_click(&mut self, label: &str) -> EditResponse {
+            let index = self.find_labelled(label, |w| {
+                !matches!(w.kind, WidgetKind::Group | WidgetKind::Window)
+            });
+            let response = self.click_at(index, imgui::MouseButton::Right, false);
+            response | self.settle()
+        }
+
+        /// Toggles the checkbox labelled or following the label
+        /// provided.
+        pub fn toggle(&mut self, label: &str) -> EditResponse {
+            let index = self.find_following(label, |w| w.kind == WidgetKind::Checkbox);
+            let response = self.click_at(index, imgui::MouseButton::Left, false);
+            response | self.settle()
+        }
+
+        /// Sets the value of the drag value or the slider labelled or
+        /// following the label provided, by typing it in.
+        pub fn drag(&mut self, label: &str, value: f64) -> EditResponse {
+            let index = self.find_following(label, |w| {
+                matches!(w.kind, WidgetKind::DragValue | WidgetKind::Slider)
+            });
+            // The drag values and the sliders are typed in after a
+            // click with the control key held.
+            let response = self.click_at(index, imgui::MouseButton::Left, true);
+            response | self.enter_text(&value.to_string())
+        }
+
+        /// Replaces the text of the text input labelled or following the
+        /// label provided.
+        pub fn type_text(&mut self, label: &str, text: &str) -> EditResponse {
+            let index = self.find_following(label, |w| w.kind == WidgetKind::TextEdit);
+            let response = self.click_at(index, imgui::MouseButton::Left, false);
+            response | self.enter_text(text)
+        }
+
+        /// Selects the option of the combo box labelled or following
+        /// the label provided.
+        pub fn select(&mut self, label: &str, option: &str) -> EditResponse {
+            let combo_box = self.find_following(label, |w| w.kind == WidgetKind::ComboBox);
+            // The options are shown in a popup window, which is only
+            // drawn after it is laid out during its first frame.
+            let mut response =
+                self.click_at(combo_box, imgui::MouseButton::Left, false) | self.run();
+            let index = self
+                .tree
+                .iter()
+                .enumerate()
+                .skip(combo_box + 1)
+                .find(|(_, w)| w.label.as_deref() == Some(option))
+                .map(|(i, _)| i)
+                .unwrap_or_else(|| {
+                    panic!(
+                        "No option {option:?} in the combo box {label:?}:\n{}",
+                        self.tree
+                    )
+                });
+            response |= self.click_at(index, imgui::MouseButton::Left, false);
+            response | self.settle()
+        }
+
+        /// Moves the mouse away and runs the frames to capture the
+        /// updated widgets, which aren't highlighted then.
+        fn settle(&mut self) -> EditResponse {
+            self.io_mut().add_mouse_pos_event([-f32::MAX, -f32::MAX]);
+            self.run() | self.run()
+        }
+
+        /// Moves the mouse over the widget and clicks it with the button,
+        /// possibly with the control key held.
+        fn click_at(
+            &mut self,
+            index: usize,
+            button: imgui::MouseButton,
+            control: bool,
+        ) -> EditResponse {
+            let rect =
+                self.rects[index].unwrap_or_else(|| panic!("The widget {index} has no bounds."));
+            let position = center(rect);
+
+            self.io_mut().add_mouse_pos_event(position);
+            let mut response = self.run();
+            if control {
+                self.io_mut().add_key_event(imgui::Key::ModCtrl, true);
+            }
+            self.io_mut().add_mouse_button_event(button, true);
+            response |= self.run();
+            self.io_mut().add_mouse_button_event(button, false);
+            if control {
+                self.io_mut().add_key_event(imgui::Key::ModCtrl, false);
+            }
+            response | self.run()
+        }
+
+        /// Replaces the text of the active text input and presses the
+        /// enter key.
+        fn enter_text(&mut self, text: &str) -> EditResponse {
+            self.io_mut().add_key_event(imgui::Key::ModCtrl, true);
+            self.io_mut().add_key_event(imgui::Key::A, true);
+            let mut response = self.run();
+            self.io_mut().add_key_event(imgui::Key::A, false);
+            self.io_mut().add_key_event(imgui::Key::ModCtrl, false);
+            response |= self.run();
+
+            for character in text.chars() {
+                self.io_mut().add_input_character(character);
+            }
+            response |= self.run();
+
+            self.io_mut().add_key_event(imgui::Key::Enter, true);
+            response |= self.run();
+            self.io_mut().add_key_event(imgui::Key::Enter, false);
+            response | self.settle()
+        }
+
+        /// Returns the index of the enabled widget with the label
+        /// provided.
+        fn find_labelled(&self, label: &str, predicate: impl Fn(&Widget) -> bool) -> usize {
+            self.tree
+                .iter()
+                .position(|w| w.enabled && w.label.as_deref() == Some(label) && predicate(w))
+                .unwrap_or_else(|| panic!("No widget labelled {label:?}:\n{}", self.tree))
+        }
+
+        /// Returns the index of the enabled widget matching the
+        /// predicate, which either has the label provided or is the
+        /// value of the widget with the label provided.
+        ///
+        /// The value is the nearest widget to the right of the label on
+        /// its row, or else the first one below the label, as long as it
+        /// isn't on the row of another label. The tables are drawn
+        /// column by column, so the order of the widgets alone doesn't
+        /// tell the rows apart.
+        fn find_following(&self, label: &str, predicate: impl Fn(&Widget) -> bool) -> usize {
+            let widgets: Vec<(usize, &Widget, Rect)> = self
+                .tree
+                .iter()
+                .zip(&self.rects)
+                .enumerate()
+                .filter_map(|(i, (w, rect))| Some((i, w, (*rect)?)))
+                .collect();
+            let (start, labelled, label_rect) = widgets
+                .iter()
+                .find(|(_, w, _)| w.label.as_deref() == Some(label))
+                .copied()
+                .unwrap_or_else(|| panic!("No widget labelled {label:?}:\n{}", self.tree));
+            if labelled.enabled && predicate(labelled) {
+                return start;
+            }
+
+            let candidates = || {
+                widgets
+                    .iter()
+                    .filter(|(i, w, _)| *i != start && w.enabled && predicate(w))
+            };
+            let on_row = candidates()
+                .filter(|(_, _, rect)| {
+                    overlap_vertically(*rect, label_rect) && rect[0] >= label_rect[2] - 0.5
+                })
+                .min_by(|a, b| a.2[0].total_cmp(&b.2[0]));
+            if let Some((index, _, _)) = on_row {
+                return *index;
+            }
+
+            let (index, _, rect) = candidates()
+                .filter(|(i, _, rect)| *i > start && rect[1] >= label_rect[3] - 0.5)
+                .min_by(|a, b| a.2[1].total_cmp(&b.2[1]).then(a.0.cmp(&b.0)))
+                .unwrap_or_else(|| panic!("No suitable widget for {label:?}:\n{}", self.tree));
+            // The widgets on the rows of the other labels are theirs.
+            if let Some((_, other, _)) = widgets.iter().find(|(i, w, other)| {
+                i != index
+                    && w.kind == WidgetKind::Label
+                    && overlap_vertically(*other, *rect)
+                    && other[2] <= rect[0] + 0.5
+            }) {
+                panic!(
+                    "The widget for {label:?} is on the row of {:?}:\n{}",
+                    other.label, self.tree
+                );
+            }
+            *index
+        }
+
+        /// Renders the frame and reads the widgets back from the draw
+        /// data, one window per draw list.
+        fn capture(&mut self) {
+            let inner_spacing = self.context.style().item_inner_spacing[0];
+            let draw_data = self.context.render();
+            let mut root = Widget {
+                kind: WidgetKind::Group,
+                label: None,
+                value: None,
+                enabled: true,
+                children: Vec::new(),
+            };
+            let mut rects = vec![None];
+            for list in draw_lists(draw_data) {
+                let primitives = self.reader.read(list);
+                if primitives.runs.is_empty() {
+                    continue;
+                }
+                let (window, window_rects) = recognize(&primitives, &self.palette, inner_spacing);
+                root.children.push(window);
+                rects.push(None);
+                rects.extend(window_rects.into_iter().map(Some));
+            }
+            self.tree = root;
+            self.rects = rects;
+        }
+    }
+}
+
+#[allow(unused)]
+#[cfg(feature = "imgui_backend")]
+pub use imgui_backend::*;
+
+#[cfg(feature = "ratatui_backend")]
+mod ratatui_backend {
+    use ratatui::backend::TestBackend;
+    use ratatui::style::Modifier;
+    use ratatui::Terminal;
+
+    use crate::tui::{TuiKey, TuiState};
+    use crate::{EditResponse, TuiPresentable};
+
+    /// Renders a value using its [`TuiPresentable`] implementation into
+    /// a [`TestBackend`] of a fixed size.
+    pub struct TuiHarness<T> {
+        terminal: Terminal<TestBackend>,
+        state: TuiState,
+        value: T,
+    }
+
+    impl<T: TuiPresentable> TuiHarness<T> {
+        /// Creates a harness for the value, drawn into a terminal of
+        /// the size provided, and runs the first frame.
+        pub fn new(value: T, width: u16, height: u16) -> Self {
+            let terminal = Terminal::new(TestBackend::new(width, height))
+                .expect("The test backend can always be created.");
+            let mut harness = Self {
+                terminal,
+                state: TuiState::default(),
+                value,
+            };
+            let _ = harness.run();
+            harness
+        }
+
+        /// Runs a single frame of the mutable presentation and returns
+        /// what the user has done to the value.
+        pub fn run(&mut self) -> EditResponse {
+            let mut response = EditResponse::default();
+            let _ = self
+                .terminal
+                .draw(|frame| {
+                    response = self
+                        .value
+                        .render_window_mut(frame, frame.area(), &mut self.state);
+                })
+                .expect("The test backend doesn't fail to draw.");
+            response
+        }
+
+        /// Runs a single frame of the immutable presentation.
+        pub fn run_readonly(&mut self) {
+            let _ = self
+                .terminal
+                .draw(|frame| {
+                    self.value
+                        .render_window(frame, frame.area(), &mut self.state)
+                })
+                .expect("The test backend doesn't fail to draw.");
+        }
+
+        /// Presses the key and runs the frame handling it.
+        pub fn press(&mut self, key: TuiKey) -> EditResponse {
+            self.state.push_key(key);
+            let mut response = EditResponse::default();
+            while self.state.has_pending_keys() {
+                response |= self.run();
+            }
+            response
+        }
+
+        /// Presses the keys one after another, running a frame for
+        /// each.
+        pub fn press_all(&mut self, keys: impl IntoIterator<Item = TuiKey>) -> EditResponse {
+            keys.into_iter().map(|key| self.press(key)).collect()
+        }
+
+        /// Moves the focus to the first row containing the text,
+        /// returning `false` if there is no such row.
+        pub fn focus(&mut self, text: &str) -> bool {
+            let _ = self.press(TuiKey::Home);
+            loop {
+                if self.focused_line().is_some_and(|line| line.contains(text)) {
+                    return true;
+                }
+                let focus = self.state.focus();
+                let _ = self.press(TuiKey::Down);
+                if self.state.focus() == focus {
+                    return false;
+                }
+            }
+        }
+
+        /// Types the text into the focused row, which has to be edited
+        /// already.
+        pub fn type_text(&mut self, text: &str) -> EditResponse {
+            self.press_all(text.chars().map(TuiKey::Char))
+        }
+
+        /// Returns the lines drawn during the last frame, with the
+        /// trailing spaces trimmed.
+        pub fn lines(&self) -> Vec<String> {
+            let buffer = self.terminal.backend().buffer();
+            (0..buffer.area.height)
+                .map(|y| {
+                    let line: String = (0..buffer.area.width)
+                        .filter_map(|x| buffer.cell((x, y)))
+                        .map(|cell| cell.symbol())
+                        .collect();
+                    line.trim_end().to_owned()
+                })
+                .collect()
+        }
+
+        /// Returns the line of the focused row drawn during the last
+        /// frame.
+        pub fn focused_line(&self) -> Option<String> {
+            let buffer = self.terminal.backend().buffer();
+            let y = (0..buffer.area.height).find(|&y| {
+                (0..buffer.area.width)
+                    .filter_map(|x| buffer.cell((x, y)))
+                    .any(|cell| cell.modifier.contains(Modifier::REVERSED))
+            })?;
+            self.lines().into_iter().nth(usize::from(y))
+        }
+
+        /// Returns the state of the presentation.
+        pub fn state(&self) -> &TuiState {
+            &self.state
+        }
+
+        /// Returns the value presented.
+        pub fn value(&self) -> &T {
+            &self.value
+        }
+
+        /// Returns the value presented, allowing to change it between
+        /// the frames.
+        pub fn value_mut(&mut self) -> &mut T {
+            &mut self.value
+        }
+
+        /// Returns the value presented, consuming the harness.
+        pub fn into_inner(self) -> T {
+            self.value
+        }
+    }
+}
+
+#[allow(unused)]
+#[cfg(feature = "ratatui_backend")]
+pub use ratatui_backend::*;
@@ -0,0 +1,1091 @@
+//! The immediate mode user interface for the terminals, which the
+//! [`crate::TuiPresentable`] trait renders the values with.
+//!
+//! Unlike ImGui and egui, [`ratatui`] only draws the widgets, so this
+//! module provides the rest. Every value is presented as one or more
+//! rows of a tree, which the user navigates and edits with the
+//! keyboard:
+//!
+//! - `Up`, `Down`, `PageUp`, `PageDown`, `Home` and `End` move the
+//!   focus between the rows.
+//! - `Left` and `Right` change the focused number or choice, and close
+//!   or open the focused tree node.
+//! - `Enter` or `Space` toggle the checkboxes and the tree nodes, push
+//!   the buttons and start editing the numbers and the text. `Enter`
+//!   finishes the editing, while `Esc` cancels it.
+//!
+//! The keys are passed in via [`TuiState::push_key`], and a single key
+//! is handled per frame, so the presentation should be drawn again
+//! while [`TuiState::has_pending_keys`] returns `true`.
+//!
+//! ```rust,ignore
+//! let mut state = TuiState::default();
+//! loop {
+//!     terminal.draw(|frame| {
+//!         let _ = object.render_window_mut(frame, frame.area(), &mut state);
+//!     })?;
+//!     if !state.has_pending_keys() {
+//!         state.push_key(read_key()?);
+//!     }
+//! }
+//! ```
+
+use std::any::Any;
+use std::collections::{HashMap, VecDeque};
+
+use ratatui::layout::Rect;
+use ratatui::style::{Color, Modifier, Style};
+use ratatui::text::{Line, Span};
+use ratatui::widgets::{Block, Paragraph};
+use ratatui::Frame;
+
+use crate::reflect::{FieldInfo, Reflect, ReflectMut, ReflectRef, ValueMut, ValueRef};
+use crate::EditResponse;
+
+/// A key pressed by the user. The keys are backend-agnostic, so any
+/// terminal library may be used to read those.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash)]
+pub enum TuiKey {
+    /// Moves the focus to the previous row.
+    Up,
+    /// Moves the focus to the next row.
+    Down,
+    /// Decreases the focused value or closes the focused tree node.
+    Left,
+    /// Increases the focused value or opens the focused tree node.
+    Right,
+    /// Moves the focus a page up.
+    PageUp,
+    /// Moves the focus a page down.
+    PageDown,
+    /// Moves the focus to the first row.
+    Home,
+    /// Moves the focus to the last row.
+    End,
+    /// Activates the focused row, or finishes editing it.
+    Enter,
+    /// Cancels editing the focused row.
+    Esc,
+    /// Removes the last character of the text being edited.
+    Backspace,
+    /// Types a character into the text being edited. The space
+    /// activates the focused row when nothing is being edited.
+    Char(char),
+    /// Reverts the last edit of a value presented with its history
+    /// (see [`crate::history::EditHistory`]).
+    Undo,
+    /// Applies the last reverted edit of a value presented with its
+    /// history again.
+    Redo,
+}
+
+impl TuiKey {
+    /// Returns `true` if the key moves the focus between the rows.
+    fn is_navigation(self) -> bool {
+        matches!(
+            self,
+            Self::Up | Self::Down | Self::PageUp | Self::PageDown | Self::Home | Self::End
+        )
+    }
+
+    /// Returns `true` if the key activates the focused row.
+    fn is_activation(self) -> bool {
+        matches!(self, Self::Enter | Self::Char(' '))
+    }
+}
+
+/// The state of a presentation which is kept between the frames: the
+/// focused row, the open tree nodes and the keys not handled yet.
+#[derive(Default)]
+pub struct TuiState {
+    /// The index of the focused row.
+    focus: usize,
+    /// The index of the first row shown.
+    scroll: usize,
+    /// The number of rows shown during the last frame.
+    page: usize,
+    /// The keys not handled yet.
+    keys: VecDeque<TuiKey>,
+    /// Whether the tree nodes are open, by their paths.
+    open: HashMap<String, bool>,
+    /// The text of the focused row being edited.
+    editing: Option<String>,
+    /// The state of the widgets, by their paths.
+    data: HashMap<String, Box<dyn Any>>,
+    /// The number of the frames drawn.
+    frames: u64,
+}
+
+impl std::fmt::Debug for TuiState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("TuiState")
+            .field("focus", &self.focus)
+            .field("scroll", &self.scroll)
+            .field("keys", &self.keys)
+            .field("editing", &self.editing)
+            .finish_non_exhaustive()
+    }
+}
+
+impl TuiState {
+    /// Queues a key to be handled during the next frames.
+    pub fn push_key(&mut self, key: TuiKey) {
+        self.keys.push_back(key);
+    }
+
+    /// Returns `true` if there are keys which haven't been handled
+    /// yet.
+    pub fn has_pending_keys(&self) -> bool {
+        !self.keys.is_empty()
+    }
+
+    /// Returns the index of the focused row.
+    pub fn focus(&self) -> usize {
+        self.focus
+    }
+
+    /// Moves the focus to the row with the index provided.
+    pub fn set_focus(&mut self, row: usize) {
+        self.focus = row;
+        self.editing = None;
+    }
+
+    /// Returns `true` if the focused row is being edited.
+    pub fn is_editing(&self) -> bool {
+        self.editing.is_some()
+    }
+}
+
+/// A single row of the tree.
+struct Row {
+    depth: usize,
+    marker: Option<&'static str>,
+    label: Option<String>,
+    spans: Vec<Span<'static>>,
+    hint: Option<String>,
+    enabled: bool,
+}
+
+/// The user interface of a single frame, which the values add their
+/// rows to. Once everything is added, [`TuiUi::show`] draws the rows.
+pub struct TuiUi<'a> {
+    state: &'a mut TuiState,
+    /// The key to handle during this frame.
+    key: Option<TuiKey>,
+    rows: Vec<Row>,
+    depth: usize,
+    /// The labels of the fields and the tree nodes the rows are added
+    /// within, identifying the state of the nodes.
+    path: Vec<String>,
+    /// The label of the field which hasn't got a row yet, along with
+    /// the depth of the field.
+    pending_label: Option<(String, usize)>,
+    pending_hint: Option<String>,
+    /// Whether the field scopes have been turned into the tree nodes,
+    /// which have been closed.
+    fields: Vec<bool>,
+    /// The number of the closed tree nodes the rows are added within.
+    hidden: usize,
+    /// The number of the disabled scopes the rows are added within.
+    disabled: usize,
+    /// Whether the text being edited has been shown this frame.
+    editing_shown: bool,
+}
+
+impl<'a> TuiUi<'a> {
+    /// Starts a new frame, taking the next key to handle from the
+    /// state.
+    pub fn new(state: &'a mut TuiState) -> Self {
+        let key = state.keys.pop_front();
+        state.frames += 1;
+        Self {
+            state,
+            key,
+            rows: Vec::new(),
+            depth: 0,
+            path: Vec::new(),
+            pending_label: None,
+            pending_hint: None,
+            fields: Vec::new(),
+            hidden: 0,
+            disabled: 0,
+            editing_shown: false,
+        }
+    }
+
+    /// Returns the number of the current frame.
+    pub(crate) fn frame(&self) -> u64 {
+        self.state.frames
+    }
+
+    /// Returns `true` if the next row added is the focused one.
+    fn is_next_focused(&self) -> bool {
+        self.hidden == 0 && self.rows.len() == self.state.focus
+    }
+
+    /// Takes the key to be handled by the next row added, if it is the
+    /// focused and enabled one. The navigation keys are only passed
+    /// while the row is being edited.
+    fn take_key(&mut self) -> Option<TuiKey> {
+        if !self.is_next_focused() || self.disabled > 0 {
+            return None;
+        }
+
+        match self.key {
+            Some(key) if !key.is_navigation() || self.state.editing.is_some() => self.key.take(),
+            _ => None,
+        }
+    }
+
+    /// Takes the undo or the redo key, unless the focused row is being
+    /// edited.
+    pub(crate) fn take_history_key(&mut self) -> Option<TuiKey> {
+        match self.key {
+            Some(TuiKey::Undo | TuiKey::Redo) if self.state.editing.is_none() => self.key.take(),
+            _ => None,
+        }
+    }
+
+    /// Returns the key identifying the state of a widget within the
+    /// current path.
+    fn path_key(&self, name: &str) -> String {
+        let mut key = self.path.join("/");
+        key.push('/');
+        key.push_str(name);
+        key
+    }
+
+    fn push_row(&mut self, marker: Option<&'static str>, spans: Vec<Span<'static>>) {
+        let (label, depth) = match self.pending_label.take() {
+            Some((label, depth)) => (Some(label), depth),
+            None => (None, self.depth),
+        };
+        let hint = self.pending_hint.take();
+
+        if self.hidden > 0 {
+            return;
+        }
+
+        self.rows.push(Row {
+            depth,
+            marker,
+            label,
+            spans,
+            hint,
+            enabled: self.disabled == 0,
+        });
+    }
+
+    /// Turns the field which hasn't got a row yet into a tree node, as
+    /// its value takes more than a single row.
+    fn flush_pending_label(&mut self) {
+        let Some((label, depth)) = self.pending_label.take() else {
+            return;
+        };
+
+        let key = self.path.join("/");
+        let open = self.node_toggle(&key);
+        let marker = if open { "▾" } else { "▸" };
+
+        self.pending_label = Some((label, depth));
+        self.push_row(Some(marker), Vec::new());
+
+        if !open {
+            self.hidden += 1;
+            if let Some(closed) = self.fields.last_mut() {
+                *closed = true;
+            }
+        }
+    }
+
+    /// Handles the keys for the tree node to be added next, and returns
+    /// whether it is open.
+    fn node_toggle(&mut self, key: &str) -> bool {
+        let mut open = self.state.open.get(key).copied().unwrap_or(true);
+        match self.take_key() {
+            Some(TuiKey::Left) => open = false,
+            Some(TuiKey::Right) => open = true,
+            Some(key) if key.is_activation() => open = !open,
+            _ => {}
+        }
+        let _ = self.state.open.insert(key.to_owned(), open);
+        open
+    }
+
+    /// Sets the text shown at the bottom while the next row added is
+    /// focused, unless a hint has already been set for it.
+    pub fn hint(&mut self, text: impl Into<String>) {
+        if self.pending_hint.is_none() {
+            self.pending_hint = Some(text.into());
+        }
+    }
+
+    /// Adds a row of text.
+    pub fn label(&mut self, text: impl Into<String>) {
+        self.flush_pending_label_if_nested();
+        self.push_row(None, vec![Span::raw(text.into())]);
+    }
+
+    /// Adds a row of dimmed text.
+    pub fn weak(&mut self, text: impl Into<String>) {
+        self.flush_pending_label_if_nested();
+        self.push_row(
+            None,
+            vec![Span::styled(
+                text.into(),
+                Style::default().add_modifier(Modifier::DIM),
+            )],
+        );
+    }
+
+    /// Adds a row of red text, telling what is wrong with a value.
+    pub fn error(&mut self, text: impl Into<String>) {
+        self.flush_pending_label_if_nested();
+        self.push_row(
+            None,
+            vec![Span::styled(text.into(), Style::default().fg(Color::Red))],
+        );
+    }
+
+    /// Adds a row of a bar filled by the fraction, followed by the text.
+    pub fn progress(&mut self, fraction: f32, text: &str) {
+        const WIDTH: usize = 20;
+        let filled = (fraction.clamp(0.0, 1.0) * WIDTH as f32).round() as usize;
+        self.flush_pending_label_if_nested();
+        self.push_row(
+            None,
+            vec![
+                Span::raw("█".repeat(filled)),
+                Span::styled(
+                    "░".repeat(WIDTH - filled),
+                    Style::default().add_modifier(Modifier::DIM),
+                ),
+                Span::raw(format!(" {text}")),
+            ],
+        );
+    }
+
+    /// The rows are only merged with the pending label of the field
+    /// they belong to directly.
+    fn flush_pending_label_if_nested(&mut self) {
+        if matches!(&self.pending_label, Some((_, depth)) if depth + 1 != self.depth) {
+            self.flush_pending_label();
+        }
+    }
+
+    /// Adds a button, returning `true` if it has been pushed.
+    pub fn button(&mut self, text: &str) -> bool {
+        self.flush_pending_label_if_nested();
+        let pushed = self.take_key().is_some_and(TuiKey::is_activation);
+        self.push_row(None, vec![Span::raw(format!("[ {text} ]"))]);
+        pushed
+    }
+
+    /// Adds a checkbox, which is toggled by activating it or by
+    /// `Left` and `Right`.
+    pub fn checkbox(&mut self, checked: &mut bool, text: &str) -> EditResponse {
+        self.flush_pending_label_if_nested();
+        let toggled = self
+            .take_key()
+            .is_some_and(|key| key.is_activation() || matches!(key, TuiKey::Left | TuiKey::Right));
+        if toggled {
+            *checked = !*checked;
+        }
+
+        let mark = if *checked { "[x]" } else { "[ ]" };
+        let text = if text.is_empty() {
+            mark.to_owned()
+        } else {
+            format!("{mark} {text}")
+        };
+        self.push_row(None, vec![Span::raw(text)]);
+        EditResponse::changed(toggled)
+    }
+
+    /// Adds a selector of one of the options, which are switched with
+    /// `Left` and `Right`, or by activating it.
+    pub fn choice(&mut self, selected: &mut usize, options: &[&str]) -> EditResponse {
+        self.flush_pending_label_if_nested();
+        let previous = *selected;
+        if !options.is_empty() {
+            match self.take_key() {
+                Some(TuiKey::Left) => *selected = (*selected + options.len() - 1) % options.len(),
+                Some(TuiKey::Right) => *selected = (*selected + 1) % options.len(),
+                Some(key) if key.is_activation() => *selected = (*selected + 1) % options.len(),
+                _ => {}
+            }
+        }
+
+        let text = options.get(*selected).copied().unwrap_or_default();
+        self.push_row(None, vec![Span::raw(format!("◂ {text} ▸"))]);
+        EditResponse::changed(*selected != previous)
+    }
+
+    /// Adds a single-line text input. Activating it starts editing the
+    /// text, which is only changed once the editing is finished.
+    pub fn text_edit(&mut self, text: &mut String) -> EditResponse {
+        self.flush_pending_label_if_nested();
+        let mut response = EditResponse::default();
+        if let Some(edited) = self.edit_text(text.clone()) {
+            if edited != *text {
+                *text = edited;
+                response = EditResponse::changed(true);
+            }
+        }
+
+        match (&self.state.editing, self.is_next_focused()) {
+            (Some(buffer), true) => {
+                response.active = true;
+                self.editing_shown = true;
+                let spans = vec![Span::raw(buffer.clone()), Span::raw("▏")];
+                self.push_row(None, spans);
+            }
+            _ => self.push_row(None, vec![Span::raw(text.clone())]),
+        }
+
+        response
+    }
+
+    /// Handles the keys of the text input to be added next, returning
+    /// the text once the editing is finished.
+    fn edit_text(&mut self, initial: String) -> Option<String> {
+        let key = self.take_key()?;
+        let buffer = match self.state.editing.as_mut() {
+            Some(buffer) => buffer,
+            None => {
+                if key == TuiKey::Enter {
+                    self.state.editing = Some(initial);
+                }
+                return None;
+            }
+        };
+
+        match key {
+            TuiKey::Char(c) => buffer.push(c),
+            TuiKey::Backspace => {
+                let _ = buffer.pop();
+            }
+            TuiKey::Esc => self.state.editing = None,
+            TuiKey::Enter => return self.state.editing.take(),
+            _ => {}
+        }
+
+        None
+    }
+
+    /// Adds the rows of a field labelled. If the value takes a single
+    /// row, it is shown next to the label, otherwise the field becomes
+    /// a tree node the rows of the value are nested in.
+    pub fn field<R>(&mut self, label: &str, add_contents: impl FnOnce(&mut Self) -> R) -> R {
+        self.flush_pending_label_if_nested();
+        self.pending_label = Some((label.to_owned(), self.depth));
+        self.path.push(label.to_owned());
+        self.fields.push(false);
+        self.depth += 1;
+
+        let result = add_contents(self);
+
+        self.depth -= 1;
+        if self.pending_label.is_some() {
+            // The value hasn't added any rows.
+            self.push_row(None, Vec::new());
+        }
+        if self.fields.pop() == Some(true) {
+            self.hidden -= 1;
+        }
+        let _ = self.path.pop();
+
+        result
+    }
+
+    /// Adds a tree node, which may be closed to hide the rows added
+    /// within it. The nodes are open by default.
+    pub fn collapsing<R>(&mut self, label: &str, add_contents: impl FnOnce(&mut Self) -> R) -> R {
+        self.flush_pending_label_if_nested();
+        let key = self.path_key(label);
+        let open = self.node_toggle(&key);
+        let marker = if open { "▾" } else { "▸" };
+        self.push_row(Some(marker), vec![Span::raw(label.to_owned())]);
+
+        self.path.push(label.to_owned());
+        self.depth += 1;
+        if !open {
+            self.hidden += 1;
+        }
+
+        let result = add_contents(self);
+
+        if !open {
+            self.hidden -= 1;
+        }
+        self.depth -= 1;
+        let _ = self.path.pop();
+
+        result
+    }
+
+    /// Adds the rows which may not be changed if `disabled` is `true`.
+    pub fn disabled<R>(&mut self, disabled: bool, add_contents: impl FnOnce(&mut Self) -> R) -> R {
+        self.disabled += usize::from(disabled);
+        let result = add_contents(self);
+        self.disabled -= usize::from(disabled);
+        result
+    }
+
+    /// Returns the state of a widget with the name provided, kept
+    /// between the frames.
+    pub fn data<T: Any + Clone + Default>(&self, name: &str) -> T {
+        self.state
+            .data
+            .get(&self.path_key(name))
+            .and_then(|data| data.downcast_ref::<T>())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    /// Stores the state of a widget with the name provided, to be kept
+    /// between the frames.
+    pub fn set_data<T: Any>(&mut self, name: &str, data: T) {
+        let key = self.path_key(name);
+        let _ = self.state.data.insert(key, Box::new(data));
+    }
+
+    /// Handles the navigation and draws the rows into the area of the
+    /// frame, within a block with the title provided. The hint of the
+    /// focused row is shown at the bottom of the block.
+    pub fn show(mut self, frame: &mut Frame<'_>, area: Rect, title: &str) {
+        if self.pending_label.is_some() {
+            self.push_row(None, Vec::new());
+        }
+
+        let last = self.rows.len().saturating_sub(1);
+        let state = &mut *self.state;
+        if !self.editing_shown {
+            state.editing = None;
+        }
+
+        if state.editing.is_none() {
+            state.focus = match self.key {
+                Some(TuiKey::Up) => state.focus.saturating_sub(1),
+                Some(TuiKey::Down) => state.focus + 1,
+                Some(TuiKey::PageUp) => state.focus.saturating_sub(state.page.max(1)),
+                Some(TuiKey::PageDown) => state.focus + state.page.max(1),
+                Some(TuiKey::Home) => 0,
+                Some(TuiKey::End) => last,
+                _ => state.focus,
+            };
+        }
+        state.focus = state.focus.min(last);
+
+        let block = Block::bordered().title(title.to_owned());
+        let inner = block.inner(area);
+        state.page = usize::from(inner.height);
+        if state.focus < state.scroll {
+            state.scroll = state.focus;
+        } else if state.page > 0 && state.focus >= state.scroll + state.page {
+            state.scroll = state.focus + 1 - state.page;
+        }
+
+        let block = match self.rows.get(state.focus).and_then(|row| row.hint.as_ref()) {
+            Some(hint) => block.title_bottom(hint.clone()),
+            None => block,
+        };
+
+        let lines: Vec<Line<'static>> = self
+            .rows
+            .into_iter()
+            .enumerate()
+            .skip(state.scroll)
+            .take(state.page)
+            .map(|(i, row)| {
+                let mut spans = vec![Span::raw("  ".repeat(row.depth))];
+                if let Some(marker) = row.marker {
+                    spans.push(Span::raw(format!("{marker} ")));
+                }
+                if let Some(label) = row.label {
+                    let separator = if row.spans.is_empty() { "" } else { ": " };
+                    spans.push(Span::raw(format!("{label}{separator}")));
+                }
+                spans.extend(row.spans);
+
+                let mut style = Style::default();
+                if !row.enabled {
+                    style = style.add_modifier(Modifier::DIM);
+                }
+                if i == state.focus {
+                    style = style.add_modifier(Modifier::REVERSED);
+                }
+                Line::from(spans).style(style)
+            })
+            .collect();
+
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+}
+
+/// The numeric types which may be edited with [`TuiScalar`].
+pub trait TuiNumber: Copy + PartialOrd + std::fmt::Display + std::str::FromStr + 'static {
+    /// The change of the value a single key press makes by default.
+    const DEFAULT_STEP: f64;
+
+    /// Returns the value changed by the number of steps provided,
+    /// saturating at the bounds of the type.
+    fn offset(self, step: f64) -> Self;
+
+    /// Formats the value with the number of decimals provided, if any.
+    fn format(self, decimals: Option<usize>) -> String;
+}
+
+macro_rules! define_number_for_integer {
+    ($integer_type:ty) => {
+        impl TuiNumber for $integer_type {
+            const DEFAULT_STEP: f64 = 1.0;
+
+            fn offset(self, step: f64) -> Self {
+                (self as i128 + step.round() as i128)
+                    .clamp(<$integer_type>::MIN as i128, <$integer_type>::MAX as i128)
+                    as $integer_type
+            }
+
+            fn format(self, _decimals: Option<usize>) -> String {
+                self.to_string()
+            }
+        }
+    };
+}
+
+define_number_for_integer!(i8);
+define_number_for_integer!(u8);
+define_number_for_integer!(i16);
+define_number_for_integer!(u16);
+define_number_for_integer!(i32);
+define_number_for_integer!(u32);
+define_number_for_integer!(i64);
+define_number_for_integer!(u64);
+define_number_for_integer!(isize);
+define_number_for_integer!(usize);
+
+macro_rules! define_number_for_float {
+    ($float_type:ty) => {
+        impl TuiNumber for $float_type {
+            const DEFAULT_STEP: f64 = 0.1;
+
+            fn offset(self, step: f64) -> Self {
+                self + step as $float_type
+            }
+
+            fn format(self, decimals: Option<usize>) -> String {
+                match decimals {
+                    Some(decimals) => format!("{self:.decimals$}"),
+                    None => self.to_string(),
+                }
+            }
+        }
+    };
+}
+
+define_number_for_float!(f32);
+define_number_for_float!(f64);
+
+/// An input of a number, changed by a step with `Left` and `Right`,
+/// or typed in after activating it.
+#[must_use = "The scalar is only added once shown."]
+pub struct TuiScalar<'a, T> {
+    value: &'a mut T,
+    step: f64,
+    range: Option<(T, T)>,
+    decimals: Option<usize>,
+    prefix: String,
+    suffix: String,
+}
+
+impl<'a, T: TuiNumber> TuiScalar<'a, T> {
+    /// Creates an input of the value.
+    pub fn new(value: &'a mut T) -> Self {
+        Self {
+            value,
+            step: T::DEFAULT_STEP,
+            range: None,
+            decimals: None,
+            prefix: String::new(),
+            suffix: String::new(),
+        }
+    }
+
+    /// Sets the change of the value a single key press makes.
+    pub fn step(mut self, step: f64) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Limits the values which may be set.
+    pub fn range(mut self, min: T, max: T) -> Self {
+        self.range = Some((min, max));
+        self
+    }
+
+    /// Sets the number of decimals shown.
+    pub fn decimals(mut self, decimals: usize) -> Self {
+        self.decimals = Some(decimals);
+        self
+    }
+
+    /// Sets the text shown before the value.
+    pub fn prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.prefix = prefix.into();
+        self
+    }
+
+    /// Sets the text shown after the value.
+    pub fn suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.suffix = suffix.into();
+        self
+    }
+
+    fn clamp(&self, value: T) -> T {
+        match self.range {
+            Some((min, _)) if value < min => min,
+            Some((_, max)) if value > max => max,
+            _ => value,
+        }
+    }
+
+    /// Adds the input to the user interface.
+    pub fn show(self, ui: &mut TuiUi<'_>) -> EditResponse {
+        ui.flush_pending_label_if_nested();
+        let previous = *self.value;
+
+        if ui.state.editing.is_some() {
+            if let Some(text) = ui.edit_text(String::new()) {
+                if let Ok(value) = text.trim().parse() {
+                    *self.value = self.clamp(value);
+                }
+            }
+        } else {
+            match ui.take_key() {
+                Some(TuiKey::Left) => *self.value = self.clamp(self.value.offset(-self.step)),
+                Some(TuiKey::Right) => *self.value = self.clamp(self.value.offset(self.step)),
+                Some(TuiKey::Enter) => {
+                    ui.state.editing = Some(self.value.format(self.decimals));
+                }
+                _ => {}
+            }
+        }
+
+        let mut response = EditResponse::changed(*self.value != previous);
+        match (&ui.state.editing, ui.is_next_focused()) {
+            (Some(buffer), true) => {
+                response.active = true;
+                ui.editing_shown = true;
+                let spans = vec![Span::raw(buffer.clone()), Span::raw("▏")];
+                ui.push_row(None, spans);
+            }
+            _ => {
+                let text = format!(
+                    "{}{}{}",
+                    self.prefix,
+                    self.value.format(self.decimals),
+                    self.suffix
+                );
+                ui.push_row(None, vec![Span::raw(text)]);
+            }
+        }
+
+        response
+    }
+}
+
+/// Presents a value from its backend-agnostic description (see
+/// [`crate::reflect`]), so the types only implementing [`Reflect`] may
+/// be shown and edited in the terminal as well.
+///
+/// ```rust,ignore
+/// let mut object = Reflected(object);
+/// let _ = object.render_window_mut(frame, frame.area(), &mut state);
+/// ```
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq, Hash)]
+pub struct Reflected<T>(pub T);
+
+impl<T: Reflect> crate::TuiPresentable for Reflected<T> {
+    fn render_component(&self, ui: &mut TuiUi<'_>) {
+        render_reflected(self.0.reflect(), None, ui);
+    }
+
+    fn render_component_mut(&mut self, ui: &mut TuiUi<'_>) -> EditResponse {
+        render_reflected_mut(self.0.reflect_mut(), None, ui)
+    }
+}
+
+/// The description of the field a value is stored in, along with the
+/// range of the values of the field.
+#[derive(Debug, Copy, Clone)]
+struct Field<'a> {
+    info: &'a FieldInfo,
+    range: Option<(f64, f64)>,
+}
+
+/// Returns the number of decimals of a "printf" display format, like
+/// `"%.2f"`.
+fn printf_decimals(format: &str) -> Option<usize> {
+    let (_, specification) = format.split_once("%.")?;
+    let digits: String = specification
+        .chars()
+        .take_while(char::is_ascii_digit)
+        .collect();
+    digits.parse().ok()
+}
+
+/// Shows a primitive value, using the presentation attributes of the
+/// field it is stored in.
+fn render_value(value: ValueMut<'_>, field: Option<Field<'_>>, ui: &mut TuiUi<'_>) -> EditResponse {
+    macro_rules! scalar {
+        ($value:expr, $number_type:ty) => {{
+            let mut scalar = TuiScalar::new($value);
+            if let Some(Field { info: field, range }) = field {
+                if let Some(speed) = field.speed {
+                    scalar = scalar.step(speed as f64);
+                }
+                if let Some((min, max)) = range {
+                    scalar = scalar.range(min as $number_type, max as $number_type);
+                }
+                if let Some(decimals) = field.format.and_then(printf_decimals) {
+                    scalar = scalar.decimals(decimals);
+                }
+                if let Some(prefix) = field.prefix {
+                    scalar = scalar.prefix(prefix);
+                }
+                if let Some(suffix) = field.suffix {
+                    scalar = scalar.suffix(suffix);
+                }
+            }
+            scalar.show(ui)
+        }};
+    }
+
+    match value {
+        ValueMut::Bool(value) => ui.checkbox(value, ""),
+        ValueMut::I8(value) => scalar!(value, i8),
+        ValueMut::U8(value) => scalar!(value, u8),
+        ValueMut::I16(value) => scalar!(value, i16),
+        ValueMut::U16(value) => scalar!(value, u16),
+        ValueMut::I32(value) => scalar!(value, i32),
+        ValueMut::U32(value) => scalar!(value, u32),
+        ValueMut::I64(value) => scalar!(value, i64),
+        ValueMut::U64(value) => scalar!(value, u64),
+        ValueMut::Isize(value) => scalar!(value, isize),
+        ValueMut::Usize(value) => scalar!(value, usize),
+        ValueMut::F32(value) => scalar!(value, f32),
+        ValueMut::F64(value) => scalar!(value, f64),
+        ValueMut::String(value) => ui.text_edit(value),
+    }
+}
+
+/// Shows a primitive value not allowing to change it.
+fn render_value_ref(value: ValueRef<'_>, field: Option<Field<'_>>, ui: &mut TuiUi<'_>) {
+    let value = match value {
+        ValueRef::Bool(value) => ValueMut::Bool(&mut { *value }),
+        ValueRef::I8(value) => ValueMut::I8(&mut { *value }),
+        ValueRef::U8(value) => ValueMut::U8(&mut { *value }),
+        ValueRef::I16(value) => ValueMut::I16(&mut { *value }),
+        ValueRef::U16(value) => ValueMut::U16(&mut { *value }),
+        ValueRef::I32(value) => ValueMut::I32(&mut { *value }),
+        ValueRef::U32(value) => ValueMut::U32(&mut { *value }),
+        ValueRef::I64(value) => ValueMut::I64(&mut { *value }),
+        ValueRef::U64(value) => ValueMut::U64(&mut { *value }),
+        ValueRef::Isize(value) => ValueMut::Isize(&mut { *value }),
+        ValueRef::Usize(value) => ValueMut::Usize(&mut { *value }),
+        ValueRef::F32(value) => ValueMut::F32(&mut { *value }),
+        ValueRef::F64(value) => ValueMut::F64(&mut { *value }),
+        ValueRef::String(value) => return ui.label(value),
+    };
+
+    ui.disabled(true, |ui| {
+        let _ = render_value(value, field, ui);
+    });
+}
+
+/// Shows the fields described, taking the values and the ranges of
+/// those with the functions provided.
+fn render_fields<'a>(
+    fields: &[FieldInfo],
+    get: impl Fn(usize) -> Option<&'a dyn Reflect>,
+    range: impl Fn(usize) -> Option<(f64, f64)>,
+    ui: &mut TuiUi<'_>,
+) {
+    for (i, info) in fields.iter().enumerate() {
+        let Some(value) = get(i) else {
+            continue;
+        };
+        if let Some(docs) = info.docs {
+            ui.hint(docs);
+        }
+        let field = Field {
+            info,
+            range: range(i),
+        };
+        ui.field(info.title, |ui| {
+            render_reflected(value.reflect(), Some(field), ui)
+        });
+    }
+}
+
+/// Shows the value not allowing to change it.
+fn render_reflected(value: ReflectRef<'_>, field: Option<Field<'_>>, ui: &mut TuiUi<'_>) {
+    match value {
+        ReflectRef::Struct(strukt) => {
+            let info = strukt.info();
+            if let Some(docs) = info.docs {
+                ui.weak(docs);
+            }
+            render_fields(
+                info.fields,
+                |i| strukt.field(i),
+                |i| strukt.field_range(i),
+                ui,
+            );
+        }
+        ReflectRef::Enum(enumm) => {
+            let info = enumm.info();
+            let titles: Vec<&str> = info.variants.iter().map(|v| v.title).collect();
+            let mut current = enumm.variant_index();
+            ui.disabled(true, |ui| {
+                let _ = ui.choice(&mut current, &titles);
+            });
+            render_fields(
+                enumm.variant().fields,
+                |i| enumm.field(i),
+                |i| enumm.field_range(i),
+                ui,
+            );
+        }
+        ReflectRef::List(list) => {
+            ui.collapsing(&format!("elements: {}", list.len()), |ui| {
+                for (i, element) in (0..list.len()).filter_map(|i| list.get(i)).enumerate() {
+                    ui.field(&i.to_string(), |ui| {
+                        render_reflected(element.reflect(), field, ui)
+                    });
+                }
+            });
+        }
+        ReflectRef::Map(map) => {
+            ui.collapsing(&format!("entries: {}", map.len()), |ui| {
+                for (i, (key, value)) in (0..map.len()).filter_map(|i| map.get_index(i)).enumerate()
+                {
+                    ui.field(&i.to_string(), |ui| {
+                        ui.field("key", |ui| render_reflected(key.reflect(), None, ui));
+                        ui.field("value", |ui| render_reflected(value.reflect(), field, ui));
+                    });
+                }
+            });
+        }
+        ReflectRef::Value(value) => render_value_ref(value, field, ui),
+        ReflectRef::Opaque => ui.weak("unavailable"),
+    }
+}
+
+/// Shows the value allowing to change it.
+fn render_reflected_mut(
+    value: ReflectMut<'_>,
+    field: Option<Field<'_>>,
+    ui: &mut TuiUi<'_>,
+) -> EditResponse {
+    let mut response = EditResponse::default();
+
+    match value {
+        ReflectMut::Struct(strukt) => {
+            let info = strukt.info();
+            if let Some(docs) = info.docs {
+                ui.weak(docs);
+            }
+            for (i, info) in info.fields.iter().enumerate() {
+                if let Some(docs) = info.docs {
+                    ui.hint(docs);
+                }
+                let field = Field {
+                    info,
+                    range: strukt.field_range(i),
+                };
+                if let Some(value) = strukt.field_mut(i) {
+                    response |= ui.field(info.title, |ui| {
+                        render_reflected_mut(value.reflect_mut(), Some(field), ui)
+                    });
+                } else if let Some(value) = strukt.field(i) {
+                    ui.field(info.title, |ui| {
+                        render_reflected(value.reflect(), Some(field), ui)
+                    });
+                }
+            }
+        }
+        ReflectMut::Enum(enumm) => {
+            let info = enumm.info();
+            let titles: Vec<&str> = info.variants.iter().map(|v| v.title).collect();
+            let mut current = enumm.variant_index();
+            if info.readonly {
+                ui.disabled(true, |ui| {
+                    let _ = ui.choice(&mut current, &titles);
+                });
+            } else {
+                response |= ui.choice(&mut current, &titles);
+                if current != enumm.variant_index() {
+                    let _ = enumm.set_variant(current);
+                }
+            }
+            for (i, info) in enumm.variant().fields.iter().enumerate() {
+                if let Some(docs) = info.docs {
+                    ui.hint(docs);
+                }
+                let field = Field {
+                    info,
+                    range: enumm.field_range(i),
+                };
+                if let Some(value) = enumm.field_mut(i) {
+                    response |= ui.field(info.title, |ui| {
+                        render_reflected_mut(value.reflect_mut(), Some(field), ui)
+                    });
+                } else if let Some(value) = enumm.field(i) {
+                    ui.field(info.title, |ui| {
+                        render_reflected(value.reflect(), Some(field), ui)
+                    });
+                }
+            }
+        }
+        ReflectMut::List(list) => {
+            response |= ui.collapsing(&format!("elements: {}", list.len()), |ui| {
+                let mut response = EditResponse::default();
+                for i in 0..list.len() {
+                    if let Some(element) = list.get_mut(i) {
+                        response |= ui.field(&i.to_string(), |ui| {
+                            render_reflected_mut(element.reflect_mut(), field, ui)
+                        });
+                    } else if let Some(element) = list.get(i) {
+                        ui.field(&i.to_string(), |ui| {
+                            render_reflected(element.reflect(), field, ui)
+                        });
+                    }
+                }
+                response
+            });
+        }
+        ReflectMut::Map(map) => {
+            response |= ui.collapsing(&format!("entries: {}", map.len()), |ui| {
+                let mut response = EditResponse::default();
+                for i in 0..map.len() {
+                    let Some((key, value)) = map.get_index_mut(i) else {
+                        continue;
+                    };
+                    response |= ui.field(&i.to_string(), |ui| {
+                        ui.field("key", |ui| render_reflected(key.reflect(), None, ui));
+                        ui.field("value", |ui| {
+                            render_reflected_mut(value.reflect_mut(), field, ui)
+                        })
+                    });
+                }
+                response
+            });
+        }
+        ReflectMut::Value(value) => response |= render_value(value, field, ui),
+        ReflectMut::Opaque => ui.weak("unavailable"),
+    }
+
+    response
+}
@@ -0,0 +1,603 @@
+//! The validation of the values against the invariants of the data.
+//!
+//! The fields of the derived types are validated with the functions
+//! specified by the `validate` attribute, returning
+//! `Result<(), String>`, and the built-in checks: `non_empty`,
+//! `min_len`, `max_len` (see [`Length`]) and `regex` (which requires
+//! the `regex` feature). The structs and the enums may be validated
+//! as a whole with the `validate` attribute too:
+//!
+//! ```rust,ignore
+//! fn is_port(port: &u16) -> Result<(), String> {
+//!     (*port >= 1024).then_some(()).ok_or_else(|| "Must not be privileged.".to_owned())
+//! }
+//!
+//! #[derive(ImguiPresentation)]
+//! #[imgui_presentation(validate = "Server::check")]
+//! struct Server {
+//!     #[imgui_presentation(non_empty, regex = "^[a-z.]+$")]
+//!     host: String,
+//!     #[imgui_presentation(validate = "is_port", reject_invalid)]
+//!     port: u16,
+//! }
+//! ```
+//!
+//! The errors are shown in red below the fields, and the fields marked
+//! `reject_invalid` undo the edits making their valid values invalid,
+//! telling why. Marking a struct or an enum that way rejects the edits
+//! making any of its values invalid. The rejection requires the values
+//! to implement [`Clone`], and to be `'static`, as the copies of the
+//! last valid values are kept between the frames.
+//!
+//! The derived types implement [`Validate`], which returns all the
+//! errors of a value, along with the paths of the values those are
+//! about (see [`crate::path`]). The errors of the nested values are
+//! found if their types implement [`Validate`] too, which the derived
+//! types, the [`Vec`], the [`std::collections::VecDeque`], the arrays,
+//! the [`Option`] and the [`Box`] of such types do. As with the
+//! [`crate::elements`] actions, the implementations are only found for
+//! the concrete types, not for the generic parameters.
+
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+use crate::EditResponse;
+
+/// An invariant a value doesn't hold.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationError {
+    /// The path of the value, like `"servers[0].host"`, which is empty
+    /// for the value validated itself.
+    pub path: String,
+    /// What is wrong with the value.
+    pub message: String,
+}
+
+impl std::fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.path.is_empty() {
+            write!(f, "{}", self.message)
+        } else {
+            write!(f, "{}: {}", self.path, self.message)
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Allows to check a value and the values nested in it against their
+/// invariants. Implemented by the derive macros.
+pub trait Validate {
+    /// Appends the errors of the value and the values nested in it,
+    /// the paths of which start with the path provided.
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>);
+
+    /// Returns all the errors of the value and the values nested in it.
+    fn validate_all(&self) -> Vec<ValidationError> {
+        let mut errors = Vec::new();
+        self.validate_into("", &mut errors);
+        errors
+    }
+
+    /// Returns `true` if neither the value nor the values nested in it
+    /// have any errors.
+    fn is_valid(&self) -> bool {
+        self.validate_all().is_empty()
+    }
+}
+
+impl<T: Validate> Validate for Vec<T> {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.as_slice().validate_into(path, errors);
+    }
+}
+
+impl<T: Validate> Validate for VecDeque<T> {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (index, element) in self.iter().enumerate() {
+            element.validate_into(&element_path(path, index), errors);
+        }
+    }
+}
+
+impl<T: Validate> Validate for [T] {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        for (index, element) in self.iter().enumerate() {
+            element.validate_into(&element_path(path, index), errors);
+        }
+    }
+}
+
+impl<T: Validate, const N: usize> Validate for [T; N] {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        self.as_slice().validate_into(path, errors);
+    }
+}
+
+impl<T: Validate> Validate for Option<T> {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        if let Some(value) = self {
+            value.validate_into(path, errors);
+        }
+    }
+}
+
+impl<T: Validate + ?Sized> Validate for Box<T> {
+    fn validate_into(&self, path: &str, errors: &mut Vec<ValidationError>) {
+        (**self).validate_into(path, errors);
+    }
+}
+
+/// Returns the path of a field of the value at the path provided.
+pub fn field_path(path: &str, field: &str) -> String {
+    if path.is_empty() {
+        field.to_owned()
+    } else {
+        format!("{path}.{field}")
+    }
+}
+
+/// Returns the path of an element of the list at the path provided.
+pub fn element_path(path: &str, index: usize) -> String {
+    format!("{path}[{index}]")
+}
+
+/// Returns the messages of the failed checks.
+pub fn messages<const N: usize>(results: [Result<(), String>; N]) -> Vec<String> {
+    results.into_iter().filter_map(Result::err).collect()
+}
+
+/// Appends the messages as the errors of the value at the path.
+pub fn push_errors(path: &str, messages: Vec<String>, errors: &mut Vec<ValidationError>) {
+    errors.extend(messages.into_iter().map(|message| ValidationError {
+        path: path.to_owned(),
+        message,
+    }));
+}
+
+/// A value the length of which may be checked, like a string or a
+/// collection.
+pub trait Length {
+    /// What the length is counted in, like "character".
+    const UNIT: &'static str;
+
+    /// Returns the length of the value, in [`Self::UNIT`]s.
+    fn length(&self) -> usize;
+}
+
+impl Length for str {
+    const UNIT: &'static str = "character";
+
+    fn length(&self) -> usize {
+        self.chars().count()
+    }
+}
+
+impl Length for String {
+    const UNIT: &'static str = "character";
+
+    fn length(&self) -> usize {
+        self.as_str().length()
+    }
+}
+
+impl<T: Length + ?Sized> Length for &T {
+    const UNIT: &'static str = T::UNIT;
+
+    fn length(&self) -> usize {
+        (**self).length()
+    }
+}
+
+/// Implements [`Length`] for the collections, the length of which is
+/// the number of the elements.
+macro_rules! define_length_for_collections {
+    ($($type: ty => [$($parameters: tt)*]),* $(,)?) => {
+        $(
+            impl<$($parameters)*> Length for $type {
+                const UNIT: &'static str = "element";
+
+                fn length(&self) -> usize {
+                    self.len()
+                }
+            }
+        )*
+    };
+}
+
+define_length_for_collections! {
+    [T] => [T],
+    [T; N] => [T, const N: usize],
+    Vec<T> => [T],
+    VecDeque<T> => [T],
+    BTreeSet<T> => [T],
+    HashSet<T, S> => [T, S],
+    BTreeMap<K, V> => [K, V],
+    HashMap<K, V, S> => [K, V, S],
+}
+
+/// Returns the length with its unit, like "1 character".
+fn length_text<T: Length + ?Sized>(length: usize) -> String {
+    let plural = if length == 1 { "" } else { "s" };
+    format!("{length} {}{plural}", T::UNIT)
+}
+
+/// The `non_empty` check.
+pub fn non_empty<T: Length + ?Sized>(value: &T) -> Result<(), String> {
+    match value.length() {
+        0 => Err("Must not be empty.".to_owned()),
+        _ => Ok(()),
+    }
+}
+
+/// The `min_len` check.
+pub fn min_len<T: Length + ?Sized>(value: &T, min: usize) -> Result<(), String> {
+    if value.length() < min {
+        Err(format!("Must have at least {}.", length_text::<T>(min)))
+    } else {
+        Ok(())
+    }
+}
+
+/// The `max_len` check.
+pub fn max_len<T: Length + ?Sized>(value: &T, max: usize) -> Result<(), String> {
+    if value.length() > max {
+        Err(format!("Must have at most {}.", length_text::<T>(max)))
+    } else {
+        Ok(())
+    }
+}
+
+/// The `regex` check. The pattern is compiled once per thread. The
+/// derive macro checks the patterns of the attributes, and the values
+/// checked against an invalid one are invalid, with the reason.
+#[cfg(feature = "regex")]
+pub fn matches_regex<T: AsRef<str> + ?Sized>(
+    value: &T,
+    pattern: &'static str,
+) -> Result<(), String> {
+    thread_local! {
+        static REGEXES: RefCell<HashMap<&'static str, Result<regex::Regex, String>>> =
+            RefCell::default();
+    }
+
+    let matches = REGEXES.with(|regexes| {
+        regexes
+            .borrow_mut()
+            .entry(pattern)
+            .or_insert_with(|| {
+                regex::Regex::new(pattern)
+                    .map_err(|error| format!("The pattern `{pattern}` is invalid: {error}"))
+            })
+            .as_ref()
+            .map(|regex| regex.is_match(value.as_ref()))
+            .map_err(Clone::clone)
+    })?;
+    if matches {
+        Ok(())
+    } else {
+        Err(format!("Must match `{pattern}`."))
+    }
+}
+
+/// Identifies a value by its address and the name of its type.
+type ValueKey = (usize, &'static str);
+
+thread_local! {
+    /// The errors of the last edits rejected, identified by the
+    /// addresses and the names of the types of the values, shown until
+    /// the values are changed.
+    static REJECTED: RefCell<HashMap<ValueKey, Vec<String>>> = RefCell::default();
+}
+
+/// Returns the key identifying the value in [`REJECTED`].
+fn rejection_key<T>(value: &T) -> ValueKey {
+    (
+        std::ptr::from_ref(value) as usize,
+        std::any::type_name::<T>(),
+    )
+}
+
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+thread_local! {
+    /// The copies of the last valid values presented with the
+    /// `reject_invalid` attribute, or `None` for the values which are
+    /// invalid. The copies of the values not presented during a frame
+    /// are dropped.
+    static LAST_VALID: RefCell<crate::frame_state::FrameStates<ValueKey, Box<dyn std::any::Any>>> =
+        RefCell::default();
+}
+
+/// The copy of the last valid value of a field or an item presented
+/// with the `reject_invalid` attribute, restored once an edit makes the
+/// value invalid. The copy is kept between the frames, so the value is
+/// only cloned and validated once it is changed, and the first time it
+/// is presented. Used by the derive macros.
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+#[doc(hidden)]
+#[derive(Debug)]
+pub struct LastValid<T> {
+    frame: u64,
+    value: Option<T>,
+}
+
+#[cfg(any(
+    feature = "imgui_backend",
+    feature = "egui_backend",
+    feature = "ratatui_backend"
+))]
+impl<T: Clone + 'static> LastValid<T> {
+    /// Takes the copy of the value out before the value is presented,
+    /// so that the values nested into it may use theirs.
+    pub fn take<U>(ui: &U, value: &T, is_valid: impl FnOnce(&T) -> bool) -> Self
+    where
+        U: crate::frame_state::Frame + ?Sized,
+    {
+        let frame = ui.frame();
+        let kept = LAST_VALID
+            .with(|all| all.borrow_mut().take(frame, &rejection_key(value)))
+            .and_then(|kept| kept.downcast::<Option<T>>().ok());
+        let value = match kept {
+            Some(kept) => *kept,
+            None => is_valid(value).then(|| value.clone()),
+        };
+        Self { frame, value }
+    }
+
+    /// Undoes the edit of the value if it has made the value invalid,
+    /// which is when the `errors` of the checks of the value or the
+    /// `nested` errors aren't empty, unless the value was invalid
+    /// already. The errors of the rejected edit are remembered until
+    /// the value is changed (see [`rejected`]) and are added to the
+    /// `errors` to show, and the response of the rejected edit tells
+    /// nothing has changed.
+    pub fn reject_invalid(
+        mut self,
+        value: &mut T,
+        errors: &mut Vec<String>,
+        nested: impl FnOnce(&T) -> Vec<String>,
+        response: EditResponse,
+    ) -> EditResponse {
+        let key = rejection_key(value);
+        let response = if response.changed {
+            let mut all = errors.clone();
+            all.extend(nested(value));
+            match self.value {
+                Some(ref previous) if !all.is_empty() => {
+                    value.clone_from(previous);
+                    // The value restored was valid.
+                    errors.clear();
+                    REJECTED.with(|rejected| {
+                        let _ = rejected.borrow_mut().insert(key, all);
+                    });
+                    EditResponse {
+                        changed: false,
+                        edit_finished: false,
+                        ..response
+                    }
+                }
+                _ => {
+                    let _ = REJECTED.with(|rejected| rejected.borrow_mut().remove(&key));
+                    self.value = all.is_empty().then(|| value.clone());
+                    response
+                }
+            }
+        } else {
+            response
+        };
+
+        errors.extend(rejected(value));
+        LAST_VALID.with(|all| {
+            all.borrow_mut()
+                .insert(self.frame, key, Box::new(self.value));
+        });
+        response
+    }
+}
+
+/// Returns the errors of the last edit of the value rejected, to show
+/// those until the value is changed.
+pub fn rejected<T>(value: &T) -> Vec<String> {
+    REJECTED.with(|all| {
+        all.borrow()
+            .get(&rejection_key(value))
+            .map(|errors| {
+                errors
+                    .iter()
+                    .map(|message| format!("{message} The edit was rejected."))
+                    .collect()
+            })
+            .unwrap_or_default()
+    })
+}
+
+/// Shows the errors of a value in red.
+#[cfg(feature = "imgui_backend")]
+pub fn show_errors_imgui(ui: &imgui::Ui, errors: &[String]) {
+    for error in errors {
+        ui.text_colored([1.0, 0.3, 0.3, 1.0], error);
+    }
+}
+
+/// Shows the errors of a value in red.
+#[cfg(feature = "egui_backend")]
+pub fn show_errors_egui(ui: &mut egui::Ui, errors: &[String]) {
+    for error in errors {
+        let _ = ui.colored_label(ui.visuals().error_fg_color, error);
+    }
+}
+
+/// Shows the errors of a value in red.
+#[cfg(feature = "ratatui_backend")]
+pub fn show_errors_tui(ui: &mut crate::tui::TuiUi<'_>, errors: &[String]) {
+    for error in errors {
+        ui.error(error.as_str());
+    }
+}
+
+/// Validates the values nested in the derived types if their types
+/// implement [`Validate`], the same way as [`crate::elements::probe`]
+/// picks the element actions. Used by the derive macros.
+#[doc(hidden)]
+pub mod probe {
+    use super::{Validate, ValidationError};
+    pub use crate::elements::probe::Probe;
+
+    pub trait ViaValidate<T> {
+        fn validate_into(&self, value: &T, path: &str, errors: &mut Vec<ValidationError>);
+    }
+
+    impl<T: Validate> ViaValidate<T> for &Probe<T> {
+        fn validate_into(&self, value: &T, path: &str, errors: &mut Vec<ValidationError>) {
+            value.validate_into(path, errors);
+        }
+    }
+
+    pub trait Unsupported<T> {
+        fn validate_into(&self, value: &T, path: &str, errors: &mut Vec<ValidationError>);
+    }
+
+    impl<T> Unsupported<T> for Probe<T> {
+        fn validate_into(&self, _: &T, _: &str, _: &mut Vec<ValidationError>) {}
+    }
+}
+
+/// Appends the errors of a value of the type if it implements
+/// [`Validate`]. The implementations are only found for the concrete
+/// types, not for the generic parameters.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! validate_nested {
+    ($type: ty, $value: expr, $path: expr, $errors: expr) => {{
+        #[allow(unused_imports)]
+        use $crate::validation::probe::{Probe, Unsupported, ViaValidate};
+        (&&Probe::<$type>::new()).validate_into($value, $path, $errors)
+    }};
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{max_len, messages, min_len, non_empty, Validate};
+
+    #[test]
+    fn checks_the_lengths() {
+        assert!(non_empty("").is_err());
+        assert!(non_empty(&vec![1]).is_ok());
+        assert_eq!(
+            min_len("ab", 3),
+            Err("Must have at least 3 characters.".to_owned())
+        );
+        assert_eq!(
+            max_len(&[1, 2][..], 1),
+            Err("Must have at most 1 element.".to_owned())
+        );
+        // The characters are counted, not the bytes.
+        assert!(max_len("ää", 2).is_ok());
+        assert_eq!(messages([Ok(()), Err("a".to_owned())]), ["a"]);
+    }
+
+    #[cfg(feature = "regex")]
+    #[test]
+    fn checks_the_patterns() {
+        assert!(super::matches_regex("abc", "^[a-z]+$").is_ok());
+        assert!(super::matches_regex(&String::from("a1"), "^[a-z]+$").is_err());
+
+        // The invalid patterns don't panic.
+        let error = super::matches_regex("abc", "(").unwrap_err();
+        assert!(error.starts_with("The pattern `(` is invalid"), "{error}");
+    }
+
+    struct Even(u32);
+
+    impl Validate for Even {
+        fn validate_into(&self, path: &str, errors: &mut Vec<super::ValidationError>) {
+            if self.0 % 2 == 1 {
+                super::push_errors(path, vec!["Odd.".to_owned()], errors);
+            }
+        }
+    }
+
+    #[test]
+    fn finds_the_nested_errors() {
+        let values = vec![Some(Even(2)), None, Some(Even(3))];
+        let errors = values.validate_all();
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].to_string(), "[2]: Odd.");
+
+        let mut errors = Vec::new();
+        crate::validate_nested!(Vec<u32>, &vec![1], "a", &mut errors);
+        crate::validate_nested!([Even; 1], &[Even(1)], "b", &mut errors);
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].path, "b[0]");
+    }
+
+    #[cfg(any(
+        feature = "imgui_backend",
+        feature = "egui_backend",
+        feature = "ratatui_backend"
+    ))]
+    #[test]
+    fn rejects_the_edits_making_the_values_invalid() {
+        use super::{rejected, LastValid};
+        use crate::EditResponse;
+
+        struct Frame(u64);
+
+        impl crate::frame_state::Frame for Frame {
+            fn frame(&self) -> u64 {
+                self.0
+            }
+        }
+
+        let too_large = |value: &u32| {
+            if *value > 2 {
+                vec!["Too large.".to_owned()]
+            } else {
+                Vec::new()
+            }
+        };
+        let edit = |frame, value: &mut u32, new_value, validated: &mut bool| {
+            let last_valid = LastValid::take(&Frame(frame), value, |value| {
+                *validated = true;
+                too_large(value).is_empty()
+            });
+            let changed = *value != new_value;
+            *value = new_value;
+            let mut errors = Vec::new();
+            let response = last_valid.reject_invalid(
+                value,
+                &mut errors,
+                too_large,
+                EditResponse::changed(changed),
+            );
+            (response.changed, errors)
+        };
+
+        let mut value = 1;
+        let mut validated = false;
+        assert_eq!(edit(1, &mut value, 1, &mut validated), (false, Vec::new()));
+        assert!(validated);
+
+        // The copy is kept, so the value isn't validated until changed.
+        validated = false;
+        let (changed, errors) = edit(2, &mut value, 3, &mut validated);
+        assert!(!validated);
+        assert_eq!(value, 1);
+        assert!(!changed);
+        assert_eq!(errors, ["Too large. The edit was rejected."]);
+        assert_eq!(rejected(&value), errors);
+
+        // The valid edits are accepted and forget the rejection.
+        assert_eq!(edit(3, &mut value, 2, &mut validated), (true, Vec::new()));
+        assert_eq!(value, 2);
+        assert!(rejected(&value).is_empty());
+    }
+}
@@ -0,0 +1,166 @@
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, PartialEq, ImguiPresentation)]
+struct Material {
+    #[imgui_presentation(color)]
+    base: glam::Vec3,
+    #[imgui_presentation(color(linear, hdr))]
+    emission: [f32; 3],
+    #[imgui_presentation(color(no_alpha))]
+    tint: [u8; 4],
+    highlight: Srgba8,
+    position: glam::Vec4,
+}
+
+fn material() -> Material {
+    Material {
+        tint: [255, 128, 0, 64],
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind, WidgetValue};
+    use imgui_presentable::*;
+
+    use super::material;
+
+    #[derive(Debug, ImguiPresentation)]
+    struct Theme {
+        accent: egui::Color32,
+    }
+
+    #[test]
+    fn the_colors_are_picked_in_the_srgb_space() {
+        let mut harness = EguiHarness::new(material());
+
+        assert!(harness.drag("base", 128.0).changed);
+        assert_eq!(harness.value().base.x, 128.0 / 255.0);
+
+        // The linear channels are converted.
+        assert!(harness.drag("emission", 128.0).changed);
+        assert!((harness.value().emission[0] - 0.215_861).abs() < 1e-4);
+    }
+
+    #[test]
+    fn the_alpha_is_only_edited_if_asked() {
+        let mut harness = EguiHarness::new(material());
+        let hidden = harness
+            .tree()
+            .iter()
+            .filter(|w| w.label.as_deref() == Some("Alpha"));
+        // Only the `Srgba8` shows its alpha.
+        assert_eq!(hidden.count(), 1);
+
+        assert!(harness.drag("tint", 0.0).changed);
+        assert_eq!(harness.value().tint, [0, 128, 0, 64]);
+    }
+
+    #[test]
+    fn the_hdr_channels_exceed_one() {
+        let mut harness = EguiHarness::new(material());
+        harness.value_mut().emission = [2.5, 0.0, 0.0];
+        assert!(!harness.run().changed);
+
+        let shown = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue)
+            .any(|w| w.value == Some(WidgetValue::Number(2.5)));
+        assert!(shown);
+        assert_eq!(harness.value().emission, [2.5, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn the_vectors_are_only_colors_if_marked() {
+        let harness = EguiHarness::new(material());
+        assert!(harness.tree().find("w").is_some());
+
+        let mut harness = EguiHarness::new(Theme {
+            accent: egui::Color32::BLACK,
+        });
+        assert!(harness.drag("accent", 255.0).changed);
+        assert_eq!(harness.value().accent.r(), 255);
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::{ImguiHarness, WidgetValue};
+
+    use super::material;
+
+    fn channels(harness: &ImguiHarness<super::Material>, prefix: &str) -> Vec<String> {
+        harness
+            .tree()
+            .iter()
+            .filter_map(|w| match &w.value {
+                Some(WidgetValue::Text(text)) if text.starts_with(prefix) => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn the_colors_are_picked_in_the_srgb_space() {
+        let mut harness = ImguiHarness::new(material());
+
+        assert!(harness.drag("base", 128.0).changed);
+        assert_eq!(harness.value().base.x, 128.0 / 255.0);
+
+        // The linear channels are converted, and the floating point
+        // ones are edited as such.
+        assert!(harness.drag("emission", 0.5).changed);
+        assert!((harness.value().emission[0] - 0.214_041).abs() < 1e-4);
+    }
+
+    #[test]
+    fn the_alpha_is_only_edited_if_asked() {
+        let mut harness = ImguiHarness::new(material());
+        // Only the `Srgba8` shows its alpha.
+        assert_eq!(channels(&harness, "A:").len(), 1);
+
+        assert!(harness.drag("tint", 0.0).changed);
+        assert_eq!(harness.value().tint, [0, 128, 0, 64]);
+    }
+
+    #[test]
+    fn the_hdr_channels_exceed_one() {
+        let mut harness = ImguiHarness::new(material());
+        harness.value_mut().emission = [2.5, 0.0, 0.0];
+        assert!(!harness.run().changed);
+
+        // The sRGB channels shown exceed one too.
+        assert_eq!(channels(&harness, "R:")[1], "R:1.490");
+        assert_eq!(harness.value().emission, [2.5, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn the_vectors_are_only_colors_if_marked() {
+        let mut harness = ImguiHarness::new(material());
+        assert!(harness.tree().find("w").is_some());
+
+        assert!(harness.drag("w", 2.0).changed);
+        assert_eq!(harness.value().position.w, 2.0);
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_edit_the_hexadecimal_codes() {
+    use imgui_presentable::testing::TuiHarness;
+    use imgui_presentable::tui::TuiKey;
+
+    let mut harness = TuiHarness::new(material(), 60, 30);
+    let lines = harness.lines();
+    assert!(lines.iter().any(|l| l.contains("tint: #ff8000")));
+    assert!(lines.iter().any(|l| l.contains("highlight: #00000000")));
+
+    assert!(harness.focus("tint"));
+    let _ = harness.press(TuiKey::Enter);
+    let _ = harness.press_all([TuiKey::Backspace; 7]);
+    let _ = harness.type_text("#00ff00");
+    assert!(harness.press(TuiKey::Enter).changed);
+    assert_eq!(harness.value().tint, [0, 255, 0, 64]);
+}
@@ -0,0 +1,117 @@
+use imgui_presentable::*;
+
+#[derive(Debug, Clone, Copy, PartialEq, ImguiPresentation)]
+enum LightKind {
+    Point,
+    Directional,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Light {
+    kind: LightKind,
+    #[imgui_presentation(visible_if = "self.kind == LightKind::Point")]
+    falloff: f32,
+    on: bool,
+    #[imgui_presentation(enabled_if = "self.on")]
+    intensity: f32,
+}
+
+fn light() -> Light {
+    Light {
+        kind: LightKind::Point,
+        falloff: 2.0,
+        on: true,
+        intensity: 1.0,
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::filter::Filter;
+    use imgui_presentable::testing::EguiHarness;
+    use imgui_presentable::EguiPresentable;
+
+    use super::{light, LightKind};
+
+    #[test]
+    fn the_fields_are_hidden_while_the_conditions_dont_hold() {
+        let mut harness = EguiHarness::new(light());
+        assert!(harness.tree().find("falloff").is_some());
+
+        let _ = harness.select("kind", "Directional");
+        assert_eq!(harness.value().kind, LightKind::Directional);
+        assert!(harness.tree().find("falloff").is_none());
+
+        // The hidden fields don't match the filters.
+        let filter = Filter::new("falloff");
+        assert!(!harness.value().matches_filter(&filter));
+        assert!(light().matches_filter(&filter));
+    }
+
+    #[test]
+    fn the_fields_are_disabled_while_the_conditions_dont_hold() {
+        let mut harness = EguiHarness::new(light());
+        assert!(harness.tree().find("intensity").unwrap().enabled);
+
+        let _ = harness.toggle("on");
+        assert!(!harness.value().on);
+        assert!(!harness.tree().find("intensity").unwrap().enabled);
+
+        // The read-only presentations aren't grayed out.
+        let harness = EguiHarness::new_readonly(super::Light {
+            on: false,
+            ..light()
+        });
+        assert!(harness.tree().find("intensity").unwrap().enabled);
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::{ImguiHarness, WidgetKind};
+
+    use super::{light, LightKind};
+
+    #[test]
+    fn the_fields_are_hidden_while_the_conditions_dont_hold() {
+        let mut harness = ImguiHarness::new(light());
+        assert!(harness.tree().find("falloff").is_some());
+
+        assert!(harness.select("kind", "Directional").changed);
+        assert_eq!(harness.value().kind, LightKind::Directional);
+        assert!(harness.tree().find("falloff").is_none());
+
+        assert!(harness.select("kind", "Point").changed);
+        assert!(harness.drag("falloff", 3.0).changed);
+        assert_eq!(harness.value().falloff, 3.0);
+    }
+
+    #[test]
+    fn the_fields_are_disabled_while_the_conditions_dont_hold() {
+        let mut harness = ImguiHarness::new(light());
+        assert!(harness.drag("intensity", 0.5).changed);
+        assert_eq!(harness.value().intensity, 0.5);
+
+        assert!(harness.toggle("on").changed);
+        assert!(!harness.value().on);
+        let disabled = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue && !w.enabled)
+            .count();
+        assert_eq!(disabled, 1);
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_hide_the_fields() {
+    use imgui_presentable::testing::TuiHarness;
+
+    let mut harness = TuiHarness::new(light(), 60, 20);
+    assert!(harness.lines().iter().any(|l| l.contains("falloff")));
+
+    harness.value_mut().kind = LightKind::Directional;
+    let _ = harness.run();
+    assert!(!harness.lines().iter().any(|l| l.contains("falloff")));
+}
@@ -1,5 +1,8 @@
 use imgui_presentable::*;
+use std::cell::{Cell, RefCell};
 use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+use std::rc::Rc;
+use std::sync::{Arc, Mutex, RwLock};
 
 fn check_that_implements<T: ImguiPresentable>(_object: T) {}
 
@@ -47,11 +50,11 @@ mod imgui_presentation_is_properly_derived_for {
     #[derive(Default, ImguiPresentation)]
     struct StringsOwnedTuple(String, String);
 
-    // TODO: work with mutable/immutable references to objects.
-    // #[derive(Default, ImguiPresentation)]
-    // struct StringsSlice<'a> {
-    //     string_slice: &'a mut str,
-    // }
+    #[derive(Default, ImguiPresentation)]
+    struct StringsSlice<'a> {
+        string_slice: &'a str,
+        mutable_string_slice: &'a mut str,
+    }
 
     #[derive(Default, ImguiPresentation)]
     struct Sequential {
@@ -87,6 +90,53 @@ mod imgui_presentation_is_properly_derived_for {
         field: StrictType,
     }
 
+    trait Shape: ImguiPresentable + EguiPresentable + TuiPresentable {}
+
+    #[derive(Default, ImguiPresentation)]
+    struct Circle {
+        radius: f32,
+    }
+
+    impl Shape for Circle {}
+
+    #[derive(Default, ImguiPresentation)]
+    struct Transform {
+        position: [f32; 3],
+        flags: (u8, bool),
+    }
+
+    #[derive(Default, ImguiPresentation)]
+    struct Wrappers {
+        counter: Rc<RefCell<u32>>,
+        samples: Arc<RwLock<Vec<f32>>>,
+        name: Arc<Mutex<String>>,
+        enabled: Cell<bool>,
+        shape: Rc<Circle>,
+    }
+
+    #[derive(Default, ImguiPresentation)]
+    struct Counters {
+        hits: Cell<u32>,
+        label: RefCell<String>,
+        total: u32,
+    }
+
+    #[derive(ImguiPresentation)]
+    enum SharedCounter {
+        Hits { hits: Cell<u32>, total: u32 },
+        Label(RefCell<String>),
+    }
+
+    #[derive(ImguiPresentation)]
+    struct CompoundTypes<'a> {
+        array: [f32; 3],
+        nested_array: [[u8; 2]; 2],
+        tuple: (u32, String),
+        optional_pair: Option<(f32, f32)>,
+        reference: &'a mut Vec<u8>,
+        shape: Box<dyn Shape>,
+    }
+
     #[derive(ImguiPresentation)]
     enum PodEnum1 {
         Variant1,
@@ -102,6 +152,36 @@ mod imgui_presentation_is_properly_derived_for {
         Variant4 = 100,
     }
 
+    #[derive(ImguiPresentation)]
+    enum DataEnum {
+        Unit,
+        Tuple(f32, String),
+        Struct { value: i32, values: Vec<u8> },
+    }
+
+    #[derive(ImguiPresentation)]
+    enum DataEnumWithAttributes {
+        #[imgui_presentation(rename = "A sphere")]
+        Sphere {
+            /// The radius of the sphere.
+            #[imgui_presentation(range = 0.0f32..100.0f32)]
+            radius: f32,
+            #[imgui_presentation(skip)]
+            _cached_volume: f32,
+        },
+        #[imgui_presentation(constructor = "unit_box")]
+        Box(
+            #[imgui_presentation(tooltip = "The extents of the box.")] glam::Vec3,
+            u32,
+        ),
+    }
+
+    impl DataEnumWithAttributes {
+        fn unit_box() -> Self {
+            Self::Box(glam::Vec3::ONE, 0)
+        }
+    }
+
     #[derive(Default, ImguiPresentation)]
     struct Options {
         option_string: Option<String>,
@@ -128,6 +208,113 @@ mod imgui_presentation_is_properly_derived_for {
         check_that_implements(s);
         let s = StringsOwnedTuple::default();
         check_that_implements(s);
+        let s = StringsSlice::default();
+        check_that_implements(s);
+    }
+
+    #[test]
+    fn compound_types() {
+        let mut values = Vec::new();
+        let s = CompoundTypes {
+            array: [0.0; 3],
+            nested_array: [[0; 2]; 2],
+            tuple: (0, String::new()),
+            optional_pair: None,
+            reference: &mut values,
+            shape: Box::new(Circle::default()),
+        };
+        check_that_implements(s);
+    }
+
+    #[test]
+    fn compound_types_are_editable() {
+        use imgui_presentable::testing::{EguiHarness, WidgetKind, WidgetValue};
+
+        let mut harness = EguiHarness::new(Transform {
+            position: [1.0, 2.0, 3.0],
+            flags: (4, false),
+        });
+        let values: Vec<_> = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue)
+            .map(|w| w.value.clone())
+            .collect();
+        assert_eq!(
+            values,
+            [1.0, 2.0, 3.0, 4.0].map(|v| Some(WidgetValue::Number(v)))
+        );
+
+        assert!(harness.drag("position", 5.0).changed);
+        assert!(harness.toggle("flags").changed);
+        assert_eq!(harness.value().position, [5.0, 2.0, 3.0]);
+        assert_eq!(harness.value().flags, (4, true));
+    }
+
+    #[test]
+    fn wrappers() {
+        let w = Wrappers::default();
+        check_that_implements(w);
+    }
+
+    #[test]
+    fn shared_values_are_editable() {
+        use imgui_presentable::testing::{EguiHarness, WidgetKind};
+
+        let counter = Rc::new(RefCell::new(1u32));
+        let samples = Arc::new(RwLock::new(vec![1.0f32]));
+        let mut harness = EguiHarness::new(Wrappers {
+            counter: Rc::clone(&counter),
+            samples: Arc::clone(&samples),
+            ..Default::default()
+        });
+
+        assert!(harness.drag("counter", 2.0).changed);
+        assert!(harness.toggle("enabled").changed);
+        assert_eq!(*counter.borrow(), 2);
+        assert!(harness.value().enabled.get());
+
+        let borrowed = counter.borrow_mut();
+        let write_guard = samples.write().unwrap();
+        let _ = harness.run();
+        let placeholders = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::Label && w.label.as_deref() == Some("locked"))
+            .count();
+        assert_eq!(placeholders, 2);
+        drop((borrowed, write_guard));
+    }
+
+    #[test]
+    fn shared_structs_are_editable() {
+        use imgui_presentable::testing::EguiHarness;
+
+        let counters = Rc::new(Counters::default());
+        let mut harness = EguiHarness::new(Rc::clone(&counters));
+
+        assert!(harness.drag("hits", 3.0).changed);
+        let _ = harness.type_text("label", "clicks");
+        assert_eq!(counters.hits.get(), 3);
+        assert_eq!(*counters.label.borrow(), "clicks");
+        assert_eq!(harness.value().total, 0);
+    }
+
+    #[test]
+    fn shared_enums_are_editable() {
+        use imgui_presentable::testing::EguiHarness;
+
+        let counter = Rc::new(SharedCounter::Hits {
+            hits: Cell::new(0),
+            total: 0,
+        });
+        let mut harness = EguiHarness::new(Rc::clone(&counter));
+
+        assert!(harness.drag("hits", 3.0).changed);
+        assert!(matches!(
+            &*counter,
+            SharedCounter::Hits { hits, total: 0 } if hits.get() == 3
+        ));
     }
 
     #[test]
@@ -160,6 +347,26 @@ mod imgui_presentation_is_properly_derived_for {
         check_that_implements(e);
     }
 
+    #[test]
+    fn data_enums() {
+        let e = DataEnum::Unit;
+        check_that_implements(e);
+        let e = DataEnum::Tuple(0.0, String::new());
+        check_that_implements(e);
+        let e = DataEnum::Struct {
+            value: 0,
+            values: Vec::new(),
+        };
+        check_that_implements(e);
+        let e = DataEnumWithAttributes::Sphere {
+            radius: 1.0,
+            _cached_volume: 0.0,
+        };
+        check_that_implements(e);
+        let e = DataEnumWithAttributes::unit_box();
+        check_that_implements(e);
+    }
+
     #[test]
     fn options() {
         let e = Options::default();
@@ -205,6 +412,16 @@ mod imgui_presentation_attributes {
         value: f32,
     }
 
+    #[derive(Default, ImguiPresentation)]
+    struct StructWithScalarAttributes {
+        #[imgui_presentation(prefix = "~", suffix = " m/s", speed = 0.1f32)]
+        speed: f32,
+        #[imgui_presentation(format = "%d%%", range = 0..100)]
+        percent: u8,
+        #[imgui_presentation(suffix = " items", readonly)]
+        count: usize,
+    }
+
     #[derive(Default, ImguiPresentation)]
     struct StructWithDocCommentField {
         /// This is the way.
@@ -218,6 +435,12 @@ mod imgui_presentation_attributes {
         value: f32,
     }
 
+    #[derive(Default, ImguiPresentation)]
+    struct StructWithPunctuationInAttributes {
+        #[imgui_presentation(tooltip = "The speed, in m/s (v = s / t).", rename = "Speed, m/s")]
+        value: f32,
+    }
+
     #[derive(Default, ImguiPresentation)]
     #[imgui_presentation(button("Hello world": "on_hello_world"))]
     #[imgui_presentation(button("Hello world 2": "on_hello_world_2"))]
@@ -248,6 +471,12 @@ mod imgui_presentation_attributes {
         check_that_implements(e);
     }
 
+    #[test]
+    fn struct_with_scalar_attributes() {
+        let e = StructWithScalarAttributes::default();
+        check_that_implements(e);
+    }
+
     #[test]
     fn struct_with_doc_comment_field() {
         let e = StructWithDocCommentField::default();
@@ -259,4 +488,69 @@ mod imgui_presentation_attributes {
         let e = StructWithToolTip::default();
         check_that_implements(e);
     }
+
+    #[test]
+    fn struct_with_punctuation_in_attributes() {
+        let e = StructWithPunctuationInAttributes::default();
+        check_that_implements(e);
+    }
+
+    #[test]
+    fn struct_with_renamed_field() {
+        let e = StructWithRenamedField::default();
+        check_that_implements(e);
+    }
+
+    #[test]
+    fn struct_with_buttons() {
+        let e = StructWithButtons::default();
+        check_that_implements(e);
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod data_enums_are_edited {
+    use imgui_presentable::testing::EguiHarness;
+    use imgui_presentable::*;
+
+    #[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+    enum Shape {
+        Point,
+        Circle {
+            radius: f32,
+        },
+        #[imgui_presentation(constructor = "unit_square")]
+        Square(f32),
+    }
+
+    impl Shape {
+        fn unit_square() -> Self {
+            Self::Square(1.0)
+        }
+    }
+
+    #[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+    struct Scene {
+        shape: Shape,
+    }
+
+    #[test]
+    fn selecting_a_variant_builds_it() {
+        let mut harness = EguiHarness::new(Scene {
+            shape: Shape::Point,
+        });
+        assert!(harness.select("shape", "Circle").changed);
+        assert_eq!(harness.value().shape, Shape::Circle { radius: 0.0 });
+        assert!(harness.drag("radius", 2.0).changed);
+        assert_eq!(harness.value().shape, Shape::Circle { radius: 2.0 });
+    }
+
+    #[test]
+    fn selecting_a_variant_calls_its_constructor() {
+        let mut harness = EguiHarness::new(Scene {
+            shape: Shape::Point,
+        });
+        assert!(harness.select("shape", "Square").changed);
+        assert_eq!(harness.value().shape, Shape::Square(1.0));
+    }
 }
@@ -0,0 +1,165 @@
+use std::collections::VecDeque;
+
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, PartialEq, ImguiPresentation)]
+struct Object {
+    name: String,
+}
+
+/// Can neither be created nor duplicated.
+#[derive(Debug, PartialEq, ImguiPresentation)]
+struct Handle {
+    id: u32,
+}
+
+#[derive(Debug, Default, Clone, ImguiPresentation)]
+struct Wrapper<T: ImguiPresentable + EguiPresentable + TuiPresentable> {
+    value: T,
+}
+
+#[derive(Debug, ImguiPresentation)]
+struct Scene {
+    objects: Vec<Object>,
+    handles: VecDeque<Handle>,
+}
+
+fn object(name: &str) -> Object {
+    Object {
+        name: name.to_owned(),
+    }
+}
+
+fn scene() -> Scene {
+    Scene {
+        objects: vec![object("a"), object("b"), object("c")],
+        handles: VecDeque::from([Handle { id: 1 }, Handle { id: 2 }]),
+    }
+}
+
+fn names(scene: &Scene) -> Vec<&str> {
+    scene.objects.iter().map(|o| o.name.as_str()).collect()
+}
+
+#[cfg(feature = "egui_backend")]
+#[test]
+fn the_actions_are_picked_from_the_implemented_traits() {
+    let actions = <Object as EguiPresentable>::element_actions();
+    assert!(actions.create.is_some() && actions.duplicate.is_some());
+    assert_eq!((actions.duplicate.unwrap())(&object("a")), object("a"));
+
+    let actions = <Handle as EguiPresentable>::element_actions();
+    assert!(actions.create.is_none() && actions.duplicate.is_none());
+
+    // The implementations for the generic types aren't found.
+    let actions = <Wrapper<u32> as EguiPresentable>::element_actions();
+    assert!(actions.create.is_none() && actions.duplicate.is_none());
+
+    let actions = <String as EguiPresentable>::element_actions();
+    assert_eq!((actions.create.unwrap())(), "");
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind};
+
+    use super::{names, object, scene};
+
+    #[test]
+    fn the_elements_are_moved() {
+        let mut harness = EguiHarness::new(scene());
+
+        // The first one can't be moved up.
+        let up = harness.tree().find("⏶").unwrap();
+        assert!(!up.enabled);
+
+        assert!(harness.click("⏷").changed);
+        assert_eq!(names(harness.value()), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn the_elements_are_added_and_duplicated() {
+        let mut harness = EguiHarness::new(scene());
+
+        assert!(harness.click("🗐").changed);
+        assert_eq!(names(harness.value()), ["a", "a", "b", "c"]);
+
+        assert!(harness.click("+").changed);
+        assert_eq!(names(harness.value()), ["", "a", "a", "b", "c"]);
+
+        assert!(harness.click("Add new").changed);
+        assert_eq!(harness.value().objects.last(), Some(&object("")));
+
+        assert!(harness.click("X").changed);
+        assert_eq!(harness.value().objects.len(), 5);
+    }
+
+    #[test]
+    fn only_the_supported_actions_are_shown() {
+        let harness = EguiHarness::new(scene());
+        let count = |label: &str| {
+            harness
+                .tree()
+                .iter()
+                .filter(|w| w.kind == WidgetKind::Button && w.label.as_deref() == Some(label))
+                .count()
+        };
+
+        // The objects have all the actions, the handles may only be
+        // moved and removed.
+        assert_eq!(count("Add new"), 1);
+        assert_eq!(count("🗐"), 3);
+        assert_eq!(count("⏷"), 5);
+        assert_eq!(count("X"), 5);
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{names, object, scene};
+
+    #[test]
+    fn the_elements_are_moved() {
+        let mut harness = ImguiHarness::new(scene());
+
+        // The actions are in the context menus of the elements, and the
+        // first one can't be moved up.
+        assert!(!harness.secondary_click("0: elements::Object").changed);
+        assert!(!harness.tree().find("Move up").unwrap().enabled);
+
+        assert!(harness.click("Move down").changed);
+        assert_eq!(names(harness.value()), ["b", "a", "c"]);
+    }
+
+    #[test]
+    fn the_elements_are_added_and_duplicated() {
+        let mut harness = ImguiHarness::new(scene());
+
+        let _ = harness.secondary_click("0: elements::Object");
+        assert!(harness.click("Duplicate").changed);
+        assert_eq!(names(harness.value()), ["a", "a", "b", "c"]);
+
+        let _ = harness.secondary_click("0: elements::Object");
+        assert!(harness.click("Insert before").changed);
+        assert_eq!(names(harness.value()), ["", "a", "a", "b", "c"]);
+
+        assert!(harness.click("Add new").changed);
+        assert_eq!(harness.value().objects.last(), Some(&object("")));
+    }
+
+    #[test]
+    fn only_the_supported_actions_are_shown() {
+        let mut harness = ImguiHarness::new(scene());
+
+        // The handles may only be moved and removed.
+        let _ = harness.secondary_click("1: elements::Handle");
+        let tree = harness.tree();
+        assert!(tree.find("Insert before").is_none() && tree.find("Duplicate").is_none());
+
+        assert!(harness.click("Remove").changed);
+        assert_eq!(harness.value().handles.len(), 1);
+        assert_eq!(harness.value().handles[0].id, 1);
+    }
+}
@@ -0,0 +1,190 @@
+use std::collections::{BTreeMap, HashSet};
+
+use imgui_presentable::*;
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Registry {
+    ids: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Tags {
+    tags: HashSet<String>,
+}
+
+fn registry() -> Registry {
+    Registry {
+        ids: BTreeMap::from([("a".to_owned(), 1), ("b".to_owned(), 2)]),
+    }
+}
+
+fn keys(registry: &Registry) -> Vec<&str> {
+    registry.ids.keys().map(String::as_str).collect()
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use std::collections::HashSet;
+
+    use imgui_presentable::testing::EguiHarness;
+
+    use super::{keys, registry, Tags};
+
+    #[test]
+    fn the_entries_are_added() {
+        let mut harness = EguiHarness::new(registry());
+
+        assert!(!harness.click("Add entry").changed);
+        assert!(!harness.type_text("New key:", "c").changed);
+        assert!(harness.click("Add").changed);
+        assert_eq!(harness.value().ids.get("c"), Some(&0));
+
+        // The key of another entry can't be added again.
+        let _ = harness.click("Add entry");
+        let _ = harness.type_text("New key:", "a");
+        assert!(!harness.click("Add").changed);
+        assert!(harness
+            .tree()
+            .find("An entry with this key already exists.")
+            .is_some());
+        assert_eq!(harness.value().ids["a"], 1);
+
+        assert!(!harness.click("Cancel").changed);
+        assert!(harness.tree().find("New key:").is_none());
+    }
+
+    #[test]
+    fn the_keys_are_renamed() {
+        let mut harness = EguiHarness::new(registry());
+
+        let _ = harness.click("✏");
+        assert!(!harness.type_text("Rename to:", "c").changed);
+        assert!(harness.click("Rename").changed);
+        assert_eq!(keys(harness.value()), ["b", "c"]);
+        assert_eq!(harness.value().ids["c"], 1);
+
+        // Renaming to a key of another entry keeps the map as it is.
+        let _ = harness.click("✏");
+        let _ = harness.type_text("Rename to:", "c");
+        assert!(!harness.click("Rename").changed);
+        assert_eq!(keys(harness.value()), ["b", "c"]);
+        assert!(harness.tree().find("Rename to:").is_some());
+    }
+
+    #[test]
+    fn the_entries_are_removed() {
+        let mut harness = EguiHarness::new(registry());
+
+        assert!(harness.click("X").changed);
+        assert_eq!(keys(harness.value()), ["b"]);
+
+        assert!(harness.click("Clear").changed);
+        assert!(harness.value().ids.is_empty());
+    }
+
+    #[test]
+    fn the_hash_sets_are_edited() {
+        let mut harness = EguiHarness::new(Tags {
+            tags: HashSet::from(["x".to_owned()]),
+        });
+
+        let _ = harness.click("Add entry");
+        let _ = harness.type_text("New key:", "y");
+        assert!(harness.click("Add").changed);
+
+        let _ = harness.click("✏");
+        let _ = harness.type_text("Rename to:", "z");
+        assert!(harness.click("Rename").changed);
+        assert_eq!(harness.value().tags.len(), 2);
+        assert!(harness.value().tags.contains("z"));
+
+        assert!(harness.click("X").changed);
+        assert_eq!(harness.value().tags.len(), 1);
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use std::collections::HashSet;
+
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{keys, registry, Tags};
+
+    #[test]
+    fn the_entries_are_added() {
+        let mut harness = ImguiHarness::new(registry());
+
+        assert!(!harness.click("Add entry").changed);
+        assert!(!harness.type_text("New key:", "c").changed);
+        assert!(harness.click("Add").changed);
+        assert_eq!(harness.value().ids.get("c"), Some(&0));
+
+        // The key of another entry can't be added again.
+        let _ = harness.click("Add entry");
+        let _ = harness.type_text("New key:", "a");
+        assert!(!harness.click("Add").changed);
+        assert!(harness
+            .tree()
+            .find("An entry with this key already exists.")
+            .is_some());
+        assert_eq!(harness.value().ids["a"], 1);
+
+        assert!(!harness.click("Cancel").changed);
+        assert!(harness.tree().find("New key:").is_none());
+    }
+
+    #[test]
+    fn the_keys_are_renamed() {
+        let mut harness = ImguiHarness::new(registry());
+
+        let _ = harness.click("Rename");
+        assert!(!harness.type_text("Rename to:", "c").changed);
+        assert!(harness.click("Rename").changed);
+        assert_eq!(keys(harness.value()), ["b", "c"]);
+        assert_eq!(harness.value().ids["c"], 1);
+
+        // Renaming to a key of another entry keeps the map as it is.
+        let _ = harness.click("Rename");
+        let _ = harness.type_text("Rename to:", "c");
+        assert!(!harness.click("Rename").changed);
+        assert_eq!(keys(harness.value()), ["b", "c"]);
+        assert!(harness.tree().find("Rename to:").is_some());
+    }
+
+    #[test]
+    fn the_entries_are_removed() {
+        let mut harness = ImguiHarness::new(registry());
+
+        assert!(harness.click("X").changed);
+        assert_eq!(keys(harness.value()), ["b"]);
+
+        assert!(harness.click("Clear").changed);
+        assert!(harness.value().ids.is_empty());
+    }
+
+    #[test]
+    fn the_hash_sets_are_edited() {
+        let mut harness = ImguiHarness::new(Tags {
+            tags: HashSet::from(["x".to_owned()]),
+        });
+
+        let _ = harness.click("Add entry");
+        let _ = harness.type_text("New key:", "y");
+        assert!(harness.click("Add").changed);
+        assert_eq!(harness.value().tags.len(), 2);
+
+        // The elements of the sets are renamed from their context menus.
+        let header = harness
+            .tree()
+            .iter()
+            .find_map(|w| w.label.clone().filter(|l| l.starts_with("0: ")))
+            .unwrap();
+        let _ = harness.secondary_click(&header);
+        let _ = harness.click("Rename");
+        let _ = harness.type_text("Rename to:", "z");
+        assert!(harness.click("Rename").changed);
+        assert_eq!(harness.value().tags.len(), 2);
+        assert!(harness.value().tags.contains("z"));
+    }
+}
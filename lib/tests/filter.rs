@@ -0,0 +1,188 @@
+use imgui_presentable::filter::{Filter, FilterMatch};
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, Copy, ImguiPresentation)]
+enum Material {
+    #[default]
+    Wood,
+    Glass,
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+enum Light {
+    #[default]
+    Off,
+    #[imgui_presentation(rename = "Dimmed")]
+    On { brightness: f32 },
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Object {
+    name: String,
+    visible: bool,
+    material: Material,
+    light: Light,
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Scene {
+    title: String,
+    objects: Vec<Object>,
+}
+
+fn scene() -> Scene {
+    Scene {
+        title: "Kitchen".to_owned(),
+        objects: vec![
+            Object {
+                name: "Chair".to_owned(),
+                ..Default::default()
+            },
+            Object {
+                name: "Lamp".to_owned(),
+                material: Material::Glass,
+                light: Light::On { brightness: 0.5 },
+                ..Default::default()
+            },
+        ],
+    }
+}
+
+#[test]
+fn the_queries_are_matched() {
+    let filter = Filter::new(" LaM ");
+    assert!(filter.matches("Lamp"));
+    assert!(!filter.matches("Chair"));
+    assert!(filter.matches_display(&"a lamp"));
+
+    // Without a filter everything is shown.
+    assert_eq!(
+        FilterMatch::of(|_| false, |_| false),
+        FilterMatch {
+            shown: true,
+            ..Default::default()
+        }
+    );
+
+    Filter::with(Some(filter.into()), || {
+        let filter_match = FilterMatch::of(|_| false, |_| true);
+        assert!(filter_match.shown && filter_match.value_matches);
+        assert!(!FilterMatch::of(|_| false, |_| false).shown);
+
+        // The values nested into a matching one aren't filtered.
+        FilterMatch::of(|_| true, |_| false).render(|| assert!(Filter::active().is_none()));
+        assert!(Filter::active().is_some());
+    });
+    assert!(Filter::active().is_none());
+}
+
+#[cfg(feature = "imgui_backend")]
+#[test]
+fn the_derived_values_are_matched() {
+    let scene = scene();
+    let matches = |query: &str| ImguiPresentable::matches_filter(&scene, &Filter::new(query));
+
+    // The field names.
+    assert!(matches("objects"));
+    // The strings, the variants, their titles and the numbers nested.
+    assert!(matches("lamp"));
+    assert!(matches("glass"));
+    assert!(matches("dimmed"));
+    assert!(matches("0.5"));
+    assert!(!matches("table"));
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind, WidgetValue};
+    use imgui_presentable::Filtered;
+
+    use super::scene;
+
+    fn has_text(harness: &EguiHarness<Filtered<super::Scene>>, text: &str) -> bool {
+        harness.tree().iter().any(|w| {
+            w.kind == WidgetKind::TextEdit && w.value == Some(WidgetValue::Text(text.to_owned()))
+        })
+    }
+
+    #[test]
+    fn the_values_not_matching_are_hidden() {
+        let harness = EguiHarness::new(Filtered::new(scene()).with_query("lamp"));
+        let tree = harness.tree();
+
+        assert!(tree.find("title").is_none());
+        assert!(tree.find("objects").is_some());
+        // The sections containing the match are expanded.
+        assert!(has_text(&harness, "Lamp"));
+        assert!(!has_text(&harness, "Chair"));
+        assert!(tree.find("visible").is_none());
+    }
+
+    #[test]
+    fn the_values_with_matching_names_are_shown_entirely() {
+        let harness = EguiHarness::new(Filtered::new(scene()).with_query("objects"));
+        let tree = harness.tree();
+
+        assert!(tree.find("title").is_none());
+        let elements = tree
+            .iter()
+            .filter(|w| {
+                w.label
+                    .as_deref()
+                    .is_some_and(|label| label.starts_with("0: ") || label.starts_with("1: "))
+            })
+            .count();
+        assert_eq!(elements, 2);
+    }
+
+    #[test]
+    fn everything_is_shown_without_a_query() {
+        let mut harness = EguiHarness::new(Filtered::new(scene()).with_query("lamp"));
+        harness.value().set_query("  ");
+        let _ = harness.run();
+
+        assert!(harness.tree().find("title").is_some());
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::{ImguiHarness, WidgetKind, WidgetValue};
+    use imgui_presentable::Filtered;
+
+    use super::scene;
+
+    #[test]
+    fn the_matching_elements_are_expanded() {
+        let harness = ImguiHarness::new(Filtered::new(scene()).with_query("lamp"));
+        let tree = harness.tree();
+
+        assert!(tree.find("title").is_none());
+        assert!(tree.iter().any(|w| {
+            w.kind == WidgetKind::TextEdit && w.value == Some(WidgetValue::Text("Lamp".to_owned()))
+        }));
+        assert!(tree.find("visible").is_none());
+    }
+
+    #[test]
+    fn the_matching_values_are_edited() {
+        let mut harness = ImguiHarness::new(Filtered::new(scene()).with_query("lamp"));
+
+        let response = harness.type_text("name", "Lantern");
+        assert!(response.changed);
+        let names: Vec<_> = harness
+            .value()
+            .value()
+            .objects
+            .iter()
+            .map(|object| object.name.as_str())
+            .collect();
+        assert_eq!(names, ["Chair", "Lantern"]);
+        // The renamed object no longer matches.
+        assert!(harness.tree().find("objects").is_none());
+
+        harness.value().set_query("");
+        assert!(!harness.run().changed);
+        assert!(harness.tree().find("title").is_some());
+    }
+}
@@ -0,0 +1,198 @@
+use imgui_presentable::*;
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Meters(f32);
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(inline)]
+struct Transform {
+    x: f32,
+    y: f32,
+    scale: f32,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Common {
+    name: String,
+    visible: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Object {
+    #[imgui_presentation(flatten)]
+    common: Common,
+    #[imgui_presentation(inline)]
+    transform: Transform,
+    distance: Meters,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ImguiPresentation)]
+enum Axis {
+    X,
+    Y,
+    Z,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(inline)]
+struct Axes {
+    first: Axis,
+    second: Axis,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Joint {
+    #[imgui_presentation(inline)]
+    axes: Axes,
+}
+
+fn object() -> Object {
+    Object {
+        common: Common {
+            name: "player".to_owned(),
+            visible: true,
+        },
+        transform: Transform {
+            x: 0.0,
+            y: 0.0,
+            scale: 1.0,
+        },
+        distance: Meters(10.0),
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::filter::Filter;
+    use imgui_presentable::testing::{EguiHarness, WidgetKind};
+    use imgui_presentable::EguiPresentable;
+
+    use super::{object, Axes, Axis, Joint};
+
+    #[test]
+    fn the_flattened_and_the_inline_fields_have_no_nodes() {
+        let harness = EguiHarness::new(object());
+        let labels: Vec<_> = harness
+            .tree()
+            .iter()
+            .filter_map(|w| w.label.clone())
+            .collect();
+        assert_eq!(
+            labels,
+            [
+                "name",
+                "visible",
+                "transform",
+                "x",
+                "y",
+                "scale",
+                "distance"
+            ]
+        );
+        assert_eq!(
+            harness.tree().find("name").unwrap().kind,
+            WidgetKind::Button
+        );
+        assert_eq!(
+            harness.tree().find("transform").unwrap().kind,
+            WidgetKind::Label
+        );
+        assert_eq!(harness.tree().find("x").unwrap().kind, WidgetKind::Label);
+    }
+
+    #[test]
+    fn the_flattened_and_the_inline_fields_are_edited() {
+        let mut harness = EguiHarness::new(object());
+        assert!(harness.type_text("name", "enemy").changed);
+        assert_eq!(harness.value().common.name, "enemy");
+        assert!(harness.drag("y", 2.0).changed);
+        assert_eq!(harness.value().transform.y, 2.0);
+        assert!(harness.drag("distance", 3.0).changed);
+        assert_eq!(harness.value().distance.0, 3.0);
+    }
+
+    #[test]
+    fn the_inline_fields_keep_the_widgets_apart() {
+        let mut harness = EguiHarness::new(Joint {
+            axes: Axes {
+                first: Axis::X,
+                second: Axis::Y,
+            },
+        });
+        assert!(harness.select("second", "Z").changed);
+        assert!(harness.select("first", "Y").changed);
+        assert_eq!(
+            harness.value().axes,
+            Axes {
+                first: Axis::Y,
+                second: Axis::Z,
+            }
+        );
+    }
+
+    #[test]
+    fn the_names_of_the_flattened_fields_dont_match_the_filters() {
+        assert!(!object().matches_filter(&Filter::new("common")));
+        assert!(object().matches_filter(&Filter::new("name")));
+        assert!(object().matches_filter(&Filter::new("scale")));
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{object, Axes, Axis, Joint};
+
+    #[test]
+    fn the_flattened_and_the_inline_fields_have_no_nodes() {
+        let harness = ImguiHarness::new(object());
+        let tree = harness.tree();
+        assert!(tree.find("common").is_none() && tree.find("Meters").is_none());
+        assert!(tree.find("name").is_some() && tree.find("scale").is_some());
+    }
+
+    #[test]
+    fn the_flattened_and_the_inline_fields_are_edited() {
+        let mut harness = ImguiHarness::new(object());
+        assert!(harness.type_text("name", "enemy").changed);
+        assert_eq!(harness.value().common.name, "enemy");
+        assert!(harness.drag("y", 2.0).changed);
+        assert_eq!(harness.value().transform.y, 2.0);
+        assert_eq!(harness.value().transform.x, 0.0);
+        assert!(harness.drag("distance", 3.0).changed);
+        assert_eq!(harness.value().distance.0, 3.0);
+    }
+
+    #[test]
+    fn the_inline_fields_keep_the_widgets_apart() {
+        let mut harness = ImguiHarness::new(Joint {
+            axes: Axes {
+                first: Axis::X,
+                second: Axis::Y,
+            },
+        });
+        assert!(harness.select("second", "Z").changed);
+        assert!(harness.select("first", "Y").changed);
+        assert_eq!(
+            harness.value().axes,
+            Axes {
+                first: Axis::Y,
+                second: Axis::Z,
+            }
+        );
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_show_the_flattened_fields() {
+    use imgui_presentable::testing::TuiHarness;
+
+    let harness = TuiHarness::new(object(), 60, 20);
+    let lines = harness.lines();
+    assert!(lines.iter().any(|l| l.contains("name")));
+    assert!(lines.iter().any(|l| l.contains("distance")));
+    assert!(!lines.iter().any(|l| l.contains("common")));
+    assert!(!lines.iter().any(|l| l.contains("Meters")));
+}
@@ -0,0 +1,170 @@
+use std::marker::PhantomData;
+
+use imgui_presentable::reflect::ReflectRef;
+use imgui_presentable::*;
+
+/// Isn't presentable, so may only be used by the skipped fields.
+#[derive(Debug, Clone, PartialEq)]
+struct Unit;
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Storage<T> {
+    items: Vec<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Named<'a, T> {
+    name: &'a str,
+    value: T,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Samples<T, Tag, const N: usize> {
+    values: [T; N],
+    #[imgui_presentation(skip)]
+    tag: PhantomData<Tag>,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+enum Either<L, R> {
+    Left(L),
+    Right(R),
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Choice {
+    either: Either<u8, String>,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Maybe<T> {
+    value: Option<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Tree<T> {
+    value: T,
+    children: Vec<Tree<T>>,
+}
+
+fn samples() -> Samples<f32, Unit, 3> {
+    Samples {
+        values: [1.0, 2.0, 3.0],
+        tag: PhantomData,
+    }
+}
+
+#[test]
+fn the_generic_types_are_reflected() {
+    let storage = Storage { items: vec![1u32] };
+    assert!(matches!(
+        storage.get_field("items[0]"),
+        Ok(ReflectRef::Value(_))
+    ));
+    let named = Named {
+        name: "answer",
+        value: 42i64,
+    };
+    assert!(named.get_field("value").is_ok());
+    assert!(matches!(
+        Either::<u8, String>::Left(1).reflect(),
+        ReflectRef::Enum(_)
+    ));
+    let tree = Tree {
+        value: 1u8,
+        children: vec![Tree {
+            value: 2,
+            children: Vec::new(),
+        }],
+    };
+    assert!(matches!(
+        tree.get_field("children[0].value"),
+        Ok(ReflectRef::Value(_))
+    ));
+    assert!(Maybe { value: Some(1u8) }.get_field("value").is_ok());
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::EguiHarness;
+
+    use super::{samples, Choice, Either, Maybe, Named, Storage};
+
+    #[test]
+    fn the_generic_structs_are_edited() {
+        let harness = EguiHarness::new(Storage {
+            items: vec![1.0f32, 2.0],
+        });
+        assert!(harness.tree().find("items").is_some());
+
+        let mut harness = EguiHarness::new(samples());
+        assert!(harness.drag("values", 4.0).changed);
+        assert_eq!(harness.value().values[0], 4.0);
+
+        let harness = EguiHarness::new_readonly(Named {
+            name: "answer",
+            value: 42u8,
+        });
+        assert!(harness.tree().find("value").is_some());
+
+        let harness = EguiHarness::new(Maybe { value: Some(1i32) });
+        assert!(harness.tree().find("value").is_some());
+    }
+
+    #[test]
+    fn the_generic_enums_are_edited() {
+        let mut harness = EguiHarness::new(Choice {
+            either: Either::Left(1),
+        });
+        assert!(harness.drag("Left", 5.0).changed);
+        assert_eq!(harness.value().either, Either::Left(5));
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{samples, Choice, Either, Maybe, Storage};
+
+    #[test]
+    fn the_generic_structs_are_edited() {
+        let harness = ImguiHarness::new(Storage {
+            items: vec![1.0f32, 2.0],
+        });
+        assert!(harness.tree().find("items").is_some());
+        drop(harness);
+
+        let mut harness = ImguiHarness::new(samples());
+        assert!(harness.drag("values", 4.0).changed);
+        assert_eq!(harness.value().values, [4.0, 2.0, 3.0]);
+        drop(harness);
+
+        let mut harness = ImguiHarness::new(Maybe { value: Some(1i32) });
+        assert!(harness.drag("value", 2.0).changed);
+        assert_eq!(harness.value().value, Some(2));
+    }
+
+    #[test]
+    fn the_generic_enums_are_edited() {
+        let mut harness = ImguiHarness::new(Choice {
+            either: Either::Left(1),
+        });
+        assert!(harness.drag("Left", 5.0).changed);
+        assert_eq!(harness.value().either, Either::Left(5));
+        drop(harness);
+
+        let mut harness = ImguiHarness::new(Either::<u8, String>::Right("a".to_owned()));
+        assert!(harness.type_text("Right", "b").changed);
+        assert_eq!(*harness.value(), Either::Right("b".to_owned()));
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_show_the_generic_types() {
+    use imgui_presentable::testing::TuiHarness;
+
+    let harness = TuiHarness::new(Maybe { value: Some(7u8) }, 40, 6);
+    assert!(harness.lines().iter().any(|l| l.contains("value")));
+}
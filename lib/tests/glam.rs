@@ -0,0 +1,43 @@
+#![cfg(all(feature = "glam", feature = "egui_backend"))]
+
+use imgui_presentable::testing::EguiHarness;
+use imgui_presentable::*;
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Body {
+    orientation: glam::Quat,
+    transform: glam::Affine3A,
+    cells: glam::UVec3,
+}
+
+#[test]
+fn quaternions_are_edited_as_euler_angles() {
+    let mut harness = EguiHarness::new(Body::default());
+
+    let _ = harness.toggle("orientation");
+    let _ = harness.select("Order", "YXZ");
+    let response = harness.drag("Order", 90.0);
+    assert!(response.changed);
+
+    let expected = glam::Quat::from_rotation_y(90f32.to_radians());
+    assert!(harness.value().orientation.abs_diff_eq(expected, 1e-6));
+    assert!(harness.value().orientation.is_normalized());
+}
+
+#[test]
+fn affine_transforms_are_edited_as_translation_rotation_and_scale() {
+    let mut harness = EguiHarness::new(Body {
+        transform: glam::Affine3A::from_scale(glam::Vec3::splat(2.0)),
+        ..Default::default()
+    });
+
+    let response = harness.drag("translation", 5.0);
+    assert!(response.changed);
+    let _ = harness.drag("cells", 3.0);
+
+    let (scale, rotation, translation) = harness.value().transform.to_scale_rotation_translation();
+    assert_eq!(translation, glam::Vec3::new(5.0, 0.0, 0.0));
+    assert!(rotation.abs_diff_eq(glam::Quat::IDENTITY, 1e-6));
+    assert!(scale.abs_diff_eq(glam::Vec3::splat(2.0), 1e-6));
+    assert_eq!(harness.value().cells, glam::UVec3::new(3, 0, 0));
+}
@@ -0,0 +1,240 @@
+use imgui_presentable::history::Edit;
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, PartialEq, ImguiPresentation)]
+struct Car {
+    speed: f32,
+    name: String,
+}
+
+/// Appends a wheel, without copying the rest of the wheels.
+struct AddWheel(u8);
+
+impl Edit<Vec<u8>> for AddWheel {
+    fn undo(&mut self, value: &mut Vec<u8>) {
+        let _ = value.pop();
+    }
+
+    fn redo(&mut self, value: &mut Vec<u8>) {
+        value.push(self.0);
+    }
+}
+
+fn dragging(changed: bool) -> EditResponse {
+    EditResponse {
+        changed,
+        edit_finished: false,
+        active: true,
+        clicked: false,
+    }
+}
+
+#[test]
+fn the_snapshots_are_undone_and_redone() {
+    let mut history = EditHistory::new(Car::default());
+    assert!(!history.can_undo());
+    assert!(!history.undo());
+
+    history.modify(|car| car.speed = 1.0);
+    history.modify(|car| car.name = "Beetle".to_owned());
+    assert!(history.can_undo());
+
+    assert!(history.undo());
+    assert_eq!(history.value().name, "");
+    assert_eq!(history.value().speed, 1.0);
+    assert!(history.can_redo());
+
+    assert!(history.redo());
+    assert_eq!(history.value().name, "Beetle");
+    assert!(!history.redo());
+
+    assert!(history.undo());
+    assert!(history.undo());
+    assert_eq!(*history.value(), Car::default());
+
+    // A new edit forgets the reverted ones.
+    history.modify(|car| car.speed = 2.0);
+    assert!(!history.can_redo());
+}
+
+#[test]
+fn the_reversible_edits_are_undone_and_redone() {
+    let mut history = EditHistory::new(vec![1u8]).with_limit(2);
+    history.apply(AddWheel(2));
+    history.apply(AddWheel(3));
+    history.apply(AddWheel(4));
+    assert_eq!(*history.value(), [1, 2, 3, 4]);
+
+    // Only the last two edits are kept.
+    assert!(history.undo());
+    assert!(history.undo());
+    assert!(!history.undo());
+    assert_eq!(*history.value(), [1, 2]);
+
+    assert!(history.redo());
+    assert_eq!(history.into_inner(), [1, 2, 3]);
+}
+
+#[test]
+fn the_continuous_edits_are_merged() {
+    let mut history = EditHistory::new(Car::default());
+
+    for speed in [1.0, 2.0, 3.0] {
+        let _ = history.record(|car| {
+            car.speed = speed;
+            dragging(true)
+        });
+    }
+    // Holding the value without changing it doesn't finish the edit.
+    let _ = history.record(|_| dragging(false));
+    let _ = history.record(|_| EditResponse {
+        changed: false,
+        edit_finished: true,
+        active: false,
+        clicked: false,
+    });
+    // The frames without any changes aren't recorded.
+    let _ = history.record(|_| EditResponse::default());
+
+    assert!(history.undo());
+    assert_eq!(history.value().speed, 0.0);
+    assert!(!history.can_undo());
+
+    assert!(history.redo());
+    assert_eq!(history.value().speed, 3.0);
+}
+
+#[test]
+fn the_edit_in_progress_is_undone() {
+    let mut history = EditHistory::new(Car::default());
+    let _ = history.record(|car| {
+        car.speed = 5.0;
+        dragging(true)
+    });
+    assert!(history.can_undo());
+
+    assert!(history.undo());
+    assert_eq!(history.value().speed, 0.0);
+    assert!(history.redo());
+    assert_eq!(history.value().speed, 5.0);
+}
+
+#[test]
+fn the_clicks_leaving_the_value_unchanged_are_not_recorded() {
+    let clicked = EditResponse {
+        clicked: true,
+        ..EditResponse::default()
+    };
+    let mut history = EditHistory::new(Car::default());
+    let _ = history.record(|_| clicked);
+    assert!(!history.can_undo());
+
+    let _ = history.record(|car| {
+        car.speed = 1.0;
+        clicked
+    });
+    assert!(history.undo());
+    assert_eq!(history.value().speed, 0.0);
+    assert!(!history.can_undo());
+}
+
+#[cfg(feature = "egui_backend")]
+#[test]
+fn the_egui_shortcuts_undo_and_redo() {
+    use imgui_presentable::testing::EguiHarness;
+
+    fn press_z(harness: &mut EguiHarness<EditHistory<Car>>, modifiers: egui::Modifiers) {
+        harness.push_event(egui::Event::Key {
+            key: egui::Key::Z,
+            physical_key: None,
+            pressed: true,
+            repeat: false,
+            modifiers,
+        });
+        let _ = harness.run();
+    }
+
+    let mut harness = EguiHarness::new(EditHistory::new(Car::default()));
+    let _ = harness.drag("speed", 3.0);
+    let _ = harness.drag("speed", 4.0);
+    let _ = harness.type_text("name", "Beetle");
+    assert_eq!(harness.value().value().name, "Beetle");
+
+    press_z(&mut harness, egui::Modifiers::COMMAND);
+    assert_eq!(harness.value().value().name, "");
+    press_z(&mut harness, egui::Modifiers::COMMAND);
+    assert_eq!(harness.value().value().speed, 3.0);
+
+    press_z(
+        &mut harness,
+        egui::Modifiers::COMMAND | egui::Modifiers::SHIFT,
+    );
+    assert_eq!(harness.value().value().speed, 4.0);
+}
+
+#[cfg(feature = "imgui_backend")]
+#[test]
+fn the_imgui_shortcuts_undo_and_redo() {
+    use imgui_presentable::testing::ImguiHarness;
+
+    fn press_z(harness: &mut ImguiHarness<EditHistory<Car>>, shift: bool) {
+        let command = if harness.io_mut().config_mac_os_behaviors {
+            imgui::Key::ModSuper
+        } else {
+            imgui::Key::ModCtrl
+        };
+        let io = harness.io_mut();
+        io.add_key_event(command, true);
+        io.add_key_event(imgui::Key::ModShift, shift);
+        io.add_key_event(imgui::Key::Z, true);
+        let _ = harness.run();
+
+        let io = harness.io_mut();
+        io.add_key_event(imgui::Key::Z, false);
+        io.add_key_event(imgui::Key::ModShift, false);
+        io.add_key_event(command, false);
+        let _ = harness.run();
+    }
+
+    let mut harness = ImguiHarness::new(EditHistory::new(Car::default()));
+    let _ = harness.run();
+    harness.value_mut().modify(|car| car.speed = 2.0);
+
+    press_z(&mut harness, false);
+    assert_eq!(harness.value().value().speed, 0.0);
+
+    press_z(&mut harness, true);
+    assert_eq!(harness.value().value().speed, 2.0);
+
+    // The Command key is used on macOS.
+    harness.io_mut().config_mac_os_behaviors = true;
+    press_z(&mut harness, false);
+    assert_eq!(harness.value().value().speed, 0.0);
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminal_keys_undo_and_redo() {
+    use imgui_presentable::testing::TuiHarness;
+    use imgui_presentable::tui::TuiKey;
+
+    let mut harness = TuiHarness::new(EditHistory::new(Car::default()), 40, 10);
+    assert!(harness.focus("speed:"));
+    let _ = harness.press_all([TuiKey::Right, TuiKey::Right]);
+    assert_eq!(harness.value().value().speed, 0.2);
+
+    let _ = harness.press(TuiKey::Undo);
+    assert_eq!(harness.value().value().speed, 0.1);
+
+    let _ = harness.press(TuiKey::Redo);
+    assert_eq!(harness.value().value().speed, 0.2);
+
+    // The typed text has its own editing, which the keys don't undo.
+    assert!(harness.focus("name:"));
+    let _ = harness.press(TuiKey::Enter);
+    let _ = harness.press_all([TuiKey::Char('a'), TuiKey::Undo, TuiKey::Enter]);
+    assert_eq!(harness.value().value().name, "a");
+
+    let _ = harness.press(TuiKey::Undo);
+    assert_eq!(harness.value().value().name, "");
+}
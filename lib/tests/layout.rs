@@ -0,0 +1,233 @@
+use imgui_presentable::*;
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Config {
+    #[imgui_presentation(group = "Physics")]
+    gravity: f32,
+    #[imgui_presentation(tab = "Rendering")]
+    fov: f32,
+    #[imgui_presentation(tab = "Audio", group = "Mixer")]
+    volume: f32,
+    #[imgui_presentation(group = "Physics", separator)]
+    friction: f32,
+    #[imgui_presentation(order = -1)]
+    name: String,
+    #[imgui_presentation(same_line)]
+    paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(layout = "grid")]
+struct Canvas {
+    width: u32,
+    #[imgui_presentation(separator)]
+    height: u32,
+    title: String,
+    #[imgui_presentation(group = "Background")]
+    color: [f32; 3],
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, ImguiPresentation)]
+enum Side {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(layout = "grid")]
+struct Sides {
+    first: Side,
+    second: Side,
+}
+
+fn config() -> Config {
+    Config {
+        gravity: 9.8,
+        fov: 90.0,
+        volume: 0.5,
+        friction: 0.1,
+        name: "level".to_owned(),
+        paused: false,
+    }
+}
+
+fn canvas() -> Canvas {
+    Canvas {
+        width: 640,
+        height: 480,
+        title: "untitled".to_owned(),
+        color: [0.0; 3],
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind};
+
+    use super::{canvas, config, Side, Sides};
+
+    /// Returns the labels of the widgets in their order.
+    fn labels<T: imgui_presentable::EguiPresentable>(harness: &EguiHarness<T>) -> Vec<String> {
+        harness
+            .tree()
+            .iter()
+            .filter_map(|w| w.label.clone())
+            .collect()
+    }
+
+    #[test]
+    fn the_fields_are_ordered_grouped_and_put_into_the_tabs() {
+        let mut harness = EguiHarness::new(config());
+        assert_eq!(
+            labels(&harness),
+            [
+                "name",
+                "Physics",
+                "gravity",
+                "friction",
+                "Rendering",
+                "Audio",
+                "fov",
+                "paused"
+            ]
+        );
+
+        let _ = harness.click("Audio");
+        let labels = labels(&harness);
+        assert!(labels.contains(&"volume".to_owned()));
+        assert!(labels.contains(&"Mixer".to_owned()));
+        assert!(!labels.contains(&"fov".to_owned()));
+
+        // The fields in the tabs are edited.
+        let _ = harness.drag("volume", 1.0);
+        assert_eq!(harness.value().volume, 1.0);
+    }
+
+    #[test]
+    fn the_grid_shows_the_names_next_to_the_values() {
+        let mut harness = EguiHarness::new(canvas());
+        assert_eq!(
+            harness.tree().find("width").unwrap().kind,
+            WidgetKind::Label
+        );
+        assert!(harness.tree().find("Background").is_some());
+
+        assert!(harness.type_text("title", "sketch").changed);
+        assert_eq!(harness.value().title, "sketch");
+        assert!(harness.drag("height", 600.0).changed);
+        assert_eq!(harness.value().height, 600);
+    }
+
+    #[test]
+    fn the_grid_rows_keep_the_widgets_apart() {
+        let mut harness = EguiHarness::new(Sides {
+            first: Side::A,
+            second: Side::B,
+        });
+        let combo_boxes = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::ComboBox)
+            .count();
+        assert_eq!(combo_boxes, 2);
+
+        assert!(harness.select("second", "C").changed);
+        assert!(harness.select("first", "B").changed);
+        assert_eq!(
+            *harness.value(),
+            Sides {
+                first: Side::B,
+                second: Side::C,
+            }
+        );
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::{ImguiHarness, WidgetKind};
+
+    use super::{canvas, config, Side, Sides};
+
+    /// Returns the labels of the widgets in their order.
+    fn labels<T: imgui_presentable::ImguiPresentable>(harness: &ImguiHarness<T>) -> Vec<String> {
+        harness
+            .tree()
+            .iter()
+            .filter_map(|w| w.label.clone())
+            .collect()
+    }
+
+    #[test]
+    fn the_fields_are_ordered_grouped_and_put_into_the_tabs() {
+        let mut harness = ImguiHarness::new(config());
+        let shown = labels(&harness);
+        let position = |label: &str| shown.iter().position(|l| l == label).unwrap();
+        assert!(position("name") < position("Physics"));
+        assert!(position("gravity") < position("friction"));
+        assert!(position("Rendering") < position("fov"));
+        assert!(!shown.contains(&"volume".to_owned()));
+
+        let _ = harness.click("Audio");
+        let shown = labels(&harness);
+        assert!(shown.contains(&"volume".to_owned()));
+        assert!(shown.contains(&"Mixer".to_owned()));
+        assert!(!shown.contains(&"fov".to_owned()));
+
+        // The fields in the tabs are edited.
+        assert!(harness.drag("volume", 1.0).changed);
+        assert_eq!(harness.value().volume, 1.0);
+    }
+
+    #[test]
+    fn the_grid_shows_the_names_next_to_the_values() {
+        let mut harness = ImguiHarness::new(canvas());
+        assert_eq!(
+            harness.tree().find("width").unwrap().kind,
+            WidgetKind::Label
+        );
+        assert!(harness.tree().find("Background").is_some());
+
+        assert!(harness.type_text("title", "sketch").changed);
+        assert_eq!(harness.value().title, "sketch");
+        assert!(harness.drag("height", 600.0).changed);
+        assert_eq!((harness.value().width, harness.value().height), (640, 600));
+    }
+
+    #[test]
+    fn the_grid_rows_keep_the_widgets_apart() {
+        let mut harness = ImguiHarness::new(Sides {
+            first: Side::A,
+            second: Side::B,
+        });
+
+        assert!(harness.select("second", "C").changed);
+        assert!(harness.select("first", "B").changed);
+        assert_eq!(
+            *harness.value(),
+            Sides {
+                first: Side::B,
+                second: Side::C,
+            }
+        );
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_show_the_groups_and_the_tabs() {
+    use imgui_presentable::testing::TuiHarness;
+    use imgui_presentable::tui::TuiKey;
+
+    let mut harness = TuiHarness::new(config(), 60, 30);
+    let lines = harness.lines();
+    assert!(lines.iter().any(|l| l.contains("Physics")));
+    assert!(lines.iter().any(|l| l.contains("◂ Rendering ▸")));
+    assert!(lines.iter().any(|l| l.contains("fov")));
+    assert!(!lines.iter().any(|l| l.contains("volume")));
+
+    assert!(harness.focus("Rendering"));
+    assert!(!harness.press(TuiKey::Right).changed);
+    assert!(harness.lines().iter().any(|l| l.contains("volume")));
+}
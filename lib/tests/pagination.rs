@@ -0,0 +1,119 @@
+use std::collections::BTreeMap;
+
+use imgui_presentable::*;
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Samples {
+    #[imgui_presentation(page_size = 100)]
+    values: Vec<u32>,
+    #[imgui_presentation(page_size = 100)]
+    names: BTreeMap<u32, String>,
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Log {
+    entries: Vec<u32>,
+}
+
+fn samples() -> Samples {
+    Samples {
+        values: (0..10_000).collect(),
+        names: (0..250).map(|i| (i, format!("sample {i}"))).collect(),
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::EguiHarness;
+
+    use super::samples;
+
+    #[test]
+    fn the_pages_are_switched() {
+        let mut harness = EguiHarness::new(samples());
+        let tree = harness.tree();
+        assert!(tree.find("rows 0–99 of 10 000").is_some());
+        assert!(tree.find("rows 0–99 of 250").is_some());
+        assert!(tree.find("99: u32").is_some() && tree.find("100: u32").is_none());
+
+        assert!(!harness.click("▶").changed);
+        let tree = harness.tree();
+        assert!(tree.find("rows 100–199 of 10 000").is_some());
+        assert!(tree.find("99: u32").is_none() && tree.find("100: u32").is_some());
+        // The other collections keep their pages.
+        assert!(tree.find("rows 0–99 of 250").is_some());
+    }
+
+    #[test]
+    fn only_the_visible_rows_are_laid_out() {
+        let mut harness = EguiHarness::new(super::Log {
+            entries: (0..10_000).collect(),
+        });
+        harness
+            .context()
+            .memory_mut(|memory| memory.set_everything_is_visible(false));
+        let _ = harness.click("entries");
+
+        let rows = harness
+            .tree()
+            .iter()
+            .filter(|w| w.label.as_deref().is_some_and(|l| l.ends_with(": u32")))
+            .count();
+        assert!(rows > 0 && rows < 1_000, "{rows} rows are laid out");
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::samples;
+
+    #[test]
+    fn the_pages_are_switched() {
+        let mut harness = ImguiHarness::new(samples());
+        // The default font of ImGui has no dash, which is drawn as a
+        // question mark.
+        let tree = harness.tree();
+        assert!(tree.find("rows 0?99 of 10 000").is_some());
+        assert!(tree.find("rows 0?99 of 250").is_some());
+        assert!(tree.find("99: u32").is_some() && tree.find("100: u32").is_none());
+
+        assert!(!harness.click("▶").changed);
+        let tree = harness.tree();
+        assert!(tree.find("rows 100?199 of 10 000").is_some());
+        assert!(tree.find("99: u32").is_none() && tree.find("100: u32").is_some());
+        // The other collections keep their pages.
+        assert!(tree.find("rows 0?99 of 250").is_some());
+
+        // The elements are edited on the other pages too.
+        let _ = harness.click("100: u32");
+        assert!(harness.drag("u32", 7.0).changed);
+        assert_eq!(harness.value().values[100], 7);
+        assert_eq!(harness.value().values[99], 99);
+    }
+
+    #[test]
+    fn only_the_visible_rows_are_laid_out_until_one_is_open() {
+        let mut harness = ImguiHarness::new(super::Log {
+            entries: (0..10_000).collect(),
+        });
+        let rows = |harness: &ImguiHarness<super::Log>| {
+            harness
+                .tree()
+                .iter()
+                .filter(|w| w.label.as_deref().is_some_and(|l| l.ends_with(": u32")))
+                .count()
+        };
+        let visible = rows(&harness);
+        assert!(
+            visible > 0 && visible < 1_000,
+            "{visible} rows are laid out"
+        );
+
+        // The rows following the open one are placed after its content.
+        let _ = harness.click("0: u32");
+        assert!(harness.tree().find("u32").is_some());
+        assert_eq!(rows(&harness), visible - 1);
+    }
+}
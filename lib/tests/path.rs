@@ -0,0 +1,212 @@
+use std::collections::HashMap;
+
+use imgui_presentable::path::PathError;
+use imgui_presentable::reflect::{ReflectMut, ReflectRef, Value, ValueMut, ValueRef};
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, ImguiPresentation)]
+enum Shape {
+    #[default]
+    Cube,
+    #[imgui_presentation(rename = "Round")]
+    Sphere { radius: f32 },
+}
+
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+struct Object {
+    name: String,
+    visible: bool,
+    #[imgui_presentation(readonly)]
+    id: u32,
+    #[cfg(feature = "glam")]
+    position: glam::Vec3,
+    shape: Shape,
+}
+
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+struct Data {
+    objects: Vec<Object>,
+    tags: HashMap<String, i64>,
+}
+
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+struct Scene {
+    data: Data,
+    layers: [u8; 2],
+}
+
+fn scene() -> Scene {
+    Scene {
+        data: Data {
+            objects: vec![Object::default(), Object::default()],
+            tags: HashMap::from([("size".to_owned(), 3), ("a.b".to_owned(), 4)]),
+        },
+        layers: [1, 2],
+    }
+}
+
+#[test]
+fn the_nested_values_are_found() {
+    let mut scene = scene();
+    scene.data.objects[1].name = "Lamp".to_owned();
+
+    assert!(matches!(
+        scene.get_field("data.objects[1].name"),
+        Ok(ReflectRef::Value(ValueRef::String("Lamp")))
+    ));
+    assert!(matches!(
+        scene.get_field("data.tags[size]"),
+        Ok(ReflectRef::Value(ValueRef::I64(3)))
+    ));
+    assert!(matches!(
+        scene.get_field("data.tags[\"a.b\"]"),
+        Ok(ReflectRef::Value(ValueRef::I64(4)))
+    ));
+    assert!(matches!(
+        scene.get_field("layers[1]"),
+        Ok(ReflectRef::Value(ValueRef::U8(2)))
+    ));
+    assert!(matches!(scene.get_field(""), Ok(ReflectRef::Struct(_))));
+
+    if let Ok(ReflectMut::Value(ValueMut::Bool(visible))) =
+        scene.get_field_mut("data.objects[0].visible")
+    {
+        *visible = true;
+    }
+    assert!(scene.data.objects[0].visible);
+}
+
+#[test]
+fn the_nested_values_are_set() {
+    let mut scene = scene();
+
+    scene
+        .set_field("data.objects[1].name", "Lamp".into())
+        .unwrap();
+    scene
+        .set_field("data.objects[1].visible", "true".into())
+        .unwrap();
+    scene
+        .set_field("data.tags[size]", Value::Integer(5))
+        .unwrap();
+    scene.set_field("layers[0]", " 7 ".into()).unwrap();
+    // The integral floats are accepted by the integers.
+    scene.set_field("data.tags[\"a.b\"]", 6.0.into()).unwrap();
+
+    assert_eq!(scene.data.objects[1].name, "Lamp");
+    assert!(scene.data.objects[1].visible);
+    assert_eq!(scene.data.tags["size"], 5);
+    assert_eq!(scene.data.tags["a.b"], 6);
+    assert_eq!(scene.layers, [7, 2]);
+}
+
+#[test]
+fn the_enum_variants_are_set_by_name() {
+    let mut scene = scene();
+
+    scene
+        .set_field("data.objects[0].shape", "Sphere".into())
+        .unwrap();
+    scene
+        .set_field("data.objects[0].shape.radius", 2.5.into())
+        .unwrap();
+    assert_eq!(scene.data.objects[0].shape, Shape::Sphere { radius: 2.5 });
+
+    // The titles are accepted as well.
+    scene
+        .set_field("data.objects[1].shape", "Round".into())
+        .unwrap();
+    assert_eq!(scene.data.objects[1].shape, Shape::Sphere { radius: 0.0 });
+
+    assert!(matches!(
+        scene.get_field("data.objects[1].shape.height"),
+        Err(PathError::NoField { field, .. }) if field == "height"
+    ));
+}
+
+#[test]
+fn the_errors_are_reported() {
+    let mut scene = scene();
+
+    assert_eq!(
+        scene.set_field("data.objects[2].name", "Lamp".into()),
+        Err(PathError::NoElement { index: 2, len: 2 })
+    );
+    assert_eq!(
+        scene.set_field("data.objects[0].id", Value::Integer(1)),
+        Err(PathError::ReadOnly("id".to_owned()))
+    );
+    assert_eq!(
+        scene.set_field("data.tags[colour]", Value::Integer(1)),
+        Err(PathError::NoEntry("colour".to_owned()))
+    );
+    assert_eq!(
+        scene.set_field("layers[0]", Value::Integer(256)),
+        Err(PathError::InvalidValue {
+            value: Value::Integer(256),
+            type_name: "u8",
+        })
+    );
+    assert!(matches!(
+        scene.set_field("data.objects[0].visible", "maybe".into()),
+        Err(PathError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        scene.set_field("data.objects", Value::Integer(1)),
+        Err(PathError::InvalidValue { .. })
+    ));
+    assert!(matches!(
+        scene.get_field("data.objects.name"),
+        Err(PathError::NotNested(_))
+    ));
+    assert_eq!(
+        scene.get_field("data..objects").err(),
+        Some(PathError::Syntax(5))
+    );
+
+    assert_eq!(scene, self::scene());
+}
+
+#[cfg(feature = "egui_backend")]
+#[test]
+fn the_fields_not_reflected_are_reported() {
+    #[derive(Debug, Default, ImguiPresentation)]
+    struct Lamp {
+        brightness: f32,
+        color: egui::Color32,
+    }
+
+    let mut lamp = Lamp::default();
+    lamp.set_field("brightness", 0.5.into()).unwrap();
+    assert_eq!(lamp.brightness, 0.5);
+    assert_eq!(
+        lamp.get_field("color").err(),
+        Some(PathError::NotReflected("color".to_owned()))
+    );
+    assert_eq!(
+        lamp.set_field("color", Value::Integer(1)),
+        Err(PathError::NotReflected("color".to_owned()))
+    );
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn the_glam_components_are_addressed() {
+    let mut scene = scene();
+
+    scene
+        .set_field("data.objects[1].position.y", 1.5.into())
+        .unwrap();
+    assert_eq!(
+        scene.data.objects[1].position,
+        glam::Vec3::new(0.0, 1.5, 0.0)
+    );
+    assert!(matches!(
+        scene.get_field("data.objects[1].position.y"),
+        Ok(ReflectRef::Value(ValueRef::F32(y))) if *y == 1.5
+    ));
+
+    let mut transform = glam::Mat2::IDENTITY;
+    transform.set_field("x_axis.y", 2.0.into()).unwrap();
+    assert_eq!(transform.x_axis, glam::Vec2::new(1.0, 2.0));
+}
@@ -0,0 +1,337 @@
+use std::collections::BTreeMap;
+
+use imgui_presentable::reflect::{ReflectMut, ReflectRef, ValueMut, ValueRef};
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, ImguiPresentation)]
+enum Fuel {
+    #[default]
+    Petrol,
+    Diesel,
+}
+
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+enum Engine {
+    #[default]
+    None,
+    /// A combustion engine.
+    #[imgui_presentation(rename = "Combustion engine")]
+    Combustion {
+        #[imgui_presentation(range = 1u8..16u8)]
+        cylinders: u8,
+        #[imgui_presentation(skip)]
+        _serial: u32,
+        fuel: Fuel,
+    },
+    Electric(#[imgui_presentation(readonly)] f32),
+}
+
+/// A car.
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+struct Car {
+    /// The name of the car.
+    name: String,
+    #[imgui_presentation(speed = 0.5, format = "%.1f", suffix = " km/h", range = 0.0..)]
+    speed: f32,
+    #[imgui_presentation(skip)]
+    _cache: u64,
+    #[imgui_presentation(readonly, rename = "Serial number")]
+    serial: u32,
+    engine: Engine,
+    wheels: Vec<u32>,
+    owners: BTreeMap<String, Option<u8>>,
+}
+
+#[derive(Debug, Default, ImguiPresentation)]
+#[imgui_presentation(readonly)]
+struct Point(i32, i32);
+
+#[derive(Debug, Default, ImguiPresentation)]
+struct Gauge {
+    #[imgui_presentation(range = 0.0f32..self.limit)]
+    value: f32,
+    limit: f32,
+    #[cfg(feature = "egui_backend")]
+    color: egui::Color32,
+}
+
+fn strukt(value: &dyn Reflect) -> &dyn reflect::Struct {
+    match value.reflect() {
+        ReflectRef::Struct(s) => s,
+        _ => panic!("{} isn't a struct.", value.type_name()),
+    }
+}
+
+fn strukt_mut(value: &mut dyn Reflect) -> &mut dyn reflect::Struct {
+    match value.reflect_mut() {
+        ReflectMut::Struct(s) => s,
+        _ => panic!("Not a struct."),
+    }
+}
+
+fn display(value: &dyn Reflect) -> String {
+    match value.reflect() {
+        ReflectRef::Value(value) => value.to_string(),
+        _ => panic!("{} isn't a primitive value.", value.type_name()),
+    }
+}
+
+fn enumm_mut(value: &mut dyn Reflect) -> &mut dyn reflect::Enum {
+    match value.reflect_mut() {
+        ReflectMut::Enum(e) => e,
+        _ => panic!("Not an enum."),
+    }
+}
+
+#[test]
+fn the_struct_is_described() {
+    let car = Car::default();
+    let s = strukt(&car);
+    let info = s.info();
+
+    assert_eq!(info.name, "Car");
+    assert_eq!(info.docs, Some("A car."));
+    assert!(!info.readonly);
+
+    let names: Vec<_> = info.fields.iter().map(|f| f.name).collect();
+    assert_eq!(
+        names,
+        ["name", "speed", "serial", "engine", "wheels", "owners"]
+    );
+
+    assert_eq!(info.fields[0].docs, Some("The name of the car."));
+    assert_eq!(info.fields[1].speed, Some(0.5));
+    assert_eq!(info.fields[1].format, Some("%.1f"));
+    assert_eq!(info.fields[1].suffix, Some(" km/h"));
+    assert_eq!(s.field_range(1), Some((0.0, f64::INFINITY)));
+    assert_eq!(s.field_range(0), None);
+    assert_eq!(info.fields[2].title, "Serial number");
+    assert!(info.fields[2].readonly);
+
+    assert_eq!(s.fields().count(), 6);
+    assert!(matches!(
+        s.field_by_name("serial").unwrap().reflect(),
+        ReflectRef::Value(ValueRef::U32(0))
+    ));
+    assert!(s.field_by_name("_cache").is_none());
+}
+
+#[test]
+fn the_fields_are_changed_through_the_description() {
+    let mut car = Car::default();
+    let s = strukt_mut(&mut car);
+
+    match s.field_by_name_mut("name").unwrap().reflect_mut() {
+        ReflectMut::Value(ValueMut::String(name)) => name.push_str("Beetle"),
+        _ => panic!("The name is a string."),
+    }
+    match s.field_mut(1).unwrap().reflect_mut() {
+        ReflectMut::Value(ValueMut::F32(speed)) => *speed = 90.0,
+        _ => panic!("The speed is a number."),
+    }
+    // The read-only fields can only be looked at.
+    assert!(s.field_by_name_mut("serial").is_none());
+    assert!(s.field_by_name("serial").is_some());
+
+    assert_eq!(car.name, "Beetle");
+    assert_eq!(car.speed, 90.0);
+
+    let mut point = Point(1, 2);
+    let s = strukt_mut(&mut point);
+    assert!(s.info().readonly);
+    assert_eq!(s.info().fields[1].name, "1");
+    assert!(s.field_mut(0).is_none());
+    assert_eq!(display(s.field(1).unwrap()), "2");
+}
+
+#[test]
+fn the_enum_variants_are_described_and_selected() {
+    let mut engine = Engine::default();
+    let e = enumm_mut(&mut engine);
+
+    let titles: Vec<_> = e.info().variants.iter().map(|v| v.title).collect();
+    assert_eq!(titles, ["None", "Combustion engine", "Electric"]);
+    assert_eq!(e.info().variants[1].docs, Some("A combustion engine."));
+    assert_eq!(e.variant().name, "None");
+    assert!(e.field(0).is_none());
+
+    assert!(e.set_variant(1));
+    assert_eq!(e.variant_index(), 1);
+    let fields: Vec<_> = e.variant().fields.iter().map(|f| f.name).collect();
+    assert_eq!(fields, ["cylinders", "fuel"]);
+    assert_eq!(e.field_range(0), Some((1.0, 16.0)));
+    assert_eq!(e.field_range(1), None);
+
+    let fuel = enumm_mut(e.field_mut(1).unwrap());
+    assert!(fuel.set_variant(1));
+    assert!(!fuel.set_variant(2));
+    assert_eq!(
+        engine,
+        Engine::Combustion {
+            cylinders: 0,
+            _serial: 0,
+            fuel: Fuel::Diesel,
+        }
+    );
+
+    // Selecting the current variant keeps the data.
+    let e = enumm_mut(&mut engine);
+    assert!(e.set_variant(1));
+    assert!(matches!(
+        engine,
+        Engine::Combustion {
+            fuel: Fuel::Diesel,
+            ..
+        }
+    ));
+
+    let mut engine = Engine::Electric(5.0);
+    let e = enumm_mut(&mut engine);
+    assert_eq!(e.variant().fields[0].name, "0");
+    assert!(e.variant().fields[0].readonly);
+    assert!(e.field_mut(0).is_none());
+    assert_eq!(display(e.field(0).unwrap()), "5");
+}
+
+#[test]
+fn the_collections_are_walked() {
+    let mut car = Car {
+        wheels: vec![1, 2, 3],
+        owners: BTreeMap::from([("Ann".to_owned(), Some(30)), ("Bob".to_owned(), None)]),
+        ..Default::default()
+    };
+    let s = strukt_mut(&mut car);
+
+    let ReflectMut::List(wheels) = s.field_by_name_mut("wheels").unwrap().reflect_mut() else {
+        panic!("The wheels are a list.");
+    };
+    assert_eq!(wheels.len(), 3);
+    if let ReflectMut::Value(ValueMut::U32(wheel)) = wheels.get_mut(2).unwrap().reflect_mut() {
+        *wheel = 4;
+    }
+    assert!(wheels.get(3).is_none());
+
+    let ReflectMut::Map(owners) = s.field_by_name_mut("owners").unwrap().reflect_mut() else {
+        panic!("The owners are a map.");
+    };
+    let (key, value) = owners.get_index_mut(1).unwrap();
+    assert_eq!(display(key), "Bob");
+    let age = enumm_mut(value);
+    assert_eq!(age.variant().name, "None");
+    assert!(age.set_variant(1));
+
+    assert_eq!(car.wheels, [1, 2, 4]);
+    assert_eq!(car.owners["Bob"], Some(0));
+}
+
+#[test]
+fn the_pointers_are_looked_through() {
+    let boxed = Box::new(Point(3, 4));
+    assert_eq!(strukt(&boxed).info().name, "Point");
+    assert_eq!(boxed.type_name(), std::any::type_name::<Point>());
+
+    let mut shared = std::rc::Rc::new(5u8);
+    let other = shared.clone();
+    assert!(matches!(shared.reflect_mut(), ReflectMut::Opaque));
+    drop(other);
+    assert!(matches!(
+        shared.reflect_mut(),
+        ReflectMut::Value(ValueMut::U8(5))
+    ));
+
+    let mut cell = std::cell::RefCell::new(1.5f64);
+    assert!(matches!(cell.reflect(), ReflectRef::Opaque));
+    if let ReflectMut::Value(ValueMut::F64(value)) = cell.reflect_mut() {
+        *value = 2.5;
+    }
+    assert_eq!(*cell.borrow(), 2.5);
+
+    let tuple = (1u8, "a".to_owned(), true);
+    let ReflectRef::List(list) = tuple.reflect() else {
+        panic!("The tuples are lists.");
+    };
+    assert_eq!(list.len(), 3);
+    assert_eq!(display(list.get(2).unwrap()), "true");
+}
+
+#[test]
+fn the_ranges_depend_on_the_values() {
+    let mut gauge = Gauge {
+        limit: 2.0,
+        ..Default::default()
+    };
+    assert_eq!(strukt(&gauge).field_range(0), Some((0.0, 2.0)));
+    gauge.limit = 5.0;
+    assert_eq!(strukt(&gauge).field_range(0), Some((0.0, 5.0)));
+}
+
+#[cfg(feature = "egui_backend")]
+#[test]
+fn the_fields_not_reflected_have_no_values() {
+    let gauge = Gauge::default();
+    let s = strukt(&gauge);
+    assert_eq!(s.info().fields[2].name, "color");
+    assert!(s.field_by_name("color").is_none());
+    assert!(s.field_by_name("limit").is_some());
+}
+
+#[cfg(feature = "glam")]
+#[test]
+fn the_glam_types_are_described() {
+    let mut vector = glam::Vec3::new(1.0, 2.0, 3.0);
+    let s = strukt_mut(&mut vector);
+    assert_eq!(s.info().name, "glam::Vec3");
+    if let ReflectMut::Value(ValueMut::F32(y)) = s.field_by_name_mut("y").unwrap().reflect_mut() {
+        *y = 5.0;
+    }
+    assert_eq!(vector, glam::Vec3::new(1.0, 5.0, 3.0));
+
+    let matrix = glam::Mat2::IDENTITY;
+    let column = strukt(strukt(&matrix).field(1).unwrap());
+    assert_eq!(display(column.field(1).unwrap()), "1");
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminal_presents_the_reflected_values() {
+    use imgui_presentable::testing::TuiHarness;
+    use imgui_presentable::tui::{Reflected, TuiKey};
+
+    let car = Car {
+        wheels: vec![1, 2],
+        engine: Engine::Combustion {
+            cylinders: 15,
+            _serial: 0,
+            fuel: Fuel::Petrol,
+        },
+        ..Default::default()
+    };
+    let mut harness = TuiHarness::new(Reflected(car), 50, 30);
+    assert!(harness.lines().iter().any(|l| l.contains("A car.")));
+    assert!(harness
+        .lines()
+        .iter()
+        .any(|l| l.contains("speed: 0.0 km/h")));
+
+    assert!(harness.focus("speed:"));
+    let _ = harness.press(TuiKey::Left);
+    // The range starts at zero.
+    assert_eq!(harness.value().0.speed, 0.0);
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().0.speed, 0.5);
+
+    assert!(harness.focus("cylinders:"));
+    let _ = harness.press_all([TuiKey::Right, TuiKey::Right]);
+    assert!(matches!(
+        harness.value().0.engine,
+        Engine::Combustion { cylinders: 16, .. }
+    ));
+
+    assert!(harness.focus("Serial number:"));
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().0.serial, 0);
+
+    assert!(harness.focus("Combustion engine"));
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().0.engine, Engine::Electric(0.0));
+}
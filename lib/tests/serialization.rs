@@ -0,0 +1,201 @@
+#![cfg(feature = "serde")]
+
+use imgui_presentable::*;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ImguiPresentation)]
+struct Light {
+    name: String,
+    intensity: f32,
+}
+
+/// Can neither be copied nor pasted.
+#[derive(Debug, PartialEq, ImguiPresentation)]
+struct Handle {
+    id: u32,
+}
+
+#[derive(Debug, ImguiPresentation)]
+struct Stage {
+    key: Light,
+    handle: Handle,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ImguiPresentation)]
+struct Rig {
+    key: Light,
+    fill: Light,
+}
+
+fn light(name: &str, intensity: f32) -> Light {
+    Light {
+        name: name.to_owned(),
+        intensity,
+    }
+}
+
+fn stage() -> Stage {
+    Stage {
+        key: light("key", 1.0),
+        handle: Handle { id: 1 },
+    }
+}
+
+fn rig() -> Rig {
+    Rig {
+        key: light("key", 1.0),
+        fill: light("fill", 0.5),
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::EguiHarness;
+    use imgui_presentable::EguiPresentable;
+
+    use super::{light, rig, stage, Rig};
+
+    #[test]
+    fn the_fields_are_copied_and_pasted() {
+        let mut harness = EguiHarness::new(stage());
+
+        let _ = harness.secondary_click("key");
+        assert!(!harness.tree().find("Paste").unwrap().enabled);
+        assert!(!harness.click("Copy as RON").changed);
+
+        harness.value_mut().key = light("other", 3.0);
+        let _ = harness.secondary_click("key");
+        assert!(harness.click("Paste").changed);
+        assert_eq!(harness.value().key, light("key", 1.0));
+    }
+
+    #[test]
+    fn the_text_pasted_into_the_application_is_used() {
+        let mut harness = EguiHarness::new(stage());
+
+        harness.push_event(egui::Event::Paste(
+            r#"{ "name": "pasted", "intensity": 2.0 }"#.to_owned(),
+        ));
+        let _ = harness.secondary_click("key");
+        assert!(harness.click("Paste").changed);
+        assert_eq!(harness.value().key, light("pasted", 2.0));
+
+        // The text of another value can't be pasted.
+        harness.push_event(egui::Event::Paste("[1, 2]".to_owned()));
+        let _ = harness.secondary_click("key");
+        assert!(!harness.tree().find("Paste").unwrap().enabled);
+    }
+
+    #[test]
+    fn only_the_supported_actions_are_offered() {
+        let mut harness = EguiHarness::new(stage());
+        let _ = harness.secondary_click("handle");
+        assert!(harness.tree().find("Copy as JSON").is_none());
+
+        let mut harness = EguiHarness::new_readonly(stage());
+        let _ = harness.secondary_click("key");
+        assert!(harness.tree().find("Copy as JSON").is_some());
+        assert!(harness.tree().find("Paste").is_none());
+    }
+
+    /// Renders the window of the rig.
+    struct Window(Rig);
+
+    impl EguiPresentable for Window {
+        fn render_component(&self, ui: &mut egui::Ui) {
+            self.0.render_window(ui.ctx());
+        }
+
+        fn render_component_mut(&mut self, ui: &mut egui::Ui) -> imgui_presentable::EditResponse {
+            self.0.render_window_mut(ui.ctx())
+        }
+    }
+
+    #[test]
+    fn the_presets_are_saved_and_loaded() {
+        let path = std::env::temp_dir().join(format!(
+            "imgui_presentable_preset_{}.json",
+            std::process::id()
+        ));
+        let mut harness = EguiHarness::new(Window(rig()));
+
+        let _ = harness.click("Save preset…");
+        let _ = harness.type_text("Preset file:", path.to_str().unwrap());
+        let _ = harness.click("Save");
+        assert!(harness.tree().find("Preset file:").is_none());
+        assert_eq!(
+            imgui_presentable::serialization::load_preset::<Rig>(&path).unwrap(),
+            rig()
+        );
+
+        harness.value_mut().0.fill = light("other", 0.0);
+        let _ = harness.click("Load preset…");
+        // The path is kept.
+        assert!(harness.click("Load").changed);
+        assert_eq!(harness.value().0, rig());
+
+        std::fs::remove_file(&path).unwrap();
+        let _ = harness.click("Load preset…");
+        assert!(!harness.click("Load").changed);
+        assert!(harness.tree().find("Preset file:").is_some());
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{light, rig, stage, Rig};
+
+    #[test]
+    fn the_fields_are_copied_and_pasted() {
+        let mut harness = ImguiHarness::new(stage());
+
+        let _ = harness.secondary_click("key");
+        assert!(!harness.tree().find("Paste").unwrap().enabled);
+        assert!(!harness.click("Copy as RON").changed);
+
+        harness.value_mut().key = light("other", 3.0);
+        let _ = harness.secondary_click("key");
+        assert!(harness.click("Paste").changed);
+        assert_eq!(harness.value().key, light("key", 1.0));
+    }
+
+    #[test]
+    fn only_the_supported_actions_are_offered() {
+        let mut harness = ImguiHarness::new(stage());
+        let _ = harness.secondary_click("handle");
+        assert!(harness.tree().find("Copy as JSON").is_none());
+    }
+
+    #[test]
+    fn the_presets_are_saved_and_loaded() {
+        let path = std::env::temp_dir().join(format!(
+            "imgui_presentable_imgui_preset_{}.json",
+            std::process::id()
+        ));
+        let mut harness = ImguiHarness::new(rig());
+
+        // The default font of ImGui has no ellipsis, which is drawn as
+        // a question mark.
+        let _ = harness.click("Save preset?");
+        let _ = harness.type_text("Preset file:", path.to_str().unwrap());
+        let _ = harness.click("Save");
+        assert!(harness.tree().find("Preset file:").is_none());
+        assert_eq!(
+            imgui_presentable::serialization::load_preset::<Rig>(&path).unwrap(),
+            rig()
+        );
+
+        harness.value_mut().fill = light("other", 0.0);
+        let _ = harness.click("Load preset?");
+        // The path is kept.
+        assert!(harness.click("Load").changed);
+        assert_eq!(*harness.value(), rig());
+
+        std::fs::remove_file(&path).unwrap();
+        let _ = harness.click("Load preset?");
+        assert!(!harness.click("Load").changed);
+        assert!(harness.tree().find("Preset file:").is_some());
+    }
+}
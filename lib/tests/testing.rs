@@ -0,0 +1,243 @@
+#[cfg(feature = "egui_backend")]
+mod egui {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind, WidgetValue};
+    use imgui_presentable::*;
+
+    #[derive(Debug, Default, ImguiPresentation)]
+    #[imgui_presentation(button("Reload": "reload"))]
+    struct Car {
+        #[imgui_presentation(range = 0.0f32..10.0f32)]
+        speed: f32,
+        name: String,
+        enabled: bool,
+        #[imgui_presentation(readonly)]
+        reloads: u32,
+    }
+
+    impl Car {
+        fn reload(&mut self) {
+            self.reloads += 1;
+        }
+    }
+
+    #[test]
+    fn captures_the_widget_tree() {
+        let harness = EguiHarness::new(Car {
+            speed: 1.5,
+            name: "Beetle".to_owned(),
+            ..Default::default()
+        });
+        let tree = harness.tree();
+
+        let speed = tree.find("speed").expect("The speed header.");
+        assert_eq!(speed.kind, WidgetKind::Button);
+        let drag = tree
+            .iter()
+            .find(|w| w.kind == WidgetKind::DragValue)
+            .expect("The speed drag value.");
+        assert_eq!(drag.value, Some(WidgetValue::Number(1.5)));
+        assert!(drag.enabled);
+
+        let name = tree
+            .iter()
+            .find(|w| w.kind == WidgetKind::TextEdit)
+            .expect("The name input.");
+        assert_eq!(name.value, Some(WidgetValue::Text("Beetle".to_owned())));
+
+        let reloads = tree
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue)
+            .last()
+            .expect("The reloads drag value.");
+        assert!(!reloads.enabled);
+    }
+
+    #[test]
+    fn simulates_interactions() {
+        let mut harness = EguiHarness::new(Car::default());
+
+        let response = harness.drag("speed", 3.0);
+        assert!(response.changed);
+        assert_eq!(harness.value().speed, 3.0);
+
+        // The range is respected.
+        let _ = harness.drag("speed", 30.0);
+        assert_eq!(harness.value().speed, 10.0);
+
+        let response = harness.click("Reload");
+        assert!(response.clicked);
+        assert!(!response.changed);
+        assert_eq!(harness.value().reloads, 1);
+
+        let _ = harness.type_text("name", "Beetle");
+        assert_eq!(harness.value().name, "Beetle");
+
+        let _ = harness.toggle("enabled");
+        assert!(harness.value().enabled);
+    }
+
+    #[test]
+    fn readonly_values_are_not_changed() {
+        let mut harness = EguiHarness::new_readonly(Car::default());
+        assert!(harness
+            .tree()
+            .iter()
+            .all(|w| w.kind != WidgetKind::DragValue || !w.enabled));
+        assert!(!harness.run().any());
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui {
+    use imgui_presentable::testing::{ImguiHarness, WidgetKind, WidgetValue};
+    use imgui_presentable::*;
+
+    #[derive(Debug, Default, ImguiPresentation)]
+    #[imgui_presentation(button("Reload": "reload"))]
+    struct Car {
+        #[imgui_presentation(range = 0.0f32..10.0f32)]
+        speed: f32,
+        name: String,
+        enabled: bool,
+        #[imgui_presentation(readonly)]
+        reloads: u32,
+    }
+
+    impl Car {
+        fn reload(&mut self) {
+            self.reloads += 1;
+        }
+    }
+
+    #[test]
+    fn captures_the_widget_tree() {
+        let harness = ImguiHarness::new(Car {
+            speed: 1.5,
+            name: "Beetle".to_owned(),
+            ..Default::default()
+        });
+        let tree = harness.tree();
+
+        let speed = tree.find("speed").expect("The speed header.");
+        assert_eq!(speed.kind, WidgetKind::Button);
+        let drag = tree
+            .iter()
+            .find(|w| w.kind == WidgetKind::DragValue)
+            .expect("The speed drag value.");
+        assert_eq!(drag.label.as_deref(), Some("f32"));
+        assert_eq!(drag.value, Some(WidgetValue::Number(1.5)));
+        assert!(drag.enabled);
+
+        let name = tree
+            .iter()
+            .find(|w| w.kind == WidgetKind::TextEdit)
+            .expect("The name input.");
+        assert_eq!(name.value, Some(WidgetValue::Text("Beetle".to_owned())));
+
+        let enabled = tree
+            .iter()
+            .find(|w| w.kind == WidgetKind::Checkbox)
+            .expect("The enabled checkbox.");
+        assert_eq!(enabled.value, Some(WidgetValue::Bool(false)));
+
+        let reloads = tree
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue)
+            .last()
+            .expect("The reloads drag value.");
+        assert!(!reloads.enabled);
+    }
+
+    #[test]
+    fn simulates_interactions() {
+        let mut harness = ImguiHarness::new(Car::default());
+
+        let response = harness.drag("speed", 3.0);
+        assert!(response.changed);
+        assert_eq!(harness.value().speed, 3.0);
+
+        let response = harness.click("Reload");
+        assert!(response.clicked);
+        assert!(!response.changed);
+        assert_eq!(harness.value().reloads, 1);
+
+        let _ = harness.type_text("name", "Beetle");
+        assert_eq!(harness.value().name, "Beetle");
+
+        let _ = harness.toggle("enabled");
+        assert!(harness.value().enabled);
+    }
+
+    #[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+    enum Shape {
+        Point,
+        Circle { radius: f32 },
+    }
+
+    #[derive(Debug, ImguiPresentation)]
+    struct Scene {
+        shape: Shape,
+    }
+
+    #[test]
+    fn selects_the_options() {
+        let mut harness = ImguiHarness::new(Scene {
+            shape: Shape::Point,
+        });
+        let combo_box = harness
+            .tree()
+            .iter()
+            .find(|w| w.kind == WidgetKind::ComboBox)
+            .expect("The variant combo box.");
+        assert_eq!(combo_box.value, Some(WidgetValue::Text("Point".to_owned())));
+
+        assert!(harness.select("shape", "Circle").changed);
+        assert_eq!(harness.value().shape, Shape::Circle { radius: 0.0 });
+        assert!(harness.drag("radius", 2.0).changed);
+        assert_eq!(harness.value().shape, Shape::Circle { radius: 2.0 });
+    }
+
+    #[derive(Debug, ImguiPresentation)]
+    #[imgui_presentation(layout = "grid")]
+    struct Canvas {
+        width: u32,
+        height: u32,
+        visible: bool,
+    }
+
+    fn canvas() -> Canvas {
+        Canvas {
+            width: 640,
+            height: 480,
+            visible: false,
+        }
+    }
+
+    #[test]
+    fn pairs_the_labels_with_the_values_of_their_rows() {
+        // The tables draw the names before all the values.
+        let mut harness = ImguiHarness::new(canvas());
+
+        assert!(harness.drag("height", 600.0).changed);
+        assert_eq!((harness.value().width, harness.value().height), (640, 600));
+        assert!(harness.drag("width", 800.0).changed);
+        assert_eq!((harness.value().width, harness.value().height), (800, 600));
+        assert!(harness.toggle("visible").changed);
+        assert!(harness.value().visible);
+    }
+
+    #[test]
+    #[should_panic(expected = "on the row of")]
+    fn does_not_pair_the_labels_with_the_values_of_other_rows() {
+        let mut harness = ImguiHarness::new(canvas());
+        let _ = harness.toggle("width");
+    }
+
+    #[test]
+    fn runs_headless() {
+        let mut harness = ImguiHarness::new(Car::default());
+        harness.run_readonly();
+        assert!(!harness.run().changed);
+        assert_eq!(harness.value().speed, 0.0);
+    }
+}
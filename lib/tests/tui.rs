@@ -0,0 +1,218 @@
+#![cfg(feature = "ratatui_backend")]
+
+use imgui_presentable::testing::TuiHarness;
+use imgui_presentable::tui::TuiKey;
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, ImguiPresentation)]
+enum Fuel {
+    #[default]
+    Petrol,
+    Diesel,
+    Electric,
+}
+
+#[derive(Debug, Default, PartialEq, ImguiPresentation)]
+enum Engine {
+    #[default]
+    None,
+    Combustion {
+        #[imgui_presentation(range = 1u8..16u8)]
+        cylinders: u8,
+    },
+}
+
+/// A car.
+#[derive(Debug, Default, ImguiPresentation)]
+struct Car {
+    /// The name of the car.
+    name: String,
+    #[imgui_presentation(speed = 0.5, format = "%.1f km/h")]
+    speed: f32,
+    fuel: Fuel,
+    engine: Engine,
+    wheels: Vec<u32>,
+    ready: bool,
+}
+
+fn car() -> Car {
+    Car {
+        wheels: vec![1, 2],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn the_rows_are_navigated_with_the_keys() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.lines()[0].contains("Car"));
+    assert_eq!(harness.state().focus(), 0);
+
+    let _ = harness.press_all([TuiKey::Down, TuiKey::Down]);
+    assert!(harness.focused_line().unwrap().contains("speed: 0.0 km/h"));
+
+    let _ = harness.press(TuiKey::End);
+    assert!(harness.focused_line().unwrap().contains("ready: [ ]"));
+
+    let _ = harness.press(TuiKey::Up);
+    assert!(harness.focused_line().unwrap().contains("[ Clear ]"));
+
+    let _ = harness.press(TuiKey::Home);
+    assert!(harness.focused_line().unwrap().contains("A car."));
+}
+
+#[test]
+fn the_documentation_is_shown_for_the_focused_field() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("name:"));
+    assert!(harness
+        .lines()
+        .last()
+        .unwrap()
+        .contains("The name of the car."));
+
+    assert!(harness.focus("speed:"));
+    assert!(!harness
+        .lines()
+        .last()
+        .unwrap()
+        .contains("The name of the car."));
+}
+
+#[test]
+fn the_scalars_are_stepped_and_typed_in() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("speed:"));
+
+    let response = harness.press(TuiKey::Right);
+    assert!(response.changed);
+    assert_eq!(harness.value().speed, 0.5);
+    assert!(harness.focused_line().unwrap().contains("speed: 0.5 km/h"));
+
+    let _ = harness.press(TuiKey::Enter);
+    assert!(harness.state().is_editing());
+    let _ = harness.press_all([TuiKey::Backspace; 3]);
+    let response = harness.type_text("12");
+    assert!(!response.changed);
+    assert!(response.active);
+
+    let response = harness.press(TuiKey::Enter);
+    assert!(response.changed);
+    assert!(!harness.state().is_editing());
+    assert_eq!(harness.value().speed, 12.0);
+
+    // The editing may be cancelled.
+    let _ = harness.press(TuiKey::Enter);
+    let _ = harness.type_text("5");
+    let response = harness.press(TuiKey::Esc);
+    assert!(!response.changed);
+    assert_eq!(harness.value().speed, 12.0);
+}
+
+#[test]
+fn the_strings_are_edited() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("name:"));
+
+    let _ = harness.press(TuiKey::Enter);
+    let _ = harness.type_text("Herbie");
+    assert_eq!(harness.value().name, "");
+    assert!(harness.focused_line().unwrap().contains("name: Herbie"));
+
+    let response = harness.press(TuiKey::Enter);
+    assert!(response.changed);
+    assert_eq!(harness.value().name, "Herbie");
+
+    // The navigation keys are typed in while editing.
+    let _ = harness.press(TuiKey::Enter);
+    let _ = harness.press(TuiKey::Down);
+    assert!(harness.focused_line().unwrap().contains("name:"));
+}
+
+#[test]
+fn the_enums_are_cycled_through() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("fuel:"));
+
+    let response = harness.press(TuiKey::Right);
+    assert!(response.changed);
+    assert_eq!(harness.value().fuel, Fuel::Diesel);
+    let _ = harness.press_all([TuiKey::Left, TuiKey::Left]);
+    assert_eq!(harness.value().fuel, Fuel::Electric);
+
+    assert!(harness.focus("engine:"));
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().engine, Engine::Combustion { cylinders: 0 });
+
+    assert!(harness.focus("cylinders:"));
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().engine, Engine::Combustion { cylinders: 1 });
+    let _ = harness.press(TuiKey::Left);
+    assert_eq!(harness.value().engine, Engine::Combustion { cylinders: 1 });
+}
+
+#[test]
+fn the_tree_nodes_are_collapsed() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("wheels:"));
+    assert!(harness.lines().iter().any(|line| line.contains("0: 1")));
+
+    let _ = harness.press(TuiKey::Left);
+    assert!(harness.focused_line().unwrap().contains("▸ wheels"));
+    assert!(!harness.lines().iter().any(|line| line.contains("0: 1")));
+
+    let _ = harness.press(TuiKey::Down);
+    assert!(harness.focused_line().unwrap().contains("ready:"));
+
+    let _ = harness.press_all([TuiKey::Up, TuiKey::Enter]);
+    assert!(harness.lines().iter().any(|line| line.contains("0: 1")));
+}
+
+#[test]
+fn the_collections_are_changed() {
+    let mut harness = TuiHarness::new(car(), 50, 20);
+    assert!(harness.focus("0: 1"));
+    let _ = harness.press(TuiKey::Right);
+    assert_eq!(harness.value().wheels, [2, 2]);
+
+    assert!(harness.focus("Remove"));
+    let response = harness.press(TuiKey::Enter);
+    assert!(response.changed);
+    assert_eq!(harness.value().wheels, [2]);
+
+    assert!(harness.focus("ready:"));
+    let _ = harness.press(TuiKey::Char(' '));
+    assert!(harness.value().ready);
+}
+
+#[test]
+fn the_focused_row_is_scrolled_into_view() {
+    let mut harness = TuiHarness::new(car(), 40, 6);
+    let _ = harness.press(TuiKey::End);
+    assert!(harness.focused_line().unwrap().contains("ready:"));
+    assert!(!harness.lines().iter().any(|line| line.contains("name:")));
+
+    let _ = harness.press(TuiKey::PageUp);
+    let _ = harness.press(TuiKey::Home);
+    assert!(harness.lines().iter().any(|line| line.contains("name:")));
+}
+
+#[test]
+fn the_immutable_presentation_is_only_navigated() {
+    let value = car();
+    let mut state = tui::TuiState::default();
+    let mut terminal = ratatui::Terminal::new(ratatui::backend::TestBackend::new(50, 20)).unwrap();
+
+    [TuiKey::Down, TuiKey::Down, TuiKey::Right, TuiKey::Enter]
+        .into_iter()
+        .for_each(|key| state.push_key(key));
+    while state.has_pending_keys() {
+        let _ = terminal
+            .draw(|frame| TuiPresentable::render_window(&value, frame, frame.area(), &mut state))
+            .unwrap();
+    }
+
+    assert_eq!(state.focus(), 2);
+    assert!(!state.is_editing());
+    assert_eq!(value.speed, 0.0);
+}
@@ -0,0 +1,196 @@
+use imgui_presentable::validation::{Validate, ValidationError};
+use imgui_presentable::*;
+
+fn is_port(port: &u16) -> Result<(), String> {
+    if *port == 0 {
+        Err("Must not be zero.".to_owned())
+    } else {
+        Ok(())
+    }
+}
+
+fn has_primary(cluster: &Cluster) -> Result<(), String> {
+    if cluster.servers.iter().any(|s| s.host == cluster.primary) {
+        Ok(())
+    } else {
+        Err(format!(
+            "The primary \"{}\" isn't a server.",
+            cluster.primary
+        ))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+struct Server {
+    #[imgui_presentation(non_empty, regex = "^[a-z.]*$")]
+    host: String,
+    #[imgui_presentation(validate = "is_port")]
+    port: u16,
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(validate = "has_primary")]
+struct Cluster {
+    #[imgui_presentation(max_len = 8, reject_invalid)]
+    primary: String,
+    #[imgui_presentation(min_len = 1)]
+    servers: Vec<Server>,
+}
+
+fn server(host: &str, port: u16) -> Server {
+    Server {
+        host: host.to_owned(),
+        port,
+    }
+}
+
+fn cluster() -> Cluster {
+    Cluster {
+        primary: "a.local".to_owned(),
+        servers: vec![server("a.local", 80), server("b.local", 8080)],
+    }
+}
+
+fn error(path: &str, message: &str) -> ValidationError {
+    ValidationError {
+        path: path.to_owned(),
+        message: message.to_owned(),
+    }
+}
+
+#[test]
+fn the_errors_have_the_paths_of_the_fields() {
+    assert!(cluster().is_valid());
+
+    let mut cluster = cluster();
+    cluster.primary = "c".to_owned();
+    cluster.servers[1] = server("B", 0);
+    assert_eq!(
+        cluster.validate_all(),
+        [
+            error("", "The primary \"c\" isn't a server."),
+            error("servers[1].host", "Must match `^[a-z.]*$`."),
+            error("servers[1].port", "Must not be zero."),
+        ]
+    );
+
+    cluster.servers.clear();
+    assert_eq!(
+        cluster.validate_all()[1..],
+        [error("servers", "Must have at least 1 element.")]
+    );
+    assert_eq!(
+        cluster.validate_all()[1].to_string(),
+        "servers: Must have at least 1 element."
+    );
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::EguiHarness;
+
+    use super::{cluster, server};
+
+    #[test]
+    fn the_errors_are_shown_next_to_the_fields() {
+        let mut harness = EguiHarness::new(server("a.local", 80));
+        assert!(harness.tree().find("Must not be empty.").is_none());
+
+        let _ = harness.drag("port", 0.0);
+        assert_eq!(harness.value().port, 0);
+        assert!(harness.tree().find("Must not be zero.").is_some());
+
+        let _ = harness.drag("port", 1.0);
+        assert!(harness.tree().find("Must not be zero.").is_none());
+    }
+
+    #[test]
+    fn the_invalid_edits_are_rejected() {
+        let mut harness = EguiHarness::new(cluster());
+
+        assert!(!harness.type_text("primary", "much.too.long").changed);
+        assert_eq!(harness.value().primary, "a.local");
+        assert!(harness
+            .tree()
+            .find("Must have at most 8 characters. The edit was rejected.")
+            .is_some());
+
+        // The struct's own check doesn't reject the edits of the field.
+        assert!(harness.type_text("primary", "b.local").changed);
+        assert_eq!(harness.value().primary, "b.local");
+        assert!(harness
+            .tree()
+            .find("Must have at most 8 characters. The edit was rejected.")
+            .is_none());
+
+        let _ = harness.type_text("primary", "c");
+        assert!(harness
+            .tree()
+            .find("The primary \"c\" isn't a server.")
+            .is_some());
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::ImguiHarness;
+
+    use super::{cluster, server};
+
+    #[test]
+    fn the_errors_are_shown_next_to_the_fields() {
+        let mut harness = ImguiHarness::new(server("a.local", 80));
+        assert!(harness.tree().find("Must not be empty.").is_none());
+
+        assert!(harness.drag("port", 0.0).changed);
+        assert_eq!(harness.value().port, 0);
+        assert!(harness.tree().find("Must not be zero.").is_some());
+
+        assert!(harness.drag("port", 1.0).changed);
+        assert!(harness.tree().find("Must not be zero.").is_none());
+    }
+
+    #[test]
+    fn the_invalid_edits_are_rejected() {
+        let mut harness = ImguiHarness::new(cluster());
+
+        assert!(!harness.type_text("primary", "much.too.long").changed);
+        assert_eq!(harness.value().primary, "a.local");
+        assert!(harness
+            .tree()
+            .find("Must have at most 8 characters. The edit was rejected.")
+            .is_some());
+
+        // The struct's own check doesn't reject the edits of the field.
+        assert!(harness.type_text("primary", "b.local").changed);
+        assert_eq!(harness.value().primary, "b.local");
+        assert!(harness
+            .tree()
+            .find("Must have at most 8 characters. The edit was rejected.")
+            .is_none());
+
+        let _ = harness.type_text("primary", "c");
+        assert!(harness
+            .tree()
+            .find("The primary \"c\" isn't a server.")
+            .is_some());
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_show_the_errors() {
+    use imgui_presentable::testing::TuiHarness;
+
+    let mut harness = TuiHarness::new(server("", 80), 60, 10);
+    assert!(harness
+        .lines()
+        .iter()
+        .any(|l| l.contains("Must not be empty.")));
+    harness.value_mut().host = "a".to_owned();
+    let _ = harness.run();
+    assert!(!harness
+        .lines()
+        .iter()
+        .any(|l| l.contains("Must not be empty.")));
+}
@@ -0,0 +1,162 @@
+use std::f32::consts::{FRAC_PI_2, PI};
+
+use imgui_presentable::*;
+
+#[derive(Debug, Default, Clone, PartialEq, ImguiPresentation)]
+struct Light {
+    #[imgui_presentation(widget = "slider", range = 0.0..1.0)]
+    intensity: f32,
+    #[imgui_presentation(widget = "log_slider", range = 1.0..10000.0)]
+    radius: f64,
+    #[imgui_presentation(widget = "input", range = 0..8)]
+    samples: u32,
+    #[imgui_presentation(widget = "angle")]
+    heading: f32,
+    #[imgui_presentation(widget = "percent")]
+    falloff: f64,
+    #[imgui_presentation(widget = "color")]
+    tint: [f32; 3],
+    #[imgui_presentation(widget = "color")]
+    glow: [f32; 4],
+    #[imgui_presentation(widget = "progress", range = 0..200, suffix = " ms")]
+    warmup: u16,
+}
+
+fn light() -> Light {
+    Light {
+        radius: 10.0,
+        heading: FRAC_PI_2,
+        falloff: 0.25,
+        warmup: 50,
+        ..Default::default()
+    }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::{EguiHarness, WidgetKind, WidgetValue};
+
+    use super::{light, PI};
+
+    #[test]
+    fn the_scalars_are_edited_with_the_chosen_widgets() {
+        let mut harness = EguiHarness::new(light());
+        let sliders = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::Slider)
+            .count();
+        assert_eq!(sliders, 2);
+
+        assert!(harness.drag("intensity", 0.5).changed);
+        assert_eq!(harness.value().intensity, 0.5);
+
+        // The input is limited to the range.
+        assert!(harness.drag("samples", 12.0).changed);
+        assert_eq!(harness.value().samples, 8);
+    }
+
+    #[test]
+    fn the_angles_and_the_percents_are_converted() {
+        let mut harness = EguiHarness::new(light());
+        let shown: Vec<_> = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::DragValue)
+            .filter_map(|w| w.value.clone())
+            .collect();
+        assert!(shown.contains(&WidgetValue::Number(90.0)));
+        assert!(shown.contains(&WidgetValue::Number(25.0)));
+
+        assert!(harness.drag("heading", 180.0).changed);
+        assert!((harness.value().heading - PI).abs() < 1e-6);
+
+        assert!(harness.drag("falloff", 50.0).changed);
+        assert_eq!(harness.value().falloff, 0.5);
+    }
+
+    #[test]
+    fn the_colors_are_picked() {
+        let mut harness = EguiHarness::new(light());
+
+        // The picker shows the channels in 0-255.
+        assert!(harness.drag("tint", 255.0).changed);
+        assert_eq!(harness.value().tint, [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn the_progress_is_read_only() {
+        let mut harness = EguiHarness::new(light());
+        assert!(harness.tree().find("50 ms").is_some());
+
+        assert!(!harness.run().changed);
+        assert_eq!(harness.value().warmup, 50);
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+mod imgui_harness {
+    use imgui_presentable::testing::{ImguiHarness, WidgetKind, WidgetValue};
+
+    use super::{light, PI};
+
+    fn shows(harness: &ImguiHarness<super::Light>, text: &str) -> bool {
+        let text = WidgetValue::Text(text.to_owned());
+        harness
+            .tree()
+            .iter()
+            .any(|w| w.value.as_ref() == Some(&text))
+    }
+
+    #[test]
+    fn the_scalars_are_edited_with_the_chosen_widgets() {
+        let mut harness = ImguiHarness::new(light());
+        let sliders = harness
+            .tree()
+            .iter()
+            .filter(|w| w.kind == WidgetKind::Slider)
+            .count();
+        assert_eq!(sliders, 2);
+
+        assert!(harness.drag("intensity", 0.5).changed);
+        assert_eq!(harness.value().intensity, 0.5);
+        assert!(harness.drag("radius", 100.0).changed);
+        assert_eq!(harness.value().radius, 100.0);
+
+        // The input is limited to the range.
+        assert!(harness.type_text("samples", "12").changed);
+        assert_eq!(harness.value().samples, 8);
+    }
+
+    #[test]
+    fn the_angles_and_the_percents_are_converted() {
+        let mut harness = ImguiHarness::new(light());
+        assert!(shows(&harness, "90.0°") && shows(&harness, "25.0%"));
+
+        assert!(harness.drag("heading", 180.0).changed);
+        assert!((harness.value().heading - PI).abs() < 1e-6);
+
+        assert!(harness.drag("falloff", 50.0).changed);
+        assert_eq!(harness.value().falloff, 0.5);
+    }
+
+    #[test]
+    fn the_progress_is_shown_with_the_suffix() {
+        let harness = ImguiHarness::new(light());
+        assert!(shows(&harness, "50 ms"));
+    }
+}
+
+#[cfg(feature = "ratatui_backend")]
+#[test]
+fn the_terminals_show_the_units() {
+    use imgui_presentable::testing::TuiHarness;
+
+    let harness = TuiHarness::new(light(), 60, 30);
+    let lines = harness.lines();
+    assert!(lines.iter().any(|l| l.contains("heading: 90°")));
+    assert!(lines.iter().any(|l| l.contains("falloff: 25%")));
+    assert!(lines
+        .iter()
+        .any(|l| l.contains("█████░") && l.contains("50 ms")));
+}
@@ -0,0 +1,100 @@
+use imgui_presentable::*;
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(rename = "Settings")]
+#[imgui_presentation(window(
+    resizable = false,
+    size = [300, 200],
+    position = [-10, 20],
+    anchor = "top_right"
+))]
+#[imgui_presentation(main_menu_item("Reset": "reset"))]
+struct Settings {
+    /// The loudness of the sounds.
+    volume: f32,
+}
+
+impl Settings {
+    fn reset(&mut self) {
+        self.volume = 0.5;
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(window(size = [200, 100], position = [40, 50]))]
+struct Plain {
+    count: u8,
+}
+
+// The documentation of the fields in the grids is only shown on hover.
+#[derive(Debug, Clone, PartialEq, ImguiPresentation)]
+#[imgui_presentation(layout = "grid")]
+struct Sound {
+    /// The loudness of the sounds.
+    volume: f32,
+}
+
+fn settings() -> Settings {
+    Settings { volume: 1.0 }
+}
+
+#[cfg(feature = "egui_backend")]
+mod egui_harness {
+    use imgui_presentable::testing::EguiHarness;
+
+    use super::{settings, Plain, Sound};
+
+    #[test]
+    fn the_windows_are_titled_placed_and_have_the_menus() {
+        let mut harness = EguiHarness::new_window(settings());
+        let rect = harness
+            .context()
+            .memory(|memory| memory.area_rect(egui::Id::new("Settings")))
+            .expect("The window is titled with the rename attribute.");
+        let screen = harness.context().screen_rect();
+        assert_eq!(rect.right(), screen.right() - 10.0);
+        assert_eq!(rect.top(), screen.top() + 20.0);
+
+        assert!(harness.click("Reset").clicked);
+        assert_eq!(harness.value().volume, 0.5);
+    }
+
+    #[test]
+    fn the_windows_are_placed_at_the_positions() {
+        let harness = EguiHarness::new_window(Plain { count: 1 });
+        let rect = harness
+            .context()
+            .memory(|memory| memory.area_rect(egui::Id::new(std::any::type_name::<Plain>())))
+            .expect("The window is titled with the name of the type.");
+        assert_eq!(rect.min, egui::pos2(40.0, 50.0));
+    }
+
+    #[test]
+    fn the_fields_show_the_tooltips() {
+        let harness = EguiHarness::new(Sound { volume: 1.0 });
+        assert!(harness
+            .tree()
+            .iter()
+            .any(|w| w.label.as_deref() == Some("The loudness of the sounds.")));
+    }
+}
+
+#[cfg(feature = "imgui_backend")]
+#[test]
+fn imgui_runs_the_windows() {
+    use imgui_presentable::testing::ImguiHarness;
+
+    let mut harness = ImguiHarness::new(settings());
+    harness.run_readonly();
+    assert!(!harness.run().changed);
+    assert_eq!(
+        harness.tree().children[0].label.as_deref(),
+        Some("Settings")
+    );
+    assert!(harness.click("Reset").clicked);
+    assert_eq!(harness.value().volume, 0.5);
+    drop(harness);
+
+    let mut harness = ImguiHarness::new(Plain { count: 1 });
+    assert!(!harness.run().changed);
+}